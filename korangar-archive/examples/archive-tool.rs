@@ -0,0 +1,214 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use korangar_archive::folder::FolderArchive;
+use korangar_archive::thor::apply_thor_patch;
+use korangar_archive::{Archive, ArchiveError, Compression, Writable, create_writable, open_archive};
+use korangar_debug::logging::Colorize;
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Arguments {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List all files in an archive.
+    List {
+        /// Path to the archive.
+        archive: PathBuf,
+    },
+    /// Extract files from an archive into a folder.
+    Extract {
+        /// Path to the archive.
+        archive: PathBuf,
+        /// Folder to extract the files to.
+        output: PathBuf,
+        /// Only extract files with the given extension. Can be passed multiple
+        /// times.
+        #[arg(short, long)]
+        extension: Vec<String>,
+    },
+    /// List all files in an archive that have one of the given extensions.
+    Search {
+        /// Path to the archive.
+        archive: PathBuf,
+        /// Extensions to search for, e.g. `.spr`.
+        #[arg(required = true)]
+        extensions: Vec<String>,
+    },
+    /// Compare the files of two archives.
+    Diff {
+        /// Path to the first archive.
+        left: PathBuf,
+        /// Path to the second archive.
+        right: PathBuf,
+    },
+    /// Copy all files of an archive into a new folder, GRF or 7z archive.
+    Repack {
+        /// Path to the archive to read from.
+        input: PathBuf,
+        /// Path of the new archive. The type is determined by the extension.
+        /// Paths ending in `/` are treated as folders.
+        output: PathBuf,
+        /// Compression to use for the files in the new archive.
+        #[arg(short, long, value_enum, default_value_t = CompressionArgument::Default)]
+        compression: CompressionArgument,
+    },
+    /// Apply a Thor patch to a GRF. The GRF is created if it doesn't exist.
+    Patch {
+        /// Path to the Thor patch.
+        patch: PathBuf,
+        /// Path to the GRF.
+        archive: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum CompressionArgument {
+    Off,
+    Default,
+}
+
+impl From<CompressionArgument> for Compression {
+    fn from(compression: CompressionArgument) -> Self {
+        match compression {
+            CompressionArgument::Off => Compression::Off,
+            CompressionArgument::Default => Compression::Default,
+        }
+    }
+}
+
+fn open(path: &Path) -> Result<Box<dyn Archive>, ExitCode> {
+    open_archive(path).map_err(|error| report_error(&format!("Failed to open {}", path.display()), error))
+}
+
+fn report_error(message: &str, error: ArchiveError) -> ExitCode {
+    eprintln!("[{}] {}: {:?}", "Error".red(), message, error);
+    ExitCode::FAILURE
+}
+
+/// Sorted list of all files with one of the given extensions. An empty list of
+/// extensions matches all files.
+fn collect_files(archive: &dyn Archive, extensions: &[String]) -> Vec<String> {
+    let mut files = Vec::new();
+
+    match extensions.is_empty() {
+        // Every file name ends with an empty string, so this collects all files.
+        true => archive.get_files_with_extension(&mut files, &[""]),
+        false => {
+            let extensions: Vec<String> = extensions.iter().map(|extension| extension.to_lowercase()).collect();
+            let extensions: Vec<&str> = extensions.iter().map(String::as_str).collect();
+            archive.get_files_with_extension(&mut files, &extensions);
+        }
+    }
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+fn copy_files(archive: &dyn Archive, files: &[String], target: &mut dyn Writable, compression: Compression) -> Result<usize, ExitCode> {
+    let mut copied_count = 0;
+
+    for file_name in files {
+        match archive.get_file_by_path(file_name) {
+            Some(data) => {
+                target
+                    .add_file(file_name, data, compression)
+                    .map_err(|error| report_error(&format!("Failed to write {file_name}"), error.into()))?;
+                copied_count += 1;
+            }
+            None => eprintln!("[{}] Failed to read {}", "Warning".yellow(), file_name.magenta()),
+        }
+    }
+
+    Ok(copied_count)
+}
+
+fn run(command: Command) -> Result<(), ExitCode> {
+    match command {
+        Command::List { archive } => {
+            let archive = open(&archive)?;
+            collect_files(archive.as_ref(), &[])
+                .iter()
+                .for_each(|file_name| println!("{file_name}"));
+        }
+        Command::Search { archive, extensions } => {
+            let archive = open(&archive)?;
+            collect_files(archive.as_ref(), &extensions)
+                .iter()
+                .for_each(|file_name| println!("{file_name}"));
+        }
+        Command::Extract {
+            archive,
+            output,
+            extension,
+        } => {
+            let archive = open(&archive)?;
+            let files = collect_files(archive.as_ref(), &extension);
+
+            let mut folder = FolderArchive::from_path(&output).map_err(|error| report_error("Failed to open output folder", error))?;
+            let extracted_count = copy_files(archive.as_ref(), &files, &mut folder, Compression::Off)?;
+
+            println!("[{}] Extracted {} files", "Success".green(), extracted_count);
+        }
+        Command::Diff { left, right } => {
+            let left = open(&left)?;
+            let right = open(&right)?;
+
+            let left_files: BTreeSet<String> = collect_files(left.as_ref(), &[]).into_iter().collect();
+            let right_files: BTreeSet<String> = collect_files(right.as_ref(), &[]).into_iter().collect();
+
+            left_files
+                .difference(&right_files)
+                .for_each(|file_name| println!("{} {}", "-".red(), file_name));
+
+            right_files
+                .difference(&left_files)
+                .for_each(|file_name| println!("{} {}", "+".green(), file_name));
+
+            left_files
+                .intersection(&right_files)
+                .filter(|file_name| left.get_file_by_path(file_name) != right.get_file_by_path(file_name))
+                .for_each(|file_name| println!("{} {}", "~".yellow(), file_name));
+        }
+        Command::Repack {
+            input,
+            output,
+            compression,
+        } => {
+            let archive = open(&input)?;
+            let files = collect_files(archive.as_ref(), &[]);
+
+            let mut writable = create_writable(&output).map_err(|error| report_error("Failed to create output archive", error))?;
+            let copied_count = copy_files(archive.as_ref(), &files, writable.as_mut(), compression.into())?;
+
+            writable
+                .finish()
+                .map_err(|error| report_error("Failed to write output archive", error.into()))?;
+
+            println!("[{}] Repacked {} files", "Success".green(), copied_count);
+        }
+        Command::Patch { patch, archive } => {
+            apply_thor_patch(&patch, &archive).map_err(|error| report_error("Failed to apply patch", error))?;
+
+            println!("[{}] Applied patch to {}", "Success".green(), archive.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+
+    match run(arguments.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(exit_code) => exit_code,
+    }
+}
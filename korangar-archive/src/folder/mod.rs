@@ -2,16 +2,14 @@
 use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
-use std::io::{Error, Read};
-use std::path::{Path, PathBuf};
+use std::io::{Error, ErrorKind, Read};
+use std::path::{Component, Path, PathBuf};
 
 use blake3::Hasher;
 use flate2::bufread::{GzDecoder, GzEncoder};
-#[cfg(feature = "debug")]
-use korangar_debug::logging::print_debug;
 use walkdir::WalkDir;
 
-use super::{Archive, Compression, Writable, os_specific_path};
+use super::{Archive, ArchiveError, Compression, Writable, os_specific_path};
 
 pub struct FolderArchive {
     folder_path: PathBuf,
@@ -20,7 +18,7 @@ pub struct FolderArchive {
     /// lookup from a unified format to the actual file name in the file system.
     ///
     /// Example:
-    /// ```
+    /// ```text
     /// "texture\\data\\angel.str" -> texture/data/Angel.str
     /// ```
     file_mapping: HashMap<String, PathBuf>,
@@ -52,25 +50,43 @@ impl FolderArchive {
             .collect()
     }
 
-    fn compress_gz(mut full_path: PathBuf, encoder: &mut GzEncoder<&[u8]>) -> (PathBuf, Vec<u8>) {
+    fn compress_gz(mut full_path: PathBuf, encoder: &mut GzEncoder<&[u8]>) -> Result<(PathBuf, Vec<u8>), Error> {
         let mut compressed = Vec::default();
-        encoder.read_to_end(&mut compressed).unwrap();
+        encoder.read_to_end(&mut compressed)?;
 
         let extension = full_path.extension().unwrap_or_default().to_string_lossy().into_owned();
 
         let compressed_extension = format!("{}.gz", extension);
         full_path.set_extension(compressed_extension);
 
-        (full_path, compressed)
+        Ok((full_path, compressed))
+    }
+
+    /// Converts the asset path to a path relative to the folder. Asset paths
+    /// come from other archives, so paths that are absolute or that leave the
+    /// folder are rejected.
+    fn relative_path(asset_path: &str) -> Result<PathBuf, Error> {
+        let relative_path = os_specific_path(asset_path);
+        let is_contained = relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+        match is_contained && relative_path.file_name().is_some() {
+            true => Ok(relative_path),
+            false => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("asset path {asset_path} is outside of the folder"),
+            )),
+        }
     }
 }
 
 impl Archive for FolderArchive {
-    fn from_path(path: &Path) -> Self {
+    fn from_path(path: &Path) -> Result<Self, ArchiveError> {
         let folder_path = PathBuf::from(path);
         let file_mapping = Self::load_mapping(&folder_path);
 
-        Self { folder_path, file_mapping }
+        Ok(Self { folder_path, file_mapping })
     }
 
     fn file_exists(&self, asset_path: &str) -> bool {
@@ -103,51 +119,65 @@ impl Archive for FolderArchive {
         files.extend(found_files);
     }
 
-    fn hash(&self, hasher: &mut Hasher) {
+    fn hash(&self, hasher: &mut Hasher) -> Result<(), ArchiveError> {
         let mut files: Vec<PathBuf> = self.file_mapping.values().cloned().collect();
         files.sort();
-        files.iter().for_each(|file_path| match File::open(file_path) {
-            Ok(file) => {
-                if let Err(_err) = hasher.update_reader(&file) {
-                    #[cfg(feature = "debug")]
-                    print_debug!("Can't hash archive file `{:?}`: {:?}", file_path, _err);
-                }
-            }
-            Err(_err) => {
-                #[cfg(feature = "debug")]
-                print_debug!("Can't open archive file `{:?}`: {:?}", file_path, _err);
-            }
-        });
+
+        for file_path in &files {
+            let file = File::open(file_path)?;
+            hasher.update_reader(&file)?;
+        }
+
+        Ok(())
     }
 }
 
 impl Writable for FolderArchive {
-    fn add_file(&mut self, file_path: &str, file_data: Vec<u8>, compression: Compression) {
-        let normalized_asset_path = os_specific_path(file_path);
-        let full_path = self.folder_path.join(normalized_asset_path);
+    fn add_file(&mut self, file_path: &str, file_data: Vec<u8>, compression: Compression) -> Result<(), Error> {
+        let full_path = self.folder_path.join(Self::relative_path(file_path)?);
 
         // Create parent directories if needed
         if let Some(parent) = full_path.parent()
             && !parent.exists()
-            && let Err(err) = fs::create_dir_all(parent)
         {
-            panic!("error creating directories: {}", err);
+            fs::create_dir_all(parent)?;
         }
 
         let (path, data) = match compression {
             Compression::Off => (full_path, file_data),
             Compression::Default => {
                 let mut encoder = GzEncoder::new(file_data.as_slice(), flate2::Compression::new(3));
-                Self::compress_gz(full_path, &mut encoder)
+                Self::compress_gz(full_path, &mut encoder)?
             }
         };
 
-        fs::write(&path, data).unwrap_or_else(|_| panic!("error writing to file {}", path.display()));
+        fs::write(&path, data)?;
 
         self.file_mapping.insert(file_path.to_string(), path);
+
+        Ok(())
     }
 
     fn finish(&mut self) -> Result<(), Error> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FolderArchive;
+
+    #[test]
+    fn relative_path() {
+        assert!(FolderArchive::relative_path("data\\texture\\a.bmp").is_ok());
+        assert!(FolderArchive::relative_path(".\\data\\a.bmp").is_ok());
+    }
+
+    #[test]
+    fn relative_path_outside_of_folder() {
+        assert!(FolderArchive::relative_path("..\\a.bmp").is_err());
+        assert!(FolderArchive::relative_path("data\\..\\..\\a.bmp").is_err());
+        assert!(FolderArchive::relative_path("\\a.bmp").is_err());
+        assert!(FolderArchive::relative_path("").is_err());
+    }
+}
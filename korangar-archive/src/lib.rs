@@ -0,0 +1,137 @@
+//! Reading and writing of the archives that store Ragnarok Online game assets.
+//! All archives implement the [`Archive`] trait, archives that can be written
+//! to implement [`Writable`].
+pub mod folder;
+pub mod native;
+pub mod rgz;
+pub mod seven_zip;
+pub mod thor;
+
+use std::path::{Path, PathBuf};
+
+use self::folder::FolderArchive;
+use self::native::{NativeArchive, NativeArchiveBuilder};
+use self::rgz::RgzArchive;
+use self::seven_zip::{SevenZipArchive, SevenZipArchiveBuilder};
+use self::thor::ThorArchive;
+
+/// Error that can occur when opening an archive.
+#[derive(Debug)]
+pub enum ArchiveError {
+    Io(std::io::Error),
+    /// The file extension doesn't belong to any known archive type.
+    UnknownArchiveType(PathBuf),
+    /// The archive type can only be read from.
+    NotWritable(PathBuf),
+    /// The archive has a version that we can't read.
+    UnsupportedVersion(u32),
+    /// The file table of the archive can't be read.
+    InvalidFileTable,
+}
+
+impl From<std::io::Error> for ArchiveError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+pub trait Archive: Send + Sync {
+    fn from_path(path: &Path) -> Result<Self, ArchiveError>
+    where
+        Self: Sized;
+
+    /// Tests if a file exists.
+    fn file_exists(&self, asset_path: &str) -> bool;
+
+    /// Retrieve an asset from the Archive.
+    fn get_file_by_path(&self, asset_path: &str) -> Option<Vec<u8>>;
+
+    /// Get a list of all files with a given extension.
+    fn get_files_with_extension(&self, files: &mut Vec<String>, extensions: &[&str]);
+
+    /// Hashes the archive with the given hasher.
+    fn hash(&self, hasher: &mut blake3::Hasher) -> Result<(), ArchiveError>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArchiveType {
+    Folder,
+    Native,
+    SevenZip,
+    Rgz,
+    Thor,
+}
+
+impl ArchiveType {
+    /// Determines the archive type from the file extension. Directories and
+    /// paths ending in `/` are treated as folders.
+    pub fn from_path(path: &Path) -> Result<Self, ArchiveError> {
+        if path.is_dir() || path.display().to_string().ends_with('/') {
+            return Ok(Self::Folder);
+        }
+
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+
+        match extension.as_deref() {
+            // GPF files are GRF files with a different extension.
+            Some("grf" | "gpf") => Ok(Self::Native),
+            Some("7z") => Ok(Self::SevenZip),
+            Some("rgz") => Ok(Self::Rgz),
+            Some("thor") => Ok(Self::Thor),
+            _ => Err(ArchiveError::UnknownArchiveType(path.to_path_buf())),
+        }
+    }
+}
+
+/// Opens the archive at the given path. The type of the archive is determined
+/// by [`ArchiveType::from_path`].
+pub fn open_archive(path: &Path) -> Result<Box<dyn Archive>, ArchiveError> {
+    let archive: Box<dyn Archive> = match ArchiveType::from_path(path)? {
+        ArchiveType::Folder => Box::new(FolderArchive::from_path(path)?),
+        ArchiveType::Native => Box::new(NativeArchive::from_path(path)?),
+        ArchiveType::SevenZip => Box::new(SevenZipArchive::from_path(path)?),
+        ArchiveType::Rgz => Box::new(RgzArchive::from_path(path)?),
+        ArchiveType::Thor => Box::new(ThorArchive::from_path(path)?),
+    };
+
+    Ok(archive)
+}
+
+/// Creates a new archive at the given path that files can be written to. The
+/// archive is only complete once [`Writable::finish`] is called.
+pub fn create_writable(path: &Path) -> Result<Box<dyn Writable>, ArchiveError> {
+    let writable: Box<dyn Writable> = match ArchiveType::from_path(path)? {
+        ArchiveType::Folder => Box::new(FolderArchive::from_path(path)?),
+        ArchiveType::Native => Box::new(NativeArchiveBuilder::from_path(path)?),
+        ArchiveType::SevenZip => Box::new(SevenZipArchiveBuilder::from_path(path)),
+        ArchiveType::Rgz | ArchiveType::Thor => return Err(ArchiveError::NotWritable(path.to_path_buf())),
+    };
+
+    Ok(writable)
+}
+
+/// Type of compression to apply.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Compression {
+    /// No compression.
+    Off,
+    /// Default compression level.
+    Default,
+}
+
+/// A common trait to all writable archives.
+pub trait Writable {
+    fn add_file(&mut self, path: &str, asset: Vec<u8>, compression: Compression) -> Result<(), std::io::Error>;
+    fn finish(&mut self) -> Result<(), std::io::Error>;
+}
+
+/// Converts a RO internal path to the OS specific path.
+pub fn os_specific_path(path: &str) -> PathBuf {
+    match cfg!(target_os = "windows") {
+        true => PathBuf::from(path),
+        false => PathBuf::from(path.replace('\\', "/")),
+    }
+}
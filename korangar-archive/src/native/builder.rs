@@ -0,0 +1,218 @@
+//! Implements a writable instance of a GRF File.
+//!
+//! Files are streamed into a temporary file next to the target path, which
+//! only replaces the target once [`finish`](Writable::finish) succeeds. This
+//! way a failure while writing never destroys an existing archive.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::bufread::ZlibEncoder;
+use ragnarok_bytes::{ByteWriter, FixedByteSize, ToBytes};
+use ragnarok_formats::archive::{AssetTable, FileTableRow, Header, LargeFileTableRow};
+
+use super::{FileEntry, GRF_FLAG_FILE, RawFile};
+use crate::{ArchiveError, Compression, Writable};
+
+pub struct NativeArchiveBuilder {
+    os_file_path: PathBuf,
+    temporary_file_path: PathBuf,
+    version: u32,
+    file_writer: Option<BufWriter<File>>,
+    offset: u64,
+    file_table: HashMap<String, FileEntry>,
+}
+
+impl NativeArchiveBuilder {
+    /// Version that is used for new archives. Archives that grow larger than 4
+    /// GiB are written as [`Header::LARGE_OFFSET_VERSION`] instead.
+    pub const DEFAULT_VERSION: u32 = 0x200;
+
+    pub fn from_path(path: &Path) -> Result<Self, std::io::Error> {
+        let mut temporary_file_name = OsString::from(path.as_os_str());
+        temporary_file_name.push(".tmp");
+        let temporary_file_path = PathBuf::from(temporary_file_name);
+
+        let mut file_writer = BufWriter::new(File::create(&temporary_file_path)?);
+
+        // The header is written once all files are added and we know where the file
+        // table starts.
+        let dummy_header_bytes = vec![0; Header::size_in_bytes()];
+        file_writer.write_all(&dummy_header_bytes)?;
+
+        Ok(Self {
+            os_file_path: PathBuf::from(path),
+            temporary_file_path,
+            version: Self::DEFAULT_VERSION,
+            file_writer: Some(file_writer),
+            offset: 0,
+            file_table: HashMap::new(),
+        })
+    }
+
+    /// Same as [`from_path`](Self::from_path), but the archive is written
+    /// with the given version. Only versions 0x200 and 0x300 can be written.
+    pub fn with_version(path: &Path, version: u32) -> Result<Self, ArchiveError> {
+        if version != Self::DEFAULT_VERSION && version != Header::LARGE_OFFSET_VERSION {
+            return Err(ArchiveError::UnsupportedVersion(version));
+        }
+
+        let mut builder = Self::from_path(path)?;
+        builder.version = version;
+
+        Ok(builder)
+    }
+
+    /// Adds a file that is already compressed (and possibly encrypted) without
+    /// decoding it.
+    pub(crate) fn add_raw_file(&mut self, path: &str, raw_file: RawFile) -> Result<(), std::io::Error> {
+        let file_writer = self
+            .file_writer
+            .as_mut()
+            .ok_or_else(|| std::io::Error::other("archive is already finished"))?;
+
+        file_writer.write_all(&raw_file.data)?;
+
+        let file_entry = FileEntry {
+            compressed_size: raw_file.compressed_size,
+            compressed_size_aligned: raw_file.compressed_size_aligned,
+            uncompressed_size: raw_file.uncompressed_size,
+            flags: raw_file.flags,
+            offset: self.offset,
+        };
+
+        self.offset += raw_file.data.len() as u64;
+        self.file_table.insert(path.to_string(), file_entry);
+
+        Ok(())
+    }
+
+    fn write_file_table(&self, file_writer: &mut BufWriter<File>, large_offsets: bool) -> Result<(), std::io::Error> {
+        let mut byte_writer = ByteWriter::new();
+
+        for (file_name, entry) in &self.file_table {
+            let result = match large_offsets {
+                true => LargeFileTableRow {
+                    file_name: file_name.clone(),
+                    compressed_size: entry.compressed_size,
+                    compressed_size_aligned: entry.compressed_size_aligned,
+                    uncompressed_size: entry.uncompressed_size,
+                    flags: entry.flags,
+                    offset: entry.offset,
+                }
+                .to_bytes(&mut byte_writer),
+                // All files are in front of the file table, so their offsets fit as well.
+                false => FileTableRow {
+                    file_name: file_name.clone(),
+                    compressed_size: entry.compressed_size,
+                    compressed_size_aligned: entry.compressed_size_aligned,
+                    uncompressed_size: entry.uncompressed_size,
+                    flags: entry.flags,
+                    offset: entry.offset as u32,
+                }
+                .to_bytes(&mut byte_writer),
+            };
+
+            result.map_err(|_| std::io::Error::other("can't serialize file table"))?;
+        }
+
+        let mut encoder = ZlibEncoder::new(byte_writer.as_slice(), flate2::Compression::best());
+        let mut compressed = Vec::default();
+        encoder.read_to_end(&mut compressed)?;
+
+        let asset_table = AssetTable {
+            compressed_size: compressed.len() as u32,
+            uncompressed_size: byte_writer.len() as u32,
+        };
+
+        byte_writer.clear();
+        asset_table.to_bytes(&mut byte_writer).unwrap();
+
+        // Version 0x300 has 4 unused bytes in front of the file table.
+        if large_offsets {
+            file_writer.write_all(&[0; 4])?;
+        }
+
+        file_writer.write_all(byte_writer.as_slice())?;
+        file_writer.write_all(&compressed)?;
+
+        Ok(())
+    }
+}
+
+impl Writable for NativeArchiveBuilder {
+    fn add_file(&mut self, path: &str, asset_data: Vec<u8>, compression: Compression) -> Result<(), std::io::Error> {
+        let uncompressed_size = asset_data.len() as u32;
+
+        let data = match compression {
+            Compression::Off => asset_data,
+            Compression::Default => {
+                let mut encoder = ZlibEncoder::new(asset_data.as_slice(), flate2::Compression::new(3));
+                let mut compressed = Vec::default();
+                encoder.read_to_end(&mut compressed)?;
+                compressed
+            }
+        };
+
+        let compressed_size = u32::try_from(data.len()).map_err(|_| std::io::Error::other("file is too large"))?;
+
+        self.add_raw_file(path, RawFile {
+            data,
+            compressed_size,
+            compressed_size_aligned: compressed_size,
+            uncompressed_size,
+            flags: GRF_FLAG_FILE,
+        })
+    }
+
+    fn finish(&mut self) -> Result<(), std::io::Error> {
+        let mut file_writer = self
+            .file_writer
+            .take()
+            .ok_or_else(|| std::io::Error::other("archive is already finished"))?;
+
+        let file_table_offset = self.offset;
+        let large_offsets = self.version == Header::LARGE_OFFSET_VERSION || file_table_offset > u64::from(u32::MAX);
+
+        self.write_file_table(&mut file_writer, large_offsets)?;
+
+        let raw_file_count = u32::try_from(self.file_table.len() + Header::FILE_OFFSET)
+            .map_err(|_| std::io::Error::other("archive contains too many files"))?;
+
+        // Version 0x300 stores the upper half of the file table offset in place of
+        // the reserved files.
+        let header = match large_offsets {
+            true => Header::new(
+                file_table_offset as u32,
+                (file_table_offset >> 32) as u32,
+                raw_file_count,
+                Header::LARGE_OFFSET_VERSION,
+            ),
+            false => Header::new(file_table_offset as u32, 0, raw_file_count, Self::DEFAULT_VERSION),
+        };
+
+        let mut byte_writer = ByteWriter::new();
+        header.to_bytes(&mut byte_writer).unwrap();
+
+        file_writer.seek(SeekFrom::Start(0))?;
+        file_writer.write_all(byte_writer.as_slice())?;
+
+        let file = file_writer.into_inner().map_err(|error| error.into_error())?;
+        file.sync_all()?;
+        drop(file);
+
+        std::fs::rename(&self.temporary_file_path, &self.os_file_path)
+    }
+}
+
+impl Drop for NativeArchiveBuilder {
+    fn drop(&mut self) {
+        // If the archive wasn't finished successfully, the temporary file is
+        // incomplete. The file has to be closed before it can be removed.
+        drop(self.file_writer.take());
+        let _ = std::fs::remove_file(&self.temporary_file_path);
+    }
+}
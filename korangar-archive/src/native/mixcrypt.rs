@@ -1,6 +1,6 @@
 //! Implements the mixcrypt scheme use by the original client.
 
-use ragnarok_formats::archive::FileTableRow;
+use super::FileEntry;
 
 /// File uses a mixed crypto (Simple DES + Shuffle):
 /// - Encrypts the first 0x14 blocks
@@ -15,15 +15,44 @@ pub const GRF_FLAG_HEADER_DES_CRYPT: u8 = 1 << 2;
 /// First 0x14 blocks are always encrypted.
 const HEADER_BLOCKS_SIZE: usize = 0x14;
 
+/// Files with these extensions are only header encrypted in GRFs of version
+/// 0x1XX. All other files use the mixed encryption.
+const HEADER_ONLY_EXTENSIONS: [&str; 4] = [".gnd", ".gat", ".act", ".str"];
+
 const BLOCK_SIZE: usize = 8;
 
 /// Decrypts a file using the appropriate decryption method.
-pub fn decrypt_file(file_information: &FileTableRow, data: &mut [u8]) {
+pub fn decrypt_file(file_information: &FileEntry, data: &mut [u8]) {
     if let Some((is_limited_crypt, cycle)) = determine_encryption_scheme(file_information.flags, file_information.compressed_size) {
         decrypt_data(data, is_limited_crypt, cycle);
     }
 }
 
+/// GRFs of version 0x1XX don't store the encryption in the flags, it is
+/// derived from the file extension instead.
+pub fn legacy_encryption_flags(file_name: &str) -> u8 {
+    let is_header_only = file_name
+        .rfind('.')
+        .is_some_and(|index| HEADER_ONLY_EXTENSIONS.contains(&file_name[index..].to_lowercase().as_str()));
+
+    match is_header_only {
+        true => GRF_FLAG_HEADER_DES_CRYPT,
+        false => GRF_FLAG_FULL_MIX_CRYPT,
+    }
+}
+
+/// Decrypts a file name of a GRF of version 0x1XX in place. Every block has
+/// its nibbles swapped before being decrypted with DES.
+pub fn decrypt_file_name(data: &mut [u8]) {
+    for block_data in data.chunks_exact_mut(BLOCK_SIZE) {
+        block_data.iter_mut().for_each(|byte| *byte = byte.rotate_left(4));
+
+        let mut block = u64::from_be_bytes(block_data.try_into().unwrap());
+        block = decode_des_block(block);
+        block_data.copy_from_slice(&block.to_be_bytes());
+    }
+}
+
 /// Determines the encryption scheme used for the file.
 /// Returns `Some((only_header_is_encrypted, cycle_length))` if the file is
 /// encrypted, `None` otherwise.
@@ -0,0 +1,456 @@
+//! A GRF file containing game assets.
+mod builder;
+mod mixcrypt;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::Mutex;
+
+use blake3::Hasher;
+use flate2::bufread::ZlibDecoder;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, Timer};
+use ragnarok_bytes::{ByteReader, FixedByteSize, FromBytes};
+use ragnarok_formats::archive::{AssetTable, FileTableRow, Header, LargeFileTableRow};
+
+pub use self::builder::NativeArchiveBuilder;
+use crate::native::mixcrypt::{decrypt_file, decrypt_file_name, legacy_encryption_flags};
+use crate::{Archive, ArchiveError};
+
+/// The entry is a file and not a directory.
+pub(crate) const GRF_FLAG_FILE: u8 = 1;
+
+/// Smallest possible size of a row in the compressed file table. An empty
+/// file name (1 byte), the sizes (12 bytes), the flags (1 byte) and the offset
+/// (4 bytes).
+const MINIMUM_ROW_SIZE: usize = 18;
+
+/// Location and encryption of a single file inside the archive. This is the
+/// same for all archive versions.
+#[derive(Clone, Debug)]
+struct FileEntry {
+    compressed_size: u32,
+    compressed_size_aligned: u32,
+    uncompressed_size: u32,
+    flags: u8,
+    offset: u64,
+}
+
+impl From<FileTableRow> for FileEntry {
+    fn from(row: FileTableRow) -> Self {
+        Self {
+            compressed_size: row.compressed_size,
+            compressed_size_aligned: row.compressed_size_aligned,
+            uncompressed_size: row.uncompressed_size,
+            flags: row.flags,
+            offset: u64::from(row.offset),
+        }
+    }
+}
+
+impl From<LargeFileTableRow> for FileEntry {
+    fn from(row: LargeFileTableRow) -> Self {
+        Self {
+            compressed_size: row.compressed_size,
+            compressed_size_aligned: row.compressed_size_aligned,
+            uncompressed_size: row.uncompressed_size,
+            flags: row.flags,
+            offset: row.offset,
+        }
+    }
+}
+
+/// A file as it is stored in the archive, still compressed and possibly
+/// encrypted. Used to copy files between archives without decoding them.
+pub(crate) struct RawFile {
+    pub(crate) data: Vec<u8>,
+    pub(crate) compressed_size: u32,
+    pub(crate) compressed_size_aligned: u32,
+    pub(crate) uncompressed_size: u32,
+    pub(crate) flags: u8,
+}
+
+/// Represents a GRF file. GRF Files are an archive to store game assets.
+/// Each GRF contains a [`Header`] with metadata (number of files, size,
+/// etc.) and a table [`AssetTable`] with information about individual assets.
+///
+/// Versions 0x102, 0x103, 0x200 and 0x300 are supported.
+type FileTable = HashMap<String, FileEntry>;
+
+pub struct NativeArchive {
+    version: u32,
+    file_table: FileTable,
+    file_handle: Mutex<File>,
+}
+
+impl NativeArchive {
+    /// Version of the archive as stored in the [`Header`].
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Reads a file without decrypting or decompressing it.
+    pub(crate) fn get_raw_file_by_path(&self, asset_path: &str) -> Result<RawFile, std::io::Error> {
+        let file_information = self
+            .file_table
+            .get(asset_path)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, asset_path))?;
+
+        let mut data = vec![0u8; file_information.compressed_size_aligned as usize];
+        let position = file_information.offset + Header::size_in_bytes() as u64;
+
+        {
+            let mut file_handle = self.file_handle.lock().unwrap();
+            file_handle.seek(SeekFrom::Start(position))?;
+            file_handle.read_exact(&mut data)?;
+        }
+
+        Ok(RawFile {
+            data,
+            compressed_size: file_information.compressed_size,
+            compressed_size_aligned: file_information.compressed_size_aligned,
+            uncompressed_size: file_information.uncompressed_size,
+            flags: file_information.flags,
+        })
+    }
+
+    /// Reads the compressed file table used by versions 0x200 and 0x300.
+    fn read_compressed_file_table<Row>(file: &mut File, file_count: usize, skip_bytes: i64) -> Result<FileTable, ArchiveError>
+    where
+        Row: FromBytes + Into<FileEntry> + FileName,
+    {
+        let _ = file.seek(SeekFrom::Current(skip_bytes))?;
+
+        let mut file_table_buffer = vec![0; AssetTable::size_in_bytes()];
+        file.read_exact(&mut file_table_buffer)?;
+        let file_table =
+            AssetTable::from_bytes(&mut ByteReader::without_metadata(&file_table_buffer)).map_err(|_| ArchiveError::InvalidFileTable)?;
+
+        let mut compressed_file_table_buffer = vec![0u8; file_table.compressed_size as usize];
+        file.read_exact(&mut compressed_file_table_buffer)?;
+
+        let mut decoder = ZlibDecoder::new(compressed_file_table_buffer.as_slice());
+        let mut decompressed = Vec::with_capacity(file_table.uncompressed_size as usize);
+        decoder.read_to_end(&mut decompressed).map_err(|_| ArchiveError::InvalidFileTable)?;
+
+        let mut file_table_byte_reader = ByteReader::without_metadata(&decompressed);
+        // The file count is taken from the header, so we make sure a corrupt value
+        // can't make us allocate more than the table could possibly hold.
+        let mut assets = HashMap::with_capacity(file_count.min(decompressed.len() / MINIMUM_ROW_SIZE));
+
+        for _index in 0..file_count {
+            let row = Row::from_bytes(&mut file_table_byte_reader).map_err(|_| ArchiveError::InvalidFileTable)?;
+            let file_name = row.file_name().to_lowercase();
+
+            assets.insert(file_name, row.into());
+        }
+
+        Ok(assets)
+    }
+
+    /// Reads the file table used by versions 0x102 and 0x103. The table is not
+    /// compressed, but the file names are encrypted and the sizes and offsets
+    /// are obfuscated.
+    fn read_legacy_file_table(file: &mut File, file_count: usize) -> Result<FileTable, ArchiveError> {
+        let mut table = Vec::new();
+        file.read_to_end(&mut table)?;
+
+        Self::parse_legacy_file_table(&mut table, file_count)
+    }
+
+    fn parse_legacy_file_table(table: &mut [u8], file_count: usize) -> Result<FileTable, ArchiveError> {
+        // Name length (4 bytes), followed by the encrypted name.
+        const NAME_DATA_OFFSET: usize = 6;
+        // Sizes (12 bytes), flags (1 byte) and offset (4 bytes).
+        const ENTRY_DATA_SIZE: usize = 17;
+
+        let mut assets = HashMap::with_capacity(file_count.min(table.len() / (NAME_DATA_OFFSET + ENTRY_DATA_SIZE)));
+        let mut position = 0;
+
+        for _index in 0..file_count {
+            let name_length = read_legacy_u32(table, position)? as usize;
+            let entry_position = position + name_length + 4;
+
+            let flags = *table.get(entry_position + 12).ok_or(ArchiveError::InvalidFileTable)?;
+
+            if flags & GRF_FLAG_FILE != 0 {
+                let name_data = table
+                    .get_mut(position + NAME_DATA_OFFSET..position + name_length)
+                    .ok_or(ArchiveError::InvalidFileTable)?;
+
+                decrypt_file_name(name_data);
+
+                let file_name = String::from_bytes(&mut ByteReader::without_metadata(name_data))
+                    .map_err(|_| ArchiveError::InvalidFileTable)?
+                    .to_lowercase();
+
+                let uncompressed_size = read_legacy_u32(table, entry_position + 8)?;
+                let file_entry = FileEntry {
+                    compressed_size: read_legacy_u32(table, entry_position)?
+                        .wrapping_sub(uncompressed_size)
+                        .wrapping_sub(715),
+                    compressed_size_aligned: read_legacy_u32(table, entry_position + 4)?.wrapping_sub(37579),
+                    uncompressed_size,
+                    flags: flags | legacy_encryption_flags(&file_name),
+                    offset: u64::from(read_legacy_u32(table, entry_position + 13)?),
+                };
+
+                assets.insert(file_name, file_entry);
+            }
+
+            position = entry_position + ENTRY_DATA_SIZE;
+        }
+
+        Ok(assets)
+    }
+}
+
+fn read_legacy_u32(table: &[u8], position: usize) -> Result<u32, ArchiveError> {
+    table
+        .get(position..position + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ArchiveError::InvalidFileTable)
+}
+
+/// Helper trait to get the file name of the different file table rows.
+trait FileName {
+    fn file_name(&self) -> &str;
+}
+
+impl FileName for FileTableRow {
+    fn file_name(&self) -> &str {
+        &self.file_name
+    }
+}
+
+impl FileName for LargeFileTableRow {
+    fn file_name(&self) -> &str {
+        &self.file_name
+    }
+}
+
+impl Archive for NativeArchive {
+    fn from_path(path: &Path) -> Result<Self, ArchiveError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load game data from {}", path.display().magenta()));
+
+        let mut file = File::open(path)?;
+
+        let mut file_header_buffer = vec![0u8; Header::size_in_bytes()];
+        file.read_exact(&mut file_header_buffer)?;
+        let file_header =
+            Header::from_bytes(&mut ByteReader::without_metadata(&file_header_buffer)).map_err(|_| ArchiveError::InvalidFileTable)?;
+
+        let file_table_offset = file_header.get_file_table_offset();
+        let file_count = file_header.get_file_count().map_err(|_| ArchiveError::InvalidFileTable)?;
+
+        let _ = file.seek(SeekFrom::Current(file_table_offset as i64))?;
+
+        let assets = match file_header.version {
+            0x102 | 0x103 => Self::read_legacy_file_table(&mut file, file_count)?,
+            0x200 => Self::read_compressed_file_table::<FileTableRow>(&mut file, file_count, 0)?,
+            // Version 0x300 has 4 unused bytes in front of the file table.
+            0x300 => Self::read_compressed_file_table::<LargeFileTableRow>(&mut file, file_count, 4)?,
+            version => return Err(ArchiveError::UnsupportedVersion(version)),
+        };
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        // TODO: only take 64..? bytes so that loaded game archives can be extended
+        //       as well.
+        Ok(Self {
+            version: file_header.version,
+            file_table: assets,
+            file_handle: Mutex::new(file),
+        })
+    }
+
+    fn file_exists(&self, asset_path: &str) -> bool {
+        self.file_table.contains_key(asset_path)
+    }
+
+    fn get_file_by_path(&self, asset_path: &str) -> Option<Vec<u8>> {
+        self.file_table.get(asset_path).map(|file_information| {
+            let mut compressed_file_buffer = vec![0u8; file_information.compressed_size_aligned as usize];
+
+            let position = file_information.offset + Header::size_in_bytes() as u64;
+
+            {
+                // Since the calling threads are sharing the IO bandwidth anyhow, I don't think
+                // we need to allow this to run in parallel.
+                let mut file_handle = self.file_handle.lock().unwrap();
+                file_handle.seek(SeekFrom::Start(position)).unwrap();
+                file_handle
+                    .read_exact(&mut compressed_file_buffer)
+                    .expect("can't read archive content");
+            }
+
+            decrypt_file(file_information, &mut compressed_file_buffer);
+
+            let mut decoder = ZlibDecoder::new(compressed_file_buffer.as_slice());
+            let mut decompressed = Vec::with_capacity(file_information.uncompressed_size as usize);
+            decoder.read_to_end(&mut decompressed).expect("can't decompress archive content");
+
+            decompressed
+        })
+    }
+
+    fn get_files_with_extension(&self, files: &mut Vec<String>, extensions: &[&str]) {
+        let found_files = self
+            .file_table
+            .iter()
+            .filter(|(file_name, entry)| {
+                entry.flags & GRF_FLAG_FILE != 0 && extensions.iter().any(|extension| file_name.ends_with(extension))
+            })
+            .map(|(file_name, _)| file_name.clone());
+
+        files.extend(found_files);
+    }
+
+    fn hash(&self, hasher: &mut Hasher) -> Result<(), ArchiveError> {
+        let file = self.file_handle.lock().unwrap();
+        hasher.update_reader(file.deref())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use ragnarok_formats::archive::Header;
+
+    use super::mixcrypt::{GRF_FLAG_FULL_MIX_CRYPT, GRF_FLAG_HEADER_DES_CRYPT, decode_des_block};
+    use super::{NativeArchive, NativeArchiveBuilder};
+    use crate::{Archive, ArchiveError, Compression, Writable};
+
+    fn temporary_archive_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("korangar-archive-{}-{name}.grf", std::process::id()))
+    }
+
+    fn round_trip(version: u32) {
+        let path = temporary_archive_path(&format!("round-trip-{version:x}"));
+
+        let mut builder = NativeArchiveBuilder::with_version(&path, version).unwrap();
+        builder
+            .add_file("data\\a.txt", b"compressed".repeat(10), Compression::Default)
+            .unwrap();
+        builder.add_file("data\\b.txt", b"stored".to_vec(), Compression::Off).unwrap();
+
+        // Nothing is written to the target before the archive is finished.
+        assert!(!path.exists());
+        builder.finish().unwrap();
+        drop(builder);
+
+        let archive = NativeArchive::from_path(&path).unwrap();
+        assert_eq!(archive.version(), version);
+        assert_eq!(archive.get_file_by_path("data\\a.txt").unwrap(), b"compressed".repeat(10));
+
+        let raw_file = archive.get_raw_file_by_path("data\\b.txt").unwrap();
+        assert_eq!(raw_file.data, b"stored");
+
+        drop(archive);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn builder_round_trip() {
+        round_trip(NativeArchiveBuilder::DEFAULT_VERSION);
+    }
+
+    #[test]
+    fn builder_round_trip_large_offsets() {
+        round_trip(Header::LARGE_OFFSET_VERSION);
+    }
+
+    #[test]
+    fn builder_unsupported_version() {
+        let path = temporary_archive_path("unsupported");
+
+        assert!(matches!(
+            NativeArchiveBuilder::with_version(&path, 0x103),
+            Err(ArchiveError::UnsupportedVersion(0x103))
+        ));
+        assert!(!path.with_extension("grf.tmp").exists());
+    }
+
+    #[test]
+    fn builder_unfinished() {
+        let path = temporary_archive_path("unfinished");
+
+        let mut builder = NativeArchiveBuilder::from_path(&path).unwrap();
+        builder.add_file("data\\a.txt", b"data".to_vec(), Compression::Default).unwrap();
+        drop(builder);
+
+        assert!(!path.exists());
+        assert!(!path.with_extension("grf.tmp").exists());
+    }
+
+    /// Inverse of [`decrypt_file_name`](super::mixcrypt::decrypt_file_name).
+    /// The single DES round with swapped sides is its own inverse.
+    fn encrypt_file_name(data: &mut [u8]) {
+        for block_data in data.chunks_exact_mut(8) {
+            let block = decode_des_block(u64::from_be_bytes(block_data.try_into().unwrap()));
+            block_data.copy_from_slice(&block.to_be_bytes());
+            block_data.iter_mut().for_each(|byte| *byte = byte.rotate_right(4));
+        }
+    }
+
+    fn legacy_row(table: &mut Vec<u8>, file_name: &str, compressed_size: u32, uncompressed_size: u32, flags: u8, offset: u32) {
+        let mut name_data = file_name.as_bytes().to_vec();
+        name_data.resize(name_data.len().next_multiple_of(8).max(8), 0);
+        encrypt_file_name(&mut name_data);
+
+        // The length covers two unused bytes and the name, but the entry data only
+        // starts 4 bytes after it.
+        let name_length = name_data.len() as u32 + 6;
+        table.extend_from_slice(&name_length.to_le_bytes());
+        table.extend_from_slice(&[0; 2]);
+        table.extend_from_slice(&name_data);
+        table.extend_from_slice(&[0; 4]);
+
+        // Sizes are obfuscated by adding constants.
+        table.extend_from_slice(&(compressed_size + uncompressed_size + 715).to_le_bytes());
+        table.extend_from_slice(&(compressed_size + 37579).to_le_bytes());
+        table.extend_from_slice(&uncompressed_size.to_le_bytes());
+        table.push(flags);
+        table.extend_from_slice(&offset.to_le_bytes());
+    }
+
+    #[test]
+    fn legacy_file_table() {
+        let mut table = Vec::new();
+        legacy_row(&mut table, "data\\Texture\\a.bmp", 40, 100, 1, 1234);
+        legacy_row(&mut table, "data\\map.gat", 7, 9, 1, 5678);
+        legacy_row(&mut table, "data\\folder", 0, 0, 0, 0);
+
+        let file_table = NativeArchive::parse_legacy_file_table(&mut table, 3).unwrap();
+        assert_eq!(file_table.len(), 2);
+
+        let entry = &file_table["data\\texture\\a.bmp"];
+        assert_eq!(entry.compressed_size, 40);
+        assert_eq!(entry.compressed_size_aligned, 40);
+        assert_eq!(entry.uncompressed_size, 100);
+        assert_eq!(entry.flags, 1 | GRF_FLAG_FULL_MIX_CRYPT);
+        assert_eq!(entry.offset, 1234);
+
+        let entry = &file_table["data\\map.gat"];
+        assert_eq!(entry.compressed_size, 7);
+        assert_eq!(entry.flags, 1 | GRF_FLAG_HEADER_DES_CRYPT);
+        assert_eq!(entry.offset, 5678);
+    }
+
+    #[test]
+    fn legacy_file_table_truncated() {
+        let mut table = Vec::new();
+        legacy_row(&mut table, "data\\a.bmp", 40, 100, 1, 1234);
+        table.truncate(table.len() - 3);
+
+        assert!(NativeArchive::parse_legacy_file_table(&mut table, 1).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! A gzip compressed RGZ patch containing game assets.
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use blake3::Hasher;
+use flate2::bufread::GzDecoder;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, Timer};
+use ragnarok_bytes::{ByteReader, FromBytes};
+use ragnarok_formats::archive::RgzEntry;
+
+use super::{Archive, ArchiveError};
+
+/// Represents an RGZ patch file. RGZ files are a gzip compressed stream of
+/// [`RgzEntry`]s. Since the stream can't be seeked, all files are kept in
+/// memory.
+pub struct RgzArchive {
+    file_path: PathBuf,
+    files: HashMap<String, Vec<u8>>,
+}
+
+impl Archive for RgzArchive {
+    fn from_path(path: &Path) -> Result<Self, ArchiveError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load patch data from {}", path.display().magenta()));
+
+        let file = File::open(path)?;
+
+        let mut decoder = GzDecoder::new(BufReader::new(file));
+        let mut decompressed = Vec::new();
+        decoder.read_to_end(&mut decompressed)?;
+
+        let mut byte_reader = ByteReader::without_metadata(&decompressed);
+        let mut files = HashMap::new();
+
+        while !byte_reader.is_empty() {
+            match RgzEntry::from_bytes(&mut byte_reader).map_err(|_| ArchiveError::InvalidFileTable)? {
+                RgzEntry::File { file_name, data } => {
+                    files.insert(file_name.to_lowercase(), data);
+                }
+                RgzEntry::Directory { .. } => {}
+                RgzEntry::End => break,
+            }
+        }
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(Self {
+            file_path: PathBuf::from(path),
+            files,
+        })
+    }
+
+    fn file_exists(&self, asset_path: &str) -> bool {
+        self.files.contains_key(asset_path)
+    }
+
+    fn get_file_by_path(&self, asset_path: &str) -> Option<Vec<u8>> {
+        self.files.get(asset_path).cloned()
+    }
+
+    fn get_files_with_extension(&self, files: &mut Vec<String>, extensions: &[&str]) {
+        let found_files = self
+            .files
+            .keys()
+            .filter(|file_name| extensions.iter().any(|extension| file_name.ends_with(extension)))
+            .cloned();
+
+        files.extend(found_files);
+    }
+
+    fn hash(&self, hasher: &mut Hasher) -> Result<(), ArchiveError> {
+        let file = File::open(&self.file_path)?;
+        hasher.update_reader(&file)?;
+
+        Ok(())
+    }
+}
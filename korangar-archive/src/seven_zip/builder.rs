@@ -12,7 +12,7 @@ use sevenz_rust2::encoder_options::Lzma2Options;
 use sevenz_rust2::{ArchiveEntry, ArchiveWriter, EncoderMethod, NtTime};
 
 use super::SevenZipArchive;
-use crate::loaders::archive::{Archive, Compression, Writable};
+use crate::{Archive, Compression, Writable};
 
 pub struct SevenZipArchiveBuilder {
     writer: Option<ArchiveWriter<BufWriter<File>>>,
@@ -48,14 +48,12 @@ impl SevenZipArchiveBuilder {
             compression = Compression::Off;
         }
 
-        self.add_file(path, data, compression);
-
-        true
+        self.add_file(path, data, compression).is_ok()
     }
 }
 
 impl Writable for SevenZipArchiveBuilder {
-    fn add_file(&mut self, path: &str, asset_data: Vec<u8>, compression: Compression) {
+    fn add_file(&mut self, path: &str, asset_data: Vec<u8>, compression: Compression) -> Result<(), std::io::Error> {
         let path = path.replace('\\', "/").to_string();
 
         if let Some(writer) = self.writer.as_mut() {
@@ -85,8 +83,10 @@ impl Writable for SevenZipArchiveBuilder {
 
             writer
                 .push_archive_entry(file_entry, Some(asset_data.as_slice()))
-                .expect("failed to write file to archive");
+                .map_err(|error| std::io::Error::other(error.to_string()))?;
         }
+
+        Ok(())
     }
 
     fn finish(&mut self) -> Result<(), std::io::Error> {
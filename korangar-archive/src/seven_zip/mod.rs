@@ -9,12 +9,13 @@ use std::path::{Path, PathBuf};
 
 use blake3::Hasher;
 use hashbrown::HashMap;
+use hashbrown::hash_map::Entry;
 #[cfg(feature = "debug")]
-use korangar_debug::logging::{Colorize, Timer, print_debug};
+use korangar_debug::logging::{Colorize, Timer};
 use sevenz_rust2::BlockDecoder;
 
 pub use self::builder::SevenZipArchiveBuilder;
-use crate::loaders::archive::{Archive, Compression};
+use crate::{Archive, ArchiveError, Compression};
 
 const MB_1: u64 = 1024 * 1024;
 const MB_4: u64 = 4 * MB_1;
@@ -49,13 +50,13 @@ impl SevenZipArchive {
 }
 
 impl Archive for SevenZipArchive {
-    fn from_path(path: &Path) -> Self {
+    fn from_path(path: &Path) -> Result<Self, ArchiveError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load game data from {}", path.display().magenta()));
-        let mut archive_file = File::open(path).expect("can't open archive");
+        let mut archive_file = File::open(path)?;
         let password = sevenz_rust2::Password::empty();
 
-        let archive = sevenz_rust2::Archive::read(&mut archive_file, &password).expect("can't read archive");
+        let archive = sevenz_rust2::Archive::read(&mut archive_file, &password).map_err(|_| ArchiveError::InvalidFileTable)?;
 
         assert!(!archive.is_solid, "7zip archives needs to be non-solid for fast file access");
 
@@ -90,12 +91,12 @@ impl Archive for SevenZipArchive {
         #[cfg(feature = "debug")]
         timer.stop();
 
-        Self {
+        Ok(Self {
             archive,
             password,
             file_lookup,
             file_path: PathBuf::from(path),
-        }
+        })
     }
 
     fn file_exists(&self, asset_path: &str) -> bool {
@@ -108,10 +109,11 @@ impl Archive for SevenZipArchive {
         FILE_CACHE.with(|cache| {
             let mut cache = cache.borrow_mut();
 
-            let archive_file = cache
-                .entry(self.file_path.clone())
-                .or_insert_with(|| File::open(&self.file_path).expect("can't open archive file"));
-            archive_file.seek(SeekFrom::Start(0)).expect("can't seek to file start");
+            let archive_file = match cache.entry(self.file_path.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(File::open(&self.file_path).ok()?),
+            };
+            archive_file.seek(SeekFrom::Start(0)).ok()?;
 
             let thread_count = match file_entry.compression {
                 Compression::Default if file_entry.file_size > MB_8 => 8,
@@ -163,11 +165,10 @@ impl Archive for SevenZipArchive {
             })
     }
 
-    fn hash(&self, hasher: &mut Hasher) {
-        let file = File::open(&self.file_path).unwrap();
-        if let Err(_err) = hasher.update_reader(&file) {
-            #[cfg(feature = "debug")]
-            print_debug!("Can't hash ZIP archive: {:?}", _err);
-        }
+    fn hash(&self, hasher: &mut Hasher) -> Result<(), ArchiveError> {
+        let file = File::open(&self.file_path)?;
+        hasher.update_reader(&file)?;
+
+        Ok(())
     }
 }
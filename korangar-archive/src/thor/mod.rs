@@ -0,0 +1,251 @@
+//! A Thor patch containing game assets.
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::Mutex;
+
+use blake3::Hasher;
+use flate2::bufread::ZlibDecoder;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, Timer, print_debug};
+use ragnarok_bytes::{ByteReader, FromBytes};
+use ragnarok_formats::archive::{Header, ThorFileLocation, ThorFileTable, ThorFileTableRow, ThorHeader, ThorSingleFileEntry};
+
+use super::native::{GRF_FLAG_FILE, NativeArchive, NativeArchiveBuilder, RawFile};
+use super::{Archive, ArchiveError, Writable};
+
+/// Upper bound for the size of the header and the single file entry. Both
+/// have names with a length of at most 255 bytes.
+const MAXIMUM_HEADER_SIZE: u64 = 1024;
+
+/// Represents a Thor patch file. Thor patches contain a [`ThorHeader`]
+/// followed by either a single file or a compressed table of files, where
+/// each file is compressed individually.
+///
+/// Files in the patch can also be marked as removed, in which case they will
+/// be deleted from the target when [applying](ThorArchive::apply_patch) the
+/// patch.
+pub struct ThorArchive {
+    header: ThorHeader,
+    file_table: HashMap<String, ThorFileLocation>,
+    removed_files: Vec<String>,
+    file_handle: Mutex<File>,
+}
+
+impl ThorArchive {
+    /// Name of the GRF that the patch should be merged into. If this is empty,
+    /// the default GRF of the client is used.
+    pub fn target_archive_name(&self) -> &str {
+        &self.header.target_grf_name
+    }
+
+    /// Returns `true` if the files of the patch should be merged into a GRF
+    /// and `false` if they should be written to the client folder.
+    pub fn uses_archive_merging(&self) -> bool {
+        self.header.use_grf_merging != 0
+    }
+
+    /// Applies the patch on top of `base_archive` and writes the result to
+    /// `target`. Files of the base archive that are removed or replaced by the
+    /// patch are skipped.
+    ///
+    /// Files are copied one at a time without decompressing them, so the
+    /// archives are never fully loaded into memory. The caller is responsible
+    /// for calling [`Writable::finish`].
+    pub fn apply_patch(&self, base_archive: Option<&NativeArchive>, target: &mut NativeArchiveBuilder) -> Result<(), ArchiveError> {
+        if let Some(base_archive) = base_archive {
+            let mut base_files = Vec::new();
+            // Every file name ends with an empty string, so this collects all files.
+            base_archive.get_files_with_extension(&mut base_files, &[""]);
+
+            for file_name in base_files
+                .iter()
+                .filter(|file_name| !self.file_table.contains_key(*file_name) && !self.removed_files.contains(*file_name))
+            {
+                target.add_raw_file(file_name, base_archive.get_raw_file_by_path(file_name)?)?;
+            }
+        }
+
+        for file_name in self.file_table.keys() {
+            target.add_raw_file(file_name, self.get_raw_file_by_path(file_name)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a file without decompressing it. Files in Thor patches use the
+    /// same compression as GRF files, so they can be copied as is.
+    fn get_raw_file_by_path(&self, asset_path: &str) -> Result<RawFile, std::io::Error> {
+        let location = self
+            .file_table
+            .get(asset_path)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, asset_path))?;
+
+        let mut data = vec![0u8; location.compressed_size as usize];
+
+        {
+            let mut file = self.file_handle.lock().unwrap();
+            file.seek(SeekFrom::Start(u64::from(location.offset)))?;
+            file.read_exact(&mut data)?;
+        }
+
+        Ok(RawFile {
+            data,
+            compressed_size: location.compressed_size,
+            compressed_size_aligned: location.compressed_size,
+            uncompressed_size: location.uncompressed_size,
+            flags: GRF_FLAG_FILE,
+        })
+    }
+}
+
+impl Archive for ThorArchive {
+    fn from_path(path: &Path) -> Result<Self, ArchiveError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load patch data from {}", path.display().magenta()));
+
+        let mut file = File::open(path)?;
+
+        let mut header_buffer = Vec::new();
+        file.by_ref().take(MAXIMUM_HEADER_SIZE).read_to_end(&mut header_buffer)?;
+
+        let mut byte_reader = ByteReader::without_metadata(&header_buffer);
+        let header = ThorHeader::from_bytes(&mut byte_reader).map_err(|_| ArchiveError::InvalidFileTable)?;
+
+        let mut file_table = HashMap::new();
+        let mut removed_files = Vec::new();
+
+        match header.mode {
+            ThorHeader::SINGLE_FILE_MODE => {
+                let entry = ThorSingleFileEntry::from_bytes(&mut byte_reader).map_err(|_| ArchiveError::InvalidFileTable)?;
+                let location = ThorFileLocation {
+                    offset: byte_reader.get_offset() as u32,
+                    compressed_size: entry.compressed_size,
+                    uncompressed_size: entry.uncompressed_size,
+                };
+
+                file_table.insert(entry.file_name.to_lowercase(), location);
+            }
+            ThorHeader::MULTIPLE_FILES_MODE => {
+                let table = ThorFileTable::from_bytes(&mut byte_reader).map_err(|_| ArchiveError::InvalidFileTable)?;
+
+                let _ = file.seek(SeekFrom::Start(u64::from(table.offset)))?;
+                let mut compressed_table_buffer = vec![0u8; table.compressed_size as usize];
+                file.read_exact(&mut compressed_table_buffer)?;
+
+                let mut decoder = ZlibDecoder::new(compressed_table_buffer.as_slice());
+                let mut decompressed = Vec::new();
+                decoder.read_to_end(&mut decompressed).map_err(|_| ArchiveError::InvalidFileTable)?;
+
+                let rows = Vec::<ThorFileTableRow>::from_bytes(&mut ByteReader::without_metadata(&decompressed))
+                    .map_err(|_| ArchiveError::InvalidFileTable)?;
+
+                for row in rows {
+                    let file_name = row.file_name.to_lowercase();
+
+                    match row.location {
+                        Some(location) => {
+                            file_table.insert(file_name, location);
+                        }
+                        None => removed_files.push(file_name),
+                    }
+                }
+            }
+            mode => return Err(ArchiveError::UnsupportedVersion(mode as u32)),
+        }
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(Self {
+            header,
+            file_table,
+            removed_files,
+            file_handle: Mutex::new(file),
+        })
+    }
+
+    fn file_exists(&self, asset_path: &str) -> bool {
+        self.file_table.contains_key(asset_path)
+    }
+
+    fn get_file_by_path(&self, asset_path: &str) -> Option<Vec<u8>> {
+        let location = self.file_table.get(asset_path)?;
+
+        let mut compressed_file_buffer = vec![0u8; location.compressed_size as usize];
+
+        {
+            let mut file = self.file_handle.lock().unwrap();
+            file.seek(SeekFrom::Start(u64::from(location.offset))).ok()?;
+            file.read_exact(&mut compressed_file_buffer).ok()?;
+        }
+
+        let mut decoder = ZlibDecoder::new(compressed_file_buffer.as_slice());
+        let mut decompressed = Vec::with_capacity(location.uncompressed_size as usize);
+
+        match decoder.read_to_end(&mut decompressed) {
+            Ok(_) => Some(decompressed),
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] can't decompress {} from patch: {:?}",
+                    "error".red(),
+                    asset_path.magenta(),
+                    _error
+                );
+
+                None
+            }
+        }
+    }
+
+    fn get_files_with_extension(&self, files: &mut Vec<String>, extensions: &[&str]) {
+        let found_files = self
+            .file_table
+            .keys()
+            .filter(|file_name| extensions.iter().any(|extension| file_name.ends_with(extension)))
+            .cloned();
+
+        files.extend(found_files);
+    }
+
+    fn hash(&self, hasher: &mut Hasher) -> Result<(), ArchiveError> {
+        let mut file = self.file_handle.lock().unwrap();
+        file.seek(SeekFrom::Start(0))?;
+        hasher.update_reader(file.deref())?;
+
+        Ok(())
+    }
+}
+
+/// Applies the Thor patch at `patch_path` to the GRF at `archive_path`. If
+/// there is no GRF at `archive_path` yet, a new one is created.
+pub fn apply_thor_patch(patch_path: &Path, archive_path: &Path) -> Result<(), ArchiveError> {
+    let patch = ThorArchive::from_path(patch_path)?;
+
+    let base_archive = match archive_path.exists() {
+        true => Some(NativeArchive::from_path(archive_path)?),
+        false => None,
+    };
+
+    // Older versions can't be written, so they are upgraded. Version 0x300 is
+    // kept, since the archive might be larger than 4 GiB.
+    let version = match base_archive.as_ref().map(NativeArchive::version) {
+        Some(Header::LARGE_OFFSET_VERSION) => Header::LARGE_OFFSET_VERSION,
+        _ => NativeArchiveBuilder::DEFAULT_VERSION,
+    };
+
+    // The builder writes to a temporary file that only replaces the original
+    // archive once it is complete.
+    let mut builder = NativeArchiveBuilder::with_version(archive_path, version)?;
+    patch.apply_patch(base_archive.as_ref(), &mut builder)?;
+
+    // The base archive has to be closed before it can be replaced.
+    drop(base_archive);
+
+    builder.finish()?;
+
+    Ok(())
+}
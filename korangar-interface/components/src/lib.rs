@@ -27,6 +27,7 @@ pub fn window(token_stream: TokenStream) -> TokenStream {
         maximum_height: { korangar_interface::theme::theme().window().maximum_height() },
         theme: !,
         class: { None },
+        close_event: { None },
         elements: !,
     });
 
@@ -441,6 +441,11 @@ where
     pub fn close_top_window(&mut self, state: &Context<App>) {
         if let Some(index_from_back) = self.windows.iter().rev().position(|wrapper| wrapper.window.is_closable(state)) {
             let index = self.windows.len() - 1 - index_from_back;
+
+            if let Some(custom_event) = self.windows[index].window.get_close_event() {
+                self.event_queue.queue(Event::Application { custom_event });
+            }
+
             self.remove_window(index);
         }
     }
@@ -513,6 +518,10 @@ where
                 }
                 Event::CloseWindow { window_id } => {
                     if let Some(index) = self.windows.iter().position(|wrapper| wrapper.data.id == window_id) {
+                        if let Some(custom_event) = self.windows[index].window.get_close_event() {
+                            custom_events.push(custom_event);
+                        }
+
                         self.windows.remove(index);
                     }
                 }
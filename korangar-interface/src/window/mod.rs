@@ -38,6 +38,9 @@ pub trait Window<App: Application>: private::Sealed {
     /// Returns if the window is closable or not.
     fn is_closable(&self, state: &Context<App>) -> bool;
 
+    /// Get the event that is emitted when the user closes the window (if any).
+    fn get_close_event(&self) -> Option<App::CustomEvent>;
+
     /// Create the layout info for the window.
     #[allow(private_interfaces)]
     fn create_layout_info(
@@ -240,6 +243,7 @@ where
     maximum_height: U,
     theme: App::ThemeType,
     class: Option<App::WindowClass>,
+    close_event: Option<App::CustomEvent>,
     elements: Elements,
     // HACK: This is a bit ugly since all of these store the window_id. Ideally, we would be able
     // to inject some data into the click action instead.
@@ -282,6 +286,7 @@ where
         maximum_height: U,
         theme: App::ThemeType,
         class: Option<App::WindowClass>,
+        close_event: Option<App::CustomEvent>,
         elements: Elements,
     ) -> Self {
         Self {
@@ -309,6 +314,7 @@ where
             maximum_height,
             theme,
             class,
+            close_event,
             elements,
             close_click_action: CloseClickHandler::default(),
             move_click_action: MoveClickHandler::default(),
@@ -369,6 +375,10 @@ where
         *state.get(&self.closable)
     }
 
+    fn get_close_event(&self) -> Option<App::CustomEvent> {
+        self.close_event.clone()
+    }
+
     #[allow(private_interfaces)]
     fn create_layout_info(
         &mut self,
@@ -4,7 +4,7 @@ use std::thread::sleep;
 use std::time::Duration;
 
 use korangar_debug::logging::Colorize;
-use korangar_networking::{DisconnectReason, NetworkEvent, NetworkingSystem, SupportedPacketVersion};
+use korangar_networking::{DisconnectReason, NetworkEvent, NetworkingSystem, SessionRecorder, SupportedPacketVersion};
 use reqwest::StatusCode;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -69,11 +69,18 @@ async fn main() {
     const USERNAME: &str = "username";
     const PASSWORD: &str = "password";
     const CHARACTER_NAME: &str = "character name";
+    // Set to a file path to record all network traffic.
+    const SESSION_RECORDING: Option<&str> = None;
 
     // Create the networking system and HTTP client.
     let (mut networking_system, mut network_event_buffer) = NetworkingSystem::spawn();
     let client = reqwest::Client::new();
 
+    if let Some(path) = SESSION_RECORDING {
+        let session_recorder = SessionRecorder::create(path).expect("failed to create session recording");
+        networking_system.set_session_recorder(Some(session_recorder));
+    }
+
     // Persistent data.
     let mut saved_login_data = None;
     let mut message_history = MessageHistory { hash_map: HashMap::new() };
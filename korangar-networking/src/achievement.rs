@@ -0,0 +1,61 @@
+use ragnarok_packets::{AchievementData, AchievementListPacket, AchievementUpdatePacket};
+
+/// Achievement level of the player. The level increases with the score of
+/// completed achievements.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct AchievementLevel {
+    pub total_score: u32,
+    pub level: u16,
+    pub experience: u32,
+    pub experience_to_next_level: u32,
+}
+
+/// Progress of the player towards a single achievement. The server only sends
+/// the id, so the title and objectives need to be looked up in the
+/// achievement table of the game data.
+#[derive(Debug, Clone)]
+pub struct AchievementProgress {
+    pub achievement_id: u32,
+    pub is_completed: bool,
+    /// Current value of each objective. Unused objectives are 0.
+    pub objectives: [u32; 10],
+    /// Unix timestamp of when the achievement was completed. Will be 0 if the
+    /// achievement is not completed yet.
+    pub completion_time: u32,
+    pub got_rewarded: bool,
+}
+
+impl From<AchievementData> for AchievementProgress {
+    fn from(value: AchievementData) -> Self {
+        Self {
+            achievement_id: value.acheivement_id,
+            is_completed: value.is_completed != 0,
+            objectives: value.objectives,
+            completion_time: value.completion_timestamp,
+            got_rewarded: value.got_rewarded != 0,
+        }
+    }
+}
+
+impl From<&AchievementListPacket> for AchievementLevel {
+    fn from(value: &AchievementListPacket) -> Self {
+        Self {
+            total_score: value.total_score,
+            level: value.level,
+            experience: value.acheivement_experience,
+            experience_to_next_level: value.acheivement_experience_to_next_level,
+        }
+    }
+}
+
+impl From<&AchievementUpdatePacket> for AchievementLevel {
+    fn from(value: &AchievementUpdatePacket) -> Self {
+        Self {
+            total_score: value.total_score,
+            level: value.level,
+            experience: value.acheivement_experience,
+            experience_to_next_level: value.acheivement_experience_to_next_level,
+        }
+    }
+}
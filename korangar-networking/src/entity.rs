@@ -1,6 +1,6 @@
 use ragnarok_packets::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EntityData {
     pub entity_id: EntityId,
     pub movement_speed: u16,
@@ -12,6 +12,8 @@ pub struct EntityData {
     pub maximum_health_points: i32,
     pub head_direction: usize,
     pub sex: Sex,
+    pub body_state: u16,
+    pub effect_state: u32,
 }
 
 impl EntityData {
@@ -27,6 +29,8 @@ impl EntityData {
             maximum_health_points: character_information.maximum_health_points as i32,
             head_direction: 0, // TODO: get correct rotation
             sex: character_information.sex,
+            body_state: character_information.body_state as u16,
+            effect_state: character_information.effect_state as u32,
         }
     }
 }
@@ -44,6 +48,8 @@ impl From<EntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            body_state: packet.body_state,
+            effect_state: packet.effect_state,
         }
     }
 }
@@ -61,6 +67,8 @@ impl From<EntityAppeared2Packet> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            body_state: packet.body_state,
+            effect_state: packet.effect_state,
         }
     }
 }
@@ -80,6 +88,42 @@ impl From<MovingEntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            body_state: packet.body_state,
+            effect_state: packet.effect_state,
+        }
+    }
+}
+
+/// Item lying on the ground.
+#[derive(Debug, Clone)]
+pub struct GroundItemData {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub is_identified: bool,
+    pub position: TilePosition,
+    pub count: u16,
+}
+
+impl From<ItemAppearedPacket> for GroundItemData {
+    fn from(packet: ItemAppearedPacket) -> Self {
+        Self {
+            ground_item_id: packet.ground_item_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            position: packet.position,
+            count: packet.count,
+        }
+    }
+}
+
+impl From<ItemDroppedPacket> for GroundItemData {
+    fn from(packet: ItemDroppedPacket) -> Self {
+        Self {
+            ground_item_id: packet.ground_item_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            position: packet.position,
+            count: packet.count,
         }
     }
 }
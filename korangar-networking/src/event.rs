@@ -2,10 +2,16 @@ use std::time::Instant;
 
 use ragnarok_packets::*;
 
+use crate::achievement::{AchievementLevel, AchievementProgress};
+use crate::guild::{GuildInformation, GuildMember, GuildNotice};
 use crate::hotkey::HotkeyState;
 use crate::items::ShopItem;
+use crate::mail::MailSummary;
+use crate::party::PartyMember;
+use crate::pincode::{PincodeRejection, PincodeRequest};
+use crate::quest::QuestLogEntry;
 use crate::{
-    CharacterServerLoginData, EntityData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
+    CharacterServerLoginData, EntityData, GroundItemData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
     UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
 
@@ -39,6 +45,17 @@ pub enum NetworkEvent {
     CharacterList {
         characters: Vec<CharacterInformation>,
     },
+    /// The character server requires the player to enter, set, or change the
+    /// pincode of the account before selecting a character.
+    PincodeRequested {
+        /// Seed used to encode the pincode.
+        seed: u32,
+        request: PincodeRequest,
+        /// Set if the previously entered pincode was rejected.
+        rejection: Option<PincodeRejection>,
+    },
+    /// The pincode was accepted, so the player can select a character.
+    PincodeAccepted,
     CharacterSelected {
         login_data: CharacterServerLoginData,
     },
@@ -88,6 +105,14 @@ pub enum NetworkEvent {
         entity_id: EntityId,
         reason: DisappearanceReason,
     },
+    /// An item on the ground came into view or was dropped.
+    AddGroundItem {
+        ground_item_data: GroundItemData,
+    },
+    /// An item on the ground was picked up or left the view.
+    RemoveGroundItem {
+        ground_item_id: GroundItemId,
+    },
     /// The player is pathing to a new position.
     PlayerMove {
         origin: WorldPosition,
@@ -141,6 +166,44 @@ pub enum NetworkEvent {
         entity_id: EntityId,
         heal_amount: usize,
     },
+    /// A skill of the player can't be used again until the cooldown is over.
+    SkillCooldown {
+        skill_id: SkillId,
+        duration_in_milliseconds: u32,
+    },
+    /// An entity started casting a skill. Skills without a cast time will
+    /// have a `cast_time_in_milliseconds` of 0.
+    SkillCastStarted {
+        source_entity_id: EntityId,
+        destination_entity_id: EntityId,
+        position: TilePosition,
+        skill_id: SkillId,
+        cast_time_in_milliseconds: u32,
+    },
+    /// The player failed to use a skill, e.g. because of missing spell points.
+    SkillUseFailed {
+        skill_id: SkillId,
+    },
+    /// A status effect (buff or debuff) was applied to an entity. The
+    /// durations are only known for some status effects.
+    StatusEffectStarted {
+        entity_id: EntityId,
+        status_id: u16,
+        duration_in_milliseconds: Option<u32>,
+        remaining_in_milliseconds: Option<u32>,
+    },
+    StatusEffectEnded {
+        entity_id: EntityId,
+        status_id: u16,
+    },
+    /// The body, health or effect state of an entity changed, e.g. when it
+    /// was petrified or started hiding.
+    EntityStateChanged {
+        entity_id: EntityId,
+        body_state: u16,
+        health_state: u16,
+        effect_state: u32,
+    },
     UpdateStat {
         stat_type: StatType,
     },
@@ -148,6 +211,27 @@ pub enum NetworkEvent {
         text: String,
         npc_id: EntityId,
     },
+    /// An NPC marked a position on the minimap.
+    MinimapMarkerAdded {
+        npc_id: EntityId,
+        /// Id of the marker. Unique per NPC.
+        marker_id: u8,
+        position: TilePosition,
+        color: ColorRGBA,
+        /// Temporary markers are removed after 15 seconds, all others stay
+        /// until the player leaves the map.
+        is_temporary: bool,
+    },
+    MinimapMarkerRemoved {
+        npc_id: EntityId,
+        marker_id: u8,
+    },
+    /// The server wants to guide the player to a position, for example from
+    /// the navigation system.
+    NavigationTarget {
+        map_name: String,
+        position: TilePosition,
+    },
     AddNextButton {
         npc_id: EntityId,
     },
@@ -170,6 +254,11 @@ pub enum NetworkEvent {
     IventoryItemAdded {
         item: InventoryItem<NoMetadata>,
     },
+    /// The map server refused to let the player pick up an item, e.g. because
+    /// they are overweight.
+    ItemPickupFailed {
+        result: ItemPickupResult,
+    },
     SkillTree {
         skill_information: Vec<SkillInformation>,
     },
@@ -193,6 +282,18 @@ pub enum NetworkEvent {
         effect_path: &'static str,
         entity_id: EntityId,
     },
+    /// The player gained base or job experience.
+    ExperienceGained {
+        account_id: AccountId,
+        amount: u64,
+        experience_type: ExperienceType,
+        experience_source: ExperienceSource,
+    },
+    /// An entity used an emotion, e.g. `/heh`.
+    Emotion {
+        entity_id: EntityId,
+        emotion: u8,
+    },
     AddSkillUnit {
         entity_id: EntityId,
         unit_id: UnitId,
@@ -211,6 +312,223 @@ pub enum NetworkEvent {
         account_id: AccountId,
         character_id: CharacterId,
     },
+    /// The player was invited to join a party.
+    PartyInvitation {
+        party_id: PartyId,
+        party_name: String,
+    },
+    /// The player is in a party. Contains all members of the party.
+    SetParty {
+        party_name: String,
+        members: Vec<PartyMember>,
+    },
+    /// A member joined the party or the information of a member changed, like
+    /// when they moved to another map.
+    PartyMemberUpdated {
+        party_name: String,
+        member: PartyMember,
+    },
+    /// A member left or was removed from the party. If the member is the
+    /// player, the player is no longer in a party.
+    PartyMemberLeft {
+        account_id: AccountId,
+    },
+    PartyMemberHealth {
+        account_id: AccountId,
+        health_points: usize,
+        maximum_health_points: usize,
+    },
+    PartyMemberPosition {
+        account_id: AccountId,
+        position: TilePosition,
+    },
+    PartyLeaderChanged {
+        account_id: AccountId,
+    },
+    /// All quests of the player. Received when entering the map.
+    SetQuestLog {
+        quests: Vec<QuestLogEntry>,
+    },
+    /// A quest was added to the quest log of the player.
+    QuestAdded {
+        quest: QuestLogEntry,
+    },
+    /// The kill counts of one or more hunting objectives changed.
+    QuestObjectivesUpdated {
+        objectives: Vec<HuntingObjective>,
+    },
+    QuestRemoved {
+        quest_id: u32,
+    },
+    /// The player is in a guild. Received on login or when joining a guild.
+    GuildJoined {
+        guild_id: GuildId,
+        guild_name: String,
+        is_master: bool,
+    },
+    /// The player was invited to join a guild.
+    GuildInvitation {
+        guild_id: GuildId,
+        guild_name: String,
+    },
+    SetGuildInformation {
+        information: GuildInformation,
+    },
+    /// List of all members of the guild. Member names are not included and
+    /// are resolved through [`NetworkEvent::CharacterName`].
+    SetGuildMembers {
+        members: Vec<GuildMember>,
+    },
+    SetGuildPositions {
+        positions: Vec<GuildPositionName>,
+    },
+    SetGuildNotice {
+        notice: GuildNotice,
+    },
+    GuildEmblem {
+        guild_id: GuildId,
+        emblem_id: u32,
+        /// Zlib compressed bitmap.
+        emblem_data: Vec<u8>,
+    },
+    /// A member left or was expelled from the guild. If the member is the
+    /// player, the player is no longer in a guild.
+    GuildMemberLeft {
+        character_name: String,
+    },
+    GuildMemberOnlineState {
+        account_id: AccountId,
+        character_id: CharacterId,
+        is_online: bool,
+    },
+    /// Name of a character. Received as a response to
+    /// [RequestCharacterNamePacket].
+    CharacterName {
+        character_id: CharacterId,
+        name: String,
+    },
+    /// Another player wants to trade with the player.
+    TradeRequested {
+        character_name: String,
+        base_level: u16,
+    },
+    /// The trade request was accepted by both sides and items can be added.
+    TradeStarted,
+    /// The trade partner added an item to the trade.
+    TradeItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    /// The trade partner added zeny to the trade.
+    TradeZenyAdded {
+        amount: u32,
+    },
+    /// An item the player tried to add to the trade was accepted by the
+    /// server.
+    TradeItemAccepted {
+        index: InventoryIndex,
+    },
+    /// An item the player tried to add to the trade was rejected by the
+    /// server.
+    TradeItemRejected {
+        index: InventoryIndex,
+    },
+    /// Either the player or the trade partner locked their items.
+    TradeLocked {
+        participant: TradeParticipant,
+    },
+    /// The trade was cancelled by either side or failed.
+    TradeCancelled,
+    /// The trade went through and the items were exchanged.
+    TradeCompleted,
+    /// The storage was opened. Contains all items in the storage. The index of
+    /// the items is their index in the storage.
+    SetStorage {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    StorageItemCount {
+        item_count: u16,
+        maximum_item_count: u16,
+    },
+    /// An item was added to the storage. The index of the item is its index in
+    /// the storage.
+    StorageItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    StorageItemRemoved {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    StorageClosed,
+    /// All achievements of the player. Received when entering the map.
+    SetAchievements {
+        level: AchievementLevel,
+        achievements: Vec<AchievementProgress>,
+    },
+    /// The progress of an achievement changed.
+    AchievementUpdated {
+        level: AchievementLevel,
+        achievement: AchievementProgress,
+    },
+    /// Sent when logging in and whenever the player receives a new mail.
+    NewMailStatus {
+        has_unread_mail: bool,
+    },
+    /// Part of the mails in the mailbox. Received as a response to
+    /// [OpenMailboxPacket] and [RefreshMailboxPacket].
+    MailList {
+        mails: Vec<MailSummary>,
+        /// Set on the last part of the list.
+        is_end: bool,
+    },
+    /// Content of a mail. Received as a response to [ReadMailPacket]. The
+    /// index of the items is only a placeholder.
+    MailRead {
+        mail_id: MailId,
+        open_type: MailOpenType,
+        text: String,
+        zeny: u64,
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    MailDeleted {
+        mail_id: MailId,
+    },
+    /// The zeny attached to a mail were moved into the inventory.
+    MailZenyRetrieved {
+        mail_id: MailId,
+    },
+    /// The items attached to a mail were moved into the inventory.
+    MailItemsRetrieved {
+        mail_id: MailId,
+    },
+    /// The server accepted that the player is writing a mail.
+    MailWriteOpened {
+        receiver_name: String,
+    },
+    /// An item the player tried to attach to a mail was accepted by the
+    /// server.
+    MailItemAccepted {
+        index: InventoryIndex,
+    },
+    /// An item the player tried to attach to a mail was rejected by the
+    /// server.
+    MailItemRejected {
+        index: InventoryIndex,
+    },
+    /// An item was detached from the mail that is being written.
+    MailItemRemoved {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    /// The receiver of a mail exists. Received as a response to
+    /// [CheckMailReceiverPacket].
+    MailReceiverFound {
+        character_id: CharacterId,
+        receiver_name: String,
+    },
+    MailSent,
+    /// The mail that is being written could not be sent, either because the
+    /// receiver does not exist or because the server rejected it.
+    MailSendFailed,
     SetHotkeyData {
         tab: HotbarTab,
         hotkeys: Vec<HotkeyState>,
@@ -0,0 +1,69 @@
+use ragnarok_packets::{AccountId, CharacterId, GuildId, GuildInformationPacket, GuildMemberInformation, GuildMemberState};
+
+/// General information about the guild of the player.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildInformation {
+    pub guild_id: GuildId,
+    pub name: String,
+    pub level: u32,
+    pub online_members: u32,
+    pub maximum_members: u32,
+    pub average_level: u32,
+    pub emblem_id: u32,
+    pub master_character_id: CharacterId,
+}
+
+impl From<GuildInformationPacket> for GuildInformation {
+    fn from(value: GuildInformationPacket) -> Self {
+        Self {
+            guild_id: value.guild_id,
+            name: value.guild_name,
+            level: value.level,
+            online_members: value.online_members,
+            maximum_members: value.maximum_members,
+            average_level: value.average_level,
+            emblem_id: value.emblem_id,
+            master_character_id: value.master_character_id,
+        }
+    }
+}
+
+/// The notice of the guild of the player.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildNotice {
+    pub subject: String,
+    pub notice: String,
+}
+
+/// A member of the guild of the player.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    /// The server doesn't send the member names with the member list, so this
+    /// will be empty until the name is resolved.
+    pub name: String,
+    pub job: u16,
+    pub base_level: u16,
+    pub is_online: bool,
+    pub position_id: u32,
+    pub contributed_experience: u32,
+}
+
+impl From<GuildMemberInformation> for GuildMember {
+    fn from(value: GuildMemberInformation) -> Self {
+        Self {
+            account_id: value.account_id,
+            character_id: value.character_id,
+            name: String::new(),
+            job: value.job,
+            base_level: value.base_level,
+            is_online: value.state == GuildMemberState::Online,
+            position_id: value.position_id,
+            contributed_experience: value.contributed_experience,
+        }
+    }
+}
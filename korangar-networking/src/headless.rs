@@ -0,0 +1,736 @@
+//! High-level client that runs without a renderer. It takes care of the
+//! login, character selection and map server flow, and keeps track of the
+//! state that bots usually need.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::handler::{NoPacketCallback, PacketCallback};
+use ragnarok_packets::*;
+
+use crate::{
+    DisconnectReason, EntityData, InventoryItem, InventoryItemDetails, LoginServerLoginData, MessageColor, NetworkEvent,
+    NetworkEventBuffer, NetworkingSystem, NoMetadata, NotConnectedError, PincodeRejection, PincodeRequest, SupportedPacketVersion,
+};
+
+/// Interval in which the [`HeadlessClient`] checks for new events while
+/// waiting.
+const UPDATE_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Walking diagonally takes roughly 1.4 times as long as walking straight.
+const DIAGONAL_STEP_COST: (u64, u64) = (14, 10);
+
+/// Everything needed to log in and select a character.
+#[derive(Debug, Clone)]
+pub struct HeadlessClientConfig {
+    pub packet_version: SupportedPacketVersion,
+    pub login_server_address: SocketAddr,
+    pub username: String,
+    pub password: String,
+    pub character_name: String,
+    /// Pincode of the account. Only needed if the character server has
+    /// pincodes enabled.
+    pub pincode: Option<String>,
+    /// Time that actions wait for a response from the server before failing.
+    pub action_timeout: Duration,
+}
+
+impl HeadlessClientConfig {
+    pub fn new(
+        packet_version: SupportedPacketVersion,
+        login_server_address: SocketAddr,
+        username: impl Into<String>,
+        password: impl Into<String>,
+        character_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            packet_version,
+            login_server_address,
+            username: username.into(),
+            password: password.into(),
+            character_name: character_name.into(),
+            pincode: None,
+            action_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Where the [`HeadlessClient`] is in the login flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadlessClientState {
+    Offline,
+    LoggingIn,
+    SelectingCharacter,
+    EnteringMap,
+    InGame,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadlessClientError {
+    LoginFailed(&'static str),
+    NoCharacterServer,
+    CharacterServerLoginFailed(&'static str),
+    CharacterNotFound(String),
+    CharacterSelectionFailed(&'static str),
+    /// The character server asked for a pincode, but none is configured or
+    /// the server wants it to be set or changed.
+    PincodeRequired,
+    PincodeRejected(PincodeRejection),
+    Disconnected,
+    NotConnected,
+    ActionFailed,
+    Timeout,
+}
+
+impl From<NotConnectedError> for HeadlessClientError {
+    fn from(_: NotConnectedError) -> Self {
+        Self::NotConnected
+    }
+}
+
+/// The character controlled by a [`HeadlessClient`].
+#[derive(Debug, Clone)]
+pub struct LocalPlayer {
+    pub entity_id: EntityId,
+    pub character_information: CharacterInformation,
+    pub map_name: String,
+    /// Last known position of the player. While walking, this is the tile the
+    /// walk started on, until the player arrives at the destination.
+    pub position: TilePosition,
+    /// Tile the player is currently walking to.
+    pub destination: Option<TilePosition>,
+}
+
+/// A chat message received by a [`HeadlessClient`].
+#[derive(Debug, Clone)]
+pub struct ChatEntry {
+    pub text: String,
+    pub color: MessageColor,
+}
+
+/// Headless client on top of the [`NetworkingSystem`].
+///
+/// Calling [`update`](Self::update) regularly (or awaiting one of the async
+/// functions) drives the login flow and keeps the tracked state up to date.
+pub struct HeadlessClient<Callback = NoPacketCallback> {
+    networking_system: NetworkingSystem<Callback>,
+    event_buffer: NetworkEventBuffer,
+    config: HeadlessClientConfig,
+    state: HeadlessClientState,
+    error: Option<HeadlessClientError>,
+    login_data: Option<LoginServerLoginData>,
+    characters: Vec<CharacterInformation>,
+    player: Option<LocalPlayer>,
+    /// Estimated time at which the player arrives at its destination.
+    arrival: Option<Instant>,
+    entities: HashMap<EntityId, EntityData>,
+    inventory: Vec<InventoryItem<NoMetadata>>,
+    chat_messages: Vec<ChatEntry>,
+    events: Vec<NetworkEvent>,
+}
+
+impl HeadlessClient<NoPacketCallback> {
+    pub fn new(config: HeadlessClientConfig) -> Self {
+        let (networking_system, event_buffer) = NetworkingSystem::spawn();
+        Self::with_networking_system(networking_system, event_buffer, config)
+    }
+}
+
+impl<Callback> HeadlessClient<Callback>
+where
+    Callback: PacketCallback + Send,
+{
+    /// Create a client from an existing networking system. This makes it
+    /// possible to use a packet callback, a session recorder or a replay.
+    pub fn with_networking_system(
+        networking_system: NetworkingSystem<Callback>,
+        event_buffer: NetworkEventBuffer,
+        config: HeadlessClientConfig,
+    ) -> Self {
+        Self {
+            networking_system,
+            event_buffer,
+            config,
+            state: HeadlessClientState::Offline,
+            error: None,
+            login_data: None,
+            characters: Vec::new(),
+            player: None,
+            arrival: None,
+            entities: HashMap::new(),
+            inventory: Vec::new(),
+            chat_messages: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn state(&self) -> HeadlessClientState {
+        self.state
+    }
+
+    pub fn player(&self) -> Option<&LocalPlayer> {
+        self.player.as_ref()
+    }
+
+    pub fn entities(&self) -> &HashMap<EntityId, EntityData> {
+        &self.entities
+    }
+
+    pub fn inventory(&self) -> &[InventoryItem<NoMetadata>] {
+        &self.inventory
+    }
+
+    pub fn chat_messages(&self) -> &[ChatEntry] {
+        &self.chat_messages
+    }
+
+    /// Remove and return all chat messages received so far.
+    pub fn take_chat_messages(&mut self) -> Vec<ChatEntry> {
+        std::mem::take(&mut self.chat_messages)
+    }
+
+    /// Direct access to the networking system for anything that is not
+    /// covered by the headless client.
+    pub fn networking_system(&mut self) -> &mut NetworkingSystem<Callback> {
+        &mut self.networking_system
+    }
+
+    /// Start the login flow.
+    pub fn connect(&mut self) {
+        if self.state != HeadlessClientState::Offline {
+            return;
+        }
+
+        self.error = None;
+        self.state = HeadlessClientState::LoggingIn;
+        self.networking_system.connect_to_login_server(
+            self.config.packet_version,
+            self.config.login_server_address,
+            self.config.username.clone(),
+            self.config.password.clone(),
+        );
+    }
+
+    /// Process all pending events. The events are returned after they updated
+    /// the tracked state, so bots can react to them.
+    pub fn update(&mut self) -> Result<std::vec::Drain<'_, NetworkEvent>, HeadlessClientError> {
+        self.networking_system.get_events(&mut self.event_buffer);
+
+        let events: Vec<NetworkEvent> = self.event_buffer.drain().collect();
+
+        for event in events {
+            self.handle_event(&event);
+            self.events.push(event);
+        }
+
+        self.update_player_position();
+
+        match self.error.take() {
+            Some(error) => {
+                self.events.clear();
+                Err(error)
+            }
+            None => Ok(self.events.drain(..)),
+        }
+    }
+
+    /// Wait until an event matches the predicate and return it. All other
+    /// events are still applied to the tracked state.
+    pub async fn wait_for(&mut self, mut predicate: impl FnMut(&NetworkEvent) -> bool) -> Result<NetworkEvent, HeadlessClientError> {
+        loop {
+            if let Some(event) = self.update()?.find(|event| predicate(event)) {
+                return Ok(event);
+            }
+
+            tokio::time::sleep(UPDATE_INTERVAL).await;
+        }
+    }
+
+    /// Like [`wait_for`](Self::wait_for) but fails after the configured action
+    /// timeout.
+    async fn wait_for_response(&mut self, predicate: impl FnMut(&NetworkEvent) -> bool) -> Result<NetworkEvent, HeadlessClientError> {
+        let timeout = self.config.action_timeout;
+
+        tokio::time::timeout(timeout, self.wait_for(predicate))
+            .await
+            .map_err(|_| HeadlessClientError::Timeout)?
+    }
+
+    /// Connect if necessary and wait until the character is on the map. Every
+    /// step of the login flow has to finish within the configured action
+    /// timeout.
+    pub async fn enter_game(&mut self) -> Result<(), HeadlessClientError> {
+        self.connect();
+
+        let mut state = self.state;
+        let mut deadline = Instant::now() + self.config.action_timeout;
+
+        loop {
+            self.update()?;
+
+            match self.state {
+                HeadlessClientState::InGame => return Ok(()),
+                // Disconnected without an error, for example because the player logged out.
+                HeadlessClientState::Offline => return Err(HeadlessClientError::Disconnected),
+                current_state if current_state != state => {
+                    state = current_state;
+                    deadline = Instant::now() + self.config.action_timeout;
+                }
+                _ if Instant::now() >= deadline => {
+                    // Disconnect from all servers so that `connect` can start over.
+                    self.fail(HeadlessClientError::Timeout);
+                    self.error = None;
+                    return Err(HeadlessClientError::Timeout);
+                }
+                _ => {}
+            }
+
+            tokio::time::sleep(UPDATE_INTERVAL).await;
+        }
+    }
+
+    /// Walk to a tile. Returns once the server accepted the movement.
+    pub async fn move_to(&mut self, position: TilePosition) -> Result<(), HeadlessClientError> {
+        let destination = WorldPosition::new(position.x, position.y, Direction::North);
+        self.networking_system.player_move(destination)?;
+
+        self.wait_for_response(|event| matches!(event, NetworkEvent::PlayerMove { .. }))
+            .await
+            .map(|_| ())
+    }
+
+    /// Warp to a position on a map. Returns once the player is on the new map.
+    pub async fn warp_to_map(&mut self, map_name: String, position: TilePosition) -> Result<(), HeadlessClientError> {
+        self.networking_system.warp_to_map(map_name, position)?;
+
+        self.wait_for_response(|event| matches!(event, NetworkEvent::ChangeMap { .. }))
+            .await
+            .map(|_| ())
+    }
+
+    /// Attack an entity. Returns once the player dealt damage or the server
+    /// rejected the attack.
+    pub async fn attack(&mut self, entity_id: EntityId) -> Result<(), HeadlessClientError> {
+        let player_id = self.player_entity_id()?;
+        self.networking_system.player_attack(entity_id)?;
+
+        let event = self
+            .wait_for_response(|event| match event {
+                NetworkEvent::DamageEffect {
+                    source_entity_id,
+                    destination_entity_id,
+                    ..
+                } => *source_entity_id == player_id && *destination_entity_id == entity_id,
+                NetworkEvent::AttackFailed { target_entity_id, .. } => *target_entity_id == entity_id,
+                _ => false,
+            })
+            .await?;
+
+        match event {
+            NetworkEvent::AttackFailed { .. } => Err(HeadlessClientError::ActionFailed),
+            _ => Ok(()),
+        }
+    }
+
+    /// Start talking to an NPC and return the first dialog text.
+    pub async fn talk_to_npc(&mut self, npc_id: EntityId) -> Result<String, HeadlessClientError> {
+        self.networking_system.start_dialog(npc_id)?;
+        self.next_dialog_text(npc_id).await
+    }
+
+    /// Advance the dialog of an NPC and return the next dialog text.
+    pub async fn next_dialog(&mut self, npc_id: EntityId) -> Result<String, HeadlessClientError> {
+        self.networking_system.next_dialog(npc_id)?;
+        self.next_dialog_text(npc_id).await
+    }
+
+    /// Choose a dialog option (starting at 1) and return the next dialog
+    /// text.
+    pub async fn choose_dialog_option(&mut self, npc_id: EntityId, option: i8) -> Result<String, HeadlessClientError> {
+        self.networking_system.choose_dialog_option(npc_id, option)?;
+        self.next_dialog_text(npc_id).await
+    }
+
+    pub fn close_dialog(&mut self, npc_id: EntityId) -> Result<(), HeadlessClientError> {
+        self.networking_system.close_dialog(npc_id).map_err(Into::into)
+    }
+
+    async fn next_dialog_text(&mut self, npc_id: EntityId) -> Result<String, HeadlessClientError> {
+        let event = self
+            .wait_for_response(|event| matches!(event, NetworkEvent::OpenDialog { npc_id: id, .. } if *id == npc_id))
+            .await?;
+
+        match event {
+            NetworkEvent::OpenDialog { text, .. } => Ok(text),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Use a skill on an entity.
+    pub async fn use_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, entity_id: EntityId) -> Result<(), HeadlessClientError> {
+        self.networking_system.cast_skill(skill_id, skill_level, entity_id)?;
+        // Give the networking thread a chance to send the packet before the bot
+        // continues.
+        tokio::task::yield_now().await;
+        Ok(())
+    }
+
+    /// Use a skill on a tile.
+    pub async fn use_ground_skill(
+        &mut self,
+        skill_id: SkillId,
+        skill_level: SkillLevel,
+        position: TilePosition,
+    ) -> Result<(), HeadlessClientError> {
+        self.networking_system.cast_ground_skill(skill_id, skill_level, position)?;
+        tokio::task::yield_now().await;
+        Ok(())
+    }
+
+    pub fn send_chat_message(&mut self, text: &str) -> Result<(), HeadlessClientError> {
+        let player_name = self
+            .player
+            .as_ref()
+            .map(|player| player.character_information.name.clone())
+            .ok_or(HeadlessClientError::NotConnected)?;
+
+        self.networking_system.send_chat_message(&player_name, text).map_err(Into::into)
+    }
+
+    /// Move the player to its destination once it should have arrived. The
+    /// server only tells us where a walk starts and ends, so the arrival is
+    /// estimated from the movement speed.
+    fn update_player_position(&mut self) {
+        if self.arrival.is_some_and(|arrival| Instant::now() >= arrival) {
+            self.arrival = None;
+
+            if let Some(player) = &mut self.player
+                && let Some(destination) = player.destination.take()
+            {
+                player.position = destination;
+            }
+        }
+    }
+
+    fn player_entity_id(&self) -> Result<EntityId, HeadlessClientError> {
+        self.player
+            .as_ref()
+            .map(|player| player.entity_id)
+            .ok_or(HeadlessClientError::NotConnected)
+    }
+
+    fn select_configured_character(&mut self) {
+        let Some(character) = self
+            .characters
+            .iter()
+            .find(|character| character.name == self.config.character_name)
+        else {
+            self.fail(HeadlessClientError::CharacterNotFound(self.config.character_name.clone()));
+            return;
+        };
+
+        let _ = self.networking_system.select_character(character.character_number as usize);
+    }
+
+    fn fail(&mut self, error: HeadlessClientError) {
+        if self.networking_system.is_login_server_connected() {
+            self.networking_system.disconnect_from_login_server();
+        }
+
+        if self.networking_system.is_character_server_connected() {
+            self.networking_system.disconnect_from_character_server();
+        }
+
+        if self.networking_system.is_map_server_connected() {
+            self.networking_system.disconnect_from_map_server();
+        }
+
+        self.state = HeadlessClientState::Offline;
+        self.error = Some(error);
+    }
+
+    fn handle_event(&mut self, event: &NetworkEvent) {
+        match event {
+            NetworkEvent::LoginServerConnected {
+                character_servers,
+                login_data,
+            } => {
+                self.networking_system.disconnect_from_login_server();
+
+                let Some(character_server) = character_servers.first() else {
+                    self.fail(HeadlessClientError::NoCharacterServer);
+                    return;
+                };
+
+                self.networking_system
+                    .connect_to_character_server(self.config.packet_version, login_data, character_server.clone());
+                self.login_data = Some(*login_data);
+                self.state = HeadlessClientState::SelectingCharacter;
+            }
+            NetworkEvent::LoginServerConnectionFailed { message, .. } => {
+                self.fail(HeadlessClientError::LoginFailed(message));
+            }
+            NetworkEvent::CharacterServerConnected { .. } => {
+                let _ = self.networking_system.request_character_list();
+            }
+            NetworkEvent::CharacterServerConnectionFailed { message, .. } => {
+                self.fail(HeadlessClientError::CharacterServerLoginFailed(message));
+            }
+            NetworkEvent::CharacterList { characters } => {
+                self.characters = characters.clone();
+
+                // With a pincode the character can only be selected once the server
+                // accepted it.
+                if self.config.pincode.is_none() {
+                    self.select_configured_character();
+                }
+            }
+            NetworkEvent::PincodeRequested { seed, request, rejection } => {
+                match (rejection, request, &self.config.pincode, self.login_data) {
+                    (Some(rejection), ..) => self.fail(HeadlessClientError::PincodeRejected(*rejection)),
+                    (None, PincodeRequest::Check, Some(pincode), Some(login_data)) => {
+                        let _ = self.networking_system.check_pincode(login_data.account_id, *seed, pincode);
+                    }
+                    _ => self.fail(HeadlessClientError::PincodeRequired),
+                }
+            }
+            NetworkEvent::PincodeAccepted => {
+                if self.config.pincode.is_some() {
+                    self.select_configured_character();
+                }
+            }
+            NetworkEvent::CharacterSelectionFailed { message, .. } => {
+                self.fail(HeadlessClientError::CharacterSelectionFailed(message));
+            }
+            NetworkEvent::CharacterSelected { login_data } => {
+                let Some(login_server_login_data) = self.login_data else {
+                    return;
+                };
+
+                let Some(character_information) = self
+                    .characters
+                    .iter()
+                    .find(|character| character.character_id == login_data.character_id)
+                    .cloned()
+                else {
+                    return;
+                };
+
+                self.networking_system.disconnect_from_character_server();
+                self.networking_system
+                    .connect_to_map_server(self.config.packet_version, &login_server_login_data, *login_data);
+                let _ = self.networking_system.request_client_tick();
+
+                self.player = Some(LocalPlayer {
+                    entity_id: EntityId(login_server_login_data.account_id.0),
+                    character_information,
+                    map_name: String::new(),
+                    position: TilePosition { x: 0, y: 0 },
+                    destination: None,
+                });
+                self.state = HeadlessClientState::EnteringMap;
+            }
+            NetworkEvent::LoginServerDisconnected {
+                reason: DisconnectReason::ConnectionError,
+            }
+            | NetworkEvent::CharacterServerDisconnected {
+                reason: DisconnectReason::ConnectionError,
+            }
+            | NetworkEvent::MapServerDisconnected {
+                reason: DisconnectReason::ConnectionError,
+            } => {
+                self.fail(HeadlessClientError::Disconnected);
+            }
+            NetworkEvent::ChangeMap { map_name, position } => {
+                if let Some(player) = &mut self.player {
+                    player.map_name = map_name.clone();
+                    player.position = *position;
+                    player.destination = None;
+                }
+
+                self.arrival = None;
+
+                // There is nothing to load, so we can tell the server right away.
+                self.entities.clear();
+                let _ = self.networking_system.map_loaded();
+                self.state = HeadlessClientState::InGame;
+            }
+            NetworkEvent::PlayerMove { origin, destination, .. } => {
+                if let Some(player) = &mut self.player {
+                    let movement_speed = player.character_information.movement_speed.max(0) as u64;
+
+                    player.position = origin.tile_position();
+                    player.destination = Some(destination.tile_position());
+                    self.arrival = Some(Instant::now() + walk_duration(player.position, destination.tile_position(), movement_speed));
+                }
+            }
+            NetworkEvent::AttackFailed { player_position, .. } => {
+                // The server corrects our position if the target is out of range.
+                if let Some(player) = &mut self.player {
+                    player.position = *player_position;
+                    player.destination = None;
+                }
+
+                self.arrival = None;
+            }
+            NetworkEvent::AddEntity { entity_data } => {
+                self.entities.insert(entity_data.entity_id, entity_data.clone());
+            }
+            NetworkEvent::RemoveEntity { entity_id, .. } => {
+                self.entities.remove(entity_id);
+            }
+            NetworkEvent::EntityMove {
+                entity_id, destination, ..
+            } => {
+                if let Some(entity_data) = self.entities.get_mut(entity_id) {
+                    entity_data.destination = Some(*destination);
+                }
+            }
+            NetworkEvent::UpdateEntityHealth {
+                entity_id,
+                health_points,
+                maximum_health_points,
+            } => {
+                if let Some(entity_data) = self.entities.get_mut(entity_id) {
+                    entity_data.health_points = *health_points as i32;
+                    entity_data.maximum_health_points = *maximum_health_points as i32;
+                }
+            }
+            NetworkEvent::ChatMessage { text, color } => {
+                self.chat_messages.push(ChatEntry {
+                    text: text.clone(),
+                    color: *color,
+                });
+            }
+            NetworkEvent::SetInventory { items } => {
+                self.inventory = items.clone();
+            }
+            NetworkEvent::IventoryItemAdded { item } => {
+                let existing = self.inventory.iter_mut().find(|existing| existing.index == item.index);
+
+                match (existing, &item.details) {
+                    (
+                        Some(InventoryItem {
+                            details: InventoryItemDetails::Regular { amount, .. },
+                            ..
+                        }),
+                        InventoryItemDetails::Regular { amount: added, .. },
+                    ) => *amount += added,
+                    _ => self.inventory.push(item.clone()),
+                }
+            }
+            NetworkEvent::InventoryItemRemoved { index, amount, .. } => {
+                let Some(position) = self.inventory.iter().position(|item| item.index == *index) else {
+                    return;
+                };
+
+                let remove = match &mut self.inventory[position].details {
+                    InventoryItemDetails::Regular { amount: current, .. } => {
+                        *current = current.saturating_sub(*amount);
+                        *current == 0
+                    }
+                    InventoryItemDetails::Equippable { .. } => true,
+                };
+
+                if remove {
+                    self.inventory.remove(position);
+                }
+            }
+            NetworkEvent::LoggedOut => {
+                self.networking_system.disconnect_from_map_server();
+                self.state = HeadlessClientState::Offline;
+                self.player = None;
+                self.arrival = None;
+                self.entities.clear();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Time it takes to walk from `origin` to `destination` without obstacles.
+/// The movement speed is the time in milliseconds it takes to walk one tile.
+fn walk_duration(origin: TilePosition, destination: TilePosition, movement_speed: u64) -> Duration {
+    let distance_x = u64::from(origin.x.abs_diff(destination.x));
+    let distance_y = u64::from(origin.y.abs_diff(destination.y));
+
+    let diagonal_steps = distance_x.min(distance_y);
+    let straight_steps = distance_x.max(distance_y) - diagonal_steps;
+    let (cost, scale) = DIAGONAL_STEP_COST;
+
+    Duration::from_millis(straight_steps * movement_speed + diagonal_steps * movement_speed * cost / scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::time::Duration;
+
+    use ragnarok_bytes::ByteWriter;
+    use ragnarok_packets::{LoginFailedPacket, LoginFailedReason, PacketExt, TilePosition};
+
+    use super::{HeadlessClient, HeadlessClientConfig, HeadlessClientError, HeadlessClientState, walk_duration};
+    use crate::{NetworkSession, NetworkingSystem, ReplayPacing, SessionDirection, SessionRecord, SessionServer, SupportedPacketVersion};
+
+    fn config() -> HeadlessClientConfig {
+        let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 6900);
+        HeadlessClientConfig::new(SupportedPacketVersion::_20220406, address, "user", "password", "character")
+    }
+
+    #[tokio::test]
+    async fn login_failure() {
+        let mut byte_writer = ByteWriter::new();
+        LoginFailedPacket {
+            reason: LoginFailedReason::ServerClosed,
+        }
+        .packet_to_bytes(&mut byte_writer)
+        .unwrap();
+
+        let session = NetworkSession::new(vec![SessionRecord {
+            timestamp: Duration::ZERO,
+            server: SessionServer::Login,
+            direction: SessionDirection::Incoming,
+            data: byte_writer.into_inner(),
+        }]);
+
+        let (networking_system, event_buffer) = NetworkingSystem::spawn_replay(session, ReplayPacing::Unpaced);
+        let mut client = HeadlessClient::with_networking_system(networking_system, event_buffer, config());
+
+        let result = tokio::time::timeout(Duration::from_secs(5), client.enter_game()).await.unwrap();
+
+        assert_eq!(result, Err(HeadlessClientError::LoginFailed("Server closed")));
+        assert_eq!(client.state(), HeadlessClientState::Offline);
+    }
+
+    #[tokio::test]
+    async fn enter_game_timeout() {
+        let (networking_system, event_buffer) = NetworkingSystem::spawn_replay(NetworkSession::new(Vec::new()), ReplayPacing::Unpaced);
+        let mut config = config();
+        config.action_timeout = Duration::from_millis(100);
+        let mut client = HeadlessClient::with_networking_system(networking_system, event_buffer, config);
+
+        let result = tokio::time::timeout(Duration::from_secs(5), client.enter_game()).await.unwrap();
+
+        assert_eq!(result, Err(HeadlessClientError::Timeout));
+        assert_eq!(client.state(), HeadlessClientState::Offline);
+    }
+
+    #[test]
+    fn walk_duration_diagonal() {
+        let origin = TilePosition { x: 10, y: 10 };
+
+        assert_eq!(
+            walk_duration(origin, TilePosition { x: 15, y: 10 }, 150),
+            Duration::from_millis(750)
+        );
+        assert_eq!(
+            walk_duration(origin, TilePosition { x: 7, y: 13 }, 150),
+            Duration::from_millis(630)
+        );
+        assert_eq!(
+            walk_duration(origin, TilePosition { x: 12, y: 5 }, 100),
+            Duration::from_millis(580)
+        );
+    }
+}
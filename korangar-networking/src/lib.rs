@@ -1,13 +1,22 @@
 #![cfg_attr(feature = "interface", feature(impl_trait_in_assoc_type))]
 #![cfg_attr(feature = "interface", feature(negative_impls))]
 
+mod achievement;
 mod entity;
 mod event;
+mod guild;
+mod headless;
 mod hotkey;
 mod items;
+mod mail;
 mod message;
 mod packet_versions;
+mod party;
+mod pincode;
+mod quest;
+mod replay;
 mod server;
+mod session;
 
 use std::net::{IpAddr, SocketAddr};
 use std::sync::{Arc, Mutex};
@@ -20,23 +29,34 @@ use ragnarok_bytes::encoding::UTF_8;
 use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes};
 use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
 use ragnarok_packets::*;
+use replay::{Replay, ReplaySource};
 use server::{ServerConnectCommand, ServerConnection};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::JoinHandle;
 
-pub use self::entity::EntityData;
+pub use self::achievement::{AchievementLevel, AchievementProgress};
+pub use self::entity::{EntityData, GroundItemData};
 pub use self::event::{DisconnectReason, NetworkEvent};
+pub use self::guild::{GuildInformation, GuildMember, GuildNotice};
+pub use self::headless::{ChatEntry, HeadlessClient, HeadlessClientConfig, HeadlessClientError, HeadlessClientState, LocalPlayer};
 pub use self::hotkey::HotkeyState;
 pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
+pub use self::mail::MailSummary;
 pub use self::message::MessageColor;
 pub use self::packet_versions::SupportedPacketVersion;
+pub use self::party::PartyMember;
+pub use self::pincode::{PINCODE_LENGTH, PincodeRejection, PincodeRequest};
+pub use self::quest::{QuestLogEntry, QuestObjective};
+pub use self::replay::ReplayPacing;
 pub use self::server::{
     CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
+pub use self::session::{NetworkSession, SessionDirection, SessionFileError, SessionRecord, SessionRecorder, SessionServer};
 use crate::server::NetworkTaskError;
+use crate::session::ConnectionRecorder;
 
 /// Buffer for networking events. This struct exists to reduce heap allocations
 /// and is purely an optimization.
@@ -48,6 +68,12 @@ impl NetworkEventBuffer {
     }
 }
 
+/// Stream that a server connection reads from and writes to. This is either a
+/// TCP stream or the replay of a recorded session.
+trait ConnectionStream: AsyncRead + AsyncWrite + Unpin {}
+
+impl<T> ConnectionStream for T where T: AsyncRead + AsyncWrite + Unpin {}
+
 /// Simple time synchronization using the Cristian's algorithm.
 struct TimeSynchronization {
     request_send: Instant,
@@ -90,11 +116,21 @@ pub struct NetworkingSystem<Callback> {
     character_server_connection: ServerConnection,
     map_server_connection: ServerConnection,
     packet_callback: Callback,
+    session_recorder: Option<SessionRecorder>,
 }
 
 impl NetworkingSystem<NoPacketCallback> {
     pub fn spawn() -> (Self, NetworkEventBuffer) {
-        let (command_sender, time_synchronization) = Self::spawn_networking_thread(NoPacketCallback);
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(NoPacketCallback, None);
+        Self::inner_new(command_sender, time_synchronization, NoPacketCallback)
+    }
+
+    /// Spawn a networking system that replays a recorded session instead of
+    /// connecting to a server. Connecting to a server will replay the packets
+    /// received from that server, and all packets sent are discarded.
+    pub fn spawn_replay(session: NetworkSession, pacing: ReplayPacing) -> (Self, NetworkEventBuffer) {
+        let replay = Replay::new(session, pacing);
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(NoPacketCallback, Some(replay));
         Self::inner_new(command_sender, time_synchronization, NoPacketCallback)
     }
 }
@@ -115,6 +151,7 @@ where
             character_server_connection: ServerConnection::Disconnected,
             map_server_connection: ServerConnection::Disconnected,
             packet_callback,
+            session_recorder: None,
         };
         let event_buffer = NetworkEventBuffer(Vec::new());
 
@@ -122,11 +159,24 @@ where
     }
 
     pub fn spawn_with_callback(packet_callback: Callback) -> (Self, NetworkEventBuffer) {
-        let (command_sender, time_synchronization) = Self::spawn_networking_thread(packet_callback.clone());
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(packet_callback.clone(), None);
         Self::inner_new(command_sender, time_synchronization, packet_callback)
     }
 
-    fn spawn_networking_thread(packet_callback: Callback) -> (UnboundedSender<ServerConnectCommand>, Arc<Mutex<TimeSynchronization>>) {
+    pub fn spawn_replay_with_callback(
+        session: NetworkSession,
+        pacing: ReplayPacing,
+        packet_callback: Callback,
+    ) -> (Self, NetworkEventBuffer) {
+        let replay = Replay::new(session, pacing);
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(packet_callback.clone(), Some(replay));
+        Self::inner_new(command_sender, time_synchronization, packet_callback)
+    }
+
+    fn spawn_networking_thread(
+        packet_callback: Callback,
+        replay: Option<Replay>,
+    ) -> (UnboundedSender<ServerConnectCommand>, Arc<Mutex<TimeSynchronization>>) {
         let (command_sender, mut command_receiver) = tokio::sync::mpsc::unbounded_channel::<ServerConnectCommand>();
         let time_synchronization = Arc::new(Mutex::new(TimeSynchronization::new()));
         let thread_time_synchronization = Arc::clone(&time_synchronization);
@@ -149,6 +199,7 @@ where
                             action_receiver,
                             event_sender,
                             packet_version,
+                            session_recorder,
                         } => {
                             if let Some(handle) = login_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
@@ -158,6 +209,8 @@ where
                             let packet_handler = Self::create_login_server_packet_handler(packet_callback.clone(), packet_version).unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
+                                replay.as_ref().map(|replay| replay.source(SessionServer::Login)),
+                                session_recorder.map(|session_recorder| session_recorder.for_server(SessionServer::Login)),
                                 action_receiver,
                                 event_sender,
                                 packet_handler,
@@ -174,6 +227,7 @@ where
                             action_receiver,
                             event_sender,
                             packet_version,
+                            session_recorder,
                         } => {
                             if let Some(handle) = character_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
@@ -184,6 +238,8 @@ where
                                 Self::create_character_server_packet_handler(packet_callback.clone(), packet_version).unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
+                                replay.as_ref().map(|replay| replay.source(SessionServer::Character)),
+                                session_recorder.map(|session_recorder| session_recorder.for_server(SessionServer::Character)),
                                 action_receiver,
                                 event_sender,
                                 packet_handler,
@@ -200,6 +256,7 @@ where
                             action_receiver,
                             event_sender,
                             packet_version,
+                            session_recorder,
                         } => {
                             if let Some(handle) = map_server_task_handle.take() {
                                 // TODO: Maybe add a timeout here? Maybe handle Result?
@@ -209,6 +266,8 @@ where
                             let packet_handler = Self::create_map_server_packet_handler(packet_callback.clone(), packet_version).unwrap();
                             let handle = local_set.spawn_local(Self::handle_server_connection(
                                 address,
+                                replay.as_ref().map(|replay| replay.source(SessionServer::Map)),
+                                session_recorder.map(|session_recorder| session_recorder.for_server(SessionServer::Map)),
                                 action_receiver,
                                 event_sender,
                                 packet_handler,
@@ -280,6 +339,8 @@ where
     #[allow(clippy::too_many_arguments)]
     async fn handle_server_connection<PingPacket>(
         address: SocketAddr,
+        replay_source: Option<ReplaySource>,
+        connection_recorder: Option<ConnectionRecorder>,
         mut action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         mut packet_handler: PacketHandler<NetworkEventList, (), Callback>,
@@ -295,7 +356,10 @@ where
         PingPacket: Packet + ClientPacket,
         Callback: PacketCallback,
     {
-        let mut stream = TcpStream::connect(address).await.map_err(|_| NetworkTaskError::FailedToConnect)?;
+        let mut stream: Box<dyn ConnectionStream> = match replay_source {
+            Some(replay_source) => Box::new(replay_source.into_stream()),
+            None => Box::new(TcpStream::connect(address).await.map_err(|_| NetworkTaskError::FailedToConnect)?),
+        };
         let mut interval = tokio::time::interval(ping_frequency);
         let mut buffer = [0u8; 8192];
         let mut cut_off_buffer_base = 0;
@@ -311,6 +375,10 @@ where
                         break Ok(());
                     };
 
+                    if let Some(connection_recorder) = &connection_recorder {
+                        connection_recorder.outgoing(&action);
+                    }
+
                     stream.write_all(&action).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
                 }
                 // Receive some packets from the server.
@@ -326,6 +394,10 @@ where
                         break Err(NetworkTaskError::ConnectionClosed);
                     }
 
+                    if let Some(connection_recorder) = &connection_recorder {
+                        connection_recorder.incoming(&buffer[cut_off_buffer_base..cut_off_buffer_base + received_bytes]);
+                    }
+
                     let data = &buffer[..cut_off_buffer_base + received_bytes];
                     let mut byte_reader = ByteReader::without_metadata(data);
                     byte_reader.set_encoding(UTF_8);
@@ -379,6 +451,11 @@ where
                 // Send a keep-alive packet to the server.
                 _ = interval.tick() => {
                     ping_factory(&time_synchronization).packet_to_bytes(&mut byte_writer).unwrap();
+
+                    if let Some(connection_recorder) = &connection_recorder {
+                        connection_recorder.outgoing(byte_writer.as_slice());
+                    }
+
                     stream.write_all(byte_writer.as_slice()).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
                     byte_writer.clear();
                 }
@@ -386,6 +463,12 @@ where
         }
     }
 
+    /// Record the traffic of all connections established from now on. Passing
+    /// `None` stops recording new connections.
+    pub fn set_session_recorder(&mut self, session_recorder: Option<SessionRecorder>) {
+        self.session_recorder = session_recorder;
+    }
+
     pub fn connect_to_login_server(
         &mut self,
         packet_version: SupportedPacketVersion,
@@ -406,6 +489,7 @@ where
                 action_receiver,
                 event_sender,
                 packet_version,
+                session_recorder: self.session_recorder.clone(),
             })
             .expect("network thread dropped");
 
@@ -447,6 +531,7 @@ where
                 action_receiver,
                 event_sender,
                 packet_version,
+                session_recorder: self.session_recorder.clone(),
             })
             .expect("network thread dropped");
 
@@ -493,6 +578,7 @@ where
                 action_receiver,
                 event_sender,
                 packet_version,
+                session_recorder: self.session_recorder.clone(),
             })
             .expect("network thread dropped");
 
@@ -594,6 +680,7 @@ where
         let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
 
         match packet_version {
+            SupportedPacketVersion::_20200401 => packet_versions::version_20200401::register_login_server_packets(&mut packet_handler)?,
             SupportedPacketVersion::_20220406 => packet_versions::version_20220406::register_login_server_packets(&mut packet_handler)?,
         }
 
@@ -607,6 +694,7 @@ where
         let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
 
         match packet_version {
+            SupportedPacketVersion::_20200401 => packet_versions::version_20200401::register_character_server_packets(&mut packet_handler)?,
             SupportedPacketVersion::_20220406 => packet_versions::version_20220406::register_character_server_packets(&mut packet_handler)?,
         }
 
@@ -620,6 +708,7 @@ where
         let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
 
         match packet_version {
+            SupportedPacketVersion::_20200401 => packet_versions::version_20200401::register_map_server_packets(&mut packet_handler)?,
             SupportedPacketVersion::_20220406 => packet_versions::version_20220406::register_map_server_packets(&mut packet_handler)?,
         }
 
@@ -628,13 +717,56 @@ where
 
     pub fn request_character_list(&mut self) -> Result<(), NotConnectedError> {
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(RequestCharacterListPacket::default()),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(RequestCharacterListPacket::default())
+            }
         }
     }
 
     pub fn select_character(&mut self, character_slot: usize) -> Result<(), NotConnectedError> {
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(SelectCharacterPacket::new(character_slot as u8)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(SelectCharacterPacket::new(character_slot as u8))
+            }
+        }
+    }
+
+    /// Send the pincode entered by the player. `seed` is the seed of the last
+    /// [`NetworkEvent::PincodeRequested`].
+    pub fn check_pincode(&mut self, account_id: AccountId, seed: u32, pincode: &str) -> Result<(), NotConnectedError> {
+        let pincode = pincode::encode_pincode(seed, pincode);
+
+        match self.character_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(CheckPincodePacket::new(account_id, pincode))
+            }
+        }
+    }
+
+    pub fn set_pincode(&mut self, account_id: AccountId, seed: u32, pincode: &str) -> Result<(), NotConnectedError> {
+        let pincode = pincode::encode_pincode(seed, pincode);
+
+        match self.character_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(SetPincodePacket::new(account_id, pincode))
+            }
+        }
+    }
+
+    pub fn change_pincode(
+        &mut self,
+        account_id: AccountId,
+        seed: u32,
+        old_pincode: &str,
+        new_pincode: &str,
+    ) -> Result<(), NotConnectedError> {
+        let old_pincode = pincode::encode_pincode(seed, old_pincode);
+        let new_pincode = pincode::encode_pincode(seed, new_pincode);
+
+        match self.character_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(ChangePincodePacket::new(account_id, old_pincode, new_pincode))
+            }
         }
     }
 
@@ -645,9 +777,9 @@ where
         let sex = Sex::Male;
 
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(CreateCharacterPacket::new(
-                name, slot as u8, hair_color, hair_style, start_job, sex,
-            )),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_character_server_packet(
+                CreateCharacterPacket::new(name, slot as u8, hair_color, hair_style, start_job, sex),
+            ),
         }
     }
 
@@ -655,13 +787,15 @@ where
         let email = "a@a.com".to_string();
 
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(DeleteCharacterPacket::new(character_id, email)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(DeleteCharacterPacket::new(character_id, email))
+            }
         }
     }
 
     pub fn switch_character_slot(&mut self, origin_slot: usize, destination_slot: usize) -> Result<(), NotConnectedError> {
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
                 self.send_character_server_packet(SwitchCharacterSlotPacket::new(origin_slot as u16, destination_slot as u16))
             }
         }
@@ -669,7 +803,9 @@ where
 
     pub fn map_loaded(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(MapLoadedPacket::default()),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MapLoadedPacket::default())
+            }
         }
     }
 
@@ -681,43 +817,57 @@ where
             .unwrap_or(100);
 
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestServerTickPacket::new(ClientTick(client_tick))),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestServerTickPacket::new(ClientTick(client_tick)))
+            }
         }
     }
 
     pub fn respawn(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RestartPacket::new(RestartType::Respawn)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RestartPacket::new(RestartType::Respawn))
+            }
         }
     }
 
     pub fn log_out(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RestartPacket::new(RestartType::Disconnect)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RestartPacket::new(RestartType::Disconnect))
+            }
         }
     }
 
     pub fn player_move(&mut self, position: WorldPosition) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestPlayerMovePacket::new(position)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestPlayerMovePacket::new(position))
+            }
         }
     }
 
     pub fn warp_to_map(&mut self, map_name: String, position: TilePosition) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestWarpToMapPacket::new(map_name, position)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestWarpToMapPacket::new(map_name, position))
+            }
         }
     }
 
     pub fn entity_details(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestDetailsPacket::new(entity_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestDetailsPacket::new(entity_id))
+            }
         }
     }
 
     pub fn player_attack(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestActionPacket::new(entity_id, Action::Attack)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestActionPacket::new(entity_id, Action::Attack))
+            }
         }
     }
 
@@ -725,49 +875,89 @@ where
         let message = format!("{} : {}", player_name, text);
 
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(GlobalMessagePacket::new(message)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(GlobalMessagePacket::new(message))
+            }
+        }
+    }
+
+    pub fn send_emotion(&mut self, emotion: u8) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestEmotionPacket::new(emotion))
+            }
         }
     }
 
     pub fn start_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(StartDialogPacket::new(npc_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(StartDialogPacket::new(npc_id))
+            }
         }
     }
 
     pub fn next_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(NextDialogPacket::new(npc_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(NextDialogPacket::new(npc_id))
+            }
         }
     }
 
     pub fn close_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(CloseDialogPacket::new(npc_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseDialogPacket::new(npc_id))
+            }
         }
     }
 
     pub fn choose_dialog_option(&mut self, npc_id: EntityId, option: i8) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(ChooseDialogOptionPacket::new(npc_id, option)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ChooseDialogOptionPacket::new(npc_id, option))
+            }
         }
     }
 
     pub fn request_item_equip(&mut self, item_index: InventoryIndex, equip_position: EquipPosition) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestEquipItemPacket::new(item_index, equip_position)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestEquipItemPacket::new(item_index, equip_position))
+            }
         }
     }
 
     pub fn request_item_unequip(&mut self, item_index: InventoryIndex) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestUnequipItemPacket::new(item_index)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestUnequipItemPacket::new(item_index))
+            }
+        }
+    }
+
+    pub fn pick_up_item(&mut self, ground_item_id: GroundItemId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestPickUpItemPacket::new(ground_item_id))
+            }
+        }
+    }
+
+    pub fn drop_item(&mut self, item_index: InventoryIndex, count: u16) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestDropItemPacket::new(item_index, count))
+            }
         }
     }
 
     pub fn cast_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, entity_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(UseSkillAtIdPacket::new(skill_level, skill_id, entity_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(UseSkillAtIdPacket::new(skill_level, skill_id, entity_id))
+            }
         }
     }
 
@@ -778,7 +968,7 @@ where
         target_position: TilePosition,
     ) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
                 self.send_map_server_packet(UseSkillOnGroundPacket::new(skill_level, skill_id, target_position))
             }
         }
@@ -791,57 +981,472 @@ where
         entity_id: EntityId,
     ) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(StartUseSkillPacket::new(skill_id, skill_level, entity_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(StartUseSkillPacket::new(skill_id, skill_level, entity_id))
+            }
         }
     }
 
     pub fn stop_channeling_skill(&mut self, skill_id: SkillId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(EndUseSkillPacket::new(skill_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(EndUseSkillPacket::new(skill_id))
+            }
         }
     }
 
     pub fn add_friend(&mut self, name: String) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(AddFriendPacket::new(name)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddFriendPacket::new(name))
+            }
         }
     }
 
     pub fn remove_friend(&mut self, account_id: AccountId, character_id: CharacterId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RemoveFriendPacket::new(account_id, character_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RemoveFriendPacket::new(account_id, character_id))
+            }
         }
     }
 
     pub fn reject_friend_request(&mut self, account_id: AccountId, character_id: CharacterId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(FriendRequestResponsePacket::new(
-                account_id,
-                character_id,
-                FriendRequestResponse::Reject,
-            )),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(
+                FriendRequestResponsePacket::new(account_id, character_id, FriendRequestResponse::Reject),
+            ),
         }
     }
 
     pub fn accept_friend_request(&mut self, account_id: AccountId, character_id: CharacterId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(FriendRequestResponsePacket::new(
-                account_id,
-                character_id,
-                FriendRequestResponse::Accept,
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(
+                FriendRequestResponsePacket::new(account_id, character_id, FriendRequestResponse::Accept),
+            ),
+        }
+    }
+
+    pub fn create_party(&mut self, party_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(CreatePartyPacket::new(
+                party_name,
+                PartyItemPickupRule::EachTake,
+                PartyItemDivisionRule::EachTake,
+            )),
+        }
+    }
+
+    pub fn invite_to_party(&mut self, character_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(InviteToPartyPacket::new(character_name))
+            }
+        }
+    }
+
+    pub fn reject_party_invitation(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Reject))
+            }
+        }
+    }
+
+    pub fn accept_party_invitation(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Accept))
+            }
+        }
+    }
+
+    pub fn leave_party(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(LeavePartyPacket::default())
+            }
+        }
+    }
+
+    pub fn expel_party_member(&mut self, account_id: AccountId, character_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ExpelPartyMemberPacket::new(account_id, character_name))
+            }
+        }
+    }
+
+    pub fn change_party_leader(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ChangePartyLeaderPacket::new(account_id))
+            }
+        }
+    }
+
+    pub fn send_party_message(&mut self, player_name: &str, text: &str) -> Result<(), NotConnectedError> {
+        let message = format!("{} : {}", player_name, text);
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(PartyMessagePacket::new(message))
+            }
+        }
+    }
+
+    pub fn request_guild_information(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestGuildInformationPacket::new(GuildInformationRequest::GuildInformation))
+            }
+        }
+    }
+
+    pub fn request_guild_members(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestGuildInformationPacket::new(GuildInformationRequest::MemberList))
+            }
+        }
+    }
+
+    pub fn request_guild_positions(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestGuildInformationPacket::new(GuildInformationRequest::Positions))
+            }
+        }
+    }
+
+    pub fn request_guild_emblem(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestGuildEmblemPacket::new(guild_id))
+            }
+        }
+    }
+
+    pub fn request_character_name(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestCharacterNamePacket::new(character_id))
+            }
+        }
+    }
+
+    pub fn create_guild(&mut self, character_id: CharacterId, guild_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CreateGuildPacket::new(character_id, guild_name))
+            }
+        }
+    }
+
+    pub fn invite_to_guild(&mut self, character_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(InviteToGuildPacket::new(character_name))
+            }
+        }
+    }
+
+    pub fn reject_guild_invitation(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(GuildInviteResponsePacket::new(guild_id, GuildInviteResponse::Reject))
+            }
+        }
+    }
+
+    pub fn accept_guild_invitation(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(GuildInviteResponsePacket::new(guild_id, GuildInviteResponse::Accept))
+            }
+        }
+    }
+
+    pub fn leave_guild(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        character_id: CharacterId,
+        reason: String,
+    ) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(LeaveGuildPacket::new(guild_id, account_id, character_id, reason))
+            }
+        }
+    }
+
+    pub fn expel_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        character_id: CharacterId,
+        reason: String,
+    ) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ExpelGuildMemberPacket::new(guild_id, account_id, character_id, reason))
+            }
+        }
+    }
+
+    pub fn change_guild_notice(&mut self, guild_id: GuildId, subject: String, notice: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ChangeGuildNoticePacket::new(guild_id, subject, notice))
+            }
+        }
+    }
+
+    pub fn send_guild_message(&mut self, player_name: &str, text: &str) -> Result<(), NotConnectedError> {
+        let message = format!("{} : {}", player_name, text);
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(GuildMessagePacket::new(message))
+            }
+        }
+    }
+
+    pub fn request_trade(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestTradePacket::new(account_id))
+            }
+        }
+    }
+
+    pub fn reject_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(TradeRequestResponsePacket::new(TradeRequestResponse::Reject))
+            }
+        }
+    }
+
+    pub fn accept_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(TradeRequestResponsePacket::new(TradeRequestResponse::Accept))
+            }
+        }
+    }
+
+    pub fn add_trade_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        // The packet uses the raw index, since an index of 0 is used for zeny.
+        let raw_index = index.0 + 2;
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddTradeItemPacket::new(raw_index, amount))
+            }
+        }
+    }
+
+    pub fn add_trade_zeny(&mut self, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddTradeItemPacket::new(0, amount))
+            }
+        }
+    }
+
+    pub fn lock_trade(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(LockTradePacket::default())
+            }
+        }
+    }
+
+    pub fn cancel_trade(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CancelTradePacket::default())
+            }
+        }
+    }
+
+    pub fn commit_trade(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CommitTradePacket::default())
+            }
+        }
+    }
+
+    pub fn move_item_to_storage(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MoveItemToStoragePacket::new(index, amount))
+            }
+        }
+    }
+
+    pub fn move_item_from_storage(&mut self, index: StorageIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MoveItemFromStoragePacket::new(index, amount))
+            }
+        }
+    }
+
+    pub fn close_storage(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseStoragePacket::default())
+            }
+        }
+    }
+
+    /// Request all mails of the given type. Character, account and returned
+    /// mails are listed separately, so each type needs to be requested.
+    pub fn open_mailbox(&mut self, open_type: MailOpenType) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(OpenMailboxPacket::new(open_type, MailId(0)))
+            }
+        }
+    }
+
+    /// Request all mails of the given type that are newer than
+    /// `newest_mail_id`.
+    pub fn refresh_mailbox(&mut self, open_type: MailOpenType, newest_mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RefreshMailboxPacket::new(open_type, newest_mail_id))
+            }
+        }
+    }
+
+    pub fn close_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseMailboxPacket::default())
+            }
+        }
+    }
+
+    pub fn read_mail(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ReadMailPacket::new(open_type, mail_id))
+            }
+        }
+    }
+
+    pub fn delete_mail(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(DeleteMailPacket::new(open_type, mail_id))
+            }
+        }
+    }
+
+    pub fn retrieve_mail_zeny(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RetrieveMailZenyPacket::new(mail_id, open_type))
+            }
+        }
+    }
+
+    pub fn retrieve_mail_items(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RetrieveMailItemsPacket::new(mail_id, open_type))
+            }
+        }
+    }
+
+    pub fn open_write_mail(&mut self, receiver_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(OpenWriteMailPacket::new(receiver_name))
+            }
+        }
+    }
+
+    pub fn cancel_write_mail(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CancelWriteMailPacket::default())
+            }
+        }
+    }
+
+    pub fn add_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddMailItemPacket::new(index, amount))
+            }
+        }
+    }
+
+    pub fn remove_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RemoveMailItemPacket::new(index, amount))
+            }
+        }
+    }
+
+    pub fn check_mail_receiver(&mut self, receiver_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CheckMailReceiverPacket::new(receiver_name))
+            }
+        }
+    }
+
+    /// Send the mail that is currently being written. The character id of the
+    /// receiver needs to be looked up with
+    /// [`check_mail_receiver`](Self::check_mail_receiver) first.
+    pub fn send_mail(
+        &mut self,
+        receiver_name: String,
+        receiver_character_id: CharacterId,
+        sender_name: String,
+        title: String,
+        text: String,
+        zeny: u64,
+    ) -> Result<(), NotConnectedError> {
+        // The lengths include the null terminator.
+        let title_length = title.len() as u16 + 1;
+        let text_length = text.len() as u16 + 1;
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(SendMailPacket::new(
+                receiver_name,
+                sender_name,
+                zeny,
+                title_length,
+                text_length,
+                receiver_character_id,
+                title,
+                text,
             )),
         }
     }
 
     pub fn set_hotkey_data(&mut self, tab: HotbarTab, index: HotbarSlot, hotkey_data: HotkeyData) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(SetHotkeyData2Packet::new(tab, index, hotkey_data)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SetHotkeyData2Packet::new(tab, index, hotkey_data))
+            }
         }
     }
 
     pub fn select_buy_or_sell(&mut self, shop_id: ShopId, buy_or_sell: BuyOrSellOption) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(SelectBuyOrSellPacket::new(shop_id, buy_or_sell)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SelectBuyOrSellPacket::new(shop_id, buy_or_sell))
+            }
         }
     }
 
@@ -855,34 +1460,60 @@ where
             .collect();
 
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(BuyShopItemsPacket::new(item_information)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(BuyShopItemsPacket::new(item_information))
+            }
         }
     }
 
     pub fn close_shop(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(CloseShopPacket::new()),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(CloseShopPacket::new()),
         }
     }
 
     pub fn sell_items(&mut self, items: Vec<SoldItemInformation>) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(SellItemsPacket { items }),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(SellItemsPacket { items }),
         }
     }
 
     pub fn request_stat_up(&mut self, stat_type: StatUpType) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestStatUpPacket::new(stat_type)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestStatUpPacket::new(stat_type))
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod packet_handlers {
-    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_bytes::{ByteReader, ByteWriter};
+    use ragnarok_packets::handler::{HandlerResult, NoPacketCallback};
+    use ragnarok_packets::{
+        EquipPosition, InventoryIndex, ItemId, ItemOptions, ItemPickupPacket, ItemPickupResult, LegacyItemPickupPacket, PacketExt,
+    };
+
+    use crate::{NetworkEvent, NetworkingSystem, SupportedPacketVersion};
 
-    use crate::{NetworkingSystem, SupportedPacketVersion};
+    fn process_map_server_packet(packet_version: SupportedPacketVersion, bytes: &[u8]) -> Vec<NetworkEvent> {
+        let mut packet_handler = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback, packet_version).unwrap();
+        let mut byte_reader = ByteReader::with_default_metadata(bytes);
+
+        match packet_handler.process_one(&mut byte_reader) {
+            HandlerResult::Ok(events) => events.0,
+            _ => panic!("failed to process packet"),
+        }
+    }
+
+    fn option_data() -> [ItemOptions; 5] {
+        std::array::from_fn(|_| ItemOptions {
+            index: 0,
+            value: 0,
+            parameter: 0,
+        })
+    }
 
     #[test]
     fn login_server() {
@@ -901,4 +1532,64 @@ mod packet_handlers {
         let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20220406);
         assert!(result.is_ok());
     }
+    #[test]
+    fn item_pickup_failed() {
+        let mut byte_writer = ByteWriter::new();
+        ItemPickupPacket {
+            index: InventoryIndex(2),
+            count: 1,
+            item_id: ItemId(501),
+            is_identified: 1,
+            is_broken: 0,
+            cards: [0; 4],
+            equip_position: EquipPosition::empty(),
+            item_type: 0,
+            result: ItemPickupResult::Overweight,
+            hire_expiration_date: 0,
+            bind_on_equip_type: 0,
+            option_data: option_data(),
+            favorite: 0,
+            look: 0,
+            refinement_level: 0,
+            enchantment_level: 0,
+        }
+        .packet_to_bytes(&mut byte_writer)
+        .unwrap();
+
+        let events = process_map_server_packet(SupportedPacketVersion::_20220406, &byte_writer.into_inner());
+
+        assert!(matches!(events.as_slice(), [NetworkEvent::ItemPickupFailed {
+            result: ItemPickupResult::Overweight
+        }]));
+    }
+
+    #[test]
+    fn legacy_item_pickup_failed() {
+        let mut byte_writer = ByteWriter::new();
+        LegacyItemPickupPacket {
+            index: InventoryIndex(2),
+            count: 1,
+            item_id: ItemId(501),
+            is_identified: 1,
+            is_broken: 0,
+            refinement_level: 0,
+            cards: [0; 4],
+            equip_position: EquipPosition::empty(),
+            item_type: 0,
+            result: ItemPickupResult::NoSpace,
+            hire_expiration_date: 0,
+            bind_on_equip_type: 0,
+            option_data: option_data(),
+            favorite: 0,
+            look: 0,
+        }
+        .packet_to_bytes(&mut byte_writer)
+        .unwrap();
+
+        let events = process_map_server_packet(SupportedPacketVersion::_20200401, &byte_writer.into_inner());
+
+        assert!(matches!(events.as_slice(), [NetworkEvent::ItemPickupFailed {
+            result: ItemPickupResult::NoSpace
+        }]));
+    }
 }
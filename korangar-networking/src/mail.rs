@@ -0,0 +1,35 @@
+use ragnarok_packets::{MailId, MailListEntry, MailOpenType};
+
+const MAIL_TYPE_ZENY: u8 = 0x02;
+const MAIL_TYPE_ITEM: u8 = 0x04;
+
+/// A mail in the mailbox of the player. The content of the mail is only sent
+/// once the mail is read.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailSummary {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub sender_name: String,
+    pub title: String,
+    pub is_read: bool,
+    pub has_zeny: bool,
+    pub has_items: bool,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+}
+
+impl From<MailListEntry> for MailSummary {
+    fn from(value: MailListEntry) -> Self {
+        Self {
+            mail_id: value.mail_id,
+            open_type: value.open_type,
+            sender_name: value.sender_name,
+            title: value.title,
+            is_read: value.is_read != 0,
+            has_zeny: value.mail_type & MAIL_TYPE_ZENY != 0,
+            has_items: value.mail_type & MAIL_TYPE_ITEM != 0,
+            expiration_time: value.expiration_time,
+        }
+    }
+}
@@ -1,7 +1,9 @@
+pub mod version_20200401;
 pub mod version_20220406;
 
 /// All supported packet versions.
 #[derive(Debug, Clone, Copy)]
 pub enum SupportedPacketVersion {
+    _20200401,
     _20220406,
 }
@@ -0,0 +1,64 @@
+//! Packets of the 2020-04-01 client. Most packets are the same as in
+//! [`version_20220406`](super::version_20220406), so only the ones with a
+//! different id or layout are handled here.
+
+use ragnarok_packets::handler::{DuplicateHandlerError, PacketCallback, PacketHandler};
+use ragnarok_packets::*;
+
+use super::version_20220406::{
+    InventoryItems, add_equippable_items, add_mail_item_result_event, item_pickup_event, mail_read_event,
+    register_shared_character_server_packets, register_shared_map_server_packets, storage_item_added_event, trade_item_added_event,
+};
+use crate::NetworkEvent;
+use crate::event::NetworkEventList;
+
+pub fn register_login_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    super::version_20220406::register_login_server_packets(packet_handler)
+}
+
+pub fn register_character_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    packet_handler.register(|packet: LegacyRequestCharacterListSuccessPacket| NetworkEvent::CharacterList {
+        characters: packet.character_information.into_iter().map(CharacterInformation::from).collect(),
+    })?;
+    packet_handler.register(|packet: LegacyCreateCharacterSuccessPacket| NetworkEvent::CharacterCreated {
+        character_information: packet.character_information.into(),
+    })?;
+
+    register_shared_character_server_packets(packet_handler)
+}
+
+pub fn register_map_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    let inventory_items = InventoryItems::default();
+
+    packet_handler.register({
+        let inventory_items = inventory_items.clone();
+        move |packet: LegacyEquippableItemListPacket| {
+            add_equippable_items(
+                &inventory_items,
+                packet.item_information.into_iter().map(EquippableItemInformation::from),
+            )
+        }
+    })?;
+    packet_handler.register(|packet: LegacyItemPickupPacket| item_pickup_event(packet.into()))?;
+    packet_handler.register(|packet: LegacyTradeItemAddedPacket| trade_item_added_event(packet.into()))?;
+    packet_handler.register(|packet: LegacyStorageItemAddedPacket| storage_item_added_event(packet.into()))?;
+    packet_handler.register(|packet: LegacyMailContentPacket| mail_read_event(packet.into()))?;
+    packet_handler.register(|packet: LegacyAddMailItemResultPacket| add_mail_item_result_event(packet.into()))?;
+
+    register_shared_map_server_packets(packet_handler, inventory_items)
+}
@@ -9,10 +9,327 @@ use ragnarok_packets::*;
 use crate::event::{NetworkEventList, NoNetworkEvents};
 use crate::items::ItemQuantity;
 use crate::{
-    CharacterServerLoginData, HotkeyState, InventoryItem, InventoryItemDetails, LoginServerLoginData, MessageColor, NetworkEvent,
-    NoMetadata, ShopItem, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    AchievementLevel, AchievementProgress, CharacterServerLoginData, GuildInformation, GuildMember, GuildNotice, HotkeyState,
+    InventoryItem, InventoryItemDetails, LoginServerLoginData, MailSummary, MessageColor, NetworkEvent, NoMetadata, PartyMember,
+    PincodeRejection, PincodeRequest, QuestLogEntry, ShopItem, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
 
+const INVENTORY_TYPE_INVENTORY: u8 = 0;
+const INVENTORY_TYPE_STORAGE: u8 = 2;
+
+const NAVIGATION_TARGET_POSITION: u8 = 0;
+const NAVIGATION_TARGET_POSITION_OTHER_MAP: u8 = 1;
+
+/// Create the item details for packets that don't contain the equip position
+/// of the item. In that case we have to rely on the item type (armor, weapon,
+/// pet armor and shadow gear) instead.
+fn item_details_from_type(
+    item_type: u8,
+    amount: u32,
+    is_identified: u8,
+    is_broken: u8,
+    option_data: [ItemOptions; 5],
+    refinement_level: u8,
+    enchantment_level: u8,
+) -> InventoryItemDetails {
+    match matches!(item_type, 4 | 5 | 8 | 12) {
+        false => InventoryItemDetails::Regular {
+            amount: amount as u16,
+            equipped_position: EquipPosition::empty(),
+            flags: {
+                let mut flags = RegularItemFlags::empty();
+                flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                flags
+            },
+        },
+        true => InventoryItemDetails::Equippable {
+            equip_position: EquipPosition::empty(),
+            equipped_position: EquipPosition::empty(),
+            bind_on_equip_type: 0,
+            w_item_sprite_number: 0,
+            option_count: option_data.len() as u8,
+            option_data,
+            refinement_level,
+            enchantment_level,
+            flags: {
+                let mut flags = EquippableItemFlags::empty();
+                flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                flags
+            },
+        },
+    }
+}
+
+/// Transient storage shared by all inventory handlers.
+///
+/// This is a bit of a workaround for the way that the inventory is
+/// sent. There is a single packet to start the inventory list,
+/// followed by an arbitary number of item packets, and in the
+/// end a sinle packet to mark the list as complete.
+pub(super) type InventoryItems = Rc<RefCell<Option<Vec<InventoryItem<NoMetadata>>>>>;
+
+pub(super) fn add_equippable_items(
+    inventory_items: &InventoryItems,
+    item_information: impl IntoIterator<Item = EquippableItemInformation>,
+) -> NoNetworkEvents {
+    inventory_items
+        .borrow_mut()
+        .as_mut()
+        .expect("Unexpected inventory packet")
+        .extend(item_information.into_iter().map(|item| {
+            let EquippableItemInformation {
+                index,
+                item_id,
+                item_type,
+                equip_position,
+                equipped_position,
+                slot,
+                hire_expiration_date,
+                bind_on_equip_type,
+                w_item_sprite_number,
+                option_count,
+                option_data,
+                refinement_level,
+                enchantment_level,
+                flags,
+            } = item;
+
+            InventoryItem {
+                index,
+                metadata: NoMetadata,
+                item_id,
+                item_type,
+                slot,
+                hire_expiration_date,
+                details: InventoryItemDetails::Equippable {
+                    equip_position,
+                    equipped_position,
+                    bind_on_equip_type,
+                    w_item_sprite_number,
+                    option_count,
+                    option_data,
+                    refinement_level,
+                    enchantment_level,
+                    flags,
+                },
+            }
+        }));
+    NoNetworkEvents
+}
+
+pub(super) fn item_pickup_event(packet: ItemPickupPacket) -> NetworkEvent {
+    let ItemPickupPacket {
+        index,
+        count,
+        item_id,
+        is_identified,
+        is_broken,
+        cards,
+        equip_position,
+        item_type,
+        result,
+        hire_expiration_date,
+        bind_on_equip_type,
+        option_data,
+        favorite,
+        look,
+        refinement_level,
+        enchantment_level,
+    } = packet;
+
+    if result != ItemPickupResult::Success {
+        return NetworkEvent::ItemPickupFailed { result };
+    }
+
+    // TODO: Not sure where to store these, since the *InventoryItem packets are not
+    // sending these either. We will certainly use them at some point though.
+    let _ = (favorite, look);
+
+    let details = match equip_position.is_empty() {
+        true => InventoryItemDetails::Regular {
+            amount: count,
+            equipped_position: equip_position,
+            flags: {
+                let mut flags = RegularItemFlags::empty();
+                flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                flags
+            },
+        },
+        false => InventoryItemDetails::Equippable {
+            equip_position,
+            equipped_position: EquipPosition::empty(),
+            bind_on_equip_type,
+            w_item_sprite_number: 0,
+            option_count: option_data.len() as u8,
+            option_data,
+            refinement_level,
+            enchantment_level,
+            flags: {
+                let mut flags = EquippableItemFlags::empty();
+                flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                flags
+            },
+        },
+    };
+
+    let item = InventoryItem {
+        metadata: NoMetadata,
+        index,
+        item_id,
+        item_type,
+        slot: cards,
+        hire_expiration_date,
+        details,
+    };
+
+    NetworkEvent::IventoryItemAdded { item }
+}
+
+pub(super) fn trade_item_added_event(packet: TradeItemAddedPacket) -> NetworkEvent {
+    let TradeItemAddedPacket {
+        item_id,
+        item_type,
+        amount,
+        is_identified,
+        is_broken,
+        cards,
+        option_data,
+        refinement_level,
+        enchantment_level,
+    } = packet;
+
+    if item_id == ItemId(0) {
+        return NetworkEvent::TradeZenyAdded { amount };
+    }
+
+    let details = item_details_from_type(
+        item_type,
+        amount,
+        is_identified,
+        is_broken,
+        option_data,
+        refinement_level,
+        enchantment_level,
+    );
+
+    // Items of the trade partner are not in the inventory of the player, so the
+    // index is only a placeholder.
+    let item = InventoryItem {
+        metadata: NoMetadata,
+        index: InventoryIndex(0),
+        item_id,
+        item_type,
+        slot: cards,
+        hire_expiration_date: 0,
+        details,
+    };
+
+    NetworkEvent::TradeItemAdded { item }
+}
+
+pub(super) fn storage_item_added_event(packet: StorageItemAddedPacket) -> NetworkEvent {
+    let StorageItemAddedPacket {
+        index,
+        amount,
+        item_id,
+        item_type,
+        is_identified,
+        is_broken,
+        cards,
+        option_data,
+        refinement_level,
+        enchantment_level,
+    } = packet;
+
+    let details = item_details_from_type(
+        item_type,
+        amount,
+        is_identified,
+        is_broken,
+        option_data,
+        refinement_level,
+        enchantment_level,
+    );
+
+    let item = InventoryItem {
+        metadata: NoMetadata,
+        index: InventoryIndex(index.0),
+        item_id,
+        item_type,
+        slot: cards,
+        hire_expiration_date: 0,
+        details,
+    };
+
+    NetworkEvent::StorageItemAdded { item }
+}
+
+pub(super) fn mail_read_event(packet: MailContentPacket) -> NetworkEvent {
+    let MailContentPacket {
+        open_type,
+        mail_id,
+        text,
+        zeny,
+        items,
+        ..
+    } = packet;
+
+    let items = items
+        .into_iter()
+        .map(|item| {
+            let details = item_details_from_type(
+                item.item_type,
+                item.amount as u32,
+                item.is_identified,
+                item.is_broken,
+                item.option_data,
+                item.refinement_level,
+                item.enchantment_level,
+            );
+
+            // Attached items are not in the inventory of the player, so the index is only
+            // a placeholder.
+            InventoryItem {
+                metadata: NoMetadata,
+                index: InventoryIndex(0),
+                item_id: item.item_id,
+                item_type: item.item_type,
+                slot: item.cards,
+                hire_expiration_date: 0,
+                details,
+            }
+        })
+        .collect();
+
+    NetworkEvent::MailRead {
+        mail_id,
+        open_type,
+        text,
+        zeny,
+        items,
+    }
+}
+
+pub(super) fn add_mail_item_result_event(packet: AddMailItemResultPacket) -> Vec<NetworkEvent> {
+    let text = match packet.result {
+        AddMailItemResult::Success => return vec![NetworkEvent::MailItemAccepted { index: packet.index }],
+        AddMailItemResult::Overweight => "The mail can't carry that much weight.",
+        AddMailItemResult::Failed => "The item can't be attached.",
+        AddMailItemResult::TooManyItems => "The mail can't carry any more items.",
+        AddMailItemResult::NotTradable => "The item can't be sent by mail.",
+    };
+
+    vec![
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        },
+        NetworkEvent::MailItemRejected { index: packet.index },
+    ]
+}
+
 pub fn register_login_server_packets<Callback>(
     packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
 ) -> Result<(), DuplicateHandlerError>
@@ -65,6 +382,24 @@ where
 pub fn register_character_server_packets<Callback>(
     packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
 ) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    packet_handler.register(|packet: RequestCharacterListSuccessPacket| NetworkEvent::CharacterList {
+        characters: packet.character_information,
+    })?;
+    packet_handler.register(|packet: CreateCharacterSuccessPacket| NetworkEvent::CharacterCreated {
+        character_information: packet.character_information,
+    })?;
+
+    register_shared_character_server_packets(packet_handler)
+}
+
+/// Register all character server packets that are the same for every
+/// supported packet version.
+pub(super) fn register_shared_character_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
 where
     Callback: PacketCallback,
 {
@@ -83,13 +418,24 @@ where
             normal_slot_count: packet.normal_slot_count as usize,
         },
     )?;
-    packet_handler.register(|packet: RequestCharacterListSuccessPacket| NetworkEvent::CharacterList {
-        characters: packet.character_information,
-    })?;
     packet_handler.register_noop::<CharacterListPacket>()?;
     packet_handler.register_noop::<CharacterSlotPagePacket>()?;
     packet_handler.register_noop::<CharacterBanListPacket>()?;
-    packet_handler.register_noop::<LoginPincodePacket>()?;
+    packet_handler.register(|packet: LoginPincodePacket| {
+        let seed = packet.pincode_seed;
+        let (request, rejection) = match packet.state {
+            PincodeState::Ok | PincodeState::Passed => return Some(NetworkEvent::PincodeAccepted),
+            PincodeState::Ask => (PincodeRequest::Check, None),
+            PincodeState::NotSet | PincodeState::New => (PincodeRequest::Set, None),
+            PincodeState::Expired => (PincodeRequest::Change, None),
+            PincodeState::Illegal => (PincodeRequest::Set, Some(PincodeRejection::Illegal)),
+            PincodeState::Wrong => (PincodeRequest::Check, Some(PincodeRejection::Wrong)),
+            // We don't store a social security number, so there is nothing to answer with.
+            PincodeState::KoreanSocialSecurityNumber => return None,
+        };
+
+        Some(NetworkEvent::PincodeRequested { seed, request, rejection })
+    })?;
     packet_handler.register_noop::<Packet0b18>()?;
     packet_handler.register(|packet: CharacterSelectionSuccessPacket| {
         let login_data = CharacterServerLoginData {
@@ -116,9 +462,6 @@ where
 
         NetworkEvent::CharacterSelectionFailed { reason, message }
     })?;
-    packet_handler.register(|packet: CreateCharacterSuccessPacket| NetworkEvent::CharacterCreated {
-        character_information: packet.character_information,
-    })?;
     packet_handler.register(|packet: CharacterCreationFailedPacket| {
         let reason = packet.reason;
         let message = match reason {
@@ -154,15 +497,30 @@ pub fn register_map_server_packets<Callback>(
 where
     Callback: PacketCallback,
 {
-    // This is a bit of a workaround for the way that the inventory is
-    // sent. There is a single packet to start the inventory list,
-    // followed by an arbitary number of item packets, and in the
-    // end a sinle packet to mark the list as complete.
-    //
-    // This variable provides some transient storage shared by all the inventory
-    // handlers.
-    let inventory_items: Rc<RefCell<Option<Vec<InventoryItem<NoMetadata>>>>> = Rc::new(RefCell::new(None));
+    let inventory_items = InventoryItems::default();
+
+    packet_handler.register({
+        let inventory_items = inventory_items.clone();
+        move |packet: EquippableItemListPacket| add_equippable_items(&inventory_items, packet.item_information)
+    })?;
+    packet_handler.register(item_pickup_event)?;
+    packet_handler.register(trade_item_added_event)?;
+    packet_handler.register(storage_item_added_event)?;
+    packet_handler.register(mail_read_event)?;
+    packet_handler.register(add_mail_item_result_event)?;
 
+    register_shared_map_server_packets(packet_handler, inventory_items)
+}
+
+/// Register all map server packets that are the same for every supported
+/// packet version.
+pub(super) fn register_shared_map_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+    inventory_items: InventoryItems,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
     packet_handler.register(|_: MapServerPingPacket| NoNetworkEvents)?;
     packet_handler.register(|packet: BroadcastMessagePacket| NetworkEvent::ChatMessage {
         text: packet.message,
@@ -204,7 +562,10 @@ where
             color,
         }
     })?;
-    packet_handler.register_noop::<DisplayEmotionPacket>()?;
+    packet_handler.register(|packet: DisplayEmotionPacket| NetworkEvent::Emotion {
+        entity_id: packet.entity_id,
+        emotion: packet.emotion,
+    })?;
     packet_handler.register(|packet: EntityMovePacket| {
         let EntityMovePacket {
             entity_id,
@@ -259,6 +620,15 @@ where
         entity_id: packet.entity_id,
         reason: packet.reason,
     })?;
+    packet_handler.register(|packet: ItemAppearedPacket| NetworkEvent::AddGroundItem {
+        ground_item_data: packet.into(),
+    })?;
+    packet_handler.register(|packet: ItemDroppedPacket| NetworkEvent::AddGroundItem {
+        ground_item_data: packet.into(),
+    })?;
+    packet_handler.register(|packet: ItemDisappearedPacket| NetworkEvent::RemoveGroundItem {
+        ground_item_id: packet.ground_item_id,
+    })?;
     packet_handler.register(|packet: UpdateStatPacket| {
         let UpdateStatPacket { stat_type } = packet;
         NetworkEvent::UpdateStat { stat_type }
@@ -276,9 +646,17 @@ where
         NetworkEvent::UpdateStat { stat_type }
     })?;
     packet_handler.register_noop::<UpdateAttackRangePacket>()?;
-    packet_handler.register_noop::<NewMailStatusPacket>()?;
-    packet_handler.register_noop::<AchievementUpdatePacket>()?;
-    packet_handler.register_noop::<AchievementListPacket>()?;
+    packet_handler.register(|packet: NewMailStatusPacket| NetworkEvent::NewMailStatus {
+        has_unread_mail: packet.new_available != 0,
+    })?;
+    packet_handler.register(|packet: AchievementUpdatePacket| NetworkEvent::AchievementUpdated {
+        level: AchievementLevel::from(&packet),
+        achievement: AchievementProgress::from(packet.acheivement_data),
+    })?;
+    packet_handler.register(|packet: AchievementListPacket| NetworkEvent::SetAchievements {
+        level: AchievementLevel::from(&packet),
+        achievements: packet.acheivement_data.into_iter().map(AchievementProgress::from).collect(),
+    })?;
     packet_handler.register_noop::<CriticalWeightUpdatePacket>()?;
     packet_handler.register(|packet: SpriteChangePacket| match packet.sprite_type {
         SpriteChangeType::Base => Some(NetworkEvent::ChangeJob {
@@ -339,58 +717,27 @@ where
     packet_handler.register({
         let inventory_items = inventory_items.clone();
 
-        move |packet: EquippableItemListPacket| {
-            inventory_items
-                .borrow_mut()
-                .as_mut()
-                .expect("Unexpected inventory packet")
-                .extend(packet.item_information.into_iter().map(|item| {
-                    let EquippableItemInformation {
-                        index,
-                        item_id,
-                        item_type,
-                        equip_position,
-                        equipped_position,
-                        slot,
-                        hire_expiration_date,
-                        bind_on_equip_type,
-                        w_item_sprite_number,
-                        option_count,
-                        option_data,
-                        refinement_level,
-                        enchantment_level,
-                        flags,
-                    } = item;
+        move |packet: InventoyEndPacket| {
+            let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
 
-                    InventoryItem {
-                        index,
-                        metadata: NoMetadata,
-                        item_id,
-                        item_type,
-                        slot,
-                        hire_expiration_date,
-                        details: InventoryItemDetails::Equippable {
-                            equip_position,
-                            equipped_position,
-                            bind_on_equip_type,
-                            w_item_sprite_number,
-                            option_count,
-                            option_data,
-                            refinement_level,
-                            enchantment_level,
-                            flags,
-                        },
-                    }
-                }));
-            NoNetworkEvents
-        }
-    })?;
-    packet_handler.register({
-        let inventory_items = inventory_items.clone();
+            match packet.inventory_type {
+                INVENTORY_TYPE_INVENTORY => Some(NetworkEvent::SetInventory { items }),
+                INVENTORY_TYPE_STORAGE => {
+                    // Storage items are only offset by one instead of two, so we need to
+                    // correct the index.
+                    let items = items
+                        .into_iter()
+                        .map(|item| InventoryItem {
+                            index: InventoryIndex(item.index.0.wrapping_add(1)),
+                            ..item
+                        })
+                        .collect();
 
-        move |_: InventoyEndPacket| {
-            let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
-            NetworkEvent::SetInventory { items }
+                    Some(NetworkEvent::SetStorage { items })
+                }
+                // TODO: Handle the cart and the guild storage.
+                _ => None,
+            }
         }
     })?;
     packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
@@ -433,8 +780,38 @@ where
     packet_handler.register_noop::<UpdatePartyInvitationStatePacket>()?;
     packet_handler.register_noop::<UpdateShowEquipPacket>()?;
     packet_handler.register_noop::<UpdateConfigurationPacket>()?;
-    packet_handler.register_noop::<NavigateToMonsterPacket>()?;
-    packet_handler.register_noop::<MarkMinimapPositionPacket>()?;
+    packet_handler.register(|packet: NavigateToMonsterPacket| match packet.target_type {
+        NAVIGATION_TARGET_POSITION | NAVIGATION_TARGET_POSITION_OTHER_MAP => Some(NetworkEvent::NavigationTarget {
+            map_name: packet.map_name.replace(".gat", ""),
+            position: packet.target_position,
+        }),
+        // Navigating to monsters requires the navigation data of the client, which we don't
+        // load yet.
+        _ => None,
+    })?;
+    packet_handler.register(|packet: MarkMinimapPositionPacket| {
+        let MarkMinimapPositionPacket {
+            npc_id,
+            marker_type,
+            position,
+            id,
+            color,
+        } = packet;
+
+        match marker_type {
+            MarkerType::RemoveMark => NetworkEvent::MinimapMarkerRemoved { npc_id, marker_id: id },
+            marker_type => NetworkEvent::MinimapMarkerAdded {
+                npc_id,
+                marker_id: id,
+                position: TilePosition {
+                    x: position.x as u16,
+                    y: position.y as u16,
+                },
+                color,
+                is_temporary: matches!(marker_type, MarkerType::DisplayFor15Seconds),
+            },
+        }
+    })?;
     packet_handler.register(|packet: NextButtonPacket| {
         let NextButtonPacket { npc_id } = packet;
 
@@ -453,19 +830,57 @@ where
         NetworkEvent::AddChoiceButtons { choices, npc_id }
     })?;
     packet_handler.register_noop::<DisplaySpecialEffectPacket>()?;
-    packet_handler.register_noop::<DisplaySkillCooldownPacket>()?;
+    packet_handler.register(|packet: DisplaySkillCooldownPacket| NetworkEvent::SkillCooldown {
+        skill_id: packet.skill_id,
+        // Despite the name of the field, the server sends the duration of the
+        // cooldown rather than the tick at which it ends.
+        duration_in_milliseconds: packet.until.0,
+    })?;
     packet_handler.register_noop::<DisplaySkillEffectAndDamagePacket>()?;
     packet_handler.register(|packet: DisplaySkillEffectNoDamagePacket| NetworkEvent::HealEffect {
         entity_id: packet.destination_entity_id,
         heal_amount: packet.heal_amount as usize,
     })?;
     packet_handler.register_noop::<DisplayPlayerHealEffect>()?;
-    packet_handler.register_noop::<StatusChangePacket>()?;
-    packet_handler.register_noop::<QuestNotificationPacket1>()?;
-    packet_handler.register_noop::<HuntingQuestNotificationPacket>()?;
-    packet_handler.register_noop::<HuntingQuestUpdateObjectivePacket>()?;
-    packet_handler.register_noop::<QuestRemovedPacket>()?;
-    packet_handler.register_noop::<QuestListPacket>()?;
+    packet_handler.register(|packet: StatusChangePacket| {
+        let StatusChangePacket {
+            index,
+            entity_id,
+            state,
+            duration_in_milliseconds,
+            remaining_in_milliseconds,
+            ..
+        } = packet;
+
+        match state {
+            0 => NetworkEvent::StatusEffectEnded {
+                entity_id,
+                status_id: index,
+            },
+            // A duration of 0 means that the status effect doesn't expire.
+            _ => NetworkEvent::StatusEffectStarted {
+                entity_id,
+                status_id: index,
+                duration_in_milliseconds: (duration_in_milliseconds != 0).then_some(duration_in_milliseconds),
+                remaining_in_milliseconds: (remaining_in_milliseconds != 0).then_some(remaining_in_milliseconds),
+            },
+        }
+    })?;
+    packet_handler.register(|packet: QuestNotificationPacket1| NetworkEvent::QuestAdded {
+        quest: QuestLogEntry::from(packet),
+    })?;
+    packet_handler.register(|packet: HuntingQuestNotificationPacket| NetworkEvent::QuestObjectivesUpdated {
+        objectives: packet.objective_details,
+    })?;
+    packet_handler.register(
+        |packet: HuntingQuestUpdateObjectivePacket| NetworkEvent::QuestObjectivesUpdated {
+            objectives: packet.objective_details,
+        },
+    )?;
+    packet_handler.register(|packet: QuestRemovedPacket| NetworkEvent::QuestRemoved { quest_id: packet.quest_id })?;
+    packet_handler.register(|packet: QuestListPacket| NetworkEvent::SetQuestLog {
+        quests: packet.quests.into_iter().map(QuestLogEntry::from).collect(),
+    })?;
     packet_handler.register(|packet: VisualEffectPacket| {
         let VisualEffectPacket { entity_id, effect } = packet;
 
@@ -484,9 +899,19 @@ where
 
         NetworkEvent::VisualEffect { effect_path, entity_id }
     })?;
-    packet_handler.register_noop::<DisplayGainedExperiencePacket>()?;
+    packet_handler.register(|packet: DisplayGainedExperiencePacket| NetworkEvent::ExperienceGained {
+        account_id: packet.account_id,
+        amount: packet.amount,
+        experience_type: packet.experience_type,
+        experience_source: packet.experience_source,
+    })?;
     packet_handler.register_noop::<DisplayImagePacket>()?;
-    packet_handler.register_noop::<StateChangePacket>()?;
+    packet_handler.register(|packet: StateChangePacket| NetworkEvent::EntityStateChanged {
+        entity_id: packet.entity_id,
+        body_state: packet.body_state,
+        health_state: packet.health_state,
+        effect_state: packet.effect_state,
+    })?;
 
     packet_handler.register(|packet: QuestEffectPacket| match packet.effect {
         QuestEffect::None => NetworkEvent::RemoveQuestEffect {
@@ -494,74 +919,6 @@ where
         },
         _ => NetworkEvent::AddQuestEffect { quest_effect: packet },
     })?;
-    packet_handler.register(|packet: ItemPickupPacket| {
-        let ItemPickupPacket {
-            index,
-            count,
-            item_id,
-            is_identified,
-            is_broken,
-            cards,
-            equip_position,
-            item_type,
-            result,
-            hire_expiration_date,
-            bind_on_equip_type,
-            option_data,
-            favorite,
-            look,
-            refinement_level,
-            enchantment_level,
-        } = packet;
-
-        if result != ItemPickupResult::Success {
-            todo!();
-        }
-
-        // TODO: Not sure where to store these, since the *InventoryItem packets are not
-        // sending these either. We will certainly use them at some point though.
-        let _ = (favorite, look);
-
-        let details = match equip_position.is_empty() {
-            true => InventoryItemDetails::Regular {
-                amount: count,
-                equipped_position: equip_position,
-                flags: {
-                    let mut flags = RegularItemFlags::empty();
-                    flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
-                    flags
-                },
-            },
-            false => InventoryItemDetails::Equippable {
-                equip_position,
-                equipped_position: EquipPosition::empty(),
-                bind_on_equip_type,
-                w_item_sprite_number: 0,
-                option_count: option_data.len() as u8,
-                option_data,
-                refinement_level,
-                enchantment_level,
-                flags: {
-                    let mut flags = EquippableItemFlags::empty();
-                    flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
-                    flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
-                    flags
-                },
-            },
-        };
-
-        let item = InventoryItem {
-            metadata: NoMetadata,
-            index,
-            item_id,
-            item_type,
-            slot: cards,
-            hire_expiration_date,
-            details,
-        };
-
-        NetworkEvent::IventoryItemAdded { item }
-    })?;
     packet_handler.register(|packet: RemoveItemFromInventoryPacket| NetworkEvent::InventoryItemRemoved {
         reason: packet.remove_reason,
         index: packet.index,
@@ -686,8 +1043,15 @@ where
             color: MessageColor::Error,
         },
     })?;
-    packet_handler.register_noop::<UseSkillSuccessPacket>()?;
-    packet_handler.register_noop::<ToUseSkillSuccessPacket>()?;
+    packet_handler.register(|packet: UseSkillSuccessPacket| NetworkEvent::SkillCastStarted {
+        source_entity_id: packet.source_entity,
+        destination_entity_id: packet.destination_entity,
+        position: packet.position,
+        skill_id: packet.skill_id,
+        cast_time_in_milliseconds: packet.delay_time,
+    })?;
+    // The server only sends this packet when using a skill failed.
+    packet_handler.register(|packet: ToUseSkillSuccessPacket| NetworkEvent::SkillUseFailed { skill_id: packet.skill_id })?;
     packet_handler.register(|packet: NotifySkillUnitPacket| {
         let NotifySkillUnitPacket {
             entity_id,
@@ -737,11 +1101,264 @@ where
         account_id: packet.account_id,
         character_id: packet.character_id,
     })?;
-    packet_handler.register_noop::<PartyInvitePacket>()?;
-    packet_handler.register_noop::<StatusChangeSequencePacket>()?;
+    packet_handler.register(|packet: PartyInvitePacket| NetworkEvent::PartyInvitation {
+        party_id: packet.party_id,
+        party_name: packet.party_name,
+    })?;
+    packet_handler.register(|packet: CreatePartyResultPacket| {
+        let text = match packet.result {
+            CreatePartyResult::Success => return None,
+            CreatePartyResult::NameAlreadyExists => "A party with that name already exists.",
+            CreatePartyResult::AlreadyInParty => "You are already in a party.",
+            CreatePartyResult::NotAllowedOnMap => "You can't create a party on this map.",
+        };
+
+        Some(NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        })
+    })?;
+    packet_handler.register(|packet: PartyInviteResultPacket| {
+        let name = packet.character_name;
+        let (text, color) = match packet.result {
+            PartyInviteResult::Accepted => (format!("{name} joined the party."), MessageColor::Information),
+            PartyInviteResult::Rejected => (format!("{name} rejected the party invitation."), MessageColor::Information),
+            PartyInviteResult::AlreadyInParty => (format!("{name} is already in a party."), MessageColor::Error),
+            PartyInviteResult::PartyFull => ("The party is full.".to_owned(), MessageColor::Error),
+            PartyInviteResult::SameAccount => (
+                "You can't invite a character of the same account.".to_owned(),
+                MessageColor::Error,
+            ),
+            PartyInviteResult::InvitationBlocked => (format!("{name} is not accepting party invitations."), MessageColor::Error),
+            PartyInviteResult::NotOnline => (format!("{name} is not online."), MessageColor::Error),
+            PartyInviteResult::MapRestricted => ("Party invitations are not allowed on this map.".to_owned(), MessageColor::Error),
+            PartyInviteResult::LevelRestricted => (format!("The level difference to {name} is too big."), MessageColor::Error),
+        };
+
+        NetworkEvent::ChatMessage { text, color }
+    })?;
+    packet_handler.register(|packet: PartyInformationPacket| NetworkEvent::SetParty {
+        party_name: packet.party_name,
+        members: packet.members.into_iter().map(PartyMember::from).collect(),
+    })?;
+    packet_handler.register(|packet: PartyMemberInformationPacket| NetworkEvent::PartyMemberUpdated {
+        party_name: packet.party_name.clone(),
+        member: packet.into(),
+    })?;
+    packet_handler.register(|packet: PartyMemberLeftPacket| match packet.reason {
+        LeavePartyReason::Left | LeavePartyReason::Expelled => NetworkEvent::PartyMemberLeft {
+            account_id: packet.account_id,
+        },
+        LeavePartyReason::LeaveNotAllowed => NetworkEvent::ChatMessage {
+            text: "You can't leave the party right now.".to_owned(),
+            color: MessageColor::Error,
+        },
+        LeavePartyReason::ExpelNotAllowed => NetworkEvent::ChatMessage {
+            text: format!("{} can't be removed from the party right now.", packet.character_name),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: PartyMemberHealthPointsPacket| NetworkEvent::PartyMemberHealth {
+        account_id: packet.account_id,
+        health_points: packet.health_points as usize,
+        maximum_health_points: packet.maximum_health_points as usize,
+    })?;
+    packet_handler.register(|packet: PartyMemberPositionPacket| NetworkEvent::PartyMemberPosition {
+        account_id: packet.account_id,
+        position: packet.position,
+    })?;
+    packet_handler.register(|packet: PartyLeaderChangedPacket| NetworkEvent::PartyLeaderChanged {
+        account_id: packet.new_leader_account_id,
+    })?;
+    packet_handler.register(|packet: PartyChatMessagePacket| NetworkEvent::ChatMessage {
+        text: packet.message,
+        color: MessageColor::Party,
+    })?;
+    packet_handler.register_noop::<PartyOptionsPacket>()?;
+    packet_handler.register(|packet: StatusChangeSequencePacket| {
+        let StatusChangeSequencePacket { index, id, state } = packet;
+        let entity_id = EntityId(id);
+
+        match state {
+            0 => NetworkEvent::StatusEffectEnded {
+                entity_id,
+                status_id: index,
+            },
+            _ => NetworkEvent::StatusEffectStarted {
+                entity_id,
+                status_id: index,
+                duration_in_milliseconds: None,
+                remaining_in_milliseconds: None,
+            },
+        }
+    })?;
     packet_handler.register_noop::<ReputationPacket>()?;
     packet_handler.register_noop::<ClanInfoPacket>()?;
     packet_handler.register_noop::<ClanOnlineCountPacket>()?;
+    packet_handler.register(|packet: GuildMembershipPacket| NetworkEvent::GuildJoined {
+        guild_id: packet.guild_id,
+        guild_name: packet.guild_name,
+        is_master: packet.is_master != 0,
+    })?;
+    packet_handler.register(|packet: GuildInformationPacket| NetworkEvent::SetGuildInformation {
+        information: GuildInformation::from(packet),
+    })?;
+    packet_handler.register(|packet: GuildMemberListPacket| NetworkEvent::SetGuildMembers {
+        members: packet.members.into_iter().map(GuildMember::from).collect(),
+    })?;
+    packet_handler.register_noop::<GuildPositionInformationPacket>()?;
+    packet_handler.register(|packet: GuildPositionNamesPacket| NetworkEvent::SetGuildPositions {
+        positions: packet.positions,
+    })?;
+    packet_handler.register(|packet: CharacterNamePacket| NetworkEvent::CharacterName {
+        character_id: packet.character_id,
+        name: packet.name,
+    })?;
+    packet_handler.register(|packet: GuildNoticePacket| NetworkEvent::SetGuildNotice {
+        notice: GuildNotice {
+            subject: packet.subject,
+            notice: packet.notice,
+        },
+    })?;
+    packet_handler.register(|packet: GuildEmblemPacket| NetworkEvent::GuildEmblem {
+        guild_id: packet.guild_id,
+        emblem_id: packet.emblem_id,
+        emblem_data: packet.emblem_data,
+    })?;
+    packet_handler.register(|packet: CreateGuildResultPacket| {
+        let (text, color) = match packet.result {
+            CreateGuildResult::Success => ("The guild was created.", MessageColor::Information),
+            CreateGuildResult::AlreadyInGuild => ("You are already in a guild.", MessageColor::Error),
+            CreateGuildResult::NameAlreadyExists => ("A guild with that name already exists.", MessageColor::Error),
+            CreateGuildResult::EmperiumRequired => ("You need an Emperium to create a guild.", MessageColor::Error),
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color,
+        }
+    })?;
+    packet_handler.register(|packet: GuildInviteResultPacket| {
+        let (text, color) = match packet.result {
+            GuildInviteResult::Accepted => ("The invitation to the guild was accepted.", MessageColor::Information),
+            GuildInviteResult::Rejected => ("The invitation to the guild was rejected.", MessageColor::Information),
+            GuildInviteResult::AlreadyInGuild => ("The character is already in a guild.", MessageColor::Error),
+            GuildInviteResult::GuildFull => ("The guild is full.", MessageColor::Error),
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color,
+        }
+    })?;
+    packet_handler.register(|packet: GuildInvitePacket| NetworkEvent::GuildInvitation {
+        guild_id: packet.guild_id,
+        guild_name: packet.guild_name,
+    })?;
+    packet_handler.register(|packet: GuildMemberLeftPacket| {
+        vec![
+            NetworkEvent::ChatMessage {
+                text: format!("{} left the guild ({}).", packet.character_name, packet.reason),
+                color: MessageColor::Guild,
+            },
+            NetworkEvent::GuildMemberLeft {
+                character_name: packet.character_name,
+            },
+        ]
+    })?;
+    packet_handler.register(|packet: GuildMemberExpelledPacket| {
+        vec![
+            NetworkEvent::ChatMessage {
+                text: format!("{} was expelled from the guild ({}).", packet.character_name, packet.reason),
+                color: MessageColor::Guild,
+            },
+            NetworkEvent::GuildMemberLeft {
+                character_name: packet.character_name,
+            },
+        ]
+    })?;
+    packet_handler.register(|packet: GuildMemberStatePacket| NetworkEvent::GuildMemberOnlineState {
+        account_id: packet.account_id,
+        character_id: packet.character_id,
+        is_online: packet.state == GuildMemberState::Online,
+    })?;
+    packet_handler.register(|packet: GuildChatMessagePacket| NetworkEvent::ChatMessage {
+        text: packet.message,
+        color: MessageColor::Guild,
+    })?;
+    packet_handler.register(|packet: TradeRequestPacket| NetworkEvent::TradeRequested {
+        character_name: packet.character_name,
+        base_level: packet.base_level,
+    })?;
+    packet_handler.register(|packet: TradeRequestResultPacket| {
+        let text = match packet.result {
+            TradeRequestResult::Accepted => return NetworkEvent::TradeStarted,
+            TradeRequestResult::TooFarAway => "The character is too far away to trade.",
+            TradeRequestResult::CharacterDoesNotExist => "The character does not exist.",
+            TradeRequestResult::Failed => "The trade request failed.",
+            TradeRequestResult::Cancelled => "The trade request was rejected.",
+            TradeRequestResult::Busy => "The character is already trading.",
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        }
+    })?;
+    packet_handler.register(|packet: AddTradeItemResultPacket| {
+        let text = match packet.result {
+            AddTradeItemResult::Success => return vec![NetworkEvent::TradeItemAccepted { index: packet.index }],
+            AddTradeItemResult::Overweight => "The trade partner can't carry that much weight.",
+            AddTradeItemResult::Failed => "The item can't be traded.",
+            AddTradeItemResult::TooManyItems => "The trade partner can't carry any more items.",
+            AddTradeItemResult::TooManyOfSameItem => "The trade partner can't carry any more of that item.",
+        };
+
+        vec![
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            },
+            NetworkEvent::TradeItemRejected { index: packet.index },
+        ]
+    })?;
+    packet_handler.register(|packet: TradeLockedPacket| NetworkEvent::TradeLocked {
+        participant: packet.participant,
+    })?;
+    packet_handler.register(|_: TradeCancelledPacket| {
+        vec![
+            NetworkEvent::ChatMessage {
+                text: "The trade was cancelled.".to_owned(),
+                color: MessageColor::Information,
+            },
+            NetworkEvent::TradeCancelled,
+        ]
+    })?;
+    packet_handler.register(|packet: TradeCompletedPacket| match packet.result {
+        TradeCompletedResult::Success => vec![
+            NetworkEvent::ChatMessage {
+                text: "The trade was completed.".to_owned(),
+                color: MessageColor::Information,
+            },
+            NetworkEvent::TradeCompleted,
+        ],
+        TradeCompletedResult::Failed => vec![
+            NetworkEvent::ChatMessage {
+                text: "The trade failed.".to_owned(),
+                color: MessageColor::Error,
+            },
+            NetworkEvent::TradeCancelled,
+        ],
+    })?;
+    packet_handler.register(|packet: StorageItemCountPacket| NetworkEvent::StorageItemCount {
+        item_count: packet.item_count,
+        maximum_item_count: packet.maximum_item_count,
+    })?;
+    packet_handler.register(|packet: StorageItemRemovedPacket| NetworkEvent::StorageItemRemoved {
+        index: InventoryIndex(packet.index.0),
+        amount: packet.amount as u16,
+    })?;
+    packet_handler.register(|_: StorageClosedPacket| NetworkEvent::StorageClosed)?;
     packet_handler.register_noop::<ChangeMapCellPacket>()?;
     packet_handler.register_noop::<OpenMarketPacket>()?;
     packet_handler.register(|packet: BuyOrSellPacket| NetworkEvent::AskBuyOrSell { shop_id: packet.shop_id })?;
@@ -769,6 +1386,78 @@ where
     packet_handler.register_noop::<RequestStatUpResponsePacket>()?;
     packet_handler.register_noop::<EquipAmmunitionPacket>()?;
     packet_handler.register_noop::<AmmunitionActionPacket>()?;
+    packet_handler.register(|packet: MailListPacket| NetworkEvent::MailList {
+        mails: packet.mails.into_iter().map(MailSummary::from).collect(),
+        is_end: packet.is_end != 0,
+    })?;
+    packet_handler.register(|packet: MailDeletedPacket| NetworkEvent::MailDeleted { mail_id: packet.mail_id })?;
+    packet_handler.register(|packet: RetrieveMailZenyResultPacket| match packet.result {
+        RetrieveMailAttachmentResult::Success => NetworkEvent::MailZenyRetrieved { mail_id: packet.mail_id },
+        RetrieveMailAttachmentResult::Failed | RetrieveMailAttachmentResult::Overweight => NetworkEvent::ChatMessage {
+            text: "The zeny could not be retrieved.".to_owned(),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: RetrieveMailItemsResultPacket| match packet.result {
+        RetrieveMailAttachmentResult::Success => NetworkEvent::MailItemsRetrieved { mail_id: packet.mail_id },
+        RetrieveMailAttachmentResult::Failed => NetworkEvent::ChatMessage {
+            text: "The items could not be retrieved.".to_owned(),
+            color: MessageColor::Error,
+        },
+        RetrieveMailAttachmentResult::Overweight => NetworkEvent::ChatMessage {
+            text: "The items are too heavy to be retrieved.".to_owned(),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: OpenWriteMailResultPacket| match packet.success != 0 {
+        true => NetworkEvent::MailWriteOpened {
+            receiver_name: packet.receiver_name,
+        },
+        false => NetworkEvent::ChatMessage {
+            text: "A mail can't be written right now.".to_owned(),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: RemoveMailItemResultPacket| match packet.result {
+        0 => NetworkEvent::MailItemRemoved {
+            index: packet.index,
+            amount: packet.amount,
+        },
+        _ => NetworkEvent::ChatMessage {
+            text: "The item can't be removed from the mail.".to_owned(),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: CheckMailReceiverResultPacket| match packet.character_id {
+        CharacterId(0) => vec![
+            NetworkEvent::ChatMessage {
+                text: "The receiver of the mail does not exist.".to_owned(),
+                color: MessageColor::Error,
+            },
+            NetworkEvent::MailSendFailed,
+        ],
+        character_id => vec![NetworkEvent::MailReceiverFound {
+            character_id,
+            receiver_name: packet.receiver_name,
+        }],
+    })?;
+    packet_handler.register(|packet: SendMailResultPacket| {
+        let text = match packet.result {
+            SendMailResult::Success => return vec![NetworkEvent::MailSent],
+            SendMailResult::Failed => "The mail could not be sent.",
+            SendMailResult::TooManyMails => "The mailbox of the receiver is full.",
+            SendMailResult::ReceiverNotFound => "The receiver of the mail does not exist.",
+            SendMailResult::InvalidContent => "The mail contains invalid content.",
+        };
+
+        vec![
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            },
+            NetworkEvent::MailSendFailed,
+        ]
+    })?;
 
     Ok(())
 }
@@ -0,0 +1,59 @@
+use ragnarok_packets::{AccountId, CharacterId, PartyMemberInformation, PartyMemberInformationPacket, PartyMemberState, TilePosition};
+
+/// A member of the party of the player.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PartyMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub name: String,
+    pub map_name: String,
+    pub is_leader: bool,
+    pub is_online: bool,
+    pub job: u16,
+    pub base_level: u16,
+    /// Only known for members that are on the same map as the player.
+    pub position: Option<TilePosition>,
+    /// Only known for members that are on the same map as the player. Will be
+    /// 0 otherwise.
+    pub health_points: usize,
+    /// Only known for members that are on the same map as the player. Will be
+    /// 0 otherwise.
+    pub maximum_health_points: usize,
+}
+
+impl From<PartyMemberInformation> for PartyMember {
+    fn from(value: PartyMemberInformation) -> Self {
+        Self {
+            account_id: value.account_id,
+            character_id: value.character_id,
+            name: value.character_name,
+            map_name: value.map_name.replace(".gat", ""),
+            is_leader: value.role == 0,
+            is_online: value.state == PartyMemberState::Online,
+            job: value.job,
+            base_level: value.base_level,
+            position: None,
+            health_points: 0,
+            maximum_health_points: 0,
+        }
+    }
+}
+
+impl From<PartyMemberInformationPacket> for PartyMember {
+    fn from(value: PartyMemberInformationPacket) -> Self {
+        Self {
+            account_id: value.account_id,
+            character_id: value.character_id,
+            name: value.character_name,
+            map_name: value.map_name.replace(".gat", ""),
+            is_leader: value.role == 0,
+            is_online: value.state == PartyMemberState::Online,
+            job: value.job,
+            base_level: value.base_level,
+            position: Some(value.position),
+            health_points: 0,
+            maximum_health_points: 0,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+/// Number of digits of a pincode.
+pub const PINCODE_LENGTH: usize = 4;
+
+/// What the character server asks the player to do before they can select a
+/// character.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum PincodeRequest {
+    /// Enter the pincode of the account.
+    #[default]
+    Check,
+    /// Choose a pincode, since the account doesn't have one yet.
+    Set,
+    /// Enter the current pincode and choose a new one.
+    Change,
+}
+
+/// Reason why the character server rejected a pincode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PincodeRejection {
+    /// The entered pincode does not match the pincode of the account.
+    Wrong,
+    /// The chosen pincode is not allowed, e.g. because it's too simple.
+    Illegal,
+}
+
+/// Encode the digits of a pincode with the seed sent by the character server.
+///
+/// The server shuffles the digits 0 to 9 based on the seed and decodes every
+/// digit we send as the digit at that position of the shuffled table. So for
+/// every digit of the pincode we send the position of that digit in the table.
+pub(crate) fn encode_pincode(seed: u32, pincode: &str) -> [u8; PINCODE_LENGTH] {
+    let table = shuffled_digits(seed);
+    let mut encoded = [b'0'; PINCODE_LENGTH];
+
+    pincode
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .zip(encoded.iter_mut())
+        .for_each(|(digit, encoded)| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because the table contains every digit.
+            let position = table.iter().position(|entry| *entry == digit - b'0').unwrap();
+            *encoded = b'0' + position as u8;
+        });
+
+    encoded
+}
+
+fn shuffled_digits(mut seed: u32) -> [u8; 10] {
+    const MULTIPLIER: u32 = 0x3498;
+    const BASE_SEED: u32 = 0x881234;
+
+    let mut table = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    for index in 1..table.len() {
+        seed = BASE_SEED.wrapping_add(seed.wrapping_mul(MULTIPLIER));
+        let position = (seed % (index as u32 + 1)) as usize;
+        table.swap(index, position);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_pincode, shuffled_digits};
+
+    /// Decode the pincode the same way the character server does.
+    fn decode_pincode(seed: u32, encoded: [u8; 4]) -> String {
+        let table = shuffled_digits(seed);
+
+        encoded
+            .iter()
+            .map(|digit| char::from(b'0' + table[(digit - b'0') as usize]))
+            .collect()
+    }
+
+    #[test]
+    fn encoded_pincode_decodes_to_original() {
+        for seed in [0, 1, 0x1234, 0xDEADBEEF, u32::MAX] {
+            for pincode in ["0000", "1234", "9876", "5050"] {
+                assert_eq!(decode_pincode(seed, encode_pincode(seed, pincode)), pincode);
+            }
+        }
+    }
+}
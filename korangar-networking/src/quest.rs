@@ -0,0 +1,97 @@
+use ragnarok_packets::{HuntingObjective, ObjectiveDetails1, Quest, QuestDetails, QuestNotificationPacket1};
+
+/// A quest in the quest log of the player.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct QuestLogEntry {
+    pub quest_id: u32,
+    pub is_active: bool,
+    /// Unix timestamp of when the quest expires. Will be 0 for quests without
+    /// a time limit.
+    pub expire_time: u32,
+    pub objectives: Vec<QuestObjective>,
+}
+
+/// A hunting objective of a quest.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct QuestObjective {
+    pub hunt_identification: u32,
+    pub mob_id: u32,
+    pub mob_name: String,
+    pub current_count: u16,
+    pub total_count: u16,
+}
+
+impl QuestLogEntry {
+    /// Apply a hunting update from the server. Depending on the packet
+    /// version, the server identifies the objective either by its hunt
+    /// identification or by the quest id, so both are checked.
+    pub fn update_objective(&mut self, update: &HuntingObjective) {
+        let quest_id = self.quest_id;
+
+        let objective = self.objectives.iter_mut().find(|objective| {
+            objective.hunt_identification == update.quest_id || (quest_id == update.quest_id && objective.mob_id == update.mob_id)
+        });
+
+        if let Some(objective) = objective {
+            objective.current_count = update.current_count;
+            objective.total_count = update.total_count;
+        }
+    }
+}
+
+impl From<QuestDetails> for QuestObjective {
+    fn from(value: QuestDetails) -> Self {
+        Self {
+            hunt_identification: value.hunt_identification,
+            mob_id: value.mob_id,
+            mob_name: value.mob_name,
+            current_count: value.kill_count,
+            total_count: value.total_count,
+        }
+    }
+}
+
+impl From<ObjectiveDetails1> for QuestObjective {
+    fn from(value: ObjectiveDetails1) -> Self {
+        Self {
+            hunt_identification: value.hunt_identification,
+            mob_id: value.mob_id,
+            mob_name: value.mob_name,
+            current_count: 0,
+            total_count: value.mob_count,
+        }
+    }
+}
+
+impl From<Quest> for QuestLogEntry {
+    fn from(value: Quest) -> Self {
+        Self {
+            quest_id: value.quest_id,
+            is_active: value.active != 0,
+            expire_time: value.expire_time,
+            objectives: value.objective_details.into_iter().map(QuestObjective::from).collect(),
+        }
+    }
+}
+
+impl From<QuestNotificationPacket1> for QuestLogEntry {
+    fn from(value: QuestNotificationPacket1) -> Self {
+        // The packet always contains three objectives, but only the first
+        // `objective_count` of them are valid.
+        let objectives = value
+            .objective_details
+            .into_iter()
+            .take(value.objective_count as usize)
+            .map(QuestObjective::from)
+            .collect();
+
+        Self {
+            quest_id: value.quest_id,
+            is_active: value.active != 0,
+            expire_time: value.expire_time,
+            objectives,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Replay of recorded sessions. Instead of connecting to a server, the
+//! networking thread reads the incoming bytes of a [`NetworkSession`] and
+//! passes them through the regular packet handlers.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+use tokio::time::Instant;
+
+use crate::session::{NetworkSession, SessionServer};
+
+/// Size of the in-memory pipe between the replay task and the connection.
+const REPLAY_BUFFER_SIZE: usize = 64 * 1024;
+
+/// How fast a recorded session is replayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayPacing {
+    /// Replay with the same timing as the recording.
+    RealTime,
+    /// Replay faster (or slower) than the recording by the given factor.
+    Accelerated(f64),
+    /// Replay all packets as soon as possible, ignoring the recorded timing.
+    Unpaced,
+}
+
+impl ReplayPacing {
+    fn scale(self, offset: Duration) -> Option<Duration> {
+        match self {
+            ReplayPacing::RealTime => Some(offset),
+            ReplayPacing::Accelerated(factor) if factor > 0.0 => Some(offset.div_f64(factor)),
+            ReplayPacing::Accelerated(_) | ReplayPacing::Unpaced => None,
+        }
+    }
+}
+
+pub(crate) struct Replay {
+    session: NetworkSession,
+    pacing: ReplayPacing,
+}
+
+impl Replay {
+    pub(crate) fn new(session: NetworkSession, pacing: ReplayPacing) -> Self {
+        Self { session, pacing }
+    }
+
+    pub(crate) fn source(&self, server: SessionServer) -> ReplaySource {
+        ReplaySource {
+            chunks: self.session.incoming_chunks(server),
+            pacing: self.pacing,
+        }
+    }
+}
+
+/// Recorded incoming bytes of a single server connection.
+pub(crate) struct ReplaySource {
+    chunks: Vec<(Duration, Vec<u8>)>,
+    pacing: ReplayPacing,
+}
+
+impl ReplaySource {
+    /// Spawn a task on the current local set that writes the recorded bytes
+    /// into a stream. The returned stream behaves like a connection to the
+    /// server that was recorded.
+    pub(crate) fn into_stream(self) -> DuplexStream {
+        let (client_stream, replay_stream) = tokio::io::duplex(REPLAY_BUFFER_SIZE);
+        tokio::task::spawn_local(self.feed(replay_stream));
+        client_stream
+    }
+
+    async fn feed(self, stream: DuplexStream) {
+        let (mut reader, mut writer) = tokio::io::split(stream);
+        let start = Instant::now();
+
+        // Packets sent by the client are simply discarded. Once the client closes
+        // the connection, the read returns and the replay is stopped.
+        let discard = async {
+            let mut buffer = [0u8; 1024];
+            while let Ok(1..) = reader.read(&mut buffer).await {}
+        };
+
+        // After the last chunk the connection stays open, the same way a server
+        // would keep it open until the client disconnects.
+        let feed = async {
+            for (offset, data) in self.chunks {
+                if let Some(delay) = self.pacing.scale(offset) {
+                    tokio::time::sleep_until(start + delay).await;
+                }
+
+                if writer.write_all(&data).await.is_err() {
+                    break;
+                }
+            }
+
+            std::future::pending::<()>().await
+        };
+
+        tokio::select! {
+            _ = discard => {}
+            _ = feed => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::time::{Duration, Instant};
+
+    use ragnarok_bytes::ByteWriter;
+    use ragnarok_packets::{LoginFailedPacket, LoginFailedReason, PacketExt};
+
+    use super::ReplayPacing;
+    use crate::{
+        NetworkEvent, NetworkSession, NetworkingSystem, SessionDirection, SessionRecord, SessionServer, SupportedPacketVersion,
+        UnifiedLoginFailedReason,
+    };
+
+    #[test]
+    fn replay_login_server() {
+        let mut byte_writer = ByteWriter::new();
+        LoginFailedPacket {
+            reason: LoginFailedReason::ServerClosed,
+        }
+        .packet_to_bytes(&mut byte_writer)
+        .unwrap();
+
+        let session = NetworkSession::new(vec![SessionRecord {
+            timestamp: Duration::from_secs(60),
+            server: SessionServer::Login,
+            direction: SessionDirection::Incoming,
+            data: byte_writer.into_inner(),
+        }]);
+
+        let (mut networking_system, mut event_buffer) = NetworkingSystem::spawn_replay(session, ReplayPacing::Unpaced);
+        let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 6900);
+        networking_system.connect_to_login_server(SupportedPacketVersion::_20220406, address, "user", "password");
+
+        let start = Instant::now();
+        let mut events = Vec::new();
+
+        while events.is_empty() && start.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+            networking_system.get_events(&mut event_buffer);
+            events.extend(event_buffer.drain());
+        }
+
+        assert!(matches!(events.as_slice(), [NetworkEvent::LoginServerConnectionFailed {
+            reason: UnifiedLoginFailedReason::ServerClosed,
+            ..
+        }]));
+    }
+}
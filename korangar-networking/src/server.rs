@@ -5,6 +5,7 @@ use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use crate::SupportedPacketVersion;
 use crate::event::NetworkEvent;
+use crate::session::SessionRecorder;
 
 #[derive(Debug, Clone, Copy)]
 pub struct LoginServerLoginData {
@@ -49,18 +50,21 @@ pub(crate) enum ServerConnectCommand {
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        session_recorder: Option<SessionRecorder>,
     },
     Character {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        session_recorder: Option<SessionRecorder>,
     },
     Map {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        session_recorder: Option<SessionRecorder>,
     },
 }
 
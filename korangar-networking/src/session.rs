@@ -0,0 +1,399 @@
+//! Recorded network sessions. A session contains every chunk of bytes that was
+//! exchanged with the login, character and map server, which makes it
+//! possible to replay a session without a running server.
+//!
+//! # File format
+//!
+//! All numbers are little endian. A session file starts with a header:
+//!
+//! | Size | Content                      |
+//! |------|------------------------------|
+//! | 4    | Magic bytes `KSES`           |
+//! | 2    | Format version (currently 1) |
+//!
+//! The header is followed by any number of records until the end of the file:
+//!
+//! | Size   | Content                                          |
+//! |--------|--------------------------------------------------|
+//! | 8      | Milliseconds since the start of the recording    |
+//! | 1      | Server (0 = login, 1 = character, 2 = map)       |
+//! | 1      | Direction (0 = incoming, 1 = outgoing)           |
+//! | 4      | Length of the data                               |
+//! | Length | Raw bytes as they were sent over the network     |
+//!
+//! # Record boundaries
+//!
+//! Records are not packets. An incoming record holds exactly the bytes of a
+//! single read from the socket, so it may contain multiple packets or only
+//! part of one, and an outgoing record holds one write to the socket. The
+//! recorder works on the byte stream instead of the `PacketCallback`, since
+//! the stream also contains data that isn't a packet, like the account id the
+//! character server sends after logging in, and packets the client can't
+//! parse yet.
+//!
+//! When replaying, the incoming records are fed to the packet handler as if
+//! they were read from the socket, so it's up to the packet parser to split
+//! them into packets again, just like it does for a live connection.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ragnarok_bytes::{ByteReader, ByteWriter, ConversionError, ConversionResult, FromBytes, ToBytes};
+
+/// Magic bytes at the start of every session file.
+const MAGIC: [u8; 4] = *b"KSES";
+/// Version of the session file format.
+const VERSION: u16 = 1;
+
+/// Server that a recorded chunk was exchanged with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionServer {
+    Login,
+    Character,
+    Map,
+}
+
+/// Direction of a recorded chunk, as seen from the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// A single chunk of bytes that was sent or received. This is the data of one
+/// read from or write to the socket, not a single packet.
+#[derive(Debug, Clone)]
+pub struct SessionRecord {
+    /// Time since the start of the recording.
+    pub timestamp: Duration,
+    pub server: SessionServer,
+    pub direction: SessionDirection,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum SessionFileError {
+    Io(std::io::Error),
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    InvalidRecord,
+}
+
+impl From<std::io::Error> for SessionFileError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl std::fmt::Display for SessionFileError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(formatter, "failed to access session file: {error}"),
+            Self::InvalidMagic => write!(formatter, "file is not a session file"),
+            Self::UnsupportedVersion(version) => write!(formatter, "unsupported session file version {version}"),
+            Self::InvalidRecord => write!(formatter, "session file contains an invalid record"),
+        }
+    }
+}
+
+/// A recorded network session.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkSession {
+    records: Vec<SessionRecord>,
+}
+
+impl NetworkSession {
+    pub fn new(records: Vec<SessionRecord>) -> Self {
+        Self { records }
+    }
+
+    pub fn records(&self) -> &[SessionRecord] {
+        &self.records
+    }
+
+    pub fn push(&mut self, record: SessionRecord) {
+        self.records.push(record);
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SessionFileError> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SessionFileError> {
+        std::fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SessionFileError> {
+        let mut byte_reader = ByteReader::without_metadata(bytes);
+
+        let magic = byte_reader.bytes::<Self, 4>().map_err(|_| SessionFileError::InvalidMagic)?;
+        if magic != MAGIC {
+            return Err(SessionFileError::InvalidMagic);
+        }
+
+        let version = u16::from_bytes(&mut byte_reader).map_err(|_| SessionFileError::InvalidMagic)?;
+        if version != VERSION {
+            return Err(SessionFileError::UnsupportedVersion(version));
+        }
+
+        let mut records = Vec::new();
+
+        while !byte_reader.is_empty() {
+            let record = SessionRecord::from_bytes(&mut byte_reader).map_err(|_| SessionFileError::InvalidRecord)?;
+            records.push(record);
+        }
+
+        Ok(Self { records })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut byte_writer = ByteWriter::new();
+
+        write_header(&mut byte_writer);
+
+        for record in &self.records {
+            // Writing into a byte writer can't fail.
+            record.to_bytes(&mut byte_writer).unwrap();
+        }
+
+        byte_writer.into_inner()
+    }
+
+    /// Get all chunks received from a specific server, together with their
+    /// time relative to the first chunk exchanged with that server.
+    pub(crate) fn incoming_chunks(&self, server: SessionServer) -> Vec<(Duration, Vec<u8>)> {
+        let mut records = self.records.iter().filter(|record| record.server == server).peekable();
+
+        let Some(start) = records.peek().map(|record| record.timestamp) else {
+            return Vec::new();
+        };
+
+        records
+            .filter(|record| record.direction == SessionDirection::Incoming)
+            .map(|record| (record.timestamp.saturating_sub(start), record.data.clone()))
+            .collect()
+    }
+}
+
+fn write_header(byte_writer: &mut ByteWriter) {
+    byte_writer.extend_from_slice(&MAGIC);
+    byte_writer.extend_from_slice(&VERSION.to_le_bytes());
+}
+
+struct RecorderState {
+    writer: Box<dyn Write + Send>,
+    start: Instant,
+}
+
+/// Writes all traffic of a [`NetworkingSystem`](crate::NetworkingSystem) to a
+/// session file while it is happening. Every record is flushed immediately,
+/// so the recording stays usable even if the client crashes.
+#[derive(Clone)]
+pub struct SessionRecorder {
+    state: Arc<Mutex<RecorderState>>,
+}
+
+impl SessionRecorder {
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, SessionFileError> {
+        let file = File::create(path)?;
+        Self::new(BufWriter::new(file))
+    }
+
+    pub fn new(mut writer: impl Write + Send + 'static) -> Result<Self, SessionFileError> {
+        let mut byte_writer = ByteWriter::new();
+        write_header(&mut byte_writer);
+
+        writer.write_all(byte_writer.as_slice())?;
+        writer.flush()?;
+
+        let state = RecorderState {
+            writer: Box::new(writer),
+            start: Instant::now(),
+        };
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    pub(crate) fn for_server(&self, server: SessionServer) -> ConnectionRecorder {
+        ConnectionRecorder {
+            recorder: self.clone(),
+            server,
+        }
+    }
+
+    fn record(&self, server: SessionServer, direction: SessionDirection, data: &[u8]) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        let record = SessionRecord {
+            timestamp: state.start.elapsed(),
+            server,
+            direction,
+            data: data.to_vec(),
+        };
+
+        let mut byte_writer = ByteWriter::new();
+        // Writing into a byte writer can't fail.
+        record.to_bytes(&mut byte_writer).unwrap();
+
+        // There is no good way to report errors from the networking thread, and a
+        // failing recording should never interrupt the connection, so errors are
+        // ignored.
+        let _ = state.writer.write_all(byte_writer.as_slice());
+        let _ = state.writer.flush();
+    }
+}
+
+/// [`SessionRecorder`] for the connection to a single server.
+pub(crate) struct ConnectionRecorder {
+    recorder: SessionRecorder,
+    server: SessionServer,
+}
+
+impl ConnectionRecorder {
+    pub(crate) fn incoming(&self, data: &[u8]) {
+        self.recorder.record(self.server, SessionDirection::Incoming, data);
+    }
+
+    pub(crate) fn outgoing(&self, data: &[u8]) {
+        self.recorder.record(self.server, SessionDirection::Outgoing, data);
+    }
+}
+
+impl FromBytes for SessionRecord {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let timestamp = Duration::from_millis(u64::from_bytes(byte_reader)?);
+
+        let server = match u8::from_bytes(byte_reader)? {
+            0 => SessionServer::Login,
+            1 => SessionServer::Character,
+            2 => SessionServer::Map,
+            _ => return Err(ConversionError::from_message("invalid session server")),
+        };
+
+        let direction = match u8::from_bytes(byte_reader)? {
+            0 => SessionDirection::Incoming,
+            1 => SessionDirection::Outgoing,
+            _ => return Err(ConversionError::from_message("invalid session direction")),
+        };
+
+        let length = u32::from_bytes(byte_reader)? as usize;
+        let data = byte_reader.slice::<Self>(length)?.to_vec();
+
+        Ok(Self {
+            timestamp,
+            server,
+            direction,
+            data,
+        })
+    }
+}
+
+impl ToBytes for SessionRecord {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        byte_writer.write_counted(|writer| {
+            (self.timestamp.as_millis() as u64).to_bytes(writer)?;
+            (self.server as u8).to_bytes(writer)?;
+            (self.direction as u8).to_bytes(writer)?;
+            (self.data.len() as u32).to_bytes(writer)?;
+            writer.extend_from_slice(&self.data);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{NetworkSession, SessionDirection, SessionFileError, SessionRecord, SessionRecorder, SessionServer};
+
+    #[test]
+    fn round_trip() {
+        let session = NetworkSession::new(vec![
+            SessionRecord {
+                timestamp: Duration::from_millis(0),
+                server: SessionServer::Login,
+                direction: SessionDirection::Outgoing,
+                data: vec![0x64, 0x00, 0x01],
+            },
+            SessionRecord {
+                timestamp: Duration::from_millis(1500),
+                server: SessionServer::Map,
+                direction: SessionDirection::Incoming,
+                data: vec![0x8E, 0x00],
+            },
+        ]);
+
+        let loaded = NetworkSession::from_bytes(&session.to_bytes()).unwrap();
+
+        assert_eq!(loaded.records().len(), 2);
+        assert_eq!(loaded.records()[1].timestamp, Duration::from_millis(1500));
+        assert_eq!(loaded.records()[1].server, SessionServer::Map);
+        assert_eq!(loaded.records()[1].direction, SessionDirection::Incoming);
+        assert_eq!(loaded.records()[1].data, vec![0x8E, 0x00]);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        let result = NetworkSession::from_bytes(b"NOPE\x01\x00");
+        assert!(matches!(result, Err(SessionFileError::InvalidMagic)));
+    }
+
+    #[test]
+    fn incoming_chunks_are_relative_to_server_start() {
+        let session = NetworkSession::new(vec![
+            SessionRecord {
+                timestamp: Duration::from_millis(200),
+                server: SessionServer::Character,
+                direction: SessionDirection::Outgoing,
+                data: vec![1],
+            },
+            SessionRecord {
+                timestamp: Duration::from_millis(500),
+                server: SessionServer::Character,
+                direction: SessionDirection::Incoming,
+                data: vec![2],
+            },
+            SessionRecord {
+                timestamp: Duration::from_millis(600),
+                server: SessionServer::Map,
+                direction: SessionDirection::Incoming,
+                data: vec![3],
+            },
+        ]);
+
+        let chunks = session.incoming_chunks(SessionServer::Character);
+
+        assert_eq!(chunks, vec![(Duration::from_millis(300), vec![2])]);
+    }
+
+    #[test]
+    fn recorder_writes_session_file() {
+        let path = std::env::temp_dir().join(format!("korangar-session-{}.kses", std::process::id()));
+
+        let recorder = SessionRecorder::create(&path).unwrap();
+        recorder.for_server(SessionServer::Login).outgoing(&[1, 2, 3]);
+        recorder.for_server(SessionServer::Character).incoming(&[4, 5]);
+        drop(recorder);
+
+        let session = NetworkSession::load(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(session.records().len(), 2);
+        assert_eq!(session.records()[0].server, SessionServer::Login);
+        assert_eq!(session.records()[0].direction, SessionDirection::Outgoing);
+        assert_eq!(session.records()[0].data, vec![1, 2, 3]);
+        assert_eq!(session.records()[1].server, SessionServer::Character);
+        assert_eq!(session.records()[1].direction, SessionDirection::Incoming);
+        assert_eq!(session.records()[1].data, vec![4, 5]);
+    }
+}
@@ -1,14 +1,13 @@
 use std::sync::Arc;
 
 use cgmath::{Deg, Matrix4, Point3, SquareMatrix, Vector2, Vector3, Vector4, Zero};
-use ragnarok_packets::EntityId;
 use wgpu::BlendFactor;
 
 use super::color::Color;
 #[cfg(feature = "debug")]
 use super::settings::RenderOptions;
 use super::vertices::ModelVertex;
-use super::{Buffer, ShadowQuality, Texture, TextureSet, TileVertex, WaterVertex};
+use super::{Buffer, PickerTarget, ShadowQuality, Texture, TextureSet, TileVertex, WaterVertex};
 use crate::graphics::{CornerDiameter, ScreenClip, ScreenPosition, ScreenSize, ShadowPadding};
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -282,7 +281,7 @@ pub struct EntityInstruction {
     pub curvature: f32,
     pub color: Color,
     pub mirror: bool,
-    pub entity_id: EntityId,
+    pub picker_target: PickerTarget,
     pub add_to_picker: bool,
     pub texture: Arc<Texture>,
     pub distance: f32,
@@ -1,6 +1,6 @@
 #[cfg(feature = "debug")]
 use korangar_container::SimpleKey;
-use ragnarok_packets::EntityId;
+use ragnarok_packets::{EntityId, GroundItemId};
 
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -22,6 +22,7 @@ pub(super) enum PickerValueType {
     EntityMarker,
     #[cfg(feature = "debug")]
     ShadowMarker,
+    GroundItem,
 }
 
 /// Encoding of a `PickerTarget` as `u64` has the following format:
@@ -36,6 +37,7 @@ pub enum PickerTarget {
         y: u16,
     },
     Entity(EntityId),
+    GroundItem(GroundItemId),
     #[cfg(feature = "debug")]
     Marker(MarkerIdentifier),
 }
@@ -58,6 +60,10 @@ impl From<u64> for PickerTarget {
             return Self::Entity(EntityId(data as u32));
         }
 
+        if data >> 32 == PickerValueType::GroundItem as u64 {
+            return Self::GroundItem(GroundItemId(data as u32));
+        }
+
         #[cfg(feature = "debug")]
         if data >> 32 == PickerValueType::ObjectMarker as u64 {
             return Self::Marker(MarkerIdentifier::Object(data as u32));
@@ -105,6 +111,7 @@ impl From<PickerTarget> for (u32, u32) {
             PickerTarget::Nothing => (PickerValueType::Nothing as u32, 0),
             PickerTarget::Tile { x, y } => (PickerValueType::Tile as u32, ((x as u32) << 16) | y as u32),
             PickerTarget::Entity(EntityId(entity_id)) => (PickerValueType::Entity as u32, entity_id),
+            PickerTarget::GroundItem(GroundItemId(ground_item_id)) => (PickerValueType::GroundItem as u32, ground_item_id),
             #[cfg(feature = "debug")]
             PickerTarget::Marker(marker_identifier) => match marker_identifier {
                 MarkerIdentifier::Object(index) => (PickerValueType::ObjectMarker as u32, index.key()),
@@ -122,7 +129,7 @@ impl From<PickerTarget> for (u32, u32) {
 #[cfg(test)]
 #[allow(clippy::unusual_byte_groupings)]
 mod encoding {
-    use ragnarok_packets::EntityId;
+    use ragnarok_packets::{EntityId, GroundItemId};
 
     use crate::graphics::PickerTarget;
     #[cfg(feature = "debug")]
@@ -163,6 +170,9 @@ mod encoding {
     const ENTITY_ID: EntityId = EntityId(7);
     const ENCODED_ENTITY_ID: u64 = 0x00000002_00000007;
 
+    // Ground item
+    const GROUND_ITEM_ID: GroundItemId = GroundItemId(9);
+
     #[test]
     fn from_u64() {
         let target = PickerTarget::Tile { x: X, y: Y };
@@ -282,4 +292,10 @@ mod encoding {
     fn decode_entity() {
         assert_eq!(PickerTarget::from(ENCODED_ENTITY_ID), PickerTarget::Entity(ENTITY_ID));
     }
+
+    #[test]
+    fn encode_decode_ground_item() {
+        let target = PickerTarget::GroundItem(GROUND_ITEM_ID);
+        assert_eq!(PickerTarget::from(u64::from(target)), target);
+    }
 }
@@ -5,14 +5,15 @@ use korangar_debug::profiling::FrameMeasurement;
 use korangar_interface::event::{ClickHandler, Event, EventQueue};
 use korangar_networking::{InventoryItem, ShopItem};
 use ragnarok_packets::{
-    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, ShopId, SoldItemInformation, StatUpType,
-    TilePosition,
+    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, GroundItemId, GuildId, HotbarSlot, MailId, MailOpenType,
+    PartyId, ShopId, SoldItemInformation, StatUpType, TilePosition,
 };
 use rust_state::Context;
 
 use crate::interface::resource::{ItemSource, SkillSource};
 use crate::inventory::Skill;
 use crate::loaders::ServiceId;
+use crate::settings::{KeyAction, KeyChord};
 use crate::state::ClientState;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -73,12 +74,36 @@ pub enum InputEvent {
     ToggleGraphicsSettingsWindow,
     /// Open or close the audio settings window.
     ToggleAudioSettingsWindow,
+    /// Open or close the keymap settings window.
+    ToggleKeymapSettingsWindow,
+    /// Bind a key chord to an action. Triggered by the first key pressed while
+    /// the action is being rebound.
+    RebindKey {
+        /// Action to rebind.
+        action: KeyAction,
+        /// New key chord of the action.
+        chord: KeyChord,
+    },
     /// Open or close the friend list window. Only works while playing.
     ToggleFriendListWindow,
+    /// Open or close the party window. Only works while playing.
+    TogglePartyWindow,
+    /// Open or close the guild window. Only works while playing.
+    ToggleGuildWindow,
+    /// Open or close the quest log window. Only works while playing.
+    ToggleQuestLogWindow,
+    /// Open or close the achievement window. Only works while playing.
+    ToggleAchievementWindow,
+    /// Open or close the emotion window. Only works while playing.
+    ToggleEmotionWindow,
+    /// Open or close the minimap window. Only works while playing.
+    ToggleMinimapWindow,
     /// Close the most recently opened or clicked closable window.
     CloseTopWindow,
     /// Toggle if the user interface should be rendered or not.
     ToggleShowInterface,
+    /// Send the pincode entered in the pincode window to the character server.
+    ConfirmPincode,
     /// Select a character to start playing.
     SelectCharacter {
         /// Slot that the selected character is in.
@@ -119,11 +144,21 @@ pub enum InputEvent {
         /// Id of the entity to interact with.
         entity_id: EntityId,
     },
+    /// Walk to an item on the ground and pick it up.
+    PickUpItem {
+        /// Id of the item to pick up.
+        ground_item_id: GroundItemId,
+    },
     /// Send a chat message.
     SendMessage {
         /// Text of the message.
         text: String,
     },
+    /// Show an emotion above the player.
+    SendEmotion {
+        /// Id of the emotion.
+        emotion: u8,
+    },
     /// Action for the "Next"-button in a dialog.
     NextDialog {
         /// Id of the NPC the player is in a dialog with.
@@ -150,6 +185,17 @@ pub enum InputEvent {
         /// Item to move.
         item: InventoryItem<ResourceMetadata>,
     },
+    /// Move a specific amount of a stackable item in the user interface.
+    MoveItemAmount {
+        /// Source of the move.
+        source: ItemSource,
+        /// Destination of the move.
+        destination: ItemSource,
+        /// Item to move.
+        item: InventoryItem<ResourceMetadata>,
+        /// Amount of the item to move.
+        amount: u16,
+    },
     /// Move a skill in the user interface.
     MoveSkill {
         /// Source of the move.
@@ -195,6 +241,119 @@ pub enum InputEvent {
         /// Character id of the requestor.
         character_id: CharacterId,
     },
+    /// Create a new party.
+    CreateParty {
+        /// Name of the new party.
+        party_name: String,
+    },
+    /// Invite a character to the party.
+    InviteToParty {
+        /// Name of the character to invite.
+        character_name: String,
+    },
+    /// Leave the current party.
+    LeaveParty,
+    /// Remove a member from the party. Only works for the party leader.
+    ExpelPartyMember {
+        /// Account id of the member.
+        account_id: AccountId,
+        /// Name of the member.
+        character_name: String,
+    },
+    /// Make a different member the party leader. Only works for the party
+    /// leader.
+    ChangePartyLeader {
+        /// Account id of the new leader.
+        account_id: AccountId,
+    },
+    /// Reject a pending party invitation.
+    RejectPartyInvitation {
+        /// Id of the party.
+        party_id: PartyId,
+    },
+    /// Accept a pending party invitation.
+    AcceptPartyInvitation {
+        /// Id of the party.
+        party_id: PartyId,
+    },
+    /// Create a new guild.
+    CreateGuild {
+        /// Name of the new guild.
+        guild_name: String,
+    },
+    /// Invite a character to the guild.
+    InviteToGuild {
+        /// Name of the character to invite.
+        character_name: String,
+    },
+    /// Leave the current guild.
+    LeaveGuild,
+    /// Remove a member from the guild. Only works for the guild master.
+    ExpelGuildMember {
+        /// Account id of the member.
+        account_id: AccountId,
+        /// Character id of the member.
+        character_id: CharacterId,
+    },
+    /// Reject a pending guild invitation.
+    RejectGuildInvitation {
+        /// Id of the guild.
+        guild_id: GuildId,
+    },
+    /// Accept a pending guild invitation.
+    AcceptGuildInvitation {
+        /// Id of the guild.
+        guild_id: GuildId,
+    },
+    /// Request a trade with another player.
+    RequestTrade {
+        /// Account id of the player.
+        account_id: AccountId,
+        /// Name of the player.
+        character_name: String,
+    },
+    /// Reject a pending trade request.
+    RejectTradeRequest,
+    /// Accept a pending trade request.
+    AcceptTradeRequest,
+    /// Offer zeny in the current trade.
+    AddTradeZeny {
+        /// Amount of zeny to offer.
+        amount: u32,
+    },
+    /// Lock the items of the current trade.
+    LockTrade,
+    /// Confirm the current trade. Only works once both sides are locked.
+    CommitTrade,
+    /// Cancel the current trade.
+    CancelTrade,
+    /// Close the storage.
+    CloseStorage,
+    /// Open the mailbox. Only works while playing.
+    OpenMailbox,
+    /// Request mails that arrived after the mailbox was opened.
+    RefreshMailbox,
+    /// Close the mailbox.
+    CloseMailbox,
+    /// Read the content of a mail.
+    ReadMail {
+        /// Mailbox of the mail.
+        open_type: MailOpenType,
+        /// Id of the mail.
+        mail_id: MailId,
+    },
+    /// Delete the mail that is currently opened.
+    DeleteMail,
+    /// Move the zeny attached to the opened mail into the inventory.
+    RetrieveMailZeny,
+    /// Move the items attached to the opened mail into the inventory.
+    RetrieveMailItems,
+    /// Start writing a new mail.
+    WriteMail,
+    /// Send the mail that is currently being written.
+    SendMail,
+    /// Stop writing the current mail.
+    CancelWriteMail,
     /// Buy items from a shop.
     BuyItems {
         /// Items to buy.
@@ -6,7 +6,7 @@ use std::mem::variant_count;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use ragnarok_packets::{ClientTick, HotbarSlot};
+use ragnarok_packets::ClientTick;
 use winit::dpi::PhysicalPosition;
 use winit::event::{ElementState, MouseButton, MouseScrollDelta};
 use winit::keyboard::KeyCode;
@@ -15,6 +15,7 @@ pub use self::event::InputEvent;
 pub use self::key::Key;
 pub use self::mode::{Grabbed, MouseInputMode, MouseModeExt};
 use crate::graphics::{PickerTarget, ScreenPosition, ScreenSize};
+use crate::settings::{KeyAction, KeyChord, KeymapSettings};
 
 const MOUSE_SCOLL_MULTIPLIER: f32 = 30.0;
 const KEY_COUNT: usize = variant_count::<KeyCode>();
@@ -49,6 +50,9 @@ pub struct InputSystem {
     left_mouse_button: Key,
     right_mouse_button: Key,
     keys: [Key; KEY_COUNT],
+    /// Keys pressed since the last update, in the order they were pressed.
+    new_pressed_key_codes: Vec<KeyCode>,
+    pressed_key_codes: Vec<KeyCode>,
     input_buffer: Vec<char>,
     picker_value: Arc<AtomicU64>,
     previous_mouse_button: Option<PreviousMouseButton>,
@@ -67,6 +71,8 @@ impl InputSystem {
         let left_mouse_button = Key::default();
         let right_mouse_button = Key::default();
         let keys = [Key::default(); KEY_COUNT];
+        let new_pressed_key_codes = Vec::new();
+        let pressed_key_codes = Vec::new();
 
         let input_buffer = Vec::new();
         let previous_mouse_button = None;
@@ -81,6 +87,8 @@ impl InputSystem {
             left_mouse_button,
             right_mouse_button,
             keys,
+            new_pressed_key_codes,
+            pressed_key_codes,
             input_buffer,
             picker_value,
             previous_mouse_button,
@@ -91,6 +99,7 @@ impl InputSystem {
         self.left_mouse_button.reset();
         self.right_mouse_button.reset();
         self.keys.iter_mut().for_each(|key| key.reset());
+        self.new_pressed_key_codes.clear();
     }
 
     pub fn update_mouse_position(&mut self, position: PhysicalPosition<f64>) {
@@ -120,6 +129,10 @@ impl InputSystem {
     pub fn update_keyboard(&mut self, key_code: KeyCode, state: ElementState) {
         let pressed = matches!(state, ElementState::Pressed);
         self.keys[key_code as usize].set_down(pressed);
+
+        if pressed {
+            self.new_pressed_key_codes.push(key_code);
+        }
     }
 
     pub fn buffer_character(&mut self, character: char) {
@@ -137,6 +150,7 @@ impl InputSystem {
         self.left_mouse_button.update();
         self.right_mouse_button.update();
         self.keys.iter_mut().for_each(|key| key.update());
+        self.pressed_key_codes = std::mem::take(&mut self.new_pressed_key_codes);
 
         let mouse_button_released = self.left_mouse_button.released() || self.right_mouse_button.released();
 
@@ -200,107 +214,57 @@ impl InputSystem {
     pub fn handle_keyboard_input(
         &mut self,
         events: &mut Vec<InputEvent>,
+        keymap: &KeymapSettings,
+        rebinding: Option<KeyAction>,
         #[cfg(feature = "debug")] process_mouse: bool,
         #[cfg(feature = "debug")] use_debug_camera: bool,
     ) {
-        let alt_down = self.get_key(KeyCode::AltLeft).down();
-        let control_down = self.get_key(KeyCode::ControlLeft).down();
-
-        if self.get_key(KeyCode::Escape).pressed() {
-            events.push(InputEvent::ToggleMenuWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyE).pressed() {
-            events.push(InputEvent::ToggleInventoryWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyS).pressed() {
-            events.push(InputEvent::ToggleSkillTreeWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyA).pressed() {
-            events.push(InputEvent::ToggleStatsWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyZ).pressed() {
-            events.push(InputEvent::ToggleFriendListWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyQ).pressed() {
-            events.push(InputEvent::ToggleEquipmentWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyS).pressed() {
-            events.push(InputEvent::ToggleGameSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyI).pressed() {
-            events.push(InputEvent::ToggleInterfaceSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyG).pressed() {
-            events.push(InputEvent::ToggleGraphicsSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyA).pressed() {
-            events.push(InputEvent::ToggleAudioSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyH).pressed() {
-            events.push(InputEvent::ToggleShowInterface);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyQ).pressed() {
-            events.push(InputEvent::CloseTopWindow);
-        }
-
-        if self.get_key(KeyCode::KeyJ).pressed() {
-            events.push(InputEvent::CastSkill { slot: HotbarSlot(0) });
-        }
-
-        if self.get_key(KeyCode::KeyJ).released() {
-            events.push(InputEvent::StopSkill { slot: HotbarSlot(0) });
-        }
-
-        if self.get_key(KeyCode::KeyL).pressed() {
-            events.push(InputEvent::CastSkill { slot: HotbarSlot(1) });
-        }
-
-        if self.get_key(KeyCode::KeyL).released() {
-            events.push(InputEvent::StopSkill { slot: HotbarSlot(1) });
-        }
-
-        if self.get_key(KeyCode::KeyU).pressed() {
-            events.push(InputEvent::CastSkill { slot: HotbarSlot(2) });
-        }
-
-        if self.get_key(KeyCode::KeyU).released() {
-            events.push(InputEvent::StopSkill { slot: HotbarSlot(2) });
-        }
-
-        #[cfg(feature = "debug")]
-        if control_down && self.get_key(KeyCode::KeyM).pressed() {
-            events.push(InputEvent::ToggleMapsWindow);
-        }
+        let control_down = self.get_key(KeyCode::ControlLeft).down() || self.get_key(KeyCode::ControlRight).down();
+        let alt_down = self.get_key(KeyCode::AltLeft).down() || self.get_key(KeyCode::AltRight).down();
+        let shift_down = self.get_key(KeyCode::ShiftLeft).down() || self.get_key(KeyCode::ShiftRight).down();
+
+        // While an action is being rebound, the next chord is captured instead of
+        // triggering any bound actions.
+        if let Some(action) = rebinding {
+            let pressed_key = self
+                .pressed_key_codes
+                .iter()
+                .copied()
+                .find(|key_code| !KeyChord::is_modifier(*key_code));
+
+            if let Some(key) = pressed_key {
+                let chord = KeyChord {
+                    key,
+                    control: control_down,
+                    alt: alt_down,
+                    shift: shift_down,
+                };
+
+                events.push(InputEvent::RebindKey { action, chord });
+            }
 
-        #[cfg(feature = "debug")]
-        if control_down && self.get_key(KeyCode::KeyC).pressed() {
-            events.push(InputEvent::ToggleClientStateInspectorWindow);
+            self.input_buffer.clear();
+            return;
         }
 
-        #[cfg(feature = "debug")]
-        if control_down && self.get_key(KeyCode::KeyR).pressed() {
-            events.push(InputEvent::ToggleRenderOptionsWindow);
-        }
+        for (action, chord) in &keymap.bindings {
+            let key = self.get_key(chord.key);
+            let modifiers_match = chord.control == control_down && chord.alt == alt_down && chord.shift == shift_down;
 
-        #[cfg(feature = "debug")]
-        if control_down && self.get_key(KeyCode::KeyP).pressed() {
-            events.push(InputEvent::ToggleProfilerWindow);
-        }
+            if key.pressed()
+                && modifiers_match
+                && let Some(event) = action.pressed_event()
+            {
+                events.push(event);
+            }
 
-        #[cfg(feature = "debug")]
-        if control_down && self.get_key(KeyCode::KeyN).pressed() {
-            events.push(InputEvent::TogglePacketInspectorWindow);
+            // Modifiers are ignored on release, so that letting go of the modifier first
+            // doesn't leave the action active.
+            if key.released()
+                && let Some(event) = action.released_event()
+            {
+                events.push(event);
+            }
         }
 
         #[cfg(feature = "debug")]
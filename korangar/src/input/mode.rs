@@ -43,6 +43,8 @@ pub trait MouseModeExt {
 
     fn walk_destination(&self) -> Option<TilePosition>;
 
+    fn moved_inventory_item(&self) -> Option<InventoryItem<ResourceMetadata>>;
+
     fn grabbed(&self) -> Option<Grabbed>;
 }
 
@@ -62,6 +64,19 @@ impl MouseModeExt for MouseMode<ClientState> {
         }
     }
 
+    fn moved_inventory_item(&self) -> Option<InventoryItem<ResourceMetadata>> {
+        match self {
+            MouseMode::Custom {
+                mode:
+                    MouseInputMode::MoveItem {
+                        source: ItemSource::Inventory,
+                        item,
+                    },
+            } => Some(item.clone()),
+            _ => None,
+        }
+    }
+
     fn grabbed(&self) -> Option<Grabbed> {
         match self {
             MouseMode::Custom {
@@ -2,6 +2,7 @@ use korangar_interface::MouseMode;
 use korangar_interface::element::store::{ElementStore, ElementStoreMut};
 use korangar_interface::element::{BaseLayoutInfo, Element};
 use korangar_interface::event::{ClickHandler, DropHandler, Event, EventQueue};
+use korangar_interface::layout::area::Area;
 use korangar_interface::layout::{MouseButton, Resolver, WindowLayout};
 use korangar_interface::prelude::{HorizontalAlignment, VerticalAlignment};
 use ragnarok_packets::SkillLevel;
@@ -13,7 +14,7 @@ use crate::interface::resource::SkillSource;
 use crate::inventory::Skill;
 use crate::loaders::{FontSize, OverflowBehavior};
 use crate::renderer::LayoutExt;
-use crate::state::ClientState;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
 
 struct LevelDisplay {
     level: SkillLevel,
@@ -179,6 +180,24 @@ where
                 Color::WHITE,
             );
 
+            // Cover the part of the slot that represents the remaining cooldown.
+            if let Some(remaining_fraction) = state.get(&client_state().skill_tree()).cooldown_fraction(skill.skill_id) {
+                let area = Area {
+                    height: layout_info.area.height * remaining_fraction,
+                    ..layout_info.area
+                };
+
+                layout.add_rectangle(
+                    area,
+                    // TODO: Put this in the theme
+                    CornerDiameter::uniform(20.0),
+                    // TODO: Put this in the theme
+                    Color::rgba_u8(0, 0, 0, 160),
+                    Color::TRANSPARENT,
+                    ShadowPadding::default(),
+                );
+            }
+
             if is_hovered {
                 layout.register_click_handler(MouseButton::Left, &self.handler);
             }
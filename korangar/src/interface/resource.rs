@@ -3,7 +3,16 @@ use ragnarok_packets::{EquipPosition, HotbarSlot};
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ItemSource {
     Inventory,
-    Equipment { position: EquipPosition },
+    Equipment {
+        position: EquipPosition,
+    },
+    Storage,
+    Trade,
+    TradePartner,
+    Mail,
+    ReceivedMail,
+    /// The ground of the map, used for dropping items.
+    Ground,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -0,0 +1,319 @@
+use std::cmp::Ordering;
+
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, ManuallyAssertExt, Path, VecIndexExt};
+
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+use crate::world::{ACHIEVEMENT_CATEGORIES, Achievement, AchievementObjective, AchievementPathExt, Achievements, AchievementsPathExt};
+
+/// Progress of all objectives of a single achievement.
+struct AchievementObjectiveList<A> {
+    objectives_path: A,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> AchievementObjectiveList<A> {
+    fn new(objectives_path: A) -> Self {
+        Self {
+            objectives_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for AchievementObjectiveList<A>
+where
+    A: Path<ClientState, Vec<AchievementObjective>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let objectives = state.get(&self.objectives_path);
+
+        match objectives.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(objectives.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..objectives.len() {
+                    let objective_path = self.objectives_path.index(index).manually_asserted();
+
+                    let objective_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let objective = objective_path.follow(state).unwrap();
+
+                        match objective.required {
+                            0 => objective.text.clone(),
+                            required => {
+                                let color = match objective.current >= required {
+                                    true => "^00aa00",
+                                    false => "^000000",
+                                };
+
+                                format!("{} {color}{}/{required}^000000", objective.text, objective.current)
+                            }
+                        }
+                    });
+
+                    self.elements.push(ErasedElement::new(text! {
+                        text: objective_selector,
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().enumerate().for_each(|(index, element)| {
+            element.create_layout_info(state, store.child_store(index as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        self.elements.iter().enumerate().for_each(|(index, element)| {
+            element.lay_out(state, store.child_store(index as u64), &(), layout);
+        });
+    }
+}
+
+/// All achievements of a single category.
+struct AchievementList<A> {
+    achievements_path: A,
+    category: &'static str,
+    /// Indices of the achievements in this category. The elements are rebuilt
+    /// whenever they change, since the elements refer to the achievements by
+    /// index.
+    indices: Vec<usize>,
+    empty_element: Option<ElementBox<ClientState>>,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> AchievementList<A> {
+    fn new(achievements_path: A, category: &'static str) -> Self {
+        Self {
+            achievements_path,
+            category,
+            indices: Vec::new(),
+            empty_element: None,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for AchievementList<A>
+where
+    A: Path<ClientState, Vec<Achievement>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let achievements = state.get(&self.achievements_path);
+
+        let indices: Vec<usize> = achievements
+            .iter()
+            .enumerate()
+            .filter(|(_, achievement)| achievement.category == self.category)
+            .map(|(index, _)| index)
+            .collect();
+
+        if indices.is_empty() {
+            let empty_element = self.empty_element.get_or_insert_with(|| {
+                ErasedElement::new(text! {
+                    text: client_state().localization().no_achievements_text(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                })
+            });
+
+            empty_element.create_layout_info(state, store.child_store(u64::MAX), resolver);
+        }
+
+        if indices != self.indices {
+            self.elements = indices
+                .iter()
+                .map(|index| {
+                    let achievement_path = self.achievements_path.index(*index).manually_asserted();
+
+                    let title_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let achievement = achievement_path.follow(state).unwrap();
+
+                        match achievement.is_completed {
+                            true => format!("^00aa00{}^000000 ({})", achievement.title, achievement.score),
+                            false => format!("{} ({})", achievement.title, achievement.score),
+                        }
+                    });
+
+                    let status_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let achievement = achievement_path.follow(state).unwrap();
+
+                        match achievement.completion_date() {
+                            Some(date) => {
+                                // SAFETY:
+                                //
+                                // Unwrap is safe here because of the bounds.
+                                let completed_text = client_state().localization().achievement_completed_text().follow(state).unwrap();
+                                format!("^00aa00{completed_text}^000000 {date}")
+                            }
+                            None => {
+                                // SAFETY:
+                                //
+                                // Unwrap is safe here because of the bounds.
+                                let not_completed_text = client_state()
+                                    .localization()
+                                    .achievement_not_completed_text()
+                                    .follow(state)
+                                    .unwrap();
+                                format!("^888888{not_completed_text}^000000")
+                            }
+                        }
+                    });
+
+                    ErasedElement::new(collapsable! {
+                        text: title_selector,
+                        children: (
+                            text! {
+                                text: achievement_path.summary(),
+                            },
+                            AchievementObjectiveList::new(achievement_path.objectives()),
+                            text! {
+                                text: status_selector,
+                                overflow_behavior: OverflowBehavior::Shrink,
+                            },
+                        ),
+                    })
+                })
+                .collect();
+            self.indices = indices;
+        }
+
+        self.elements.iter_mut().zip(self.indices.iter()).for_each(|(element, index)| {
+            let achievement_id = achievements[*index].achievement_id;
+            element.create_layout_info(state, store.child_store(achievement_id as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let achievements = state.get(&self.achievements_path);
+
+        if self.indices.is_empty()
+            && let Some(empty_element) = &self.empty_element
+        {
+            empty_element.lay_out(state, store.child_store(u64::MAX), &(), layout);
+        }
+
+        self.elements.iter().zip(self.indices.iter()).for_each(|(element, index)| {
+            let achievement_id = achievements[*index].achievement_id;
+            element.lay_out(state, store.child_store(achievement_id as u64), &(), layout);
+        });
+    }
+}
+
+pub struct AchievementWindow<A> {
+    achievements_path: A,
+}
+
+impl<A> AchievementWindow<A> {
+    pub fn new(achievements_path: A) -> Self {
+        Self { achievements_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for AchievementWindow<A>
+where
+    A: Path<ClientState, Achievements>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Achievement)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let level_path = self.achievements_path.level();
+        let achievements_path = self.achievements_path.achievements();
+
+        let level_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let level = level_path.follow(state).unwrap();
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let level_text = client_state().localization().achievement_level_text().follow(state).unwrap();
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let score_text = client_state().localization().achievement_score_text().follow(state).unwrap();
+
+            format!(
+                "{level_text} {} ({}/{})   {score_text} {}",
+                level.level, level.experience, level.experience_to_next_level, level.total_score
+            )
+        });
+
+        window! {
+            title: client_state().localization().achievement_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: level_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: ACHIEVEMENT_CATEGORIES.map(|category| {
+                        collapsable! {
+                            text: category,
+                            children: (
+                                AchievementList::new(achievements_path, category),
+                            ),
+                        }
+                    }),
+                },
+            ),
+        }
+    }
+}
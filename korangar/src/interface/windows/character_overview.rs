@@ -1,35 +1,115 @@
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{BaseLayoutInfo, Element};
+use korangar_interface::layout::area::Area;
+use korangar_interface::layout::{Resolver, WindowLayout};
 use korangar_interface::window::{CustomWindow, Window};
-use rust_state::Path;
+use rust_state::{Context, Path};
 
-use crate::graphics::Color;
+use crate::graphics::{Color, CornerDiameter, ShadowPadding};
 use crate::input::InputEvent;
 use crate::interface::windows::WindowClass;
+use crate::inventory::MailboxPathExt;
 use crate::loaders::OverflowBehavior;
+use crate::renderer::LayoutExt;
 use crate::state::localization::LocalizationPathExt;
 use crate::state::theme::InterfaceThemeType;
 use crate::state::{ClientState, ClientStatePathExt, client_state};
+use crate::world::Experience;
 
-pub struct CharacterOverviewWindow<A, B, C> {
+/// Bar showing the progress towards the next base or job level.
+struct ExperienceBar<A> {
+    experience_path: A,
+    color: Color,
+}
+
+impl<A> ExperienceBar<A> {
+    fn new(experience_path: A, color: Color) -> Self {
+        Self { experience_path, color }
+    }
+}
+
+impl<A> Element<ClientState> for ExperienceBar<A>
+where
+    A: Path<ClientState, Experience>,
+{
+    type LayoutInfo = BaseLayoutInfo;
+
+    fn create_layout_info(
+        &mut self,
+        _: &Context<ClientState>,
+        _: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        // TODO: Put this in the theme
+        let area = resolver.with_height(6.0);
+
+        Self::LayoutInfo { area }
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        _: ElementStore<'a>,
+        layout_info: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let fraction = state.get(&self.experience_path).fraction();
+
+        layout.add_rectangle(
+            layout_info.area,
+            // TODO: Put this in the theme
+            CornerDiameter::uniform(6.0),
+            // TODO: Put this in the theme
+            Color::monochrome_u8(40),
+            Color::TRANSPARENT,
+            ShadowPadding::default(),
+        );
+
+        if fraction > 0.0 {
+            let area = Area {
+                width: layout_info.area.width * fraction,
+                ..layout_info.area
+            };
+
+            layout.add_rectangle(
+                area,
+                // TODO: Put this in the theme
+                CornerDiameter::uniform(6.0),
+                self.color,
+                Color::TRANSPARENT,
+                ShadowPadding::default(),
+            );
+        }
+    }
+}
+
+pub struct CharacterOverviewWindow<A, B, C, D, E> {
     player_name_path: A,
     base_level_path: B,
     job_level_path: C,
+    base_experience_path: D,
+    job_experience_path: E,
 }
 
-impl<A, B, C> CharacterOverviewWindow<A, B, C> {
-    pub fn new(player_name_path: A, base_level_path: B, job_level_path: C) -> Self {
+impl<A, B, C, D, E> CharacterOverviewWindow<A, B, C, D, E> {
+    pub fn new(player_name_path: A, base_level_path: B, job_level_path: C, base_experience_path: D, job_experience_path: E) -> Self {
         Self {
             player_name_path,
             base_level_path,
             job_level_path,
+            base_experience_path,
+            job_experience_path,
         }
     }
 }
 
-impl<A, B, C> CustomWindow<ClientState> for CharacterOverviewWindow<A, B, C>
+impl<A, B, C, D, E> CustomWindow<ClientState> for CharacterOverviewWindow<A, B, C, D, E>
 where
     A: Path<ClientState, String>,
     B: Path<ClientState, usize>,
     C: Path<ClientState, usize>,
+    D: Path<ClientState, Experience>,
+    E: Path<ClientState, Experience>,
 {
     fn window_class() -> Option<WindowClass> {
         Some(WindowClass::CharacterOverview)
@@ -38,6 +118,32 @@ where
     fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
         use korangar_interface::prelude::*;
 
+        let base_experience_path = self.base_experience_path;
+        let job_experience_path = self.job_experience_path;
+
+        let base_percentage_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            base_experience_path.follow(state).unwrap().percentage_text()
+        });
+
+        let job_percentage_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            job_experience_path.follow(state).unwrap().percentage_text()
+        });
+
+        let mail_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            let mail_button_text = client_state().localization().mail_button_text().follow(state).unwrap();
+
+            match *client_state().mailbox().has_unread_mail().follow(state).unwrap() {
+                true => format!("{mail_button_text} ^ff8800(!)^000000"),
+                false => mail_button_text.clone(),
+            }
+        });
+
         window! {
             title: client_state().localization().character_overview_window_title(),
             class: Self::window_class(),
@@ -90,6 +196,34 @@ where
                                 },
                             ),
                         },
+                        split! {
+                            children: (
+                                text! {
+                                    text: client_state().localization().base_experience_text(),
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                                text! {
+                                    text: base_percentage_selector,
+                                    horizontal_alignment: HorizontalAlignment::Right { offset: 0.0, border: 3.0 },
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                            ),
+                        },
+                        ExperienceBar::new(base_experience_path, Color::rgb_u8(255, 200, 60)),
+                        split! {
+                            children: (
+                                text! {
+                                    text: client_state().localization().job_experience_text(),
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                                text! {
+                                    text: job_percentage_selector,
+                                    horizontal_alignment: HorizontalAlignment::Right { offset: 0.0, border: 3.0 },
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                            ),
+                        },
+                        ExperienceBar::new(job_experience_path, Color::rgb_u8(90, 170, 255)),
                     ),
                 },
                 button! {
@@ -112,6 +246,34 @@ where
                     text: client_state().localization().friend_list_button_text(),
                     event: InputEvent::ToggleFriendListWindow,
                 },
+                button! {
+                    text: client_state().localization().party_button_text(),
+                    event: InputEvent::TogglePartyWindow,
+                },
+                button! {
+                    text: client_state().localization().guild_button_text(),
+                    event: InputEvent::ToggleGuildWindow,
+                },
+                button! {
+                    text: client_state().localization().quest_log_button_text(),
+                    event: InputEvent::ToggleQuestLogWindow,
+                },
+                button! {
+                    text: client_state().localization().achievement_button_text(),
+                    event: InputEvent::ToggleAchievementWindow,
+                },
+                button! {
+                    text: client_state().localization().emotion_button_text(),
+                    event: InputEvent::ToggleEmotionWindow,
+                },
+                button! {
+                    text: client_state().localization().minimap_button_text(),
+                    event: InputEvent::ToggleMinimapWindow,
+                },
+                button! {
+                    text: mail_selector,
+                    event: InputEvent::OpenMailbox,
+                },
                 button! {
                     text: client_state().localization().menu_button_text(),
                     event: InputEvent::ToggleMenuWindow,
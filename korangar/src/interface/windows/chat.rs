@@ -69,6 +69,10 @@ where
                     MessageColor::Error => Color::monochrome_u8(255),
                     // TODO: Make the color right.
                     MessageColor::Information => Color::monochrome_u8(255),
+                    // TODO: Make the color right.
+                    MessageColor::Party => Color::rgb_u8(255, 200, 200),
+                    // TODO: Make the color right.
+                    MessageColor::Guild => Color::rgb_u8(180, 255, 180),
                 };
 
                 let (size, _) = resolver.get_text_dimensions(
@@ -122,6 +126,10 @@ where
                     MessageColor::Error => Color::monochrome_u8(255),
                     // TODO: Make the color right.
                     MessageColor::Information => Color::monochrome_u8(255),
+                    // TODO: Make the color right.
+                    MessageColor::Party => Color::rgb_u8(255, 200, 200),
+                    // TODO: Make the color right.
+                    MessageColor::Guild => Color::rgb_u8(180, 255, 180),
                 };
 
                 if offset != 0.0 {
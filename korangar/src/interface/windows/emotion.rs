@@ -0,0 +1,43 @@
+use korangar_interface::window::{CustomWindow, Window};
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+use crate::world::EMOTION_COMMANDS;
+
+pub struct EmotionWindow;
+
+impl CustomWindow<ClientState> for EmotionWindow {
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Emotion)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        const EMOTION_COLUMNS: usize = 5;
+        const EMOTION_ROWS: usize = EMOTION_COMMANDS.len() / EMOTION_COLUMNS;
+
+        window! {
+            title: client_state().localization().emotion_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: std::array::from_fn::<_, EMOTION_ROWS, _>(|row| {
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, EMOTION_COLUMNS, _>(|column| {
+                        let (command, emotion) = EMOTION_COMMANDS[row * EMOTION_COLUMNS + column];
+
+                        button! {
+                            text: command,
+                            event: InputEvent::SendEmotion { emotion },
+                        }
+                    }),
+                }
+            }),
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use std::cmp::Ordering;
+
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox, StateElement};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{GuildInformation, GuildMember, GuildNotice, GuildNoticePathExt};
+use ragnarok_packets::GuildPositionName;
+use rust_state::{Context, ManuallyAssertExt, Path, RustState, Selector, VecIndexExt};
+
+use crate::graphics::Color;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+const MINIMUM_NAME_LENGTH: usize = 1;
+const MAXIMUM_NAME_LENGTH: usize = 24;
+
+struct GuildMemberList<A, B> {
+    guild_members_path: A,
+    guild_positions_path: B,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A, B> GuildMemberList<A, B> {
+    fn new(guild_members_path: A, guild_positions_path: B) -> Self {
+        Self {
+            guild_members_path,
+            guild_positions_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A, B> Element<ClientState> for GuildMemberList<A, B>
+where
+    A: Path<ClientState, Vec<GuildMember>>,
+    B: Path<ClientState, Vec<GuildPositionName>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let guild_members = state.get(&self.guild_members_path);
+
+        match guild_members.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(guild_members.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..guild_members.len() {
+                    let member_path = self.guild_members_path.index(index).manually_asserted();
+                    let guild_positions_path = self.guild_positions_path;
+
+                    let name_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let member = member_path.follow(state).unwrap();
+
+                        let name = match member.name.is_empty() {
+                            true => "...",
+                            false => member.name.as_str(),
+                        };
+
+                        match member.is_online {
+                            true => format!("^00aa00{name}^000000"),
+                            false => format!("^888888{name}^000000"),
+                        }
+                    });
+
+                    let details_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let member = member_path.follow(state).unwrap();
+
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let position_name = guild_positions_path
+                            .follow(state)
+                            .unwrap()
+                            .iter()
+                            .find(|position| position.position_id == member.position_id)
+                            .map(|position| position.name.as_str())
+                            .unwrap_or_default();
+
+                        format!("Lv. {} {}", member.base_level, position_name)
+                    });
+
+                    self.elements.push(ErasedElement::new(collapsable! {
+                        text: name_selector,
+                        children: (
+                            text! {
+                                text: details_selector,
+                                overflow_behavior: OverflowBehavior::Shrink,
+                            },
+                            button! {
+                                text: client_state().localization().expel_button_text(),
+                                event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                    let &GuildMember { account_id, character_id, .. } = state.get(&member_path);
+
+                                    queue.queue(InputEvent::ExpelGuildMember { account_id, character_id });
+                                },
+                            },
+                        ),
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().zip(guild_members.iter()).for_each(|(element, member)| {
+            element.create_layout_info(state, store.child_store(member.character_id.0 as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let guild_members = state.get(&self.guild_members_path);
+
+        self.elements.iter().zip(guild_members.iter()).for_each(|(element, member)| {
+            element.lay_out(state, store.child_store(member.character_id.0 as u64), &(), layout);
+        });
+    }
+}
+
+/// Internal state of the guild window.
+#[derive(Default, RustState, StateElement)]
+pub struct GuildWindowState {
+    current_name: String,
+}
+
+pub struct GuildWindow<A, B, C, D, E> {
+    window_state_path: A,
+    guild_information_path: B,
+    guild_notice_path: C,
+    guild_members_path: D,
+    guild_positions_path: E,
+}
+
+impl<A, B, C, D, E> GuildWindow<A, B, C, D, E> {
+    pub fn new(
+        window_state_path: A,
+        guild_information_path: B,
+        guild_notice_path: C,
+        guild_members_path: D,
+        guild_positions_path: E,
+    ) -> Self {
+        Self {
+            window_state_path,
+            guild_information_path,
+            guild_notice_path,
+            guild_members_path,
+            guild_positions_path,
+        }
+    }
+}
+
+impl<A, B, C, D, E> CustomWindow<ClientState> for GuildWindow<A, B, C, D, E>
+where
+    A: Path<ClientState, GuildWindowState>,
+    B: Path<ClientState, Option<GuildInformation>>,
+    C: Path<ClientState, GuildNotice>,
+    D: Path<ClientState, Vec<GuildMember>>,
+    E: Path<ClientState, Vec<GuildPositionName>>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Guild)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct GuildTextBox;
+
+        fn in_guild_selector<P>(guild_information_path: P, expected: bool) -> impl Selector<ClientState, bool>
+        where
+            P: Path<ClientState, Option<GuildInformation>>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                guild_information_path.follow(state).unwrap().is_some() == expected
+            })
+        }
+
+        let guild_information_path = self.guild_information_path;
+        let current_name_path = self.window_state_path.current_name();
+
+        let guild_name_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            match guild_information_path.follow(state).unwrap() {
+                Some(information) => format!(
+                    "^ff8800{}^000000 (Lv. {}, {}/{})",
+                    information.name, information.level, information.online_members, information.maximum_members
+                ),
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                None => client_state().localization().not_in_guild_text().follow(state).unwrap().clone(),
+            }
+        });
+
+        // Depending on whether or not the player is in a guild, the entered name is
+        // either the name of the new guild or the name of the character to invite.
+        let name_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let name = state.get(&current_name_path).clone();
+
+            // TODO: Give some sort of error if the name is too short.
+            if name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(current_name_path, |input| input.clear());
+
+                match state.get(&guild_information_path).is_some() {
+                    true => queue.queue(InputEvent::InviteToGuild { character_name: name }),
+                    false => queue.queue(InputEvent::CreateGuild { guild_name: name }),
+                }
+
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        let create_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let guild_name = state.get(&current_name_path).clone();
+
+            if guild_name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(current_name_path, |input| input.clear());
+                queue.queue(InputEvent::CreateGuild { guild_name });
+            }
+        };
+
+        let invite_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let character_name = state.get(&current_name_path).clone();
+
+            if character_name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(current_name_path, |input| input.clear());
+                queue.queue(InputEvent::InviteToGuild { character_name });
+            }
+        };
+
+        window! {
+            title: client_state().localization().guild_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: guild_name_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                collapsable! {
+                    text: client_state().localization().guild_notice_text(),
+                    initially_expanded: true,
+                    children: (
+                        text! {
+                            text: self.guild_notice_path.subject(),
+                            color: Color::rgb_u8(255, 144, 13),
+                        },
+                        text! {
+                            text: self.guild_notice_path.notice(),
+                        },
+                    ),
+                },
+                GuildMemberList::new(self.guild_members_path, self.guild_positions_path),
+                text_box! {
+                    ghost_text: client_state().localization().guild_text_box_message(),
+                    state: current_name_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_NAME_LENGTH>::new(current_name_path, name_action),
+                    focus_id: GuildTextBox,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().create_guild_button_text(),
+                            disabled: in_guild_selector(guild_information_path, true),
+                            event: create_action,
+                        },
+                        button! {
+                            text: client_state().localization().invite_button_text(),
+                            disabled: in_guild_selector(guild_information_path, false),
+                            event: invite_action,
+                        },
+                    ),
+                },
+                button! {
+                    text: client_state().localization().leave_guild_button_text(),
+                    disabled: in_guild_selector(guild_information_path, false),
+                    event: InputEvent::LeaveGuild,
+                },
+            )
+        }
+    }
+}
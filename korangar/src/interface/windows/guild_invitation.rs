@@ -0,0 +1,49 @@
+use korangar_interface::window::{CustomWindow, Window};
+use ragnarok_packets::GuildId;
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::state::ClientState;
+use crate::state::theme::InterfaceThemeType;
+
+pub struct GuildInvitationWindow {
+    guild_id: GuildId,
+    guild_name: String,
+}
+
+impl GuildInvitationWindow {
+    pub fn new(guild_id: GuildId, guild_name: String) -> Self {
+        Self { guild_id, guild_name }
+    }
+}
+
+impl CustomWindow<ClientState> for GuildInvitationWindow {
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        window! {
+            title: "Guild invitation",
+            class: Some(WindowClass::GuildInvitation),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: format!("You were invited to join the guild ^ff8800{}^000000", self.guild_name),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: "Reject",
+                            event: InputEvent::RejectGuildInvitation { guild_id: self.guild_id },
+                        },
+                        button! {
+                            text: "Accept",
+                            event: InputEvent::AcceptGuildInvitation { guild_id: self.guild_id },
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::InventoryItem;
+use rust_state::{Context, Path, RustState};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+use crate::world::ResourceMetadata;
+
+const MAXIMUM_AMOUNT_LENGTH: usize = 5;
+
+/// Internal state of the item amount window.
+#[derive(Default, RustState, StateElement)]
+pub struct ItemAmountWindowState {
+    current_amount: String,
+}
+
+impl ItemAmountWindowState {
+    pub fn set_amount(&mut self, amount: u16) {
+        self.current_amount = amount.to_string();
+    }
+}
+
+/// Prompt for the amount of a stackable item that is being moved.
+pub struct ItemAmountWindow<A> {
+    window_state_path: A,
+    item: InventoryItem<ResourceMetadata>,
+    maximum_amount: u16,
+    source: ItemSource,
+    destination: ItemSource,
+}
+
+impl<A> ItemAmountWindow<A> {
+    pub fn new(
+        window_state_path: A,
+        item: InventoryItem<ResourceMetadata>,
+        maximum_amount: u16,
+        source: ItemSource,
+        destination: ItemSource,
+    ) -> Self {
+        Self {
+            window_state_path,
+            item,
+            maximum_amount,
+            source,
+            destination,
+        }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for ItemAmountWindow<A>
+where
+    A: Path<ClientState, ItemAmountWindowState>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::ItemAmount)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct AmountTextBox;
+
+        let Self {
+            window_state_path,
+            item,
+            maximum_amount,
+            source,
+            destination,
+        } = self;
+
+        let item_name = item.metadata.name.clone();
+        let current_amount_path = window_state_path.current_amount();
+
+        let move_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            // TODO: Give some sort of error if the amount is not a number.
+            if let Ok(amount) = state.get(&current_amount_path).parse::<u16>() {
+                let amount = amount.clamp(1, maximum_amount);
+
+                queue.queue(InputEvent::MoveItemAmount {
+                    source,
+                    destination,
+                    item: item.clone(),
+                    amount,
+                });
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        window! {
+            title: item_name,
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text_box! {
+                    ghost_text: client_state().localization().amount_text_box_message(),
+                    state: current_amount_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_AMOUNT_LENGTH>::new(current_amount_path, move_action.clone()),
+                    focus_id: AmountTextBox,
+                },
+                button! {
+                    text: client_state().localization().move_button_text(),
+                    event: move_action,
+                },
+            ),
+        }
+    }
+}
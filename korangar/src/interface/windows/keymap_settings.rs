@@ -0,0 +1,158 @@
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, Path, RustState};
+
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::settings::{KeyAction, KeyChord, KeymapSettings};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// Internal state of the keymap settings window.
+#[derive(Default, RustState, StateElement)]
+pub struct KeymapSettingsWindowState {
+    /// Action that is waiting for a new key chord.
+    #[hidden_element]
+    rebinding: Option<KeyAction>,
+    /// The last chord that couldn't be bound because it is already used by
+    /// another action.
+    #[hidden_element]
+    conflict: Option<(KeyChord, KeyAction)>,
+}
+
+impl KeymapSettingsWindowState {
+    pub fn get_rebinding(&self) -> Option<KeyAction> {
+        self.rebinding
+    }
+
+    pub fn stop_rebinding(&mut self) {
+        self.rebinding = None;
+    }
+
+    pub fn set_conflict(&mut self, conflict: Option<(KeyChord, KeyAction)>) {
+        self.conflict = conflict;
+    }
+}
+
+pub struct KeymapSettingsWindow<A, B> {
+    window_state_path: A,
+    keymap_settings_path: B,
+}
+
+impl<A, B> KeymapSettingsWindow<A, B> {
+    pub fn new(window_state_path: A, keymap_settings_path: B) -> Self {
+        Self {
+            window_state_path,
+            keymap_settings_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for KeymapSettingsWindow<A, B>
+where
+    A: Path<ClientState, KeymapSettingsWindowState>,
+    B: Path<ClientState, KeymapSettings>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::KeymapSettings)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let keymap_settings_path = self.keymap_settings_path;
+        let rebinding_path = self.window_state_path.rebinding();
+        let conflict_path = self.window_state_path.conflict();
+
+        let conflict_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwraps are safe here because of the bounds.
+            match conflict_path.follow(state).unwrap() {
+                Some((chord, action)) => {
+                    let localization = client_state().localization().follow(state).unwrap();
+                    let already_bound = client_state().localization().key_already_bound_text().follow(state).unwrap();
+                    let action = localization.key_action_text(*action);
+                    format!("^ff8800{chord}^000000 {already_bound} ^ff8800{action}^000000")
+                }
+                None => String::new(),
+            }
+        });
+
+        let reset_action = move |state: &Context<ClientState>, _: &mut EventQueue<ClientState>| {
+            state.update_value_with(keymap_settings_path, |keymap_settings| keymap_settings.reset());
+            state.update_value(rebinding_path, None);
+            state.update_value(conflict_path, None);
+        };
+
+        window! {
+            title: client_state().localization().keymap_settings_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: conflict_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, { KeyAction::ALL.len() }, _>(|index| {
+                        let action = KeyAction::ALL[index];
+
+                        let action_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                            // SAFETY:
+                            //
+                            // Unwrap is safe here because of the bounds.
+                            client_state().localization().follow(state).unwrap().key_action_text(action)
+                        });
+
+                        let chord_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                            // SAFETY:
+                            //
+                            // Unwraps are safe here because of the bounds.
+                            if *rebinding_path.follow(state).unwrap() == Some(action) {
+                                return client_state().localization().press_key_text().follow(state).unwrap().clone();
+                            }
+
+                            match keymap_settings_path.follow(state).unwrap().get_chord(action) {
+                                Some(chord) => chord.to_string(),
+                                None => "-".to_owned(),
+                            }
+                        });
+
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: (
+                                text! {
+                                    text: action_selector,
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                                button! {
+                                    text: chord_selector,
+                                    event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                        state.update_value(rebinding_path, Some(action));
+                                        state.update_value(conflict_path, None);
+                                        // Keys are only captured while no text box is focused.
+                                        queue.queue(Event::Unfocus);
+                                    },
+                                },
+                                button! {
+                                    text: client_state().localization().unbind_key_button_text(),
+                                    event: move |state: &Context<ClientState>, _: &mut EventQueue<ClientState>| {
+                                        state.update_value_with(keymap_settings_path, move |keymap_settings| keymap_settings.unbind(action));
+                                    },
+                                },
+                            ),
+                        }
+                    }),
+                },
+                button! {
+                    text: client_state().localization().reset_keymap_button_text(),
+                    event: reset_action,
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use korangar_components::item_box;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Path, VecIndexExt};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{OpenedMail, OpenedMailPathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// The server allows a maximum of 5 items per mail.
+pub(super) const MAIL_ITEM_COLUMNS: usize = 5;
+
+pub struct MailWindow<A> {
+    mail_path: A,
+}
+
+impl<A> MailWindow<A> {
+    pub fn new(mail_path: A) -> Self {
+        Self { mail_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for MailWindow<A>
+where
+    A: Path<ClientState, OpenedMail>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Mail)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let mail_path = self.mail_path;
+        let zeny_path = mail_path.zeny();
+        let items_path = mail_path.items();
+
+        let sender_name_path = mail_path.sender_name();
+        let title_path = mail_path.title();
+
+        let sender_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwraps are safe here because of the bounds.
+            format!(
+                "^ff8800{}^000000: {}",
+                sender_name_path.follow(state).unwrap(),
+                title_path.follow(state).unwrap()
+            )
+        });
+
+        let zeny_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            format!("{} Zeny", zeny_path.follow(state).unwrap())
+        });
+
+        let no_zeny = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            *zeny_path.follow(state).unwrap() == 0
+        });
+
+        let no_items = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            items_path.follow(state).unwrap().is_empty()
+        });
+
+        window! {
+            title: client_state().localization().mail_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: sender_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                text! {
+                    text: mail_path.text(),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, MAIL_ITEM_COLUMNS, _>(|column| {
+                        item_box! {
+                            item_path: items_path.index(column),
+                            source: ItemSource::ReceivedMail,
+                        }
+                    }),
+                },
+                text! {
+                    text: zeny_selector,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().retrieve_zeny_button_text(),
+                            disabled: no_zeny,
+                            event: InputEvent::RetrieveMailZeny,
+                        },
+                        button! {
+                            text: client_state().localization().retrieve_items_button_text(),
+                            disabled: no_items,
+                            event: InputEvent::RetrieveMailItems,
+                        },
+                        button! {
+                            text: client_state().localization().delete_mail_button_text(),
+                            event: InputEvent::DeleteMail,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
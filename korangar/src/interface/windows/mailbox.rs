@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::MailSummary;
+use rust_state::{Context, ManuallyAssertExt, Path, VecIndexExt};
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{Mailbox, MailboxPathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+struct MailList<A> {
+    mails_path: A,
+    empty_element: Option<ElementBox<ClientState>>,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> MailList<A> {
+    fn new(mails_path: A) -> Self {
+        Self {
+            mails_path,
+            empty_element: None,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for MailList<A>
+where
+    A: Path<ClientState, Vec<MailSummary>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let mails = state.get(&self.mails_path);
+
+        if mails.is_empty() {
+            let empty_element = self.empty_element.get_or_insert_with(|| {
+                ErasedElement::new(text! {
+                    text: client_state().localization().no_mail_text(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                })
+            });
+
+            empty_element.create_layout_info(state, store.child_store(u64::MAX), resolver);
+        }
+
+        match mails.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(mails.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..mails.len() {
+                    let mail_path = self.mails_path.index(index).manually_asserted();
+
+                    let mail_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let mail = mail_path.follow(state).unwrap();
+
+                        let color = match mail.is_read {
+                            true => "^888888",
+                            false => "^ff8800",
+                        };
+                        let attachment = match mail.has_zeny || mail.has_items {
+                            true => " ^000000[+]",
+                            false => "",
+                        };
+
+                        format!("{color}{}^000000 {}{attachment}", mail.sender_name, mail.title)
+                    });
+
+                    let read_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                        let mail = state.get(&mail_path);
+
+                        queue.queue(InputEvent::ReadMail {
+                            open_type: mail.open_type,
+                            mail_id: mail.mail_id,
+                        });
+                    };
+
+                    self.elements.push(ErasedElement::new(button! {
+                        text: mail_selector,
+                        event: read_action,
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().zip(mails.iter()).for_each(|(element, mail)| {
+            element.create_layout_info(state, store.child_store(mail.mail_id.0), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let mails = state.get(&self.mails_path);
+
+        if mails.is_empty()
+            && let Some(empty_element) = &self.empty_element
+        {
+            empty_element.lay_out(state, store.child_store(u64::MAX), &(), layout);
+        }
+
+        self.elements.iter().zip(mails.iter()).for_each(|(element, mail)| {
+            element.lay_out(state, store.child_store(mail.mail_id.0), &(), layout);
+        });
+    }
+}
+
+pub struct MailboxWindow<A> {
+    mailbox_path: A,
+}
+
+impl<A> MailboxWindow<A> {
+    pub fn new(mailbox_path: A) -> Self {
+        Self { mailbox_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for MailboxWindow<A>
+where
+    A: Path<ClientState, Mailbox>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Mailbox)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        window! {
+            title: client_state().localization().mailbox_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            // The server needs to know when the mailbox is closed.
+            close_event: Some(InputEvent::CloseMailbox),
+            elements: (
+                MailList::new(self.mailbox_path.mails()),
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().write_mail_button_text(),
+                            event: InputEvent::WriteMail,
+                        },
+                        button! {
+                            text: client_state().localization().refresh_mailbox_button_text(),
+                            event: InputEvent::RefreshMailbox,
+                        },
+                        button! {
+                            text: client_state().localization().close_button_text(),
+                            event: InputEvent::CloseMailbox,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
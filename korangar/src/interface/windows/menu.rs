@@ -43,6 +43,10 @@ impl CustomWindow<ClientState> for MenuWindow {
                     text: client_state().localization().audio_settings_button_text(),
                     event: InputEvent::ToggleAudioSettingsWindow,
                 },
+                button! {
+                    text: client_state().localization().keymap_settings_button_text(),
+                    event: InputEvent::ToggleKeymapSettingsWindow,
+                },
                 #[cfg(feature = "debug")]
                 button! {
                     text: "Render options",
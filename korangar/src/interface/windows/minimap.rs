@@ -0,0 +1,238 @@
+use std::cell::Cell;
+
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{BaseLayoutInfo, Element};
+use korangar_interface::event::{ClickHandler, EventQueue};
+use korangar_interface::layout::area::Area;
+use korangar_interface::layout::{MouseButton, Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::PartyMember;
+use ragnarok_packets::TilePosition;
+use rust_state::{Context, Path};
+
+use crate::graphics::{Color, CornerDiameter, ShadowPadding};
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::renderer::LayoutExt;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state, this_entity};
+use crate::world::{Entity, EntityType, Minimap};
+
+// TODO: Put these in the theme
+const MINIMAP_SIZE: f32 = 230.0;
+const ENTITY_DOT_SIZE: f32 = 4.0;
+const MARKER_DOT_SIZE: f32 = 7.0;
+const PLAYER_DOT_SIZE: f32 = 6.0;
+
+/// Moves the player to the tile under the mouse when clicking the minimap.
+#[derive(Default)]
+struct MinimapClickHandler {
+    destination: Cell<Option<TilePosition>>,
+}
+
+impl ClickHandler<ClientState> for MinimapClickHandler {
+    fn handle_click(&self, _: &Context<ClientState>, queue: &mut EventQueue<ClientState>) {
+        if let Some(destination) = self.destination.get() {
+            queue.queue(InputEvent::PlayerMove { destination });
+        }
+    }
+}
+
+/// Square part of the area that the map is drawn into.
+fn map_area(area: Area) -> Area {
+    let size = area.width.min(area.height);
+
+    Area {
+        left: area.left + (area.width - size) / 2.0,
+        top: area.top + (area.height - size) / 2.0,
+        width: size,
+        height: size,
+    }
+}
+
+struct MinimapView<A, B, C> {
+    minimap_path: A,
+    entities_path: B,
+    party_members_path: C,
+    handler: MinimapClickHandler,
+}
+
+impl<A, B, C> MinimapView<A, B, C> {
+    fn new(minimap_path: A, entities_path: B, party_members_path: C) -> Self {
+        Self {
+            minimap_path,
+            entities_path,
+            party_members_path,
+            handler: MinimapClickHandler::default(),
+        }
+    }
+}
+
+impl<A, B, C> Element<ClientState> for MinimapView<A, B, C>
+where
+    A: Path<ClientState, Minimap>,
+    B: Path<ClientState, Vec<Entity>>,
+    C: Path<ClientState, Vec<PartyMember>>,
+{
+    type LayoutInfo = BaseLayoutInfo;
+
+    fn create_layout_info(
+        &mut self,
+        _: &Context<ClientState>,
+        _: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        let area = resolver.with_height(MINIMAP_SIZE);
+        Self::LayoutInfo { area }
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        _: ElementStore<'a>,
+        layout_info: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let minimap = state.get(&self.minimap_path);
+        let area = map_area(layout_info.area);
+
+        let Some(texture) = minimap.get_texture() else {
+            return;
+        };
+
+        layout.add_texture(area, texture.clone(), Color::WHITE, true);
+
+        let (map_width, map_height) = minimap.get_map_size();
+
+        if map_width == 0 || map_height == 0 {
+            return;
+        }
+
+        // Tile positions grow towards the north, so the y axis is flipped.
+        let mut add_dot = |position: TilePosition, size: f32, color: Color| {
+            let dot_area = Area {
+                left: area.left + (position.x as f32 + 0.5) / map_width as f32 * area.width - size / 2.0,
+                top: area.top + (1.0 - (position.y as f32 + 0.5) / map_height as f32) * area.height - size / 2.0,
+                width: size,
+                height: size,
+            };
+
+            layout.add_rectangle(
+                dot_area,
+                CornerDiameter::uniform(size),
+                color,
+                Color::TRANSPARENT,
+                ShadowPadding::default(),
+            );
+        };
+
+        // The first entity is the player, which is drawn last so it is always on top.
+        for entity in state.get(&self.entities_path).iter().skip(1) {
+            let color = match entity.get_entity_type() {
+                EntityType::Npc => Color::rgb_u8(80, 200, 80),
+                EntityType::Monster => Color::rgb_u8(220, 90, 60),
+                EntityType::Player => Color::rgb_u8(80, 140, 230),
+                EntityType::Warp => Color::rgb_u8(190, 80, 220),
+                EntityType::Hidden => continue,
+            };
+
+            add_dot(entity.get_tile_position(), ENTITY_DOT_SIZE, color);
+        }
+
+        for party_member in state.get(&self.party_members_path) {
+            if let Some(position) = party_member.position {
+                add_dot(position, ENTITY_DOT_SIZE, Color::rgb_u8(240, 120, 180));
+            }
+        }
+
+        for marker in minimap.get_markers() {
+            add_dot(marker.position, MARKER_DOT_SIZE, marker.color);
+        }
+
+        if let Some(player) = state.try_get(&this_entity()) {
+            add_dot(player.get_tile_position(), PLAYER_DOT_SIZE, Color::rgb_u8(255, 230, 80));
+        }
+
+        match area.check().run(layout) {
+            true => {
+                let mouse_position = layout.get_mouse_position();
+                let x = (mouse_position.left - area.left) / area.width * map_width as f32;
+                let y = (1.0 - (mouse_position.top - area.top) / area.height) * map_height as f32;
+
+                self.handler.destination.set(Some(TilePosition {
+                    x: (x as u16).min(map_width - 1),
+                    y: (y as u16).min(map_height - 1),
+                }));
+
+                layout.register_click_handler(MouseButton::Left, &self.handler);
+            }
+            false => self.handler.destination.set(None),
+        }
+    }
+}
+
+pub struct MinimapWindow<A, B, C> {
+    minimap_path: A,
+    entities_path: B,
+    party_members_path: C,
+}
+
+impl<A, B, C> MinimapWindow<A, B, C> {
+    pub fn new(minimap_path: A, entities_path: B, party_members_path: C) -> Self {
+        Self {
+            minimap_path,
+            entities_path,
+            party_members_path,
+        }
+    }
+}
+
+impl<A, B, C> CustomWindow<ClientState> for MinimapWindow<A, B, C>
+where
+    A: Path<ClientState, Minimap>,
+    B: Path<ClientState, Vec<Entity>>,
+    C: Path<ClientState, Vec<PartyMember>>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Minimap)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let minimap_path = self.minimap_path;
+
+        let location_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let minimap = minimap_path.follow(state).unwrap();
+
+            match this_entity().follow(state) {
+                Some(player) => {
+                    let position = player.get_tile_position();
+                    format!("{} ({}, {})", minimap.get_map_name(), position.x, position.y)
+                }
+                None => minimap.get_map_name().to_owned(),
+            }
+        });
+
+        window! {
+            title: client_state().localization().minimap_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            minimum_width: 260.0,
+            maximum_width: 260.0,
+            elements: (
+                text! {
+                    text: location_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                MinimapView::new(self.minimap_path, self.entities_path, self.party_members_path),
+            ),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod achievement;
 mod audio_settings;
 mod buy;
 mod buy_cart;
@@ -10,6 +11,7 @@ mod chat;
 #[cfg(feature = "debug")]
 mod commands;
 mod dialog;
+mod emotion;
 mod equipment;
 mod error;
 #[cfg(feature = "debug")]
@@ -18,17 +20,29 @@ mod friend_list;
 mod friend_request;
 mod game_settings;
 mod graphics_settings;
+mod guild;
+mod guild_invitation;
 mod hotbar;
 mod interface_settings;
 mod inventory;
+mod item_amount;
+mod keymap_settings;
 mod login;
+mod mail;
+mod mailbox;
 #[cfg(feature = "debug")]
 mod maps;
 mod menu;
+mod minimap;
 #[cfg(feature = "debug")]
 mod packet_inspector;
+mod party;
+mod party_invitation;
+mod pincode;
+mod player_interaction;
 #[cfg(feature = "debug")]
 mod profiler;
+mod quest_log;
 #[cfg(feature = "debug")]
 mod render_options;
 mod respawn;
@@ -37,11 +51,16 @@ mod sell_cart;
 mod server_selection;
 mod skill_tree;
 mod stats;
+mod storage;
 #[cfg(feature = "debug")]
 mod theme_inspector;
+mod trade;
+mod trade_request;
+mod write_mail;
 
 use serde::{Deserialize, Serialize};
 
+pub use self::achievement::AchievementWindow;
 pub use self::audio_settings::AudioSettingsWindow;
 pub use self::buy::BuyWindow;
 pub use self::buy_cart::BuyCartWindow;
@@ -54,6 +73,7 @@ pub use self::chat::{ChatTextBox, ChatWindow, ChatWindowState};
 #[cfg(feature = "debug")]
 pub use self::commands::CommandsWindow;
 pub use self::dialog::{DialogWindow, DialogWindowState};
+pub use self::emotion::EmotionWindow;
 pub use self::equipment::EquipmentWindow;
 pub use self::error::ErrorWindow;
 #[cfg(feature = "debug")]
@@ -62,17 +82,29 @@ pub use self::friend_list::{FriendListWindow, FriendListWindowState};
 pub use self::friend_request::FriendRequestWindow;
 pub use self::game_settings::GameSettingsWindow;
 pub use self::graphics_settings::GraphicsSettingsWindow;
+pub use self::guild::{GuildWindow, GuildWindowState};
+pub use self::guild_invitation::GuildInvitationWindow;
 pub use self::hotbar::HotbarWindow;
 pub use self::interface_settings::InterfaceSettingsWindow;
 pub use self::inventory::InventoryWindow;
+pub use self::item_amount::{ItemAmountWindow, ItemAmountWindowState};
+pub use self::keymap_settings::{KeymapSettingsWindow, KeymapSettingsWindowState};
 pub use self::login::{LoginWindow, LoginWindowState};
+pub use self::mail::MailWindow;
+pub use self::mailbox::MailboxWindow;
 #[cfg(feature = "debug")]
 pub use self::maps::MapsWindow;
 pub use self::menu::MenuWindow;
+pub use self::minimap::MinimapWindow;
 #[cfg(feature = "debug")]
 pub use self::packet_inspector::PacketInspectorWindow;
+pub use self::party::{PartyWindow, PartyWindowState};
+pub use self::party_invitation::PartyInvitationWindow;
+pub use self::pincode::{PincodeSubmission, PincodeWindow, PincodeWindowState};
+pub use self::player_interaction::PlayerInteractionWindow;
 #[cfg(feature = "debug")]
 pub use self::profiler::{ProfilerWindow, ProfilerWindowState};
+pub use self::quest_log::QuestLogWindow;
 #[cfg(feature = "debug")]
 pub use self::render_options::RenderOptionsWindow;
 pub use self::respawn::RespawnWindow;
@@ -81,8 +113,12 @@ pub use self::sell_cart::SellCartWindow;
 pub use self::server_selection::ServerSelectionWindow;
 pub use self::skill_tree::SkillTreeWindow;
 pub use self::stats::StatsWindow;
+pub use self::storage::StorageWindow;
 #[cfg(feature = "debug")]
 pub use self::theme_inspector::{ThemeInspectorWindow, ThemeInspectorWindowState};
+pub use self::trade::{TradeWindow, TradeWindowState};
+pub use self::trade_request::TradeRequestWindow;
+pub use self::write_mail::{WriteMailWindow, WriteMailWindowState};
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WindowClass {
@@ -94,17 +130,35 @@ pub enum WindowClass {
     CharacterCreation,
     CharacterOverview,
     CharacterSelection,
+    Pincode,
     Dialog,
     GameSettings,
     InterfaceSettings,
     GraphicsSettings,
+    KeymapSettings,
     Hotbar,
     Inventory,
+    ItemAmount,
+    Storage,
     Equipment,
     SkillTree,
     Stats,
     FriendList,
     FriendRequest,
+    Party,
+    PartyInvitation,
+    Guild,
+    GuildInvitation,
+    QuestLog,
+    Achievement,
+    Emotion,
+    Minimap,
+    Mailbox,
+    Mail,
+    WriteMail,
+    PlayerInteraction,
+    Trade,
+    TradeRequest,
     Login,
     Menu,
     Respawn,
@@ -0,0 +1,366 @@
+use std::cmp::Ordering;
+
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{BaseLayoutInfo, Element, ElementBox, StateElement};
+use korangar_interface::layout::area::Area;
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{PartyMember, PartyMemberPathExt};
+use rust_state::{Context, ManuallyAssertExt, Path, RustState, Selector, VecIndexExt};
+
+use crate::graphics::{Color, CornerDiameter, ShadowPadding};
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::renderer::LayoutExt;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::{InterfaceThemeType, StatusBarThemePathExt, WorldThemePathExt};
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+const MINIMUM_NAME_LENGTH: usize = 1;
+const MAXIMUM_NAME_LENGTH: usize = 24;
+
+/// Health bar of a single party member.
+struct HealthBar<A, B> {
+    health_points_path: A,
+    maximum_health_points_path: B,
+}
+
+impl<A, B> HealthBar<A, B> {
+    fn new(health_points_path: A, maximum_health_points_path: B) -> Self {
+        Self {
+            health_points_path,
+            maximum_health_points_path,
+        }
+    }
+}
+
+impl<A, B> Element<ClientState> for HealthBar<A, B>
+where
+    A: Path<ClientState, usize>,
+    B: Path<ClientState, usize>,
+{
+    type LayoutInfo = BaseLayoutInfo;
+
+    fn create_layout_info(
+        &mut self,
+        _: &Context<ClientState>,
+        _: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        // TODO: Put this in the theme
+        let area = resolver.with_height(10.0);
+
+        Self::LayoutInfo { area }
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        _: ElementStore<'a>,
+        layout_info: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let health_points = *state.get(&self.health_points_path);
+        let maximum_health_points = *state.get(&self.maximum_health_points_path);
+        let background_color = *state.get(&client_state().world_theme().status_bar().background_color());
+        let health_color = *state.get(&client_state().world_theme().status_bar().player_health_color());
+
+        layout.add_rectangle(
+            layout_info.area,
+            // TODO: Put this in the theme
+            CornerDiameter::uniform(5.0),
+            background_color,
+            Color::TRANSPARENT,
+            ShadowPadding::default(),
+        );
+
+        // The server only sends the health points of members on the same map.
+        if maximum_health_points == 0 {
+            return;
+        }
+
+        let fill = (health_points as f32 / maximum_health_points as f32).clamp(0.0, 1.0);
+        let area = Area {
+            width: layout_info.area.width * fill,
+            ..layout_info.area
+        };
+
+        layout.add_rectangle(
+            area,
+            // TODO: Put this in the theme
+            CornerDiameter::uniform(5.0),
+            health_color,
+            Color::TRANSPARENT,
+            ShadowPadding::default(),
+        );
+    }
+}
+
+struct PartyMemberList<A> {
+    party_members_path: A,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> PartyMemberList<A> {
+    fn new(party_members_path: A) -> Self {
+        Self {
+            party_members_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for PartyMemberList<A>
+where
+    A: Path<ClientState, Vec<PartyMember>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let party_members = state.get(&self.party_members_path);
+
+        match party_members.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(party_members.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..party_members.len() {
+                    let member_path = self.party_members_path.index(index).manually_asserted();
+
+                    let location_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let member = member_path.follow(state).unwrap();
+
+                        match member.is_online {
+                            true => member.map_name.clone(),
+                            false => client_state().localization().offline_text().follow(state).unwrap().clone(),
+                        }
+                    });
+
+                    let name_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let member = member_path.follow(state).unwrap();
+
+                        match member.is_leader {
+                            true => {
+                                let leader_text = client_state().localization().party_leader_text().follow(state).unwrap();
+                                format!("{} ^ff8800({leader_text})^000000", member.name)
+                            }
+                            false => member.name.clone(),
+                        }
+                    });
+
+                    self.elements.push(ErasedElement::new(collapsable! {
+                        text: name_selector,
+                        initially_expanded: true,
+                        children: (
+                            text! {
+                                text: location_selector,
+                                overflow_behavior: OverflowBehavior::Shrink,
+                            },
+                            HealthBar::new(member_path.health_points(), member_path.maximum_health_points()),
+                            split! {
+                                gaps: theme().window().gaps(),
+                                children: (
+                                    button! {
+                                        text: client_state().localization().make_leader_button_text(),
+                                        event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                            let account_id = state.get(&member_path).account_id;
+                                            queue.queue(InputEvent::ChangePartyLeader { account_id });
+                                        },
+                                    },
+                                    button! {
+                                        text: client_state().localization().expel_button_text(),
+                                        event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                            let PartyMember { account_id, name, .. } = state.get(&member_path);
+
+                                            queue.queue(InputEvent::ExpelPartyMember {
+                                                account_id: *account_id,
+                                                character_name: name.clone(),
+                                            });
+                                        },
+                                    },
+                                ),
+                            },
+                        ),
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().zip(party_members.iter()).for_each(|(element, member)| {
+            element.create_layout_info(state, store.child_store(member.character_id.0 as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let party_members = state.get(&self.party_members_path);
+
+        self.elements.iter().zip(party_members.iter()).for_each(|(element, member)| {
+            element.lay_out(state, store.child_store(member.character_id.0 as u64), &(), layout);
+        });
+    }
+}
+
+/// Internal state of the party window.
+#[derive(Default, RustState, StateElement)]
+pub struct PartyWindowState {
+    current_name: String,
+}
+
+pub struct PartyWindow<A, B, C> {
+    window_state_path: A,
+    party_name_path: B,
+    party_members_path: C,
+}
+
+impl<A, B, C> PartyWindow<A, B, C> {
+    pub fn new(window_state_path: A, party_name_path: B, party_members_path: C) -> Self {
+        Self {
+            window_state_path,
+            party_name_path,
+            party_members_path,
+        }
+    }
+}
+
+impl<A, B, C> CustomWindow<ClientState> for PartyWindow<A, B, C>
+where
+    A: Path<ClientState, PartyWindowState>,
+    B: Path<ClientState, Option<String>>,
+    C: Path<ClientState, Vec<PartyMember>>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Party)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct PartyTextBox;
+
+        fn in_party_selector<P>(party_name_path: P, expected: bool) -> impl Selector<ClientState, bool>
+        where
+            P: Path<ClientState, Option<String>>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                party_name_path.follow(state).unwrap().is_some() == expected
+            })
+        }
+
+        let party_name_path = self.party_name_path;
+        let current_name_path = self.window_state_path.current_name();
+
+        let party_name_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            match party_name_path.follow(state).unwrap() {
+                Some(party_name) => format!("^ff8800{party_name}^000000"),
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                None => client_state().localization().not_in_party_text().follow(state).unwrap().clone(),
+            }
+        });
+
+        // Depending on whether or not the player is in a party, the entered name is
+        // either the name of the new party or the name of the character to invite.
+        let name_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let name = state.get(&current_name_path).clone();
+
+            // TODO: Give some sort of error if the name is too short.
+            if name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(current_name_path, |input| input.clear());
+
+                match state.get(&party_name_path).is_some() {
+                    true => queue.queue(InputEvent::InviteToParty { character_name: name }),
+                    false => queue.queue(InputEvent::CreateParty { party_name: name }),
+                }
+
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        let create_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let party_name = state.get(&current_name_path).clone();
+
+            if party_name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(current_name_path, |input| input.clear());
+                queue.queue(InputEvent::CreateParty { party_name });
+            }
+        };
+
+        let invite_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let character_name = state.get(&current_name_path).clone();
+
+            if character_name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(current_name_path, |input| input.clear());
+                queue.queue(InputEvent::InviteToParty { character_name });
+            }
+        };
+
+        window! {
+            title: client_state().localization().party_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: party_name_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                PartyMemberList::new(self.party_members_path),
+                text_box! {
+                    ghost_text: client_state().localization().party_text_box_message(),
+                    state: current_name_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_NAME_LENGTH>::new(current_name_path, name_action),
+                    focus_id: PartyTextBox,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().create_party_button_text(),
+                            disabled: in_party_selector(party_name_path, true),
+                            event: create_action,
+                        },
+                        button! {
+                            text: client_state().localization().invite_button_text(),
+                            disabled: in_party_selector(party_name_path, false),
+                            event: invite_action,
+                        },
+                    ),
+                },
+                button! {
+                    text: client_state().localization().leave_party_button_text(),
+                    disabled: in_party_selector(party_name_path, false),
+                    event: InputEvent::LeaveParty,
+                },
+            )
+        }
+    }
+}
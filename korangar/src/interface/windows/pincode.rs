@@ -0,0 +1,231 @@
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{PINCODE_LENGTH, PincodeRequest};
+use rand_aes::tls::rand_f32;
+use rust_state::{Context, Path, RustState};
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+const DIGIT_TEXTS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+/// Pincode entered by the player, ready to be sent to the character server.
+pub enum PincodeSubmission {
+    Check { pincode: String },
+    Set { pincode: String },
+    Change { old_pincode: String, new_pincode: String },
+}
+
+/// Internal state of the pincode window.
+#[derive(Default, RustState, StateElement)]
+pub struct PincodeWindowState {
+    seed: u32,
+    request: PincodeRequest,
+    /// Current pincode while changing the pincode. Once this is set, the
+    /// player enters the new pincode.
+    old_pincode: Option<String>,
+    pincode: String,
+    message: String,
+}
+
+impl PincodeWindowState {
+    pub fn new(seed: u32, request: PincodeRequest, message: Option<String>) -> Self {
+        Self {
+            seed,
+            request,
+            message: message.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    pub fn get_seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Confirm the entered pincode. When changing the pincode, the first
+    /// confirmation only stores the current pincode and asks for the new one.
+    pub fn confirm(&mut self) -> Option<PincodeSubmission> {
+        let pincode = std::mem::take(&mut self.pincode);
+
+        match self.request {
+            PincodeRequest::Check => Some(PincodeSubmission::Check { pincode }),
+            PincodeRequest::Set => Some(PincodeSubmission::Set { pincode }),
+            PincodeRequest::Change => match self.old_pincode.take() {
+                Some(old_pincode) => Some(PincodeSubmission::Change {
+                    old_pincode,
+                    new_pincode: pincode,
+                }),
+                None => {
+                    self.old_pincode = Some(pincode);
+                    self.message.clear();
+                    None
+                }
+            },
+        }
+    }
+}
+
+pub struct PincodeWindow<A> {
+    window_state_path: A,
+    /// Order of the digits on the keypad. The digits are shuffled every time
+    /// the window is opened, so the pincode can't be guessed from the mouse
+    /// position.
+    keypad: [u8; 10],
+}
+
+impl<A> PincodeWindow<A> {
+    pub fn new(window_state_path: A) -> Self {
+        let mut keypad = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        for index in (1..keypad.len()).rev() {
+            let position = ((rand_f32() * (index + 1) as f32) as usize).min(index);
+            keypad.swap(index, position);
+        }
+
+        Self { window_state_path, keypad }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for PincodeWindow<A>
+where
+    A: Path<ClientState, PincodeWindowState>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Pincode)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        const KEYPAD_COLUMNS: usize = 3;
+        const KEYPAD_ROWS: usize = 3;
+
+        let request_path = self.window_state_path.request();
+        let old_pincode_path = self.window_state_path.old_pincode();
+        let pincode_path = self.window_state_path.pincode();
+        let message_path = self.window_state_path.message();
+        let keypad = self.keypad;
+
+        let prompt_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwraps are safe here because of the bounds.
+            let request = request_path.follow(state).unwrap();
+            let is_entering_new = old_pincode_path.follow(state).unwrap().is_some();
+
+            let prompt_path = match (request, is_entering_new) {
+                (PincodeRequest::Check, _) => client_state().localization().enter_pincode_text(),
+                (PincodeRequest::Set, _) => client_state().localization().set_pincode_text(),
+                (PincodeRequest::Change, false) => client_state().localization().enter_current_pincode_text(),
+                (PincodeRequest::Change, true) => client_state().localization().enter_new_pincode_text(),
+            };
+
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            prompt_path.follow(state).unwrap().clone()
+        });
+
+        let message_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let message = message_path.follow(state).unwrap();
+            format!("^ff5555{message}^000000")
+        });
+
+        let entered_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let entered_length = pincode_path.follow(state).unwrap().len();
+
+            (0..PINCODE_LENGTH)
+                .map(|index| match index < entered_length {
+                    true => "*",
+                    false => "_",
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+
+        let cannot_confirm = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            pincode_path.follow(state).unwrap().len() != PINCODE_LENGTH
+        });
+
+        let digit_button = move |digit: u8| {
+            button! {
+                text: DIGIT_TEXTS[digit as usize],
+                event: move |state: &Context<ClientState>, _: &mut EventQueue<ClientState>| {
+                    state.update_value_with(pincode_path, move |pincode| {
+                        if pincode.len() < PINCODE_LENGTH {
+                            pincode.push(char::from(b'0' + digit));
+                        }
+                    });
+                },
+            }
+        };
+
+        window! {
+            title: client_state().localization().pincode_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::Menu,
+            closable: false,
+            minimum_width: 300.0,
+            maximum_width: 300.0,
+            elements: (
+                text! {
+                    text: prompt_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                text! {
+                    text: message_selector,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                text! {
+                    text: entered_selector,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, KEYPAD_ROWS, _>(|row| {
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: std::array::from_fn::<_, KEYPAD_COLUMNS, _>(|column| {
+                                digit_button(keypad[row * KEYPAD_COLUMNS + column])
+                            }),
+                        }
+                    }),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().clear_pincode_button_text(),
+                            event: move |state: &Context<ClientState>, _: &mut EventQueue<ClientState>| {
+                                state.update_value_with(pincode_path, |pincode| pincode.clear());
+                            },
+                        },
+                        digit_button(keypad[KEYPAD_ROWS * KEYPAD_COLUMNS]),
+                        button! {
+                            text: client_state().localization().confirm_pincode_button_text(),
+                            disabled: cannot_confirm,
+                            event: InputEvent::ConfirmPincode,
+                        },
+                    ),
+                },
+                // The window can't be closed, so this is the only way back to the login.
+                button! {
+                    text: client_state().localization().cancel_pincode_button_text(),
+                    event: InputEvent::LogOutCharacter,
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use korangar_interface::window::{CustomWindow, Window};
+use ragnarok_packets::AccountId;
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// Actions that can be taken on another player, opened by clicking them.
+pub struct PlayerInteractionWindow {
+    account_id: AccountId,
+    character_name: String,
+}
+
+impl PlayerInteractionWindow {
+    pub fn new(account_id: AccountId, character_name: String) -> Self {
+        Self {
+            account_id,
+            character_name,
+        }
+    }
+}
+
+impl CustomWindow<ClientState> for PlayerInteractionWindow {
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::PlayerInteraction)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        window! {
+            title: self.character_name.clone(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                button! {
+                    text: client_state().localization().request_trade_button_text(),
+                    event: InputEvent::RequestTrade {
+                        account_id: self.account_id,
+                        character_name: self.character_name,
+                    },
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{QuestLogEntry, QuestLogEntryPathExt, QuestObjective};
+use rust_state::{Context, ManuallyAssertExt, Path, VecIndexExt};
+
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// Kill counts of all hunting objectives of a single quest.
+struct QuestObjectiveList<A> {
+    objectives_path: A,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> QuestObjectiveList<A> {
+    fn new(objectives_path: A) -> Self {
+        Self {
+            objectives_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for QuestObjectiveList<A>
+where
+    A: Path<ClientState, Vec<QuestObjective>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let objectives = state.get(&self.objectives_path);
+
+        match objectives.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(objectives.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..objectives.len() {
+                    let objective_path = self.objectives_path.index(index).manually_asserted();
+
+                    let objective_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let objective = objective_path.follow(state).unwrap();
+
+                        let color = match objective.current_count >= objective.total_count {
+                            true => "^00aa00",
+                            false => "^000000",
+                        };
+
+                        format!(
+                            "{} {color}{}/{}^000000",
+                            objective.mob_name, objective.current_count, objective.total_count
+                        )
+                    });
+
+                    self.elements.push(ErasedElement::new(text! {
+                        text: objective_selector,
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().enumerate().for_each(|(index, element)| {
+            element.create_layout_info(state, store.child_store(index as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        self.elements.iter().enumerate().for_each(|(index, element)| {
+            element.lay_out(state, store.child_store(index as u64), &(), layout);
+        });
+    }
+}
+
+struct QuestList<A> {
+    quests_path: A,
+    empty_element: Option<ElementBox<ClientState>>,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> QuestList<A> {
+    fn new(quests_path: A) -> Self {
+        Self {
+            quests_path,
+            empty_element: None,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for QuestList<A>
+where
+    A: Path<ClientState, Vec<QuestLogEntry>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let quests = state.get(&self.quests_path);
+
+        if quests.is_empty() {
+            let empty_element = self.empty_element.get_or_insert_with(|| {
+                ErasedElement::new(text! {
+                    text: client_state().localization().no_quests_text(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                })
+            });
+
+            empty_element.create_layout_info(state, store.child_store(u64::MAX), resolver);
+        }
+
+        match quests.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(quests.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..quests.len() {
+                    let quest_path = self.quests_path.index(index).manually_asserted();
+
+                    let title_selector = ComputedSelector::new_default(move |state: &ClientState| {
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let quest = quest_path.follow(state).unwrap();
+                        // SAFETY:
+                        //
+                        // Unwrap is safe here because of the bounds.
+                        let quest_text = client_state().localization().quest_text().follow(state).unwrap();
+
+                        match quest.is_active {
+                            true => format!("{quest_text} {}", quest.quest_id),
+                            false => {
+                                // SAFETY:
+                                //
+                                // Unwrap is safe here because of the bounds.
+                                let inactive_text = client_state().localization().inactive_quest_text().follow(state).unwrap();
+                                format!("{quest_text} {} ^888888({inactive_text})^000000", quest.quest_id)
+                            }
+                        }
+                    });
+
+                    self.elements.push(ErasedElement::new(collapsable! {
+                        text: title_selector,
+                        initially_expanded: true,
+                        children: (
+                            QuestObjectiveList::new(quest_path.objectives()),
+                        ),
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().zip(quests.iter()).for_each(|(element, quest)| {
+            element.create_layout_info(state, store.child_store(quest.quest_id as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let quests = state.get(&self.quests_path);
+
+        if quests.is_empty()
+            && let Some(empty_element) = &self.empty_element
+        {
+            empty_element.lay_out(state, store.child_store(u64::MAX), &(), layout);
+        }
+
+        self.elements.iter().zip(quests.iter()).for_each(|(element, quest)| {
+            element.lay_out(state, store.child_store(quest.quest_id as u64), &(), layout);
+        });
+    }
+}
+
+pub struct QuestLogWindow<A> {
+    quests_path: A,
+}
+
+impl<A> QuestLogWindow<A> {
+    pub fn new(quests_path: A) -> Self {
+        Self { quests_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for QuestLogWindow<A>
+where
+    A: Path<ClientState, Vec<QuestLogEntry>>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::QuestLog)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        window! {
+            title: client_state().localization().quest_log_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                QuestList::new(self.quests_path),
+            ),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use korangar_components::item_box;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Path, VecIndexExt};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{Storage, StoragePathExt};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+pub struct StorageWindow<P> {
+    storage_path: P,
+}
+
+impl<P> StorageWindow<P> {
+    pub fn new(storage_path: P) -> Self {
+        Self { storage_path }
+    }
+}
+
+impl<P> CustomWindow<ClientState> for StorageWindow<P>
+where
+    P: Path<ClientState, Storage>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Storage)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        // TODO: Probably this should be more dynamic
+        const STORAGE_ROWS: usize = 10;
+        const STORAGE_COLUMNS: usize = 10;
+
+        let item_count_path = self.storage_path.item_count();
+        let maximum_item_count_path = self.storage_path.maximum_item_count();
+
+        let item_count_selector = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwraps are safe here because of the bounds.
+            format!(
+                "{} / {}",
+                item_count_path.follow(state).unwrap(),
+                maximum_item_count_path.follow(state).unwrap()
+            )
+        });
+
+        window! {
+            title: client_state().localization().storage_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            // The server needs to know when the storage is closed, so the window is closed
+            // with the button instead.
+            closable: false,
+            elements: (
+                text! {
+                    text: item_count_selector,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, STORAGE_ROWS, _>(|row| {
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: std::array::from_fn::<_, STORAGE_COLUMNS, _>(|column| {
+                                let path = self.storage_path.items().index(row * STORAGE_COLUMNS + column);
+
+                                item_box! {
+                                    item_path: path,
+                                    source: ItemSource::Storage,
+                                }
+                            }),
+                        }
+                    }),
+                },
+                button! {
+                    text: client_state().localization().close_button_text(),
+                    event: InputEvent::CloseStorage,
+                },
+            ),
+        }
+    }
+}
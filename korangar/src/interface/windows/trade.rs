@@ -0,0 +1,194 @@
+use korangar_components::item_box;
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, Path, RustState, Selector, VecIndexExt};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{Trade, TradePathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// The server allows a maximum of 10 items per side.
+const TRADE_ROWS: usize = 2;
+const TRADE_COLUMNS: usize = 5;
+const MAXIMUM_ZENY_LENGTH: usize = 10;
+
+/// Internal state of the trade window.
+#[derive(Default, RustState, StateElement)]
+pub struct TradeWindowState {
+    current_zeny: String,
+}
+
+pub struct TradeWindow<A, B> {
+    window_state_path: A,
+    trade_path: B,
+}
+
+impl<A, B> TradeWindow<A, B> {
+    pub fn new(window_state_path: A, trade_path: B) -> Self {
+        Self {
+            window_state_path,
+            trade_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for TradeWindow<A, B>
+where
+    A: Path<ClientState, TradeWindowState>,
+    B: Path<ClientState, Trade>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Trade)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct ZenyTextBox;
+
+        fn lock_state_selector<P>(name_path: P, locked_path: impl Path<ClientState, bool>) -> impl Selector<ClientState, String>
+        where
+            P: Path<ClientState, String>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwraps are safe here because of the bounds.
+                let name = name_path.follow(state).unwrap();
+                let lock_state = match *locked_path.follow(state).unwrap() {
+                    true => client_state().localization().trade_locked_text().follow(state).unwrap(),
+                    false => client_state().localization().trade_not_locked_text().follow(state).unwrap(),
+                };
+
+                format!("^ff8800{name}^000000 ({lock_state})")
+            })
+        }
+
+        fn zeny_selector(zeny_path: impl Path<ClientState, u32>) -> impl Selector<ClientState, String> {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                format!("{} Zeny", zeny_path.follow(state).unwrap())
+            })
+        }
+
+        let trade_path = self.trade_path;
+        let current_zeny_path = self.window_state_path.current_zeny();
+
+        let own_locked_path = trade_path.own_locked();
+        let partner_locked_path = trade_path.partner_locked();
+
+        let not_both_locked = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwraps are safe here because of the bounds.
+            !(*own_locked_path.follow(state).unwrap() && *partner_locked_path.follow(state).unwrap())
+        });
+
+        let zeny_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            // TODO: Give some sort of error if the amount is not a number.
+            if let Ok(amount) = state.get(&current_zeny_path).parse::<u32>() {
+                state.update_value_with(current_zeny_path, |input| input.clear());
+                queue.queue(InputEvent::AddTradeZeny { amount });
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        window! {
+            title: client_state().localization().trade_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: false,
+            elements: (
+                text! {
+                    text: lock_state_selector(trade_path.partner_name(), partner_locked_path),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, TRADE_ROWS, _>(|row| {
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: std::array::from_fn::<_, TRADE_COLUMNS, _>(|column| {
+                                let path = trade_path.partner_items().index(row * TRADE_COLUMNS + column);
+
+                                item_box! {
+                                    item_path: path,
+                                    source: ItemSource::TradePartner,
+                                }
+                            }),
+                        }
+                    }),
+                },
+                text! {
+                    text: zeny_selector(trade_path.partner_zeny()),
+                },
+                text! {
+                    text: lock_state_selector(client_state().player_name(), own_locked_path),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, TRADE_ROWS, _>(|row| {
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: std::array::from_fn::<_, TRADE_COLUMNS, _>(|column| {
+                                let path = trade_path.own_items().index(row * TRADE_COLUMNS + column);
+
+                                item_box! {
+                                    item_path: path,
+                                    source: ItemSource::Trade,
+                                }
+                            }),
+                        }
+                    }),
+                },
+                text! {
+                    text: zeny_selector(trade_path.own_zeny()),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        text_box! {
+                            ghost_text: client_state().localization().zeny_text_box_message(),
+                            state: current_zeny_path,
+                            input_handler: DefaultHandler::<_, _, MAXIMUM_ZENY_LENGTH>::new(current_zeny_path, zeny_action),
+                            focus_id: ZenyTextBox,
+                        },
+                        button! {
+                            text: client_state().localization().add_zeny_button_text(),
+                            disabled: own_locked_path,
+                            event: zeny_action,
+                        },
+                    ),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().lock_trade_button_text(),
+                            disabled: own_locked_path,
+                            event: InputEvent::LockTrade,
+                        },
+                        button! {
+                            text: client_state().localization().confirm_trade_button_text(),
+                            disabled: not_both_locked,
+                            event: InputEvent::CommitTrade,
+                        },
+                        button! {
+                            text: client_state().localization().cancel_trade_button_text(),
+                            event: InputEvent::CancelTrade,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use korangar_interface::window::{CustomWindow, Window};
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::state::ClientState;
+use crate::state::theme::InterfaceThemeType;
+
+pub struct TradeRequestWindow {
+    character_name: String,
+    base_level: u16,
+}
+
+impl TradeRequestWindow {
+    pub fn new(character_name: String, base_level: u16) -> Self {
+        Self {
+            character_name,
+            base_level,
+        }
+    }
+}
+
+impl CustomWindow<ClientState> for TradeRequestWindow {
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        window! {
+            title: "Trade request",
+            class: Some(WindowClass::TradeRequest),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: format!(
+                        "^ff8800{}^000000 (Level {}) wants to trade with you",
+                        self.character_name, self.base_level
+                    ),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: "Reject",
+                            event: InputEvent::RejectTradeRequest,
+                        },
+                        button! {
+                            text: "Accept",
+                            event: InputEvent::AcceptTradeRequest,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use korangar_components::item_box;
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, Path, RustState, VecIndexExt};
+
+use super::mail::MAIL_ITEM_COLUMNS;
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{Mailbox, MailboxPathExt};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+const MAXIMUM_RECEIVER_NAME_LENGTH: usize = 23;
+const MAXIMUM_TITLE_LENGTH: usize = 39;
+const MAXIMUM_TEXT_LENGTH: usize = 500;
+const MAXIMUM_ZENY_LENGTH: usize = 10;
+
+/// Internal state of the write mail window.
+#[derive(Default, RustState, StateElement)]
+pub struct WriteMailWindowState {
+    receiver_name: String,
+    title: String,
+    text: String,
+    zeny: String,
+}
+
+impl WriteMailWindowState {
+    pub fn new(receiver_name: String) -> Self {
+        Self {
+            receiver_name,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_receiver_name(&self) -> &str {
+        &self.receiver_name
+    }
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Zeny to attach to the mail. Invalid input is treated as no zeny.
+    pub fn get_zeny(&self) -> u64 {
+        self.zeny.parse().unwrap_or_default()
+    }
+}
+
+pub struct WriteMailWindow<A, B> {
+    window_state_path: A,
+    mailbox_path: B,
+}
+
+impl<A, B> WriteMailWindow<A, B> {
+    pub fn new(window_state_path: A, mailbox_path: B) -> Self {
+        Self {
+            window_state_path,
+            mailbox_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for WriteMailWindow<A, B>
+where
+    A: Path<ClientState, WriteMailWindowState>,
+    B: Path<ClientState, Mailbox>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::WriteMail)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct ReceiverNameTextBox;
+        struct TitleTextBox;
+        struct TextTextBox;
+        struct ZenyTextBox;
+
+        let receiver_name_path = self.window_state_path.receiver_name();
+        let title_path = self.window_state_path.title();
+        let text_path = self.window_state_path.text();
+        let zeny_path = self.window_state_path.zeny();
+        let attached_items_path = self.mailbox_path.attached_items();
+
+        let receiver_name_action = |_: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            queue.queue(Event::FocusElement {
+                focus_id: TitleTextBox.focus_id(),
+            });
+        };
+
+        let title_action = |_: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            queue.queue(Event::FocusElement {
+                focus_id: TextTextBox.focus_id(),
+            });
+        };
+
+        let text_action = |_: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            queue.queue(Event::FocusElement {
+                focus_id: ZenyTextBox.focus_id(),
+            });
+        };
+
+        let cannot_send = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwraps are safe here because of the bounds.
+            receiver_name_path.follow(state).unwrap().is_empty() || title_path.follow(state).unwrap().is_empty()
+        });
+
+        window! {
+            title: client_state().localization().write_mail_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            // The server needs to know when we stop writing the mail, so the window is closed
+            // with the button instead.
+            closable: false,
+            elements: (
+                text_box! {
+                    ghost_text: client_state().localization().mail_receiver_text(),
+                    state: receiver_name_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_RECEIVER_NAME_LENGTH>::new(receiver_name_path, receiver_name_action),
+                    focus_id: ReceiverNameTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().mail_title_text(),
+                    state: title_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_TITLE_LENGTH>::new(title_path, title_action),
+                    focus_id: TitleTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().mail_text_text(),
+                    state: text_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_TEXT_LENGTH>::new(text_path, text_action),
+                    focus_id: TextTextBox,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, MAIL_ITEM_COLUMNS, _>(|column| {
+                        item_box! {
+                            item_path: attached_items_path.index(column),
+                            source: ItemSource::Mail,
+                        }
+                    }),
+                },
+                text_box! {
+                    ghost_text: client_state().localization().zeny_text_box_message(),
+                    state: zeny_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_ZENY_LENGTH>::new(zeny_path, Event::Unfocus),
+                    focus_id: ZenyTextBox,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().send_mail_button_text(),
+                            disabled: cannot_send,
+                            event: InputEvent::SendMail,
+                        },
+                        button! {
+                            text: client_state().localization().cancel_mail_button_text(),
+                            event: InputEvent::CancelWriteMail,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
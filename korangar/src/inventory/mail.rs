@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use korangar_interface::element::StateElement;
+use korangar_networking::{InventoryItem, InventoryItemDetails, MailSummary};
+use ragnarok_packets::{InventoryIndex, ItemId, MailId, MailOpenType};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::world::ResourceMetadata;
+
+/// Content of the mail that is currently being read.
+#[derive(Default, RustState, StateElement)]
+pub struct OpenedMail {
+    mail_id: MailId,
+    open_type: MailOpenType,
+    sender_name: String,
+    title: String,
+    text: String,
+    zeny: u64,
+    // TODO: Unhide this.
+    #[hidden_element]
+    items: Vec<InventoryItem<ResourceMetadata>>,
+}
+
+impl OpenedMail {
+    pub fn get_id(&self) -> (MailOpenType, MailId) {
+        (self.open_type, self.mail_id)
+    }
+}
+
+/// State of the mailbox of the player and of the mail that is currently being
+/// written.
+#[derive(Default, RustState, StateElement)]
+pub struct Mailbox {
+    /// Set when the server notifies the player about unread mail. Cleared once
+    /// the mailbox is opened.
+    has_unread_mail: bool,
+    /// All mails in the mailbox, newest first.
+    mails: Vec<MailSummary>,
+    opened_mail: OpenedMail,
+    /// Items attached to the mail that is being written.
+    // TODO: Unhide this.
+    #[hidden_element]
+    attached_items: Vec<InventoryItem<ResourceMetadata>>,
+    /// Items that were sent to the server but were not confirmed yet. The
+    /// server only sends back the index, so we need to remember the amount.
+    #[hidden_element]
+    pending_items: Vec<(InventoryIndex, u16)>,
+    /// Set while waiting for the server to look up the receiver of the mail
+    /// that is about to be sent.
+    is_sending: bool,
+}
+
+impl Mailbox {
+    pub fn set_unread_mail(&mut self, has_unread_mail: bool) {
+        self.has_unread_mail = has_unread_mail;
+    }
+
+    pub fn clear_mails(&mut self) {
+        self.mails.clear();
+    }
+
+    pub fn add_mails(&mut self, mails: Vec<MailSummary>) {
+        for mail in mails {
+            match self.mails.iter_mut().find(|known_mail| known_mail.mail_id == mail.mail_id) {
+                Some(known_mail) => *known_mail = mail,
+                None => self.mails.push(mail),
+            }
+        }
+
+        // Mail ids are increasing, so this puts the newest mail at the top.
+        self.mails.sort_by(|first, second| second.mail_id.0.cmp(&first.mail_id.0));
+    }
+
+    /// Id of the newest mail of the given type. Will be 0 if there are no
+    /// mails of that type.
+    pub fn newest_mail_id(&self, open_type: MailOpenType) -> MailId {
+        self.mails
+            .iter()
+            .find(|mail| mail.open_type == open_type)
+            .map(|mail| mail.mail_id)
+            .unwrap_or_default()
+    }
+
+    pub fn open_mail(
+        &mut self,
+        mail_id: MailId,
+        open_type: MailOpenType,
+        text: String,
+        zeny: u64,
+        items: Vec<InventoryItem<ResourceMetadata>>,
+    ) {
+        let (sender_name, title) = match self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            Some(mail) => {
+                mail.is_read = true;
+                (mail.sender_name.clone(), mail.title.clone())
+            }
+            None => Default::default(),
+        };
+
+        self.opened_mail = OpenedMail {
+            mail_id,
+            open_type,
+            sender_name,
+            title,
+            text,
+            zeny,
+            items,
+        };
+    }
+
+    /// Remove a mail from the mailbox. Returns `true` if the mail was the one
+    /// currently being read.
+    pub fn remove_mail(&mut self, mail_id: MailId) -> bool {
+        self.mails.retain(|mail| mail.mail_id != mail_id);
+
+        match self.opened_mail.mail_id == mail_id {
+            true => {
+                self.opened_mail = OpenedMail::default();
+                true
+            }
+            false => false,
+        }
+    }
+
+    pub fn zeny_retrieved(&mut self, mail_id: MailId) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            mail.has_zeny = false;
+        }
+
+        if self.opened_mail.mail_id == mail_id {
+            self.opened_mail.zeny = 0;
+        }
+    }
+
+    pub fn items_retrieved(&mut self, mail_id: MailId) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            mail.has_items = false;
+        }
+
+        if self.opened_mail.mail_id == mail_id {
+            self.opened_mail.items.clear();
+        }
+    }
+
+    pub fn add_pending_item(&mut self, index: InventoryIndex, amount: u16) {
+        self.pending_items.push((index, amount));
+    }
+
+    /// Add a pending item to the attached items once the server accepted it.
+    pub fn accept_item(&mut self, mut item: InventoryItem<ResourceMetadata>) {
+        let Some(position) = self.pending_items.iter().position(|(index, _)| *index == item.index) else {
+            return;
+        };
+
+        let (_, added_amount) = self.pending_items.remove(position);
+
+        if let InventoryItemDetails::Regular { amount, .. } = &mut item.details {
+            *amount = added_amount;
+        }
+
+        self.attached_items.push(item);
+    }
+
+    pub fn reject_item(&mut self, index: InventoryIndex) {
+        self.pending_items.retain(|(pending_index, _)| *pending_index != index);
+    }
+
+    pub fn remove_attached_item(&mut self, index: InventoryIndex, removed_amount: u16) {
+        let Some(position) = self.attached_items.iter().position(|item| item.index == index) else {
+            return;
+        };
+
+        if let InventoryItemDetails::Regular { amount, .. } = &mut self.attached_items[position].details
+            && *amount > removed_amount
+        {
+            *amount -= removed_amount;
+            return;
+        }
+
+        self.attached_items.remove(position);
+    }
+
+    pub fn is_sending(&self) -> bool {
+        self.is_sending
+    }
+
+    pub fn set_sending(&mut self, is_sending: bool) {
+        self.is_sending = is_sending;
+    }
+
+    /// Reset everything related to the mail that is being written.
+    pub fn clear_written_mail(&mut self) {
+        self.attached_items.clear();
+        self.pending_items.clear();
+        self.is_sending = false;
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.opened_mail
+            .items
+            .iter_mut()
+            .chain(self.attached_items.iter_mut())
+            .filter(|item| item.item_id == item_id)
+            .for_each(|item| {
+                item.metadata.texture = Some(texture.clone());
+            });
+    }
+}
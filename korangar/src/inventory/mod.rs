@@ -1,5 +1,8 @@
 mod hotbar;
+mod mail;
 mod skills;
+mod storage;
+mod trade;
 
 use std::sync::Arc;
 
@@ -9,7 +12,10 @@ use ragnarok_packets::{EquipPosition, InventoryIndex, ItemId};
 use rust_state::RustState;
 
 pub use self::hotbar::{Hotbar, HotbarPathExt};
+pub use self::mail::{Mailbox, MailboxPathExt, OpenedMail, OpenedMailPathExt};
 pub use self::skills::{Skill, SkillTree, SkillTreePathExt};
+pub use self::storage::{Storage, StoragePathExt};
+pub use self::trade::{Trade, TradePathExt};
 use crate::graphics::Texture;
 use crate::loaders::AsyncLoader;
 use crate::world::{Library, ResourceMetadata};
@@ -47,6 +53,10 @@ impl Inventory {
         }
     }
 
+    pub fn get_item(&self, index: InventoryIndex) -> Option<&InventoryItem<ResourceMetadata>> {
+        self.items.iter().find(|item| item.index == index)
+    }
+
     pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
         self.items.iter_mut().filter(|item| item.item_id == item_id).for_each(|item| {
             item.metadata.texture = Some(texture.clone());
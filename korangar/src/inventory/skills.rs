@@ -22,9 +22,30 @@ pub struct Skill {
     pub animation_state: SpriteAnimationState,
 }
 
+/// Cooldown of a skill of the player.
+#[derive(Clone, Debug)]
+pub struct SkillCooldown {
+    skill_id: SkillId,
+    start_tick: ClientTick,
+    end_tick: ClientTick,
+    /// Fraction of the cooldown that is still remaining, updated every frame.
+    remaining_fraction: f32,
+}
+
+impl SkillCooldown {
+    fn remaining(&self, client_tick: ClientTick) -> u32 {
+        // The client tick may wrap around, so we compare the difference instead of the
+        // ticks themselves.
+        let remaining = self.end_tick.0.wrapping_sub(client_tick.0) as i32;
+        remaining.max(0) as u32
+    }
+}
+
 #[derive(Default, RustState, StateElement)]
 pub struct SkillTree {
     skills: Vec<Skill>,
+    #[hidden_element]
+    cooldowns: Vec<SkillCooldown>,
 }
 
 impl SkillTree {
@@ -58,4 +79,48 @@ impl SkillTree {
     pub fn find_skill(&self, skill_id: SkillId) -> Option<Skill> {
         self.skills.iter().find(|skill| skill.skill_id == skill_id).cloned()
     }
+
+    pub fn start_cooldown(&mut self, skill_id: SkillId, duration: u32, client_tick: ClientTick) {
+        self.cooldowns.retain(|cooldown| cooldown.skill_id != skill_id);
+
+        if duration > 0 {
+            self.cooldowns.push(SkillCooldown {
+                skill_id,
+                start_tick: client_tick,
+                end_tick: ClientTick(client_tick.0.wrapping_add(duration)),
+                remaining_fraction: 1.0,
+            });
+        }
+    }
+
+    /// Update the remaining fraction of all cooldowns and remove the ones that
+    /// are over.
+    pub fn update_cooldowns(&mut self, client_tick: ClientTick) {
+        self.cooldowns.retain_mut(|cooldown| {
+            let remaining = cooldown.remaining(client_tick);
+            let duration = cooldown.end_tick.0.wrapping_sub(cooldown.start_tick.0);
+
+            cooldown.remaining_fraction = remaining as f32 / duration as f32;
+            remaining > 0
+        });
+    }
+
+    pub fn is_on_cooldown(&self, skill_id: SkillId, client_tick: ClientTick) -> bool {
+        self.cooldowns
+            .iter()
+            .any(|cooldown| cooldown.skill_id == skill_id && cooldown.remaining(client_tick) > 0)
+    }
+
+    /// Fraction of the cooldown of the skill that is still remaining, as of
+    /// the last call to [`update_cooldowns`](Self::update_cooldowns).
+    pub fn cooldown_fraction(&self, skill_id: SkillId) -> Option<f32> {
+        self.cooldowns
+            .iter()
+            .find(|cooldown| cooldown.skill_id == skill_id)
+            .map(|cooldown| cooldown.remaining_fraction)
+    }
+
+    pub fn clear_cooldowns(&mut self) {
+        self.cooldowns.clear();
+    }
 }
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use korangar_interface::element::StateElement;
+use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
+use ragnarok_packets::{InventoryIndex, ItemId};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::loaders::AsyncLoader;
+use crate::world::{Library, ResourceMetadata};
+
+/// Items stored in the Kafra storage. The index of each item is its index in
+/// the storage rather than the inventory.
+#[derive(Default, RustState, StateElement)]
+pub struct Storage {
+    // TODO: Unhide this.
+    #[hidden_element]
+    items: Vec<InventoryItem<ResourceMetadata>>,
+    item_count: u16,
+    maximum_item_count: u16,
+}
+
+impl Storage {
+    pub fn fill(&mut self, async_loader: &AsyncLoader, library: &Library, items: Vec<InventoryItem<NoMetadata>>) {
+        self.items = items
+            .into_iter()
+            .map(|item| library.load_inventory_item_metadata(async_loader, item))
+            .collect();
+    }
+
+    pub fn set_item_count(&mut self, item_count: u16, maximum_item_count: u16) {
+        self.item_count = item_count;
+        self.maximum_item_count = maximum_item_count;
+    }
+
+    pub fn add_item(&mut self, async_loader: &AsyncLoader, library: &Library, item: InventoryItem<NoMetadata>) {
+        if let Some(found_item) = self.items.iter_mut().find(|storage_item| storage_item.index == item.index) {
+            if let InventoryItemDetails::Regular { amount, .. } = &mut found_item.details
+                && let InventoryItemDetails::Regular { amount: added_amount, .. } = item.details
+            {
+                *amount += added_amount;
+            }
+        } else {
+            let item = library.load_inventory_item_metadata(async_loader, item);
+
+            self.items.push(item);
+        }
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.items.iter_mut().filter(|item| item.item_id == item_id).for_each(|item| {
+            item.metadata.texture = Some(texture.clone());
+        });
+    }
+
+    pub fn remove_item(&mut self, index: InventoryIndex, remove_amount: u16) {
+        let Some(position) = self.items.iter().position(|item| item.index == index) else {
+            return;
+        };
+
+        if let InventoryItemDetails::Regular { amount, .. } = &mut self.items[position].details
+            && *amount > remove_amount
+        {
+            *amount -= remove_amount;
+            return;
+        }
+
+        self.items.remove(position);
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use korangar_interface::element::StateElement;
+use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
+use ragnarok_packets::{InventoryIndex, ItemId, TradeParticipant};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::loaders::AsyncLoader;
+use crate::world::{Library, ResourceMetadata};
+
+/// State of a trade between the player and another character.
+#[derive(Default, RustState, StateElement)]
+pub struct Trade {
+    /// Name of the character the player is trading with.
+    partner_name: String,
+    /// Items offered by the player.
+    // TODO: Unhide this.
+    #[hidden_element]
+    own_items: Vec<InventoryItem<ResourceMetadata>>,
+    /// Items offered by the trade partner.
+    // TODO: Unhide this.
+    #[hidden_element]
+    partner_items: Vec<InventoryItem<ResourceMetadata>>,
+    own_zeny: u32,
+    partner_zeny: u32,
+    own_locked: bool,
+    partner_locked: bool,
+    /// Items that were sent to the server but were not confirmed yet. The
+    /// server only sends back the index, so we need to remember the amount.
+    #[hidden_element]
+    pending_items: Vec<(InventoryIndex, u16)>,
+}
+
+impl Trade {
+    /// Reset the trade and remember the name of the character we are about to
+    /// trade with.
+    pub fn prepare(&mut self, partner_name: String) {
+        *self = Self {
+            partner_name,
+            ..Self::default()
+        };
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.own_locked
+    }
+
+    pub fn add_pending_item(&mut self, index: InventoryIndex, amount: u16) {
+        self.pending_items.push((index, amount));
+    }
+
+    /// Add a pending item to the offered items once the server accepted it.
+    pub fn accept_item(&mut self, mut item: InventoryItem<ResourceMetadata>) {
+        let Some(position) = self.pending_items.iter().position(|(index, _)| *index == item.index) else {
+            return;
+        };
+
+        let (_, added_amount) = self.pending_items.remove(position);
+
+        if let InventoryItemDetails::Regular { amount, .. } = &mut item.details {
+            *amount = added_amount;
+        }
+
+        self.own_items.push(item);
+    }
+
+    pub fn reject_item(&mut self, index: InventoryIndex) {
+        self.pending_items.retain(|(pending_index, _)| *pending_index != index);
+    }
+
+    pub fn add_partner_item(&mut self, async_loader: &AsyncLoader, library: &Library, item: InventoryItem<NoMetadata>) {
+        let item = library.load_inventory_item_metadata(async_loader, item);
+
+        self.partner_items.push(item);
+    }
+
+    pub fn set_own_zeny(&mut self, amount: u32) {
+        self.own_zeny = amount;
+    }
+
+    pub fn set_partner_zeny(&mut self, amount: u32) {
+        self.partner_zeny = amount;
+    }
+
+    pub fn lock(&mut self, participant: TradeParticipant) {
+        match participant {
+            TradeParticipant::Player => self.own_locked = true,
+            TradeParticipant::Partner => self.partner_locked = true,
+        }
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.own_items
+            .iter_mut()
+            .chain(self.partner_items.iter_mut())
+            .filter(|item| item.item_id == item_id)
+            .for_each(|item| {
+                item.metadata.texture = Some(texture.clone());
+            });
+    }
+}
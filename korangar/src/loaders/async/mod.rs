@@ -15,23 +15,27 @@ use crate::loaders::error::LoadError;
 use crate::loaders::{ActionLoader, AnimationLoader, ImageType, MapLoader, ModelLoader, SpriteLoader, TextureLoader, VideoLoader};
 #[cfg(feature = "debug")]
 use crate::threads;
-use crate::world::{AnimationData, EntityType, Library, Map};
+use crate::world::{AnimationData, EntityModel, EntityType, Library, Map};
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 pub enum ItemLocation {
     Inventory,
     Shop,
+    Ground,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub enum LoaderId {
     AnimationData(EntityId),
+    EntityModel(EntityId),
+    GroundItemAnimationData(ItemId),
     ItemSprite(ItemId),
     Map(String),
 }
 
 pub enum LoadableResource {
     AnimationData(Arc<AnimationData>),
+    EntityModel(Arc<EntityModel>),
     ItemSprite { texture: Arc<Texture>, location: ItemLocation },
     Map { map: Box<Map>, position: Option<TilePosition> },
 }
@@ -99,6 +103,22 @@ impl AsyncLoader {
         entity_id: EntityId,
         entity_type: EntityType,
         entity_part_files: Vec<String>,
+    ) -> Option<Arc<AnimationData>> {
+        self.request_animation_data_load_with_id(LoaderId::AnimationData(entity_id), entity_type, entity_part_files)
+    }
+
+    /// Load the sprite of an item lying on the ground. Items don't have any
+    /// actions besides idle, so they are loaded like NPCs.
+    #[must_use]
+    pub fn request_ground_item_animation_data_load(&self, item_id: ItemId, sprite_path: String) -> Option<Arc<AnimationData>> {
+        self.request_animation_data_load_with_id(LoaderId::GroundItemAnimationData(item_id), EntityType::Npc, vec![sprite_path])
+    }
+
+    fn request_animation_data_load_with_id(
+        &self,
+        loader_id: LoaderId,
+        entity_type: EntityType,
+        entity_part_files: Vec<String>,
     ) -> Option<Arc<AnimationData>> {
         match self.animation_loader.get(&entity_part_files) {
             Some(animation_data) => Some(animation_data),
@@ -107,7 +127,7 @@ impl AsyncLoader {
                 let action_loader = self.action_loader.clone();
                 let animation_loader = self.animation_loader.clone();
 
-                self.request_load(LoaderId::AnimationData(entity_id), move || {
+                self.request_load(loader_id, move || {
                     #[cfg(feature = "debug")]
                     let _load_measurement = Profiler::start_measurement("animation data load");
 
@@ -123,6 +143,31 @@ impl AsyncLoader {
         }
     }
 
+    #[must_use]
+    pub fn request_entity_model_load(&self, entity_id: EntityId, model_file: String) -> Option<Arc<EntityModel>> {
+        match self.model_loader.get_entity_model(&model_file) {
+            Some(entity_model) => Some(entity_model),
+            None => {
+                let model_loader = self.model_loader.clone();
+                let texture_loader = self.texture_loader.clone();
+                let video_loader = self.video_loader.clone();
+
+                self.request_load(LoaderId::EntityModel(entity_id), move || {
+                    #[cfg(feature = "debug")]
+                    let _load_measurement = Profiler::start_measurement("entity model load");
+
+                    let entity_model = match model_loader.get_entity_model(&model_file) {
+                        Some(entity_model) => entity_model,
+                        None => model_loader.load_entity_model(texture_loader, video_loader, &model_file)?,
+                    };
+                    Ok(LoadableResource::EntityModel(entity_model))
+                });
+
+                None
+            }
+        }
+    }
+
     #[must_use]
     pub fn request_item_sprite_load(
         &self,
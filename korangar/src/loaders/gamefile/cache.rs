@@ -9,12 +9,12 @@ use blake3::Hash;
 use ddsfile::{AlphaMode, D3D10ResourceDimension, Dds, DxgiFormat, NewDxgiParams};
 use hashbrown::HashSet;
 use image::{EncodableLayout, RgbaImage};
+use korangar_archive::seven_zip::{SevenZipArchive, SevenZipArchiveBuilder};
+use korangar_archive::{Archive, Compression, Writable};
 use korangar_loaders::FileLoader;
 use rayon::prelude::*;
 
 use crate::SHUTDOWN_SIGNAL;
-use crate::loaders::archive::seven_zip::{SevenZipArchive, SevenZipArchiveBuilder};
-use crate::loaders::archive::{Archive, Compression, Writable};
 use crate::loaders::texture::calculate_valid_mip_level_count;
 use crate::loaders::{CACHE_FILE_NAME, GameFileLoader, HASH_FILE_PATH, TEMPORARY_CACHE_FILE_NAME, TextureLoader};
 
@@ -92,10 +92,12 @@ pub fn sync_cache_archive(game_file_loader: &GameFileLoader, texture_loader: Arc
     };
 
     let mut builder = Box::new(SevenZipArchiveBuilder::from_path(Path::new(archive_path)));
-    builder.add_file(HASH_FILE_PATH, game_file_hash.to_hex().as_bytes().to_vec(), Compression::Off);
+    builder
+        .add_file(HASH_FILE_PATH, game_file_hash.to_hex().as_bytes().to_vec(), Compression::Off)
+        .expect("can't write hash to cache archive");
 
     if current_archive_exists {
-        let current_archive = Box::new(SevenZipArchive::from_path(path));
+        let current_archive = Box::new(SevenZipArchive::from_path(path).expect("can't open cache archive"));
         copy_existing_files(
             &mut builder,
             &current_archive,
@@ -169,7 +171,7 @@ fn analyze_files(
         return source_files.to_vec();
     }
 
-    let current_archive = SevenZipArchive::from_path(archive_path);
+    let current_archive = SevenZipArchive::from_path(archive_path).expect("can't open cache archive");
 
     let extension = match media_type {
         MediaType::Texture => DDS_FILE_EXTENSION,
@@ -388,7 +390,9 @@ fn process_texture(
 
     // `Compression::Off` currently gives the best load times with not too much
     // higher file sizes.
-    builder.add_file(dds_file_name, dds_file_data, Compression::Off);
+    builder
+        .add_file(dds_file_name, dds_file_data, Compression::Off)
+        .expect("can't write texture to cache archive");
 
     *created_count += 1;
 }
@@ -442,7 +446,9 @@ fn process_video(
 
                         ivf_data.extend_from_slice(hash.as_bytes());
 
-                        builder.add_file(ivf_file_name, ivf_data, Compression::Off);
+                        builder
+                            .add_file(ivf_file_name, ivf_data, Compression::Off)
+                            .expect("can't write video to cache archive");
 
                         *created_count += 1;
                     } else {
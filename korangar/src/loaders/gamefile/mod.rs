@@ -5,21 +5,18 @@
 mod cache;
 mod list;
 
-use core::panic;
 use std::path::Path;
 use std::sync::RwLock;
 
 use blake3::Hash;
+use korangar_archive::seven_zip::SevenZipArchive;
+use korangar_archive::{Archive, ArchiveError, Compression, Writable, create_writable, open_archive};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, Timer, print_debug};
 use korangar_loaders::{FileLoader, FileNotFoundError};
 
 pub use self::cache::{sync_cache_archive, texture_file_dds_name, video_file_ivf_name};
 use self::list::GameArchiveList;
-use super::archive::folder::FolderArchive;
-use super::archive::native::{NativeArchive, NativeArchiveBuilder};
-use super::archive::{Archive, ArchiveType, Compression, Writable};
-use crate::loaders::archive::seven_zip::{SevenZipArchive, SevenZipArchiveBuilder};
 
 pub(crate) const CACHE_FILE_NAME: &str = "cache.7z";
 pub(crate) const LUA_ARCHIVE_FILE_NAME: &str = "lua_files.7z";
@@ -41,9 +38,15 @@ struct LoaderArchive {
 ///
 /// Currently, there are two types implementing
 /// [`Archive`]:
-/// - [`NativeArchive`] - Retrieve assets from GRF files.
-/// - [`FolderArchive`] - Retrieve assets from an OS folder.
+/// - [`NativeArchive`](korangar_archive::native::NativeArchive) - Retrieve
+///   assets from GRF files.
+/// - [`FolderArchive`](korangar_archive::folder::FolderArchive) - Retrieve
+///   assets from an OS folder.
 /// - [`SevenZipArchive`] - Retrieve assets from ZIP files.
+/// - [`RgzArchive`](korangar_archive::rgz::RgzArchive) - Retrieve assets from
+///   RGZ patches.
+/// - [`ThorArchive`](korangar_archive::thor::ThorArchive) - Retrieve assets
+///   from Thor patches.
 #[derive(Default)]
 pub struct GameFileLoader {
     archives: RwLock<Vec<LoaderArchive>>,
@@ -74,30 +77,8 @@ impl GameFileLoader {
         self.archives.write().unwrap().insert(0, LoaderArchive { archive, is_game_archive });
     }
 
-    fn get_archive_type_by_path(path: &Path) -> ArchiveType {
-        if path.is_dir() || path.display().to_string().ends_with('/') {
-            ArchiveType::Folder
-        } else if let Some(extension) = path.extension()
-            && let Some("grf") = extension.to_str()
-        {
-            ArchiveType::Native
-        } else if let Some(extension) = path.extension()
-            && let Some("7z") = extension.to_str()
-        {
-            ArchiveType::SevenZip
-        } else {
-            panic!("Provided archive must be a directory or have a .grf extension")
-        }
-    }
-
-    fn load_archive_from_path(path: &str) -> Box<dyn Archive> {
-        let path = Path::new(path);
-
-        match GameFileLoader::get_archive_type_by_path(path) {
-            ArchiveType::Folder => Box::new(FolderArchive::from_path(path)),
-            ArchiveType::Native => Box::new(NativeArchive::from_path(path)),
-            ArchiveType::SevenZip => Box::new(SevenZipArchive::from_path(path)),
-        }
+    fn load_archive_from_path(path: &str) -> Result<Box<dyn Archive>, ArchiveError> {
+        open_archive(Path::new(path))
     }
 
     pub fn load_archives_from_settings(&self) {
@@ -106,10 +87,20 @@ impl GameFileLoader {
 
         let game_archive_list = GameArchiveList::load();
 
-        game_archive_list.archives.iter().for_each(|path| {
-            let game_archive = Self::load_archive_from_path(path);
-            self.add_archive(game_archive, true);
-        });
+        game_archive_list
+            .archives
+            .iter()
+            .for_each(|path| match Self::load_archive_from_path(path) {
+                Ok(game_archive) => self.add_archive(game_archive, true),
+                Err(error) => {
+                    // Always report the failure, since the client can't work
+                    // correctly with missing game assets.
+                    #[cfg(feature = "debug")]
+                    print_debug!("[{}] can't load archive {}: {:?}", "error".red(), path.magenta(), error);
+                    #[cfg(not(feature = "debug"))]
+                    eprintln!("[error] can't load archive {path}: {error:?}");
+                }
+            });
 
         #[cfg(feature = "debug")]
         timer.stop();
@@ -122,7 +113,15 @@ impl GameFileLoader {
             .unwrap()
             .iter()
             .filter(|archive| archive.is_game_archive)
-            .for_each(|archive| archive.archive.hash(&mut hasher));
+            .for_each(|archive| {
+                // A failed hash only means that the cache is rebuilt.
+                if let Err(error) = archive.archive.hash(&mut hasher) {
+                    #[cfg(feature = "debug")]
+                    print_debug!("[{}] can't hash archive: {:?}", "error".red(), error);
+                    #[cfg(not(feature = "debug"))]
+                    eprintln!("[error] can't hash archive: {error:?}");
+                }
+            });
         hasher.finalize()
     }
 
@@ -137,7 +136,7 @@ impl GameFileLoader {
             self.patch_lua_files();
         }
 
-        let lua_archive = Self::load_archive_from_path(LUA_ARCHIVE_FILE_NAME);
+        let lua_archive = Self::load_archive_from_path(LUA_ARCHIVE_FILE_NAME).expect("can't load lua archive");
         self.add_archive(lua_archive, false);
     }
 
@@ -162,11 +161,7 @@ impl GameFileLoader {
         let lua_files = self.get_files_with_extension(&[LUA_BYTECODE_EXTENSION]);
 
         let path = Path::new(LUA_ARCHIVE_FILE_NAME);
-        let mut lua_archive: Box<dyn Writable> = match GameFileLoader::get_archive_type_by_path(path) {
-            ArchiveType::Folder => Box::new(FolderArchive::from_path(path)),
-            ArchiveType::Native => Box::new(NativeArchiveBuilder::from_path(path)),
-            ArchiveType::SevenZip => Box::new(SevenZipArchiveBuilder::from_path(path)),
-        };
+        let mut lua_archive = create_writable(path).expect("can't create lua archive");
 
         let bytecode_format = Format::default();
         let settings = Settings::default();
@@ -197,7 +192,9 @@ impl GameFileLoader {
 
             // Try to unify all bytecode to Lua 5.1 and possibly 64 bit.
             match unify(&bytes, &bytecode_format, &settings) {
-                Ok(bytes) => lua_archive.add_file(&file_name, bytes, Compression::Default),
+                Ok(bytes) => lua_archive
+                    .add_file(&file_name, bytes, Compression::Default)
+                    .expect("can't write to lua archive"),
                 // If the operation fails the file with this error, the Lua file is not actually a
                 // pre-compiled binary but rather a source file, so we can safely ignore it.
                 #[cfg(feature = "debug")]
@@ -230,7 +227,7 @@ impl GameFileLoader {
             return;
         }
 
-        let archive = Box::new(SevenZipArchive::from_path(path));
+        let archive = Box::new(SevenZipArchive::from_path(path).expect("can't open cache archive"));
 
         let Some(hash_file) = archive.get_file_by_path(HASH_FILE_PATH) else {
             #[cfg(feature = "debug")]
@@ -13,7 +13,7 @@ use korangar_container::SimpleSlab;
 use korangar_debug::logging::Timer;
 use korangar_loaders::FileLoader;
 use ragnarok_bytes::{ByteReader, FromBytes};
-use ragnarok_formats::map::{GatData, GroundData, MapData, MapResources};
+use ragnarok_formats::map::{GatData, GroundData, MapData, MapResources, ResourceType};
 use ragnarok_formats::version::InternalVersion;
 use wgpu::{BufferUsages, Device, Queue};
 
@@ -191,6 +191,7 @@ impl MapLoader {
             )
         });
 
+        adjust_map_resources(&mut map_data.resources);
         apply_map_offset(&ground_data, &mut map_data.resources);
 
         let mut model_cache = HashMap::<(String, bool), Arc<Model>>::new();
@@ -337,6 +338,41 @@ struct BufferAndTextures {
     videos: Mutex<Vec<Video>>,
 }
 
+/// Map resources are stored exactly as they are in the file, so we need to move
+/// them into the coordinate system of the client.
+fn adjust_map_resources(resources: &mut MapResources) {
+    let object_indices = resources
+        .resource_order
+        .iter()
+        .enumerate()
+        .filter(|(_, resource_type)| **resource_type == ResourceType::Object)
+        .map(|(index, _)| index);
+
+    resources.objects.iter_mut().zip(object_indices).for_each(|(object, index)| {
+        // Offset the objects slightly to avoid depth buffer fighting.
+        object.transform.position += Vector3::new(0.0, 0.0005, 0.0) * index as f32;
+    });
+
+    resources.light_sources.iter_mut().for_each(|light_source| {
+        light_source.position.y = -light_source.position.y;
+
+        // Some light sources have color channels with values bigger than 1.0 (255), so
+        // we need to clamp them.
+        // TODO: Does this maybe have a special meaning?
+        light_source.color.clamp_color_channels();
+    });
+
+    resources.sound_sources.iter_mut().for_each(|sound_source| {
+        sound_source.position.y = -sound_source.position.y;
+        sound_source.cycle.get_or_insert(4.0);
+    });
+
+    resources
+        .effect_sources
+        .iter_mut()
+        .for_each(|effect_source| effect_source.position.y = -effect_source.position.y);
+}
+
 /// We shift the map resources, so that the world coordinate system's origin has
 /// the same origin as the tile grids.
 fn apply_map_offset(ground_data: &GroundData, resources: &mut MapResources) {
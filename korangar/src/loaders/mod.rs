@@ -1,6 +1,5 @@
 mod action;
 mod animation;
-mod archive;
 mod color;
 mod rectangle;
 
@@ -1,6 +1,6 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use cgmath::{Array, EuclideanSpace, Matrix4, Point3, Rad, SquareMatrix, Vector2, Vector3};
+use cgmath::{Array, EuclideanSpace, InnerSpace, Matrix, Matrix4, Point3, Rad, SquareMatrix, Vector2, Vector3, Vector4};
 use hashbrown::{HashMap, HashSet};
 use korangar_collision::{AABB, multiply_matrix4_and_point3};
 #[cfg(feature = "debug")]
@@ -8,26 +8,38 @@ use korangar_debug::logging::{Colorize, Timer, print_debug};
 use korangar_loaders::FileLoader;
 use num::Zero;
 use ragnarok_bytes::{ByteReader, FromBytes};
+use ragnarok_formats::granny::{GrannyData, GrannyMesh};
 use ragnarok_formats::model::{ModelData, NodeData};
 use ragnarok_formats::version::InternalVersion;
-use smallvec::SmallVec;
+use smallvec::{SmallVec, smallvec};
+use wgpu::{BufferUsages, Device, Queue};
 
 use super::error::LoadError;
-use super::{FALLBACK_MODEL_FILE, TextureSetBuilder, TextureSetTexture, smooth_model_normals};
-use crate::graphics::{BindlessSupport, Color, ModelVertex, NativeModelVertex, reduce_vertices};
-use crate::loaders::GameFileLoader;
-use crate::world::{Model, Node, SubMesh};
+use super::{FALLBACK_BMP_FILE, FALLBACK_MODEL_FILE, TextureSetBuilder, TextureSetTexture, smooth_model_normals};
+use crate::graphics::{BindlessSupport, Buffer, Color, ModelVertex, NativeModelVertex, reduce_vertices};
+use crate::loaders::{GameFileLoader, TextureLoader, VideoLoader};
+use crate::world::{EntityModel, Model, Node, SubMesh};
+
+/// Granny models don't have a version that matches the RSM versions, but their
+/// geometry is placed the same way as RSM2 models.
+const GRANNY_MODEL_VERSION: InternalVersion = InternalVersion { major: 2, minor: 2 };
 
 pub struct ModelLoader {
+    device: Device,
+    queue: Queue,
     game_file_loader: Arc<GameFileLoader>,
     bindless_support: BindlessSupport,
+    entity_model_cache: Mutex<HashMap<String, Arc<EntityModel>>>,
 }
 
 impl ModelLoader {
-    pub fn new(game_file_loader: Arc<GameFileLoader>, bindless_support: BindlessSupport) -> Self {
+    pub fn new(device: Device, queue: Queue, game_file_loader: Arc<GameFileLoader>, bindless_support: BindlessSupport) -> Self {
         Self {
+            device,
+            queue,
             game_file_loader,
             bindless_support,
+            entity_model_cache: Mutex::new(HashMap::new()),
         }
     }
 }
@@ -453,6 +465,332 @@ impl ModelLoader {
         }
     }
 
+    /// Registers the texture of every material. Granny files store the path
+    /// of the texture on the machine of the artist, so only the file name is
+    /// used and the texture is expected next to the model.
+    fn register_granny_textures(
+        texture_set_builder: &mut TextureSetBuilder,
+        model_file: &str,
+        granny_data: &GrannyData,
+    ) -> Vec<TextureSetTexture> {
+        let model_directory = model_file.rsplit_once(['\\', '/']).map(|(directory, _)| directory);
+
+        (0..granny_data.materials.len())
+            .map(|material_index| match granny_data.material_texture(material_index) {
+                Some(texture) => {
+                    let file_name = texture.file_name.rsplit(['\\', '/']).next().unwrap_or_default();
+
+                    match model_directory {
+                        Some(directory) => texture_set_builder.register(&format!("{directory}\\{file_name}")),
+                        None => texture_set_builder.register(file_name),
+                    }
+                }
+                None => texture_set_builder.register(FALLBACK_BMP_FILE),
+            })
+            .collect()
+    }
+
+    fn make_granny_vertices(
+        mesh: &GrannyMesh,
+        mesh_matrix: &Matrix4<f32>,
+        group_texture_indices: &[i32],
+        reverse_order: bool,
+    ) -> Vec<NativeModelVertex> {
+        let normal_matrix = mesh_matrix.invert().unwrap_or_else(Matrix4::identity).transpose();
+        let mut vertices = Vec::with_capacity(mesh.indices.len());
+
+        for (group, &texture_index) in mesh.triangle_groups.iter().zip(group_texture_indices) {
+            let Some(first_index) = group.first_triangle.checked_mul(3) else {
+                continue;
+            };
+            let Some(last_index) = group
+                .triangle_count
+                .checked_mul(3)
+                .and_then(|index_count| first_index.checked_add(index_count))
+            else {
+                continue;
+            };
+            let last_index = last_index.min(mesh.indices.len());
+
+            for triangle in mesh.indices.get(first_index..last_index).unwrap_or_default().chunks_exact(3) {
+                let Some(mut triangle) = triangle
+                    .iter()
+                    .map(|index| mesh.vertices.get(*index as usize))
+                    .collect::<Option<SmallVec<[_; 3]>>>()
+                else {
+                    continue;
+                };
+
+                if reverse_order {
+                    triangle.reverse();
+                }
+
+                let positions: [Point3<f32>; 3] =
+                    std::array::from_fn(|index| multiply_matrix4_and_point3(mesh_matrix, triangle[index].position));
+
+                // Not all Granny files are exported with normals.
+                let face_normal =
+                    NativeModelVertex::calculate_normal(positions[2], positions[1], positions[0]).unwrap_or_else(Vector3::unit_y);
+
+                for (vertex, position) in triangle.into_iter().zip(positions) {
+                    let normal = match vertex.normal.magnitude2() > 0.0 {
+                        true => (normal_matrix * vertex.normal.extend(0.0)).truncate().normalize(),
+                        false => face_normal,
+                    };
+
+                    vertices.push(NativeModelVertex::new(
+                        position,
+                        normal,
+                        vertex.texture_coordinates,
+                        texture_index,
+                        Color::WHITE,
+                        0.0,
+                        smallvec![0],
+                    ));
+                }
+            }
+        }
+
+        vertices
+    }
+
+    /// Loads a Granny (`.gr2`) model.
+    ///
+    /// Skinned and rigid meshes are stored in the bind pose, so the model is
+    /// rendered in that pose as a single static node.
+    // TODO: Play the animations of the model.
+    fn load_granny(
+        &self,
+        texture_set_builder: &mut TextureSetBuilder,
+        model_vertices: &mut Vec<ModelVertex>,
+        model_indices: &mut Vec<u32>,
+        model_file: &str,
+        reverse_order: bool,
+    ) -> Result<Model, LoadError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load granny model from {}", model_file.magenta()));
+
+        let bytes = match self.game_file_loader.get(&format!("data\\model\\{model_file}")) {
+            Ok(bytes) => bytes,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                {
+                    print_debug!("Failed to load model: {:?}", _error);
+                    print_debug!("Replacing with fallback");
+                }
+
+                return self.load(
+                    texture_set_builder,
+                    model_vertices,
+                    model_indices,
+                    FALLBACK_MODEL_FILE,
+                    reverse_order,
+                );
+            }
+        };
+
+        let granny_data = match GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)) {
+            Ok(granny_data) => granny_data,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                {
+                    print_debug!("Failed to load model: {:?}", _error);
+                    print_debug!("Replacing with fallback");
+                }
+
+                return self.load(
+                    texture_set_builder,
+                    model_vertices,
+                    model_indices,
+                    FALLBACK_MODEL_FILE,
+                    reverse_order,
+                );
+            }
+        };
+
+        let mut model_textures = Self::register_granny_textures(texture_set_builder, model_file, &granny_data);
+        let mut fallback_texture_index = None;
+        let mut native_vertices = Vec::new();
+
+        // Files without a model only contain loose meshes.
+        let placed_meshes: Vec<(Matrix4<f32>, usize)> = match granny_data.models.is_empty() {
+            true => (0..granny_data.meshes.len())
+                .map(|mesh_index| (Matrix4::identity(), mesh_index))
+                .collect(),
+            false => granny_data
+                .models
+                .iter()
+                .flat_map(|model| {
+                    let placement = model.initial_placement.to_matrix();
+                    model.mesh_indices.iter().map(move |mesh_index| (placement, *mesh_index))
+                })
+                .collect(),
+        };
+
+        for (placement, mesh_index) in placed_meshes {
+            let mesh = &granny_data.meshes[mesh_index];
+
+            let group_texture_indices: Vec<i32> = mesh
+                .triangle_groups
+                .iter()
+                .map(|group| {
+                    let material_index = mesh.material_bindings.get(group.material_index).copied().flatten();
+
+                    match material_index {
+                        Some(material_index) => material_index as i32,
+                        None => *fallback_texture_index.get_or_insert_with(|| {
+                            model_textures.push(texture_set_builder.register(FALLBACK_BMP_FILE));
+                            model_textures.len() as i32 - 1
+                        }),
+                    }
+                })
+                .collect();
+
+            native_vertices.extend(Self::make_granny_vertices(
+                mesh,
+                &placement,
+                &group_texture_indices,
+                reverse_order,
+            ));
+        }
+
+        if native_vertices.is_empty() {
+            #[cfg(feature = "debug")]
+            {
+                print_debug!("Failed to load model because it doesn't contain any geometry");
+                print_debug!("Replacing with fallback");
+            }
+
+            return self.load(
+                texture_set_builder,
+                model_vertices,
+                model_indices,
+                FALLBACK_MODEL_FILE,
+                reverse_order,
+            );
+        }
+
+        let bounding_box = AABB::from_vertices(native_vertices.iter().map(|vertex| vertex.position));
+        let centroid = Self::calculate_centroid(&native_vertices);
+
+        let vertices = NativeModelVertex::convert_to_model_vertices(native_vertices, Some(&model_textures));
+        let (vertices, mut indices) = reduce_vertices(&vertices);
+
+        let sub_meshes = match self.bindless_support {
+            BindlessSupport::Full | BindlessSupport::Limited => {
+                let index_offset = model_indices.len() as u32;
+                let index_count = indices.len() as u32;
+                let base_vertex = model_vertices.len() as i32;
+                model_vertices.extend(vertices);
+                model_indices.extend(indices);
+
+                vec![SubMesh {
+                    index_offset,
+                    index_count,
+                    base_vertex,
+                    texture_index: 0,
+                    transparent: model_textures.iter().any(|texture| texture.is_transparent),
+                }]
+            }
+            BindlessSupport::None => {
+                let texture_transparencies: HashMap<i32, bool> = model_textures
+                    .iter()
+                    .map(|texture| (texture.index, texture.is_transparent))
+                    .collect();
+
+                split_mesh_by_texture(
+                    &vertices,
+                    &mut indices,
+                    Some(model_vertices),
+                    Some(model_indices),
+                    Some(&texture_transparencies),
+                )
+            }
+        };
+
+        let root_node = Node::new(
+            GRANNY_MODEL_VERSION,
+            Matrix4::identity(),
+            Matrix4::identity(),
+            Matrix4::identity(),
+            Vector4::zero(),
+            centroid,
+            sub_meshes,
+            Vec::new(),
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        );
+
+        let model = Model::new(
+            GRANNY_MODEL_VERSION,
+            vec![root_node],
+            bounding_box,
+            true,
+            #[cfg(feature = "debug")]
+            None,
+        );
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(model)
+    }
+
+    pub fn get_entity_model(&self, model_file: &str) -> Option<Arc<EntityModel>> {
+        self.entity_model_cache.lock().unwrap().get(model_file).cloned()
+    }
+
+    /// Loads the model of an entity. Since entity models are placed on their
+    /// own, their geometry and textures are stored in separate buffers instead
+    /// of the ones of the map.
+    pub fn load_entity_model(
+        &self,
+        texture_loader: Arc<TextureLoader>,
+        video_loader: Arc<VideoLoader>,
+        model_file: &str,
+    ) -> Result<Arc<EntityModel>, LoadError> {
+        let mut texture_set_builder = TextureSetBuilder::new(texture_loader, video_loader, model_file);
+        let mut model_vertices = Vec::new();
+        let mut model_indices = Vec::new();
+
+        let model = self.load(
+            &mut texture_set_builder,
+            &mut model_vertices,
+            &mut model_indices,
+            model_file,
+            false,
+        )?;
+
+        let vertex_buffer = Arc::new(Buffer::with_data(
+            &self.device,
+            &self.queue,
+            format!("{model_file} vertices"),
+            BufferUsages::COPY_DST | BufferUsages::VERTEX,
+            &model_vertices,
+        ));
+        let index_buffer = Arc::new(Buffer::with_data(
+            &self.device,
+            &self.queue,
+            format!("{model_file} indices"),
+            BufferUsages::COPY_DST | BufferUsages::INDEX,
+            &model_indices,
+        ));
+
+        // Entity models don't have animated textures.
+        let (texture_set, _videos) = texture_set_builder.build();
+
+        let entity_model = Arc::new(EntityModel::new(model, Arc::new(texture_set), vertex_buffer, index_buffer));
+
+        self.entity_model_cache
+            .lock()
+            .unwrap()
+            .insert(model_file.to_owned(), entity_model.clone());
+
+        Ok(entity_model)
+    }
+
     pub fn load(
         &self,
         texture_set_builder: &mut TextureSetBuilder,
@@ -461,6 +799,10 @@ impl ModelLoader {
         model_file: &str,
         reverse_order: bool,
     ) -> Result<Model, LoadError> {
+        if model_file.to_ascii_lowercase().ends_with(".gr2") {
+            return self.load_granny(texture_set_builder, model_vertices, model_indices, model_file, reverse_order);
+        }
+
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load rsm model from {}", model_file.magenta()));
 
@@ -603,7 +945,7 @@ impl ModelLoader {
             model_bounding_box,
             is_static,
             #[cfg(feature = "debug")]
-            model_data,
+            Some(model_data),
         );
 
         #[cfg(feature = "debug")]
@@ -702,3 +1044,97 @@ enum TextureMapping {
     PreVersion2_3(Vec<TextureSetTexture>),
     PostVersion2_3(HashMap<String, TextureSetTexture>),
 }
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{Matrix4, Point3, SquareMatrix, Vector2, Vector3};
+    use ragnarok_formats::granny::{GrannyMesh, GrannyTriangleGroup, GrannyVertex};
+
+    use super::ModelLoader;
+
+    /// Skinned mesh like the ones of the 3D monsters, with two materials and
+    /// vertices that are bound to multiple bones.
+    fn guardian_mesh() -> GrannyMesh {
+        let vertex = |x: f32, y: f32| GrannyVertex {
+            position: Point3::new(x, y, 0.0),
+            normal: Vector3::new(0.0, 0.0, 1.0),
+            texture_coordinates: Vector2::new(x, y),
+            bone_indices: [0, 1, 0, 0],
+            bone_weights: [0.5, 0.5, 0.0, 0.0],
+        };
+
+        GrannyMesh {
+            name: "body".to_owned(),
+            vertices: vec![vertex(0.0, 0.0), vertex(1.0, 0.0), vertex(0.0, 1.0), vertex(1.0, 1.0)],
+            is_rigid: false,
+            indices: vec![0, 1, 2, 2, 1, 3],
+            triangle_groups: vec![
+                GrannyTriangleGroup {
+                    material_index: 0,
+                    first_triangle: 0,
+                    triangle_count: 1,
+                },
+                GrannyTriangleGroup {
+                    material_index: 1,
+                    first_triangle: 1,
+                    triangle_count: 1,
+                },
+            ],
+            material_bindings: vec![Some(0), Some(1)],
+            bone_bindings: vec!["Bip01".to_owned(), "Bip01 Spine".to_owned()],
+        }
+    }
+
+    #[test]
+    fn granny_vertices() {
+        let mesh = guardian_mesh();
+        let placement = Matrix4::from_translation(Vector3::new(0.0, 2.0, 0.0));
+        let vertices = ModelLoader::make_granny_vertices(&mesh, &placement, &[3, 5], false);
+
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[0].position, Point3::new(0.0, 2.0, 0.0));
+        assert_eq!(vertices[2].position, Point3::new(0.0, 3.0, 0.0));
+        assert_eq!(vertices[5].position, Point3::new(1.0, 3.0, 0.0));
+        assert_eq!(vertices[0].normal, Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(vertices[1].texture_coordinates, Vector2::new(1.0, 0.0));
+        assert!(vertices[..3].iter().all(|vertex| vertex.texture_index == 3));
+        assert!(vertices[3..].iter().all(|vertex| vertex.texture_index == 5));
+    }
+
+    #[test]
+    fn reversed_granny_vertices() {
+        let mesh = guardian_mesh();
+        let vertices = ModelLoader::make_granny_vertices(&mesh, &Matrix4::identity(), &[0, 0], true);
+
+        assert_eq!(vertices[0].position, Point3::new(0.0, 1.0, 0.0));
+        assert_eq!(vertices[2].position, Point3::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn granny_vertices_out_of_bounds() {
+        let mut mesh = guardian_mesh();
+        mesh.indices[2] = 7;
+        let vertices = ModelLoader::make_granny_vertices(&mesh, &Matrix4::identity(), &[0, 1], false);
+
+        // Triangles with invalid indices are skipped.
+        assert_eq!(vertices.len(), 3);
+        assert!(vertices.iter().all(|vertex| vertex.texture_index == 1));
+    }
+    #[test]
+    fn granny_vertices_overflow() {
+        let mut mesh = guardian_mesh();
+        mesh.triangle_groups[0].first_triangle = usize::MAX;
+        let vertices = ModelLoader::make_granny_vertices(&mesh, &Matrix4::identity(), &[0, 1], false);
+
+        // Groups with ranges that overflow are skipped.
+        assert_eq!(vertices.len(), 3);
+        assert!(vertices.iter().all(|vertex| vertex.texture_index == 1));
+
+        let mut mesh = guardian_mesh();
+        mesh.triangle_groups[1].triangle_count = usize::MAX;
+        let vertices = ModelLoader::make_granny_vertices(&mesh, &Matrix4::identity(), &[0, 1], false);
+
+        assert_eq!(vertices.len(), 3);
+        assert!(vertices.iter().all(|vertex| vertex.texture_index == 0));
+    }
+}
@@ -299,6 +299,7 @@ impl ServerType {
 
 #[derive(Debug, Clone, Copy, Deserialize, StateElement)]
 pub enum PacketVersion {
+    _20200401,
     _20220406,
     Unsupported(u64),
 }
@@ -310,6 +311,7 @@ where
     let version = Deserialize::deserialize(deserializer)?;
 
     match version {
+        20200401 => Ok(Some(PacketVersion::_20200401)),
         20220406 => Ok(Some(PacketVersion::_20220406)),
         _ => Ok(Some(PacketVersion::Unsupported(version))),
     }
@@ -52,7 +52,7 @@ use std::sync::{Arc, LazyLock, Mutex};
 use cgmath::{Point3, Vector3};
 use image::{EncodableLayout, ImageFormat, ImageReader};
 use input::{MouseInputMode, MouseModeExt};
-use inventory::{HotbarPathExt, InventoryPathExt, SkillTreePathExt};
+use inventory::{HotbarPathExt, InventoryPathExt, Mailbox, MailboxPathExt, SkillTreePathExt, Storage, Trade};
 use korangar_audio::{AudioEngine, SoundEffectKey};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
@@ -63,16 +63,16 @@ use korangar_debug::profiling::Profiler;
 use korangar_interface::Interface;
 use korangar_interface::layout::MouseButton;
 use korangar_networking::{
-    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkEventBuffer, NetworkingSystem, SellItem,
-    SupportedPacketVersion,
+    DisconnectReason, GuildNotice, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkEventBuffer, NetworkSession,
+    NetworkingSystem, PincodeRejection, ReplayPacing, SellItem, SessionRecorder, SupportedPacketVersion,
 };
 #[cfg(feature = "debug")]
 use networking::{PacketHistory, PacketHistoryCallback};
 #[cfg(not(feature = "debug"))]
 use ragnarok_packets::handler::NoPacketCallback;
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterServerInformation, Direction, DisappearanceReason, HotbarSlot, SellItemsResult, SkillId, SkillType,
-    TilePosition, UnitId, WorldPosition,
+    AccountId, BuyShopItemsResult, CharacterId, CharacterServerInformation, Direction, DisappearanceReason, EntityId, ExperienceType,
+    HotbarSlot, ItemPickupResult, MailOpenType, SellItemsResult, SkillId, SkillType, StorageIndex, TilePosition, UnitId, WorldPosition,
 };
 use renderer::InterfaceRenderer;
 use rust_state::{Context, ManuallyAssertExt};
@@ -299,6 +299,27 @@ fn main() {
         init_tls_rand();
     });
 
+    let args: Vec<String> = std::env::args().collect();
+    let sync_cache = args.len() > 1 && &args[1] == "sync-cache";
+
+    // The session files are opened before adjusting the working directory, so
+    // that relative paths work as expected.
+    let replay = match replay_from_arguments(&args) {
+        Ok(replay) => replay,
+        Err(message) => {
+            eprintln!("{message}");
+            return;
+        }
+    };
+
+    let session_recorder = match session_recorder_from_arguments(&args) {
+        Ok(session_recorder) => session_recorder,
+        Err(message) => {
+            eprintln!("{message}");
+            return;
+        }
+    };
+
     // Check if korangar is in the correct working directory and if not, try to
     // correct it.
     // NOTE: This check might be temporary or feature gated in the future.
@@ -318,10 +339,7 @@ fn main() {
         }
     });
 
-    let args: Vec<String> = std::env::args().collect();
-    let sync_cache = args.len() > 1 && &args[1] == "sync-cache";
-
-    let Some(mut client) = Client::init(sync_cache) else {
+    let Some(mut client) = Client::init(sync_cache, replay, session_recorder) else {
         return;
     };
 
@@ -330,6 +348,46 @@ fn main() {
     let _ = event_loop.run_app(&mut client);
 }
 
+fn argument_value<'a>(args: &'a [String], name: &str) -> Option<&'a String> {
+    args.iter()
+        .position(|argument| argument == name)
+        .and_then(|index| args.get(index + 1))
+}
+
+/// Parse the `--replay <file>` and `--replay-speed <factor|max>` launch
+/// options. When replaying, no connection to a server is made and the packets
+/// of the recorded session are played back instead.
+fn replay_from_arguments(args: &[String]) -> Result<Option<(NetworkSession, ReplayPacing)>, String> {
+    let Some(path) = argument_value(args, "--replay") else {
+        return Ok(None);
+    };
+
+    let pacing = match argument_value(args, "--replay-speed").map(String::as_str) {
+        None => ReplayPacing::RealTime,
+        Some("max") => ReplayPacing::Unpaced,
+        Some(speed) => match speed.parse::<f64>() {
+            Ok(factor) if factor > 0.0 => ReplayPacing::Accelerated(factor),
+            _ => return Err(format!("invalid replay speed: {speed}")),
+        },
+    };
+
+    let session = NetworkSession::load(path).map_err(|error| format!("failed to load replay {path}: {error}"))?;
+
+    Ok(Some((session, pacing)))
+}
+
+/// Parse the `--record <file>` launch option. When recording, all network
+/// traffic is written to the file so it can be replayed later.
+fn session_recorder_from_arguments(args: &[String]) -> Result<Option<SessionRecorder>, String> {
+    let Some(path) = argument_value(args, "--record") else {
+        return Ok(None);
+    };
+
+    SessionRecorder::create(path)
+        .map(Some)
+        .map_err(|error| format!("failed to create recording {path}: {error}"))
+}
+
 fn initialize_shutdown_signal() {
     ctrlc::set_handler(|| {
         println!("CTRL-C received. Shutting down");
@@ -397,6 +455,8 @@ struct Client {
     // TODO: Move or remove this.
     saved_character_server: Option<CharacterServerInformation>,
     // TODO: Move or remove this.
+    saved_character_id: Option<CharacterId>,
+    // TODO: Move or remove this.
     saved_login_server_address: Option<SocketAddr>,
     // TODO: Move or remove this.
     saved_password: String,
@@ -409,6 +469,7 @@ struct Client {
     point_light_manager: PointLightManager,
     effect_holder: EffectHolder,
     path_finder: PathFinder,
+    route_planner: RoutePlanner,
 
     point_light_set_buffer: ResourceSetBuffer<LightSourceKey>,
     directional_shadow_object_set_buffer: ResourceSetBuffer<ObjectKey>,
@@ -442,7 +503,7 @@ struct Client {
 }
 
 impl Client {
-    fn init(sync_cache: bool) -> Option<Self> {
+    fn init(sync_cache: bool, replay: Option<(NetworkSession, ReplayPacing)>, session_recorder: Option<SessionRecorder>) -> Option<Self> {
         time_phase!("load graphics settings", {
             let picker_value = Arc::new(AtomicU64::new(0));
             let directional_shadow_partitions = Arc::new(Mutex::new([DirectionalShadowPartition::default(); PARTITION_COUNT]));
@@ -531,7 +592,12 @@ impl Client {
             std::fs::create_dir_all(IN_GAME_THEMES_PATH).unwrap();
             std::fs::create_dir_all(WORLD_THEMES_PATH).unwrap();
 
-            let model_loader = Arc::new(ModelLoader::new(game_file_loader.clone(), capabilities.bindless_support()));
+            let model_loader = Arc::new(ModelLoader::new(
+                device.clone(),
+                queue.clone(),
+                game_file_loader.clone(),
+                capabilities.bindless_support(),
+            ));
             let texture_loader = Arc::new(TextureLoader::new(
                 device.clone(),
                 queue.clone(),
@@ -668,6 +734,7 @@ impl Client {
         // TODO: Move all of these to the ClientState
         let saved_login_data: Option<LoginServerLoginData> = None;
         let saved_character_server: Option<CharacterServerInformation> = None;
+        let saved_character_id = None;
         let saved_login_server_address = None;
         let saved_password = String::new();
         let saved_username = String::new();
@@ -675,12 +742,20 @@ impl Client {
 
         time_phase!("initialize networking", {
             #[cfg(not(feature = "debug"))]
-            let (networking_system, network_event_buffer) = NetworkingSystem::spawn();
+            let (mut networking_system, network_event_buffer) = match replay {
+                Some((session, pacing)) => NetworkingSystem::spawn_replay(session, pacing),
+                None => NetworkingSystem::spawn(),
+            };
 
             #[cfg(feature = "debug")]
             let (packet_history, packet_history_callback) = PacketHistory::new();
             #[cfg(feature = "debug")]
-            let (networking_system, network_event_buffer) = NetworkingSystem::spawn_with_callback(packet_history_callback);
+            let (mut networking_system, network_event_buffer) = match replay {
+                Some((session, pacing)) => NetworkingSystem::spawn_replay_with_callback(session, pacing, packet_history_callback),
+                None => NetworkingSystem::spawn_with_callback(packet_history_callback),
+            };
+
+            networking_system.set_session_recorder(session_recorder);
         });
 
         time_phase!("create resources", {
@@ -690,6 +765,7 @@ impl Client {
             let point_light_manager = PointLightManager::new();
             let effect_holder = EffectHolder::default();
             let path_finder = PathFinder::default();
+            let route_planner = RoutePlanner::default();
 
             let point_light_set_buffer = ResourceSetBuffer::default();
             let directional_shadow_object_set_buffer = ResourceSetBuffer::default();
@@ -808,6 +884,7 @@ impl Client {
             network_event_buffer,
             saved_login_data,
             saved_character_server,
+            saved_character_id,
             saved_login_server_address,
             saved_password,
             saved_username,
@@ -816,6 +893,7 @@ impl Client {
             point_light_manager,
             effect_holder,
             path_finder,
+            route_planner,
             point_light_set_buffer,
             directional_shadow_object_set_buffer,
             point_shadow_object_set_buffer,
@@ -1032,6 +1110,25 @@ impl Client {
 
                     self.client_state.follow_mut(client_state().entities()).clear();
                     self.client_state.follow_mut(client_state().dead_entities()).clear();
+                    self.client_state.follow_mut(client_state().ground_items()).clear();
+                    *self.client_state.follow_mut(client_state().buffered_pickup_item()) = None;
+
+                    *self.client_state.follow_mut(client_state().party_name()) = None;
+                    self.client_state.follow_mut(client_state().party_members()).clear();
+
+                    self.client_state.follow_mut(client_state().quests()).clear();
+                    self.client_state.follow_mut(client_state().achievements()).clear();
+                    self.client_state.follow_mut(client_state().minimap()).clear();
+                    self.client_state.follow_mut(client_state().skill_tree()).clear_cooldowns();
+
+                    *self.client_state.follow_mut(client_state().guild_information()) = None;
+                    *self.client_state.follow_mut(client_state().guild_notice()) = GuildNotice::default();
+                    self.client_state.follow_mut(client_state().guild_members()).clear();
+                    self.client_state.follow_mut(client_state().guild_positions()).clear();
+
+                    *self.client_state.follow_mut(client_state().trade()) = Trade::default();
+                    *self.client_state.follow_mut(client_state().storage()) = Storage::default();
+                    *self.client_state.follow_mut(client_state().mailbox()) = Mailbox::default();
 
                     self.audio_engine.play_background_music_track(None);
 
@@ -1089,7 +1186,11 @@ impl Client {
                         .follow_mut(client_state().character_slots())
                         .set_characters(characters);
 
-                    if !self.interface.is_window_with_class_open(WindowClass::CharacterSelection) {
+                    // While the pincode window is open, the character selection is opened once the
+                    // pincode was accepted.
+                    if !self.interface.is_window_with_class_open(WindowClass::CharacterSelection)
+                        && !self.interface.is_window_with_class_open(WindowClass::Pincode)
+                    {
                         // TODO: this will do one unnecessary restore_focus. check
                         // if that will be problematic
 
@@ -1105,6 +1206,30 @@ impl Client {
                         ));
                     }
                 }
+                NetworkEvent::PincodeRequested { seed, request, rejection } => {
+                    let message = rejection.map(|rejection| match rejection {
+                        PincodeRejection::Wrong => self.client_state.follow(client_state().localization().wrong_pincode_text()).clone(),
+                        PincodeRejection::Illegal => self
+                            .client_state
+                            .follow(client_state().localization().illegal_pincode_text())
+                            .clone(),
+                    });
+
+                    *self.client_state.follow_mut(client_state().pincode_window()) = PincodeWindowState::new(seed, request, message);
+
+                    // Reopen the window so the keypad is shuffled again.
+                    self.interface.close_window_with_class(WindowClass::CharacterSelection);
+                    self.interface.close_window_with_class(WindowClass::Pincode);
+                    self.interface.open_window(PincodeWindow::new(client_state().pincode_window()));
+                }
+                NetworkEvent::PincodeAccepted => {
+                    if !self.interface.is_window_with_class_open(WindowClass::CharacterSelection) {
+                        self.interface.open_window(CharacterSelectionWindow::new(
+                            client_state().character_slots(),
+                            client_state().switch_request(),
+                        ));
+                    }
+                }
                 NetworkEvent::CharacterSelectionFailed { message, .. } => self.interface.open_window(ErrorWindow::new(message.to_owned())),
                 NetworkEvent::CharacterDeleted => {
                     if let Some(character_id) = self.client_state.follow_mut(client_state().currently_deleting()).take() {
@@ -1121,6 +1246,7 @@ impl Client {
                     self.audio_engine.play_sound_effect(self.main_menu_click_sound_effect);
 
                     let saved_login_data = self.saved_login_data.as_ref().unwrap();
+                    self.saved_character_id = Some(login_data.character_id);
                     self.networking_system.disconnect_from_character_server();
                     self.networking_system
                         .connect_to_map_server(self.saved_packet_version, saved_login_data, login_data);
@@ -1161,6 +1287,12 @@ impl Client {
                         // TODO: Check that manually asserting is fine. Technically this window should only
                         // be open while the player is selected.
                         this_player().manually_asserted().job_level(),
+                        // TODO: Check that manually asserting is fine. Technically this window should only
+                        // be open while the player is selected.
+                        this_player().manually_asserted().base_experience(),
+                        // TODO: Check that manually asserting is fine. Technically this window should only
+                        // be open while the player is selected.
+                        this_player().manually_asserted().job_experience(),
                     ));
                     self.interface
                         .open_window(ChatWindow::new(client_state().chat_window(), client_state().chat_messages()));
@@ -1217,12 +1349,39 @@ impl Client {
                             npc.set_animation_data(animation_data);
                         }
 
+                        // The model loader replaces missing models with a fallback, so we only
+                        // request models that exist and keep the sprite otherwise.
+                        if let Some(model_file) = npc.get_model_file()
+                            && self.game_file_loader.file_exists(&format!("data\\model\\{model_file}"))
+                            && let Some(entity_model) = self.async_loader.request_entity_model_load(entity_id, model_file.to_owned())
+                        {
+                            npc.set_model(entity_model);
+                        }
+
                         #[cfg(feature = "debug")]
                         npc.generate_pathing_mesh(&self.device, &self.queue, self.graphics_engine.bindless_support(), map);
 
                         entities.push(npc);
                     }
                 }
+                NetworkEvent::AddGroundItem { ground_item_data } => {
+                    if let Some(map) = &self.map
+                        && let Some(world_position) = map.get_world_position(ground_item_data.position)
+                    {
+                        let ground_item =
+                            self.library
+                                .load_ground_item(&self.game_file_loader, &self.async_loader, &ground_item_data, world_position);
+                        let ground_items = self.client_state.follow_mut(client_state().ground_items());
+
+                        ground_items.retain(|item| item.ground_item_id != ground_item.ground_item_id);
+                        ground_items.push(ground_item);
+                    }
+                }
+                NetworkEvent::RemoveGroundItem { ground_item_id } => {
+                    self.client_state
+                        .follow_mut(client_state().ground_items())
+                        .retain(|item| item.ground_item_id != ground_item_id);
+                }
                 NetworkEvent::RemoveEntity { entity_id, reason } => {
                     //If the motive is dead, you need to set the player to dead
                     if reason == DisappearanceReason::Died {
@@ -1297,6 +1456,8 @@ impl Client {
                     destination,
                     starting_timestamp,
                 } => {
+                    self.route_planner.movement_confirmed();
+
                     if let Some(map) = &self.map
                         && let Some(player) = self.client_state.try_follow_mut(this_entity())
                     {
@@ -1313,6 +1474,7 @@ impl Client {
                 }
                 NetworkEvent::ChangeMap { map_name, position } => {
                     self.map = None;
+                    self.route_planner.cancel();
                     self.particle_holder.clear();
                     self.effect_holder.clear();
                     self.point_light_manager.clear();
@@ -1321,6 +1483,8 @@ impl Client {
                     // Only the player must stay alive between map changes.
                     self.client_state.follow_mut(client_state().entities()).truncate(1);
                     self.client_state.follow_mut(client_state().dead_entities()).clear();
+                    self.client_state.follow_mut(client_state().ground_items()).clear();
+                    *self.client_state.follow_mut(client_state().buffered_pickup_item()) = None;
 
                     // Close any remaining dialogs.
                     self.interface.close_window_with_class(WindowClass::Dialog);
@@ -1419,6 +1583,80 @@ impl Client {
                             .spawn_particle(Box::new(HealNumber::new(entity.get_position(), heal_amount.to_string())));
                     }
                 }
+                NetworkEvent::SkillCooldown {
+                    skill_id,
+                    duration_in_milliseconds,
+                } => {
+                    self.client_state.follow_mut(client_state().skill_tree()).start_cooldown(
+                        skill_id,
+                        duration_in_milliseconds,
+                        client_tick,
+                    );
+                }
+                NetworkEvent::SkillCastStarted {
+                    source_entity_id,
+                    cast_time_in_milliseconds,
+                    ..
+                } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == source_entity_id)
+                    {
+                        entity.start_cast(cast_time_in_milliseconds, client_tick);
+                    }
+                }
+                NetworkEvent::SkillUseFailed { .. } => {
+                    if let Some(player) = self.client_state.try_follow_mut(this_entity()) {
+                        player.cancel_cast();
+                    }
+                }
+                NetworkEvent::StatusEffectStarted {
+                    entity_id,
+                    status_id,
+                    duration_in_milliseconds,
+                    remaining_in_milliseconds,
+                } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.add_status_effect(StatusEffect::new(
+                            status_id,
+                            duration_in_milliseconds,
+                            remaining_in_milliseconds,
+                            client_tick,
+                        ));
+                    }
+                }
+                NetworkEvent::StatusEffectEnded { entity_id, status_id } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.remove_status_effect(status_id);
+                    }
+                }
+                NetworkEvent::EntityStateChanged {
+                    entity_id,
+                    body_state,
+                    effect_state,
+                    ..
+                } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.set_state(body_state, effect_state);
+                    }
+                }
                 NetworkEvent::UpdateEntityHealth {
                     entity_id,
                     health_points,
@@ -1447,6 +1685,38 @@ impl Client {
 
                     self.interface.open_window(DialogWindow::new(client_state().dialog_window()));
                 }
+                NetworkEvent::MinimapMarkerAdded {
+                    npc_id,
+                    marker_id,
+                    position,
+                    color,
+                    is_temporary,
+                } => {
+                    let color = Color::rgb_u8(color.red, color.green, color.blue);
+
+                    self.client_state.follow_mut(client_state().minimap()).add_marker(
+                        npc_id,
+                        marker_id,
+                        position,
+                        color,
+                        is_temporary,
+                        client_tick,
+                    );
+                }
+                NetworkEvent::MinimapMarkerRemoved { npc_id, marker_id } => {
+                    self.client_state
+                        .follow_mut(client_state().minimap())
+                        .remove_marker(npc_id, marker_id);
+                }
+                NetworkEvent::NavigationTarget { map_name, position } => {
+                    let minimap = self.client_state.follow_mut(client_state().minimap());
+
+                    // Targets on other maps would need the navigation data of the client to find
+                    // the route there.
+                    if minimap.get_map_name() == map_name {
+                        minimap.set_navigation_target(position);
+                    }
+                }
                 NetworkEvent::AddNextButton { npc_id } => {
                     self.client_state
                         .follow_mut(client_state().dialog_window())
@@ -1499,9 +1769,59 @@ impl Client {
                     // should allow you to sell the new
                     // amount of items.
                 }
+                NetworkEvent::ItemPickupFailed { result } => {
+                    let localization = client_state().localization();
+                    let text = match result {
+                        ItemPickupResult::Overweight => self.client_state.follow(localization.item_pickup_overweight_text()),
+                        ItemPickupResult::NoSpace => self.client_state.follow(localization.item_pickup_no_space_text()),
+                        ItemPickupResult::MaximumOfItem | ItemPickupResult::StackLimitation => {
+                            self.client_state.follow(localization.item_pickup_limit_text())
+                        }
+                        _ => self.client_state.follow(localization.item_pickup_failed_text()),
+                    }
+                    .clone();
+
+                    self.client_state
+                        .follow_mut(client_state().chat_messages())
+                        .push(ChatMessage::new(text, MessageColor::Error));
+                }
                 NetworkEvent::InventoryItemRemoved { index, amount, .. } => {
                     self.client_state.follow_mut(client_state().inventory()).remove_item(index, amount);
                 }
+                NetworkEvent::SetStorage { items } => {
+                    self.client_state
+                        .follow_mut(client_state().storage())
+                        .fill(&self.async_loader, &self.library, items);
+
+                    if !self.interface.is_window_with_class_open(WindowClass::Storage) {
+                        self.interface.open_window(StorageWindow::new(client_state().storage()));
+                    }
+
+                    if !self.interface.is_window_with_class_open(WindowClass::Inventory) {
+                        self.interface.open_window(InventoryWindow::new(client_state().inventory().items()));
+                    }
+                }
+                NetworkEvent::StorageItemCount {
+                    item_count,
+                    maximum_item_count,
+                } => {
+                    self.client_state
+                        .follow_mut(client_state().storage())
+                        .set_item_count(item_count, maximum_item_count);
+                }
+                NetworkEvent::StorageItemAdded { item } => {
+                    self.client_state
+                        .follow_mut(client_state().storage())
+                        .add_item(&self.async_loader, &self.library, item);
+                }
+                NetworkEvent::StorageItemRemoved { index, amount } => {
+                    self.client_state.follow_mut(client_state().storage()).remove_item(index, amount);
+                }
+                NetworkEvent::StorageClosed => {
+                    *self.client_state.follow_mut(client_state().storage()) = Storage::default();
+                    self.interface.close_window_with_class(WindowClass::Storage);
+                    self.interface.close_window_with_class(WindowClass::ItemAmount);
+                }
                 NetworkEvent::SkillTree { skill_information } => {
                     self.client_state.follow_mut(client_state().skill_tree()).fill(
                         &self.sprite_loader,
@@ -1536,6 +1856,15 @@ impl Client {
                     ) {
                         entity.set_animation_data(animation_data);
                     }
+
+                    if let Some(model_file) = entity.get_model_file()
+                        && self.game_file_loader.file_exists(&format!("data\\model\\{model_file}"))
+                        && let Some(entity_model) = self
+                            .async_loader
+                            .request_entity_model_load(entity.get_entity_id(), model_file.to_owned())
+                    {
+                        entity.set_model(entity_model);
+                    }
                 }
                 NetworkEvent::ChangeHair { account_id, hair_id } => {
                     let entity = self
@@ -1590,6 +1919,60 @@ impl Client {
                         false,
                     )));
                 }
+                NetworkEvent::ExperienceGained {
+                    account_id,
+                    amount,
+                    experience_type,
+                    ..
+                } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow(client_state().entities())
+                        .iter()
+                        .find(|entity| entity.get_entity_id() == EntityId(account_id.0))
+                        .or_else(|| self.client_state.try_follow(this_entity()))
+                    {
+                        let experience_label = match experience_type {
+                            ExperienceType::BaseExperience => {
+                                self.client_state.follow(client_state().localization().base_experience_text())
+                            }
+                            ExperienceType::JobExperience => self.client_state.follow(client_state().localization().job_experience_text()),
+                        };
+
+                        self.particle_holder.spawn_particle(Box::new(ExperienceNumber::new(
+                            entity.get_position(),
+                            amount,
+                            experience_type,
+                            experience_label,
+                        )));
+                    }
+                }
+                NetworkEvent::Emotion { entity_id, emotion } => {
+                    // Missing emotion assets shouldn't take down the client, so the emotion is
+                    // skipped instead.
+                    let assets = self
+                        .sprite_loader
+                        .get_or_load(&format!("{EMOTION_FILE_PATH}.spr"))
+                        .and_then(|sprite| Ok((sprite, self.action_loader.get_or_load(&format!("{EMOTION_FILE_PATH}.act"))?)));
+
+                    let (sprite, actions) = match assets {
+                        Ok(assets) => assets,
+                        Err(_error) => {
+                            #[cfg(feature = "debug")]
+                            print_debug!("[{}] failed to load emotion assets: {:?}", "error".red(), _error);
+                            continue;
+                        }
+                    };
+
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.show_emotion(Emotion::new(sprite, actions, emotion, client_tick));
+                    }
+                }
                 NetworkEvent::AddSkillUnit {
                     entity_id,
                     unit_id,
@@ -1659,72 +2042,415 @@ impl Client {
                 NetworkEvent::SetFriendList { friend_list } => {
                     *self.client_state.follow_mut(client_state().friend_list()) = friend_list;
                 }
-                NetworkEvent::SetHotkeyData { tab, hotkeys } => {
-                    // FIX: Since we only have one hotbar at the moment, we ignore
-                    // everything but 0.
-                    if tab.0 != 0 {
-                        continue;
+                NetworkEvent::PartyInvitation { party_id, party_name } => {
+                    self.interface.open_window(PartyInvitationWindow::new(party_id, party_name));
+                }
+                NetworkEvent::SetParty { party_name, members } => {
+                    *self.client_state.follow_mut(client_state().party_name()) = Some(party_name);
+                    *self.client_state.follow_mut(client_state().party_members()) = members;
+                }
+                NetworkEvent::PartyMemberUpdated { party_name, mut member } => {
+                    *self.client_state.follow_mut(client_state().party_name()) = Some(party_name);
+
+                    let party_members = self.client_state.follow_mut(client_state().party_members());
+
+                    match party_members.iter_mut().find(|known| known.account_id == member.account_id) {
+                        Some(known) => {
+                            // The member update doesn't contain the health points, so we keep
+                            // the ones we already know.
+                            member.health_points = known.health_points;
+                            member.maximum_health_points = known.maximum_health_points;
+                            *known = member;
+                        }
+                        None => party_members.push(member),
                     }
+                }
+                NetworkEvent::PartyMemberLeft { account_id } => {
+                    let player_account_id = self.saved_login_data.as_ref().map(|login_data| login_data.account_id);
 
-                    for (index, hotkey) in hotkeys.into_iter().take(10).enumerate() {
-                        match hotkey {
-                            HotkeyState::Bound(hotkey) => {
-                                let Some(mut skill) = self
-                                    .client_state
-                                    .follow(client_state().skill_tree())
-                                    .find_skill(SkillId(hotkey.skill_id as u16))
-                                else {
-                                    self.client_state
-                                        .follow_mut(client_state().hotbar())
-                                        .clear_slot(&mut self.networking_system, HotbarSlot(index as u16));
-                                    continue;
-                                };
-
-                                skill.skill_level = hotkey.quantity_or_skill_level;
-                                self.client_state
-                                    .follow_mut(client_state().hotbar())
-                                    .set_slot(HotbarSlot(index as u16), skill);
-                            }
-                            HotkeyState::Unbound => self
-                                .client_state
-                                .follow_mut(client_state().hotbar())
-                                .unset_slot(HotbarSlot(index as u16)),
+                    match player_account_id == Some(account_id) {
+                        true => {
+                            *self.client_state.follow_mut(client_state().party_name()) = None;
+                            self.client_state.follow_mut(client_state().party_members()).clear();
                         }
+                        false => self
+                            .client_state
+                            .follow_mut(client_state().party_members())
+                            .retain(|member| member.account_id != account_id),
                     }
                 }
-                NetworkEvent::OpenShop { items } => {
-                    // Close the dialog. Some NPCs don't use the `BuyOrSellPacket` and instead use
-                    // the regular `DialogMenuPacket`. When opening the shop that dialog should be
-                    // closed.
-                    self.client_state.follow_mut(client_state().dialog_window()).end();
-                    self.interface.close_window_with_class(WindowClass::Dialog);
+                NetworkEvent::PartyMemberHealth {
+                    account_id,
+                    health_points,
+                    maximum_health_points,
+                } => {
+                    if let Some(member) = self
+                        .client_state
+                        .follow_mut(client_state().party_members())
+                        .iter_mut()
+                        .find(|member| member.account_id == account_id)
+                    {
+                        member.health_points = health_points;
+                        member.maximum_health_points = maximum_health_points;
+                    }
+                }
+                NetworkEvent::PartyMemberPosition { account_id, position } => {
+                    if let Some(member) = self
+                        .client_state
+                        .follow_mut(client_state().party_members())
+                        .iter_mut()
+                        .find(|member| member.account_id == account_id)
+                    {
+                        member.position = Some(position);
+                    }
+                }
+                NetworkEvent::PartyLeaderChanged { account_id } => {
+                    self.client_state
+                        .follow_mut(client_state().party_members())
+                        .iter_mut()
+                        .for_each(|member| member.is_leader = member.account_id == account_id);
+                }
+                NetworkEvent::SetQuestLog { quests } => {
+                    *self.client_state.follow_mut(client_state().quests()) = quests;
+                }
+                NetworkEvent::QuestAdded { quest } => {
+                    let quests = self.client_state.follow_mut(client_state().quests());
 
-                    *self.client_state.follow_mut(client_state().shop_items()) = items
+                    match quests.iter_mut().find(|known| known.quest_id == quest.quest_id) {
+                        Some(known) => *known = quest,
+                        None => quests.push(quest),
+                    }
+                }
+                NetworkEvent::QuestObjectivesUpdated { objectives } => {
+                    let quests = self.client_state.follow_mut(client_state().quests());
+
+                    for objective in &objectives {
+                        quests.iter_mut().for_each(|quest| quest.update_objective(objective));
+                    }
+                }
+                NetworkEvent::QuestRemoved { quest_id } => {
+                    self.client_state
+                        .follow_mut(client_state().quests())
+                        .retain(|quest| quest.quest_id != quest_id);
+                }
+                NetworkEvent::SetAchievements { level, achievements } => {
+                    let achievements = achievements
                         .into_iter()
-                        .map(|item| self.library.load_shop_item_metadata(&self.async_loader, item))
+                        .map(|achievement| self.library.load_achievement(achievement))
                         .collect();
 
-                    self.interface
-                        .open_window(BuyWindow::new(client_state().shop_items(), client_state().buy_cart()));
-                    self.interface.open_window(BuyCartWindow::new(client_state().buy_cart()));
-                }
-                NetworkEvent::AskBuyOrSell { shop_id } => {
-                    self.interface.open_window(BuyOrSellWindow::new(shop_id));
+                    self.client_state.follow_mut(client_state().achievements()).set(level, achievements);
                 }
-                NetworkEvent::BuyingCompleted { result } => match result {
-                    BuyShopItemsResult::Success => {
-                        let _ = self.networking_system.close_shop();
+                NetworkEvent::AchievementUpdated { level, achievement } => {
+                    let achievement = self.library.load_achievement(achievement);
+                    let title = achievement.title.clone();
 
-                        // Clear the cart.
-                        self.client_state.follow_mut(client_state().buy_cart()).clear();
+                    let newly_completed = self
+                        .client_state
+                        .follow_mut(client_state().achievements())
+                        .update(level, achievement);
+
+                    if newly_completed {
+                        let completed_text = self.client_state.follow(client_state().localization().achievement_completed_text());
+                        let text = format!("{completed_text}: {title}");
 
-                        self.interface.close_window_with_class(WindowClass::Buy);
-                        self.interface.close_window_with_class(WindowClass::BuyCart);
-                    }
-                    BuyShopItemsResult::Error => {
                         self.client_state
                             .follow_mut(client_state().chat_messages())
-                            .push(ChatMessage::new("Failed to buy items".to_owned(), MessageColor::Error));
+                            .push(ChatMessage::new(text.clone(), MessageColor::Information));
+                        self.particle_holder.spawn_particle(Box::new(AchievementToast::new(text)));
+                    }
+                }
+                NetworkEvent::GuildJoined { guild_id, .. } => {
+                    let _ = self.networking_system.request_guild_information();
+                    let _ = self.networking_system.request_guild_positions();
+                    let _ = self.networking_system.request_guild_members();
+                    let _ = self.networking_system.request_guild_emblem(guild_id);
+                }
+                NetworkEvent::GuildInvitation { guild_id, guild_name } => {
+                    self.interface.open_window(GuildInvitationWindow::new(guild_id, guild_name));
+                }
+                NetworkEvent::SetGuildInformation { information } => {
+                    *self.client_state.follow_mut(client_state().guild_information()) = Some(information);
+                }
+                NetworkEvent::SetGuildMembers { mut members } => {
+                    let known_members = self.client_state.follow(client_state().guild_members());
+
+                    // Keep the names that we already resolved.
+                    for member in members.iter_mut() {
+                        if let Some(known) = known_members.iter().find(|known| known.character_id == member.character_id) {
+                            member.name = known.name.clone();
+                        }
+                    }
+
+                    for member in members.iter().filter(|member| member.name.is_empty()) {
+                        let _ = self.networking_system.request_character_name(member.character_id);
+                    }
+
+                    *self.client_state.follow_mut(client_state().guild_members()) = members;
+                }
+                NetworkEvent::SetGuildPositions { positions } => {
+                    *self.client_state.follow_mut(client_state().guild_positions()) = positions;
+                }
+                NetworkEvent::SetGuildNotice { notice } => {
+                    *self.client_state.follow_mut(client_state().guild_notice()) = notice;
+                }
+                NetworkEvent::GuildEmblem { .. } => {
+                    // TODO: Decode the emblem and display it in the guild
+                    // window.
+                }
+                NetworkEvent::GuildMemberLeft { character_name } => {
+                    match *self.client_state.follow(client_state().player_name()) == character_name {
+                        true => {
+                            *self.client_state.follow_mut(client_state().guild_information()) = None;
+                            *self.client_state.follow_mut(client_state().guild_notice()) = GuildNotice::default();
+                            self.client_state.follow_mut(client_state().guild_members()).clear();
+                            self.client_state.follow_mut(client_state().guild_positions()).clear();
+                        }
+                        false => self
+                            .client_state
+                            .follow_mut(client_state().guild_members())
+                            .retain(|member| member.name != character_name),
+                    }
+                }
+                NetworkEvent::GuildMemberOnlineState {
+                    account_id,
+                    character_id,
+                    is_online,
+                } => {
+                    if let Some(member) = self
+                        .client_state
+                        .follow_mut(client_state().guild_members())
+                        .iter_mut()
+                        .find(|member| member.account_id == account_id && member.character_id == character_id)
+                    {
+                        member.is_online = is_online;
+                    }
+                }
+                NetworkEvent::CharacterName { character_id, name } => {
+                    if let Some(member) = self
+                        .client_state
+                        .follow_mut(client_state().guild_members())
+                        .iter_mut()
+                        .find(|member| member.character_id == character_id)
+                    {
+                        member.name = name;
+                    }
+                }
+                NetworkEvent::TradeRequested {
+                    character_name,
+                    base_level,
+                } => {
+                    self.client_state.follow_mut(client_state().trade()).prepare(character_name.clone());
+                    self.interface.open_window(TradeRequestWindow::new(character_name, base_level));
+                }
+                NetworkEvent::TradeStarted => {
+                    *self.client_state.follow_mut(client_state().trade_window()) = TradeWindowState::default();
+                    self.interface.close_window_with_class(WindowClass::TradeRequest);
+                    self.interface
+                        .open_window(TradeWindow::new(client_state().trade_window(), client_state().trade()));
+                }
+                NetworkEvent::TradeItemAdded { item } => {
+                    self.client_state
+                        .follow_mut(client_state().trade())
+                        .add_partner_item(&self.async_loader, &self.library, item);
+                }
+                NetworkEvent::TradeZenyAdded { amount } => {
+                    self.client_state.follow_mut(client_state().trade()).set_partner_zeny(amount);
+                }
+                NetworkEvent::TradeItemAccepted { index } => {
+                    // The server only sends back the index, so we take the item from the
+                    // inventory.
+                    let item = self.client_state.follow(client_state().inventory()).get_item(index).cloned();
+
+                    if let Some(item) = item {
+                        self.client_state.follow_mut(client_state().trade()).accept_item(item);
+                    }
+                }
+                NetworkEvent::TradeItemRejected { index } => {
+                    self.client_state.follow_mut(client_state().trade()).reject_item(index);
+                }
+                NetworkEvent::TradeLocked { participant } => {
+                    self.client_state.follow_mut(client_state().trade()).lock(participant);
+                }
+                NetworkEvent::TradeCancelled | NetworkEvent::TradeCompleted => {
+                    *self.client_state.follow_mut(client_state().trade()) = Trade::default();
+                    self.interface.close_window_with_class(WindowClass::Trade);
+                }
+                NetworkEvent::NewMailStatus { has_unread_mail } => {
+                    self.client_state
+                        .follow_mut(client_state().mailbox())
+                        .set_unread_mail(has_unread_mail);
+
+                    if has_unread_mail {
+                        let text = self.client_state.follow(client_state().localization().new_mail_text()).clone();
+
+                        self.client_state
+                            .follow_mut(client_state().chat_messages())
+                            .push(ChatMessage::new(text, MessageColor::Information));
+                    }
+                }
+                NetworkEvent::MailList { mails, .. } => {
+                    self.client_state.follow_mut(client_state().mailbox()).add_mails(mails);
+                }
+                NetworkEvent::MailRead {
+                    mail_id,
+                    open_type,
+                    text,
+                    zeny,
+                    items,
+                } => {
+                    let items = items
+                        .into_iter()
+                        .map(|item| self.library.load_inventory_item_metadata(&self.async_loader, item))
+                        .collect();
+
+                    self.client_state
+                        .follow_mut(client_state().mailbox())
+                        .open_mail(mail_id, open_type, text, zeny, items);
+
+                    if !self.interface.is_window_with_class_open(WindowClass::Mail) {
+                        self.interface.open_window(MailWindow::new(client_state().mailbox().opened_mail()));
+                    }
+                }
+                NetworkEvent::MailDeleted { mail_id } => {
+                    let was_opened = self.client_state.follow_mut(client_state().mailbox()).remove_mail(mail_id);
+
+                    if was_opened {
+                        self.interface.close_window_with_class(WindowClass::Mail);
+                    }
+                }
+                NetworkEvent::MailZenyRetrieved { mail_id } => {
+                    self.client_state.follow_mut(client_state().mailbox()).zeny_retrieved(mail_id);
+                }
+                NetworkEvent::MailItemsRetrieved { mail_id } => {
+                    self.client_state.follow_mut(client_state().mailbox()).items_retrieved(mail_id);
+                }
+                NetworkEvent::MailWriteOpened { receiver_name } => {
+                    *self.client_state.follow_mut(client_state().write_mail_window()) = WriteMailWindowState::new(receiver_name);
+                    self.client_state.follow_mut(client_state().mailbox()).clear_written_mail();
+
+                    if !self.interface.is_window_with_class_open(WindowClass::WriteMail) {
+                        self.interface.open_window(WriteMailWindow::new(
+                            client_state().write_mail_window(),
+                            client_state().mailbox(),
+                        ));
+                    }
+                }
+                NetworkEvent::MailItemAccepted { index } => {
+                    // The server only sends back the index, so we take the item from the
+                    // inventory.
+                    let item = self.client_state.follow(client_state().inventory()).get_item(index).cloned();
+
+                    if let Some(item) = item {
+                        self.client_state.follow_mut(client_state().mailbox()).accept_item(item);
+                    }
+                }
+                NetworkEvent::MailItemRejected { index } => {
+                    self.client_state.follow_mut(client_state().mailbox()).reject_item(index);
+                }
+                NetworkEvent::MailItemRemoved { index, amount } => {
+                    self.client_state
+                        .follow_mut(client_state().mailbox())
+                        .remove_attached_item(index, amount);
+                }
+                NetworkEvent::MailReceiverFound {
+                    character_id,
+                    receiver_name,
+                } => {
+                    if !self.client_state.follow(client_state().mailbox()).is_sending() {
+                        continue;
+                    }
+
+                    let write_mail_window = self.client_state.follow(client_state().write_mail_window());
+                    let sender_name = self.client_state.follow(client_state().player_name()).clone();
+
+                    let result = self.networking_system.send_mail(
+                        receiver_name,
+                        character_id,
+                        sender_name,
+                        write_mail_window.get_title().to_owned(),
+                        write_mail_window.get_text().to_owned(),
+                        write_mail_window.get_zeny(),
+                    );
+
+                    if result.is_err() {
+                        self.client_state.follow_mut(client_state().mailbox()).set_sending(false);
+                    }
+                }
+                NetworkEvent::MailSent => {
+                    *self.client_state.follow_mut(client_state().write_mail_window()) = WriteMailWindowState::default();
+                    self.client_state.follow_mut(client_state().mailbox()).clear_written_mail();
+                    self.interface.close_window_with_class(WindowClass::WriteMail);
+                }
+                NetworkEvent::MailSendFailed => {
+                    self.client_state.follow_mut(client_state().mailbox()).set_sending(false);
+                }
+                NetworkEvent::SetHotkeyData { tab, hotkeys } => {
+                    // FIX: Since we only have one hotbar at the moment, we ignore
+                    // everything but 0.
+                    if tab.0 != 0 {
+                        continue;
+                    }
+
+                    for (index, hotkey) in hotkeys.into_iter().take(10).enumerate() {
+                        match hotkey {
+                            HotkeyState::Bound(hotkey) => {
+                                let Some(mut skill) = self
+                                    .client_state
+                                    .follow(client_state().skill_tree())
+                                    .find_skill(SkillId(hotkey.skill_id as u16))
+                                else {
+                                    self.client_state
+                                        .follow_mut(client_state().hotbar())
+                                        .clear_slot(&mut self.networking_system, HotbarSlot(index as u16));
+                                    continue;
+                                };
+
+                                skill.skill_level = hotkey.quantity_or_skill_level;
+                                self.client_state
+                                    .follow_mut(client_state().hotbar())
+                                    .set_slot(HotbarSlot(index as u16), skill);
+                            }
+                            HotkeyState::Unbound => self
+                                .client_state
+                                .follow_mut(client_state().hotbar())
+                                .unset_slot(HotbarSlot(index as u16)),
+                        }
+                    }
+                }
+                NetworkEvent::OpenShop { items } => {
+                    // Close the dialog. Some NPCs don't use the `BuyOrSellPacket` and instead use
+                    // the regular `DialogMenuPacket`. When opening the shop that dialog should be
+                    // closed.
+                    self.client_state.follow_mut(client_state().dialog_window()).end();
+                    self.interface.close_window_with_class(WindowClass::Dialog);
+
+                    *self.client_state.follow_mut(client_state().shop_items()) = items
+                        .into_iter()
+                        .map(|item| self.library.load_shop_item_metadata(&self.async_loader, item))
+                        .collect();
+
+                    self.interface
+                        .open_window(BuyWindow::new(client_state().shop_items(), client_state().buy_cart()));
+                    self.interface.open_window(BuyCartWindow::new(client_state().buy_cart()));
+                }
+                NetworkEvent::AskBuyOrSell { shop_id } => {
+                    self.interface.open_window(BuyOrSellWindow::new(shop_id));
+                }
+                NetworkEvent::BuyingCompleted { result } => match result {
+                    BuyShopItemsResult::Success => {
+                        let _ = self.networking_system.close_shop();
+
+                        // Clear the cart.
+                        self.client_state.follow_mut(client_state().buy_cart()).clear();
+
+                        self.interface.close_window_with_class(WindowClass::Buy);
+                        self.interface.close_window_with_class(WindowClass::BuyCart);
+                    }
+                    BuyShopItemsResult::Error => {
+                        self.client_state
+                            .follow_mut(client_state().chat_messages())
+                            .push(ChatMessage::new("Failed to buy items".to_owned(), MessageColor::Error));
                     }
                 },
                 NetworkEvent::SellItemList { items } => {
@@ -1799,6 +2525,7 @@ impl Client {
                     {
                         let nearest_tile = path.last().unwrap();
 
+                        self.route_planner.cancel();
                         let _ = self.networking_system.player_move(WorldPosition {
                             x: nearest_tile.x,
                             y: nearest_tile.y,
@@ -1827,8 +2554,12 @@ impl Client {
         }
 
         if !interface_has_focus {
+            let rebinding = self.client_state.follow(client_state().keymap_settings_window()).get_rebinding();
+
             self.input_system.handle_keyboard_input(
                 &mut self.input_event_buffer,
+                self.client_state.follow(client_state().keymap_settings()),
+                rebinding,
                 #[cfg(feature = "debug")]
                 self.interface.get_mouse_mode().is_default(),
                 #[cfg(feature = "debug")]
@@ -1858,6 +2589,7 @@ impl Client {
 
                     let packet_version = match service.packet_version {
                         Some(packet_version) => match packet_version {
+                            PacketVersion::_20200401 => SupportedPacketVersion::_20200401,
                             PacketVersion::_20220406 => SupportedPacketVersion::_20220406,
                             PacketVersion::Unsupported(packet_version) => {
                                 self.interface.open_window(ErrorWindow::new(format!(
@@ -1972,6 +2704,33 @@ impl Client {
                         .interface
                         .open_window(AudioSettingsWindow::new(client_state().audio_settings())),
                 },
+                InputEvent::ToggleKeymapSettingsWindow => match self.interface.is_window_with_class_open(WindowClass::KeymapSettings) {
+                    true => {
+                        self.client_state
+                            .follow_mut(client_state().keymap_settings_window())
+                            .stop_rebinding();
+                        self.interface.close_window_with_class(WindowClass::KeymapSettings);
+                    }
+                    false => self.interface.open_window(KeymapSettingsWindow::new(
+                        client_state().keymap_settings_window(),
+                        client_state().keymap_settings(),
+                    )),
+                },
+                InputEvent::RebindKey { action, chord } => {
+                    let conflict = self
+                        .client_state
+                        .follow(client_state().keymap_settings())
+                        .conflicting_action(action, chord);
+
+                    // Don't bind the chord if it would trigger two actions at once.
+                    if conflict.is_none() {
+                        self.client_state.follow_mut(client_state().keymap_settings()).bind(action, chord);
+                    }
+
+                    let window_state = self.client_state.follow_mut(client_state().keymap_settings_window());
+                    window_state.set_conflict(conflict.map(|conflicting_action| (chord, conflicting_action)));
+                    window_state.stop_rebinding();
+                }
                 InputEvent::ToggleFriendListWindow => {
                     if self.client_state.try_follow(this_entity()).is_some() {
                         match self.interface.is_window_with_class_open(WindowClass::FriendList) {
@@ -1983,8 +2742,97 @@ impl Client {
                         }
                     }
                 }
+                InputEvent::TogglePartyWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Party) {
+                            true => self.interface.close_window_with_class(WindowClass::Party),
+                            false => self.interface.open_window(PartyWindow::new(
+                                client_state().party_window(),
+                                client_state().party_name(),
+                                client_state().party_members(),
+                            )),
+                        }
+                    }
+                }
+                InputEvent::ToggleQuestLogWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::QuestLog) {
+                            true => self.interface.close_window_with_class(WindowClass::QuestLog),
+                            false => self.interface.open_window(QuestLogWindow::new(client_state().quests())),
+                        }
+                    }
+                }
+                InputEvent::ToggleEmotionWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Emotion) {
+                            true => self.interface.close_window_with_class(WindowClass::Emotion),
+                            false => self.interface.open_window(EmotionWindow),
+                        }
+                    }
+                }
+                InputEvent::ToggleMinimapWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Minimap) {
+                            true => self.interface.close_window_with_class(WindowClass::Minimap),
+                            false => self.interface.open_window(MinimapWindow::new(
+                                client_state().minimap(),
+                                client_state().entities(),
+                                client_state().party_members(),
+                            )),
+                        }
+                    }
+                }
+                InputEvent::ToggleAchievementWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Achievement) {
+                            true => self.interface.close_window_with_class(WindowClass::Achievement),
+                            false => self.interface.open_window(AchievementWindow::new(client_state().achievements())),
+                        }
+                    }
+                }
+                InputEvent::ToggleGuildWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Guild) {
+                            true => self.interface.close_window_with_class(WindowClass::Guild),
+                            false => {
+                                // Refresh the guild information every time the window is opened, since
+                                // the server doesn't notify us about all changes.
+                                if self.client_state.follow(client_state().guild_information()).is_some() {
+                                    let _ = self.networking_system.request_guild_information();
+                                    let _ = self.networking_system.request_guild_members();
+                                }
+
+                                self.interface.open_window(GuildWindow::new(
+                                    client_state().guild_window(),
+                                    client_state().guild_information(),
+                                    client_state().guild_notice(),
+                                    client_state().guild_members(),
+                                    client_state().guild_positions(),
+                                ));
+                            }
+                        }
+                    }
+                }
                 InputEvent::CloseTopWindow => self.interface.close_top_window(&self.client_state),
                 InputEvent::ToggleShowInterface => self.show_interface = !self.show_interface,
+                InputEvent::ConfirmPincode => {
+                    let pincode_window = self.client_state.follow_mut(client_state().pincode_window());
+                    let seed = pincode_window.get_seed();
+
+                    if let Some(submission) = pincode_window.confirm() {
+                        let account_id = self.saved_login_data.as_ref().unwrap().account_id;
+
+                        let _ = match submission {
+                            PincodeSubmission::Check { pincode } => self.networking_system.check_pincode(account_id, seed, &pincode),
+                            PincodeSubmission::Set { pincode } => self.networking_system.set_pincode(account_id, seed, &pincode),
+                            PincodeSubmission::Change { old_pincode, new_pincode } => {
+                                self.networking_system.change_pincode(account_id, seed, &old_pincode, &new_pincode)
+                            }
+                        };
+
+                        self.interface.close_window_with_class(WindowClass::Pincode);
+                    }
+                }
                 InputEvent::SelectCharacter { slot } => {
                     let _ = self.networking_system.select_character(slot);
                 }
@@ -2011,7 +2859,15 @@ impl Client {
                     let _ = self.networking_system.switch_character_slot(origin_slot, destination_slot);
                 }
                 InputEvent::PlayerMove { destination } => {
-                    if self.client_state.try_follow(this_entity()).is_some() {
+                    if let Some(player) = self.client_state.try_follow(this_entity()) {
+                        // Routes that are too long for the server are split into multiple
+                        // segments. If there is no route at all, we let the server decide.
+                        let waypoint = self.map.as_ref().and_then(|map| {
+                            self.route_planner
+                                .plan(&**map, &mut self.path_finder, player.get_tile_position(), destination)
+                        });
+                        let destination = waypoint.unwrap_or(destination);
+
                         let _ = self.networking_system.player_move(WorldPosition {
                             x: destination.x,
                             y: destination.y,
@@ -2019,10 +2875,62 @@ impl Client {
                         });
                     }
 
-                    // Unbuffer any buffered attack.
+                    // Unbuffer any buffered attack or pickup.
                     *self.client_state.follow_mut(client_state().buffered_attack_entity()) = None;
+                    *self.client_state.follow_mut(client_state().buffered_pickup_item()) = None;
+                }
+                InputEvent::PickUpItem { ground_item_id } => {
+                    let Some(player) = self.client_state.try_follow(this_entity()) else {
+                        continue;
+                    };
+
+                    let player_position = player.get_tile_position();
+
+                    let Some(ground_item) = self
+                        .client_state
+                        .follow(client_state().ground_items())
+                        .iter()
+                        .find(|item| item.ground_item_id == ground_item_id)
+                    else {
+                        continue;
+                    };
+
+                    let item_position = ground_item.tile_position;
+                    let is_in_pickup_range = ground_item.is_in_pickup_range(player_position);
+
+                    *self.client_state.follow_mut(client_state().buffered_attack_entity()) = None;
+
+                    match is_in_pickup_range {
+                        true => {
+                            self.route_planner.cancel();
+                            *self.client_state.follow_mut(client_state().buffered_pickup_item()) = None;
+
+                            let _ = self.networking_system.pick_up_item(ground_item_id);
+                        }
+                        false => {
+                            // The server doesn't walk to the item for us, so we walk there
+                            // first and pick it up once we arrived.
+                            let waypoint = self.map.as_ref().and_then(|map| {
+                                self.route_planner
+                                    .plan(&**map, &mut self.path_finder, player_position, item_position)
+                            });
+                            let destination = waypoint.unwrap_or(item_position);
+
+                            let _ = self.networking_system.player_move(WorldPosition {
+                                x: destination.x,
+                                y: destination.y,
+                                direction: Direction::North,
+                            });
+
+                            *self.client_state.follow_mut(client_state().buffered_pickup_item()) = Some(ground_item_id);
+                        }
+                    }
                 }
                 InputEvent::PlayerInteract { entity_id } => {
+                    self.route_planner.cancel();
+
+                    let player_entity_id = self.client_state.follow(this_entity().manually_asserted()).get_entity_id();
+
                     let entity = self
                         .client_state
                         .follow_mut(client_state().entities())
@@ -2050,6 +2958,21 @@ impl Client {
                                     direction: Direction::North,
                                 }
                             }),
+                            EntityType::Player => {
+                                // The name of the player is requested when hovering them, so it
+                                // should almost always be known at this point.
+                                if entity_id != player_entity_id
+                                    && let Some(character_name) = entity.get_details()
+                                {
+                                    self.interface.close_window_with_class(WindowClass::PlayerInteraction);
+                                    self.interface.open_window(PlayerInteractionWindow::new(
+                                        AccountId(entity_id.0),
+                                        character_name.split('#').next().unwrap().to_owned(),
+                                    ));
+                                }
+
+                                Ok(())
+                            }
                             _ => Ok(()),
                         };
                     }
@@ -2066,10 +2989,34 @@ impl Client {
                         continue;
                     }
 
+                    if let Some(emotion) = emotion_from_command(&text) {
+                        let _ = self.networking_system.send_emotion(emotion);
+                        continue;
+                    }
+
+                    // Messages starting with `$` are sent to the guild chat.
+                    if let Some(guild_text) = text.strip_prefix('$') {
+                        let _ = self
+                            .networking_system
+                            .send_guild_message(self.client_state.follow(client_state().player_name()), guild_text);
+                        continue;
+                    }
+
+                    // Messages starting with `%` are sent to the party chat.
+                    if let Some(party_text) = text.strip_prefix('%') {
+                        let _ = self
+                            .networking_system
+                            .send_party_message(self.client_state.follow(client_state().player_name()), party_text);
+                        continue;
+                    }
+
                     let _ = self
                         .networking_system
                         .send_chat_message(self.client_state.follow(client_state().player_name()), &text);
                 }
+                InputEvent::SendEmotion { emotion } => {
+                    let _ = self.networking_system.send_emotion(emotion);
+                }
                 InputEvent::NextDialog { npc_id } => {
                     let _ = self.networking_system.next_dialog(npc_id);
                 }
@@ -2092,8 +3039,94 @@ impl Client {
                     (ItemSource::Equipment { .. }, ItemSource::Inventory) => {
                         let _ = self.networking_system.request_item_unequip(item.index);
                     }
+                    (ItemSource::Inventory, ItemSource::Trade) => {
+                        let trade = self.client_state.follow_mut(client_state().trade());
+
+                        // Items can't be added once the trade is locked.
+                        if !trade.is_locked() {
+                            let amount = match &item.details {
+                                korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
+                                korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                            };
+
+                            trade.add_pending_item(item.index, amount);
+                            let _ = self.networking_system.add_trade_item(item.index, amount as u32);
+                        }
+                    }
+                    (ItemSource::Inventory, ItemSource::Storage)
+                    | (ItemSource::Storage, ItemSource::Inventory)
+                    | (ItemSource::Inventory, ItemSource::Ground) => match item.details {
+                        // Ask for the amount if there is more than one item in the stack.
+                        korangar_networking::InventoryItemDetails::Regular { amount, .. } if amount > 1 => {
+                            self.client_state.follow_mut(client_state().item_amount_window()).set_amount(amount);
+
+                            self.interface.close_window_with_class(WindowClass::ItemAmount);
+                            self.interface.open_window(ItemAmountWindow::new(
+                                client_state().item_amount_window(),
+                                item,
+                                amount,
+                                source,
+                                destination,
+                            ));
+                        }
+                        _ => match (source, destination) {
+                            (ItemSource::Inventory, ItemSource::Ground) => {
+                                let _ = self.networking_system.drop_item(item.index, 1);
+                            }
+                            (ItemSource::Inventory, _) => {
+                                let _ = self.networking_system.move_item_to_storage(item.index, 1);
+                            }
+                            _ => {
+                                // Items in the storage use their storage index.
+                                let _ = self.networking_system.move_item_from_storage(StorageIndex(item.index.0), 1);
+                            }
+                        },
+                    },
+                    (ItemSource::Inventory, ItemSource::Mail) => {
+                        let amount = match &item.details {
+                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
+                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                        };
+
+                        self.client_state
+                            .follow_mut(client_state().mailbox())
+                            .add_pending_item(item.index, amount);
+                        let _ = self.networking_system.add_mail_item(item.index, amount);
+                    }
+                    (ItemSource::Mail, ItemSource::Inventory) => {
+                        let amount = match &item.details {
+                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
+                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                        };
+
+                        let _ = self.networking_system.remove_mail_item(item.index, amount);
+                    }
                     _ => {}
                 },
+                InputEvent::MoveItemAmount {
+                    source,
+                    destination,
+                    item,
+                    amount,
+                } => {
+                    match (source, destination) {
+                        (ItemSource::Inventory, ItemSource::Storage) => {
+                            let _ = self.networking_system.move_item_to_storage(item.index, amount as u32);
+                        }
+                        (ItemSource::Storage, ItemSource::Inventory) => {
+                            // Items in the storage use their storage index.
+                            let _ = self
+                                .networking_system
+                                .move_item_from_storage(StorageIndex(item.index.0), amount as u32);
+                        }
+                        (ItemSource::Inventory, ItemSource::Ground) => {
+                            let _ = self.networking_system.drop_item(item.index, amount);
+                        }
+                        _ => {}
+                    }
+
+                    self.interface.close_window_with_class(WindowClass::ItemAmount);
+                }
                 InputEvent::MoveSkill {
                     source,
                     destination,
@@ -2114,7 +3147,14 @@ impl Client {
                     _ => {}
                 },
                 InputEvent::CastSkill { slot } => {
-                    if let Some(skill) = self.client_state.follow(client_state().hotbar()).get_skill_in_slot(slot).as_ref() {
+                    // Skills that are still cooling down would be rejected by the server
+                    // anyway, so we don't send a request for them.
+                    if let Some(skill) = self.client_state.follow(client_state().hotbar()).get_skill_in_slot(slot).as_ref()
+                        && !self
+                            .client_state
+                            .follow(client_state().skill_tree())
+                            .is_on_cooldown(skill.skill_id, client_tick)
+                    {
                         match skill.skill_type {
                             SkillType::Passive => {}
                             SkillType::Attack => {
@@ -2185,6 +3225,192 @@ impl Client {
                     let _ = self.networking_system.accept_friend_request(account_id, character_id);
                     self.interface.close_window_with_class(WindowClass::FriendRequest);
                 }
+                InputEvent::CreateParty { party_name } => {
+                    if party_name.len() > 24 {
+                        #[cfg(feature = "debug")]
+                        print_debug!("[{}] party name {} is too long", "error".red(), party_name.magenta());
+                    } else {
+                        let _ = self.networking_system.create_party(party_name);
+                    }
+                }
+                InputEvent::InviteToParty { character_name } => {
+                    if character_name.len() > 24 {
+                        #[cfg(feature = "debug")]
+                        print_debug!("[{}] character name {} is too long", "error".red(), character_name.magenta());
+                    } else {
+                        let _ = self.networking_system.invite_to_party(character_name);
+                    }
+                }
+                InputEvent::LeaveParty => {
+                    let _ = self.networking_system.leave_party();
+                }
+                InputEvent::ExpelPartyMember {
+                    account_id,
+                    character_name,
+                } => {
+                    let _ = self.networking_system.expel_party_member(account_id, character_name);
+                }
+                InputEvent::ChangePartyLeader { account_id } => {
+                    let _ = self.networking_system.change_party_leader(account_id);
+                }
+                InputEvent::RejectPartyInvitation { party_id } => {
+                    let _ = self.networking_system.reject_party_invitation(party_id);
+                    self.interface.close_window_with_class(WindowClass::PartyInvitation);
+                }
+                InputEvent::AcceptPartyInvitation { party_id } => {
+                    let _ = self.networking_system.accept_party_invitation(party_id);
+                    self.interface.close_window_with_class(WindowClass::PartyInvitation);
+                }
+                InputEvent::CreateGuild { guild_name } => {
+                    if guild_name.len() > 24 {
+                        #[cfg(feature = "debug")]
+                        print_debug!("[{}] guild name {} is too long", "error".red(), guild_name.magenta());
+                    } else if let Some(character_id) = self.saved_character_id {
+                        let _ = self.networking_system.create_guild(character_id, guild_name);
+                    }
+                }
+                InputEvent::InviteToGuild { character_name } => {
+                    if character_name.len() > 24 {
+                        #[cfg(feature = "debug")]
+                        print_debug!("[{}] character name {} is too long", "error".red(), character_name.magenta());
+                    } else {
+                        let _ = self.networking_system.invite_to_guild(character_name);
+                    }
+                }
+                InputEvent::LeaveGuild => {
+                    let guild_id = self
+                        .client_state
+                        .follow(client_state().guild_information())
+                        .as_ref()
+                        .map(|information| information.guild_id);
+                    let account_id = self.saved_login_data.as_ref().map(|login_data| login_data.account_id);
+
+                    if let (Some(guild_id), Some(account_id), Some(character_id)) = (guild_id, account_id, self.saved_character_id) {
+                        let _ = self
+                            .networking_system
+                            .leave_guild(guild_id, account_id, character_id, String::new());
+                    }
+                }
+                InputEvent::ExpelGuildMember { account_id, character_id } => {
+                    let guild_id = self
+                        .client_state
+                        .follow(client_state().guild_information())
+                        .as_ref()
+                        .map(|information| information.guild_id);
+
+                    if let Some(guild_id) = guild_id {
+                        let _ = self
+                            .networking_system
+                            .expel_guild_member(guild_id, account_id, character_id, String::new());
+                    }
+                }
+                InputEvent::RejectGuildInvitation { guild_id } => {
+                    let _ = self.networking_system.reject_guild_invitation(guild_id);
+                    self.interface.close_window_with_class(WindowClass::GuildInvitation);
+                }
+                InputEvent::AcceptGuildInvitation { guild_id } => {
+                    let _ = self.networking_system.accept_guild_invitation(guild_id);
+                    self.interface.close_window_with_class(WindowClass::GuildInvitation);
+                }
+                InputEvent::CloseStorage => {
+                    let _ = self.networking_system.close_storage();
+                }
+                InputEvent::OpenMailbox => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        let mailbox = self.client_state.follow_mut(client_state().mailbox());
+                        mailbox.clear_mails();
+                        mailbox.set_unread_mail(false);
+
+                        for open_type in [MailOpenType::Character, MailOpenType::Account, MailOpenType::Returned] {
+                            let _ = self.networking_system.open_mailbox(open_type);
+                        }
+
+                        if !self.interface.is_window_with_class_open(WindowClass::Mailbox) {
+                            self.interface.open_window(MailboxWindow::new(client_state().mailbox()));
+                        }
+                    }
+                }
+                InputEvent::RefreshMailbox => {
+                    for open_type in [MailOpenType::Character, MailOpenType::Account, MailOpenType::Returned] {
+                        let newest_mail_id = self.client_state.follow(client_state().mailbox()).newest_mail_id(open_type);
+                        let _ = self.networking_system.refresh_mailbox(open_type, newest_mail_id);
+                    }
+                }
+                InputEvent::CloseMailbox => {
+                    let _ = self.networking_system.close_mailbox();
+                    self.interface.close_window_with_class(WindowClass::Mail);
+                    self.interface.close_window_with_class(WindowClass::Mailbox);
+                }
+                InputEvent::ReadMail { open_type, mail_id } => {
+                    let _ = self.networking_system.read_mail(open_type, mail_id);
+                }
+                InputEvent::DeleteMail => {
+                    let (open_type, mail_id) = self.client_state.follow(client_state().mailbox().opened_mail()).get_id();
+                    let _ = self.networking_system.delete_mail(open_type, mail_id);
+                }
+                InputEvent::RetrieveMailZeny => {
+                    let (open_type, mail_id) = self.client_state.follow(client_state().mailbox().opened_mail()).get_id();
+                    let _ = self.networking_system.retrieve_mail_zeny(open_type, mail_id);
+                }
+                InputEvent::RetrieveMailItems => {
+                    let (open_type, mail_id) = self.client_state.follow(client_state().mailbox().opened_mail()).get_id();
+                    let _ = self.networking_system.retrieve_mail_items(open_type, mail_id);
+                }
+                InputEvent::WriteMail => {
+                    let _ = self.networking_system.open_write_mail(String::new());
+                }
+                InputEvent::SendMail => {
+                    let receiver_name = self
+                        .client_state
+                        .follow(client_state().write_mail_window())
+                        .get_receiver_name()
+                        .to_owned();
+
+                    // The server needs the character id of the receiver, so we look it up first
+                    // and send the mail once we get a response.
+                    let is_sending = self.networking_system.check_mail_receiver(receiver_name).is_ok();
+                    self.client_state.follow_mut(client_state().mailbox()).set_sending(is_sending);
+                }
+                InputEvent::CancelWriteMail => {
+                    let _ = self.networking_system.cancel_write_mail();
+                    self.client_state.follow_mut(client_state().mailbox()).clear_written_mail();
+                    self.interface.close_window_with_class(WindowClass::WriteMail);
+                }
+                InputEvent::RequestTrade {
+                    account_id,
+                    character_name,
+                } => {
+                    self.client_state.follow_mut(client_state().trade()).prepare(character_name);
+                    self.interface.close_window_with_class(WindowClass::PlayerInteraction);
+
+                    let _ = self.networking_system.request_trade(account_id);
+                }
+                InputEvent::RejectTradeRequest => {
+                    let _ = self.networking_system.reject_trade_request();
+                    self.interface.close_window_with_class(WindowClass::TradeRequest);
+                }
+                InputEvent::AcceptTradeRequest => {
+                    let _ = self.networking_system.accept_trade_request();
+                    self.interface.close_window_with_class(WindowClass::TradeRequest);
+                }
+                InputEvent::AddTradeZeny { amount } => {
+                    let trade = self.client_state.follow_mut(client_state().trade());
+
+                    // The server does not confirm the amount of zeny, so we set it right away.
+                    if !trade.is_locked() {
+                        trade.set_own_zeny(amount);
+                        let _ = self.networking_system.add_trade_zeny(amount);
+                    }
+                }
+                InputEvent::LockTrade => {
+                    let _ = self.networking_system.lock_trade();
+                }
+                InputEvent::CommitTrade => {
+                    let _ = self.networking_system.commit_trade();
+                }
+                InputEvent::CancelTrade => {
+                    let _ = self.networking_system.cancel_trade();
+                }
                 InputEvent::BuyItems { items } => {
                     let _ = self.networking_system.purchase_items(items);
                 }
@@ -2383,21 +3609,59 @@ impl Client {
                         entity.set_animation_data(animation_data);
                     }
                 }
-                (LoaderId::ItemSprite(item_id), LoadableResource::ItemSprite { texture, location }) => match location {
-                    ItemLocation::Inventory => {
-                        self.client_state
-                            .follow_mut(client_state().inventory())
-                            .update_item_sprite(item_id, texture);
+                (LoaderId::EntityModel(entity_id), LoadableResource::EntityModel(entity_model)) => {
+                    // The job of the entity might have changed while the model was loading.
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id && entity.get_model_file().is_some())
+                    {
+                        entity.set_model(entity_model);
                     }
-                    ItemLocation::Shop => {
-                        self.client_state
-                            .follow_mut(client_state().shop_items())
-                            .iter_mut()
-                            .filter(|item| item.item_id == item_id)
-                            .for_each(|item| item.metadata.texture = Some(texture.clone()));
+                }
+                (LoaderId::GroundItemAnimationData(item_id), LoadableResource::AnimationData(animation_data)) => {
+                    self.client_state
+                        .follow_mut(client_state().ground_items())
+                        .iter_mut()
+                        .filter(|item| item.item_id == item_id)
+                        .for_each(|item| item.animation_data = Some(animation_data.clone()));
+                }
+                (LoaderId::ItemSprite(item_id), LoadableResource::ItemSprite { texture, location }) => {
+                    // Loads are only requested once per item, so the sprite might have been
+                    // requested for a different location than the ground.
+                    self.client_state
+                        .follow_mut(client_state().ground_items())
+                        .iter_mut()
+                        .filter(|item| item.item_id == item_id)
+                        .for_each(|item| item.texture = Some(texture.clone()));
+
+                    match location {
+                        ItemLocation::Inventory => {
+                            self.client_state
+                                .follow_mut(client_state().inventory())
+                                .update_item_sprite(item_id, texture.clone());
+                            self.client_state
+                                .follow_mut(client_state().storage())
+                                .update_item_sprite(item_id, texture.clone());
+                            self.client_state
+                                .follow_mut(client_state().trade())
+                                .update_item_sprite(item_id, texture.clone());
+                            self.client_state
+                                .follow_mut(client_state().mailbox())
+                                .update_item_sprite(item_id, texture);
+                        }
+                        ItemLocation::Shop => {
+                            self.client_state
+                                .follow_mut(client_state().shop_items())
+                                .iter_mut()
+                                .filter(|item| item.item_id == item_id)
+                                .for_each(|item| item.metadata.texture = Some(texture.clone()));
+                        }
+                        ItemLocation::Ground => {}
                     }
-                },
-                (LoaderId::Map(..), LoadableResource::Map { map, position }) => {
+                }
+                (LoaderId::Map(map_name), LoadableResource::Map { map, position }) => {
                     match self.client_state.try_follow(this_player()).is_none() {
                         true => {
                             // Load of main menu map
@@ -2406,10 +3670,12 @@ impl Client {
                             map.set_ambient_sound_sources(&self.audio_engine);
                             self.audio_engine.play_background_music_track(DEFAULT_BACKGROUND_MUSIC);
 
-                            self.interface.open_window(CharacterSelectionWindow::new(
-                                client_state().character_slots(),
-                                client_state().switch_request(),
-                            ));
+                            if !self.interface.is_window_with_class_open(WindowClass::Pincode) {
+                                self.interface.open_window(CharacterSelectionWindow::new(
+                                    client_state().character_slots(),
+                                    client_state().switch_request(),
+                                ));
+                            }
 
                             self.start_camera.set_focus_point(START_CAMERA_FOCUS_POINT);
                             self.directional_shadow_camera.set_level_bound(map.get_level_bound());
@@ -2432,6 +3698,22 @@ impl Client {
                                 self.player_camera.set_focus_point(player.get_position());
                             }
 
+                            // Not every map has a minimap image, so we fall back to one generated from
+                            // the walkable tiles. The texture loader would replace a missing image with
+                            // the fallback texture, so we need to check if the file exists first.
+                            let minimap_path = format!("유저인터페이스\\map\\{map_name}.bmp");
+                            let minimap_texture = match self.game_file_loader.file_exists(&format!("data\\texture\\{minimap_path}")) {
+                                true => self.texture_loader.get_or_load(&minimap_path, ImageType::Color).ok(),
+                                false => None,
+                            }
+                            .unwrap_or_else(|| {
+                                self.texture_loader
+                                    .create_color(&format!("minimap {map_name}"), Minimap::generate_image(map), false)
+                            });
+                            self.client_state
+                                .follow_mut(client_state().minimap())
+                                .set_map(map_name, map, minimap_texture);
+
                             self.directional_shadow_camera.set_level_bound(map.get_level_bound());
                             let _ = self.networking_system.map_loaded();
                         }
@@ -2524,6 +3806,12 @@ impl Client {
                     .iter_mut()
                     .for_each(|entity| entity.update(&self.audio_engine, self.map.as_ref().unwrap(), current_camera, client_tick));
 
+                self.client_state
+                    .follow_mut(client_state().skill_tree())
+                    .update_cooldowns(client_tick);
+
+                self.client_state.follow_mut(client_state().minimap()).update_markers(client_tick);
+
                 // Buffered attack (the player tried attacking while out of range).
                 let auto_attack = *self.client_state.follow(client_state().game_settings().auto_attack());
                 if self
@@ -2540,6 +3828,41 @@ impl Client {
                         }
                     }
                 }
+
+                // Continue walking a long route once the player arrived at the current
+                // waypoint.
+                if let Some(player) = self.client_state.try_follow(this_entity()) {
+                    if let Some(waypoint) = self.route_planner.update(player.get_tile_position(), player.is_moving()) {
+                        let _ = self.networking_system.player_move(WorldPosition {
+                            x: waypoint.x,
+                            y: waypoint.y,
+                            direction: Direction::North,
+                        });
+                    }
+                }
+
+                // Buffered pickup (the player tried picking up an item while out of range).
+                if let Some(player) = self.client_state.try_follow(this_entity())
+                    && player.stopped_moving()
+                    && let Some(ground_item_id) = *self.client_state.follow(client_state().buffered_pickup_item())
+                {
+                    let is_in_pickup_range = self
+                        .client_state
+                        .follow(client_state().ground_items())
+                        .iter()
+                        .find(|item| item.ground_item_id == ground_item_id)
+                        .map(|item| item.is_in_pickup_range(player.get_tile_position()));
+
+                    match is_in_pickup_range {
+                        Some(true) => {
+                            let _ = self.networking_system.pick_up_item(ground_item_id);
+                            *self.client_state.follow_mut(client_state().buffered_pickup_item()) = None;
+                        }
+                        // Keep the pickup buffered while the player is still following the route.
+                        Some(false) if self.route_planner.is_active() => {}
+                        _ => *self.client_state.follow_mut(client_state().buffered_pickup_item()) = None,
+                    }
+                }
             }
 
             #[cfg(feature = "debug")]
@@ -2750,6 +4073,14 @@ impl Client {
                         &self.pathing_texture_set,
                     );
 
+                    #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_options.show_entities))]
+                    map.render_entity_models(
+                        &mut self.directional_shadow_model_instructions,
+                        model_batches,
+                        self.client_state.follow(client_state().entities()),
+                        &partition_camera,
+                    );
+
                     #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_options.show_entities))]
                     map.render_entities(
                         entity_instructions,
@@ -2830,6 +4161,14 @@ impl Client {
                     _ => current_camera,
                 };
 
+                #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_options.show_entities))]
+                map.render_entity_models(
+                    &mut self.model_instructions,
+                    &mut self.model_batches,
+                    self.client_state.follow(client_state().entities()),
+                    current_camera,
+                );
+
                 #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_options.show_entities))]
                 map.render_entities(
                     &mut self.entity_instructions,
@@ -2844,6 +4183,13 @@ impl Client {
                     entity_camera,
                 );
 
+                #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_options.show_entities))]
+                map.render_ground_items(
+                    &mut self.entity_instructions,
+                    self.client_state.follow(client_state().ground_items()),
+                    entity_camera,
+                );
+
                 #[cfg(feature = "debug")]
                 if render_options.show_entities_debug {
                     map.render_entities_debug(
@@ -2904,6 +4250,29 @@ impl Client {
 
                 self.effect_holder.render(&mut self.effect_renderer, current_camera);
 
+                self.client_state
+                    .follow(client_state().entities())
+                    .iter()
+                    .filter(|entity| entity.is_casting())
+                    .for_each(|entity| {
+                        entity.render_cast_bar(
+                            &self.middle_interface_renderer,
+                            current_camera,
+                            self.client_state.follow(client_state().world_theme()),
+                            client_tick,
+                            screen_size,
+                        )
+                    });
+
+                self.client_state.follow(client_state().entities()).iter().for_each(|entity| {
+                    entity.render_emotion(
+                        &self.bottom_interface_renderer,
+                        current_camera,
+                        screen_size,
+                        scaling.get_factor(),
+                    )
+                });
+
                 if let Some(player) = self.client_state.try_follow(this_entity()) {
                     #[cfg(feature = "debug")]
                     profile_block!("render player status");
@@ -2914,11 +4283,19 @@ impl Client {
                         self.client_state.follow(client_state().world_theme()),
                         screen_size,
                     );
+
+                    player.render_status_effects(
+                        &self.middle_interface_renderer,
+                        self.client_state.follow(client_state().world_theme()),
+                        client_tick,
+                        screen_size,
+                    );
                 }
 
                 let mouse_mode = self.interface.get_mouse_mode();
                 let is_mouse_mode_default = mouse_mode.is_default();
                 let last_walking_destination = mouse_mode.walk_destination();
+                let moved_inventory_item = mouse_mode.moved_inventory_item();
 
                 let mut interface_frame = {
                     #[cfg(feature = "debug")]
@@ -2966,6 +4343,9 @@ impl Client {
                                     PickerTarget::Entity(entity_id) => {
                                         self.input_event_buffer.push(InputEvent::PlayerInteract { entity_id })
                                     }
+                                    PickerTarget::GroundItem(ground_item_id) => {
+                                        self.input_event_buffer.push(InputEvent::PickUpItem { ground_item_id })
+                                    }
                                     PickerTarget::Tile { x, y } => {
                                         let destination = TilePosition { x, y };
 
@@ -2999,6 +4379,15 @@ impl Client {
                     }
 
                     if input_report.mouse_button_released {
+                        // Items that are dragged out of the inventory onto the map are dropped.
+                        if !is_interface_hovered && let Some(item) = moved_inventory_item {
+                            self.input_event_buffer.push(InputEvent::MoveItem {
+                                source: ItemSource::Inventory,
+                                destination: ItemSource::Ground,
+                                item,
+                            });
+                        }
+
                         interface_frame.drop(&self.client_state);
                     }
 
@@ -3091,6 +4480,30 @@ impl Client {
                             }
                         }
                     }
+                    PickerTarget::GroundItem(ground_item_id) => {
+                        if !interface_frame.is_interface_hovered() && is_mouse_mode_default {
+                            let ground_item = self
+                                .client_state
+                                .follow(client_state().ground_items())
+                                .iter()
+                                .find(|item| item.ground_item_id == ground_item_id);
+
+                            if let Some(ground_item) = ground_item {
+                                let offset = ScreenPosition {
+                                    left: 15.0 * scaling.get_factor(),
+                                    top: 15.0 * scaling.get_factor(),
+                                };
+
+                                self.middle_interface_renderer.render_text(
+                                    &ground_item.get_display_name(),
+                                    input_report.mouse_position + offset,
+                                    Color::WHITE,
+                                    FontSize(16.0),
+                                    AlignHorizontal::Mid,
+                                );
+                            }
+                        }
+                    }
                     _ => {}
                 }
 
@@ -0,0 +1,463 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+use korangar_interface::element::StateElement;
+use ragnarok_packets::HotbarSlot;
+use ron::ser::PrettyConfig;
+use rust_state::RustState;
+use serde::{Deserialize, Serialize};
+use winit::keyboard::KeyCode;
+
+use crate::input::InputEvent;
+
+/// Number of hotbar slots that can be bound to a key.
+const HOTBAR_SLOT_COUNT: u16 = 10;
+
+/// Action that can be bound to a key chord in the keymap.
+///
+/// The debug actions exist in every build so that the keymap file can be
+/// shared between builds with and without the `debug` feature. They only
+/// trigger events in debug builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum KeyAction {
+    ToggleMenuWindow,
+    ToggleInventoryWindow,
+    ToggleEquipmentWindow,
+    ToggleSkillTreeWindow,
+    ToggleStatsWindow,
+    ToggleFriendListWindow,
+    TogglePartyWindow,
+    ToggleGuildWindow,
+    ToggleQuestLogWindow,
+    ToggleAchievementWindow,
+    ToggleEmotionWindow,
+    ToggleMinimapWindow,
+    ToggleGameSettingsWindow,
+    ToggleInterfaceSettingsWindow,
+    ToggleGraphicsSettingsWindow,
+    ToggleAudioSettingsWindow,
+    ToggleKeymapSettingsWindow,
+    ToggleShowInterface,
+    CloseTopWindow,
+    UseHotbarSlot(u16),
+    ToggleMapsWindow,
+    ToggleClientStateInspectorWindow,
+    ToggleRenderOptionsWindow,
+    ToggleProfilerWindow,
+    TogglePacketInspectorWindow,
+}
+
+impl KeyAction {
+    /// All actions that can be bound, in the order they are displayed.
+    pub const ALL: &'static [Self] = &[
+        Self::ToggleMenuWindow,
+        Self::ToggleInventoryWindow,
+        Self::ToggleEquipmentWindow,
+        Self::ToggleSkillTreeWindow,
+        Self::ToggleStatsWindow,
+        Self::ToggleFriendListWindow,
+        Self::TogglePartyWindow,
+        Self::ToggleGuildWindow,
+        Self::ToggleQuestLogWindow,
+        Self::ToggleAchievementWindow,
+        Self::ToggleEmotionWindow,
+        Self::ToggleMinimapWindow,
+        Self::ToggleGameSettingsWindow,
+        Self::ToggleInterfaceSettingsWindow,
+        Self::ToggleGraphicsSettingsWindow,
+        Self::ToggleAudioSettingsWindow,
+        Self::ToggleKeymapSettingsWindow,
+        Self::ToggleShowInterface,
+        Self::CloseTopWindow,
+        Self::UseHotbarSlot(0),
+        Self::UseHotbarSlot(1),
+        Self::UseHotbarSlot(2),
+        Self::UseHotbarSlot(3),
+        Self::UseHotbarSlot(4),
+        Self::UseHotbarSlot(5),
+        Self::UseHotbarSlot(6),
+        Self::UseHotbarSlot(7),
+        Self::UseHotbarSlot(8),
+        Self::UseHotbarSlot(9),
+        #[cfg(feature = "debug")]
+        Self::ToggleMapsWindow,
+        #[cfg(feature = "debug")]
+        Self::ToggleClientStateInspectorWindow,
+        #[cfg(feature = "debug")]
+        Self::ToggleRenderOptionsWindow,
+        #[cfg(feature = "debug")]
+        Self::ToggleProfilerWindow,
+        #[cfg(feature = "debug")]
+        Self::TogglePacketInspectorWindow,
+    ];
+
+    /// Event that is triggered when the key chord is pressed.
+    pub fn pressed_event(self) -> Option<InputEvent> {
+        let event = match self {
+            Self::ToggleMenuWindow => InputEvent::ToggleMenuWindow,
+            Self::ToggleInventoryWindow => InputEvent::ToggleInventoryWindow,
+            Self::ToggleEquipmentWindow => InputEvent::ToggleEquipmentWindow,
+            Self::ToggleSkillTreeWindow => InputEvent::ToggleSkillTreeWindow,
+            Self::ToggleStatsWindow => InputEvent::ToggleStatsWindow,
+            Self::ToggleFriendListWindow => InputEvent::ToggleFriendListWindow,
+            Self::TogglePartyWindow => InputEvent::TogglePartyWindow,
+            Self::ToggleGuildWindow => InputEvent::ToggleGuildWindow,
+            Self::ToggleQuestLogWindow => InputEvent::ToggleQuestLogWindow,
+            Self::ToggleAchievementWindow => InputEvent::ToggleAchievementWindow,
+            Self::ToggleEmotionWindow => InputEvent::ToggleEmotionWindow,
+            Self::ToggleMinimapWindow => InputEvent::ToggleMinimapWindow,
+            Self::ToggleGameSettingsWindow => InputEvent::ToggleGameSettingsWindow,
+            Self::ToggleInterfaceSettingsWindow => InputEvent::ToggleInterfaceSettingsWindow,
+            Self::ToggleGraphicsSettingsWindow => InputEvent::ToggleGraphicsSettingsWindow,
+            Self::ToggleAudioSettingsWindow => InputEvent::ToggleAudioSettingsWindow,
+            Self::ToggleKeymapSettingsWindow => InputEvent::ToggleKeymapSettingsWindow,
+            Self::ToggleShowInterface => InputEvent::ToggleShowInterface,
+            Self::CloseTopWindow => InputEvent::CloseTopWindow,
+            Self::UseHotbarSlot(slot) if slot < HOTBAR_SLOT_COUNT => InputEvent::CastSkill { slot: HotbarSlot(slot) },
+            Self::UseHotbarSlot(_) => return None,
+            #[cfg(feature = "debug")]
+            Self::ToggleMapsWindow => InputEvent::ToggleMapsWindow,
+            #[cfg(feature = "debug")]
+            Self::ToggleClientStateInspectorWindow => InputEvent::ToggleClientStateInspectorWindow,
+            #[cfg(feature = "debug")]
+            Self::ToggleRenderOptionsWindow => InputEvent::ToggleRenderOptionsWindow,
+            #[cfg(feature = "debug")]
+            Self::ToggleProfilerWindow => InputEvent::ToggleProfilerWindow,
+            #[cfg(feature = "debug")]
+            Self::TogglePacketInspectorWindow => InputEvent::TogglePacketInspectorWindow,
+            #[cfg(not(feature = "debug"))]
+            Self::ToggleMapsWindow
+            | Self::ToggleClientStateInspectorWindow
+            | Self::ToggleRenderOptionsWindow
+            | Self::ToggleProfilerWindow
+            | Self::TogglePacketInspectorWindow => return None,
+        };
+
+        Some(event)
+    }
+
+    /// Event that is triggered when the key of the chord is released.
+    pub fn released_event(self) -> Option<InputEvent> {
+        match self {
+            Self::UseHotbarSlot(slot) if slot < HOTBAR_SLOT_COUNT => Some(InputEvent::StopSkill { slot: HotbarSlot(slot) }),
+            _ => None,
+        }
+    }
+}
+
+/// A key combined with a set of modifiers. The modifiers have to match
+/// exactly, so `Ctrl+S` and `S` are different chords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyChord {
+    pub key: KeyCode,
+    #[serde(default)]
+    pub control: bool,
+    #[serde(default)]
+    pub alt: bool,
+    #[serde(default)]
+    pub shift: bool,
+}
+
+impl KeyChord {
+    pub const fn key(key: KeyCode) -> Self {
+        Self {
+            key,
+            control: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn control(key: KeyCode) -> Self {
+        Self {
+            key,
+            control: true,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn alt(key: KeyCode) -> Self {
+        Self {
+            key,
+            control: false,
+            alt: true,
+            shift: false,
+        }
+    }
+
+    /// Modifier keys can't be bound on their own since they are part of every
+    /// chord.
+    pub fn is_modifier(key: KeyCode) -> bool {
+        matches!(
+            key,
+            KeyCode::ControlLeft
+                | KeyCode::ControlRight
+                | KeyCode::AltLeft
+                | KeyCode::AltRight
+                | KeyCode::ShiftLeft
+                | KeyCode::ShiftRight
+                | KeyCode::SuperLeft
+                | KeyCode::SuperRight
+        )
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.control {
+            write!(formatter, "Ctrl+")?;
+        }
+
+        if self.alt {
+            write!(formatter, "Alt+")?;
+        }
+
+        if self.shift {
+            write!(formatter, "Shift+")?;
+        }
+
+        // Turn names like `KeyE` and `Digit1` into `E` and `1`.
+        let key_name = format!("{:?}", self.key);
+        let key_name = key_name
+            .strip_prefix("Key")
+            .or_else(|| key_name.strip_prefix("Digit"))
+            .unwrap_or(&key_name);
+
+        write!(formatter, "{key_name}")
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
+pub struct KeymapSettings {
+    #[hidden_element]
+    pub bindings: BTreeMap<KeyAction, KeyChord>,
+    /// Actions that were unbound by the player. They are not bound to their
+    /// default chord when loading the keymap.
+    #[hidden_element]
+    #[serde(default)]
+    pub unbound: BTreeSet<KeyAction>,
+    /// Set if the keymap file couldn't be read, so that it isn't overwritten
+    /// with the default keymap.
+    #[hidden_element]
+    #[serde(skip)]
+    read_only: bool,
+}
+
+impl Default for KeymapSettings {
+    fn default() -> Self {
+        Self {
+            bindings: Self::default_bindings(),
+            unbound: BTreeSet::new(),
+            read_only: false,
+        }
+    }
+}
+
+impl KeymapSettings {
+    const FILE_NAME: &'static str = "client/keymap_settings.ron";
+
+    fn default_bindings() -> BTreeMap<KeyAction, KeyChord> {
+        let bindings = [
+            (KeyAction::ToggleMenuWindow, KeyChord::key(KeyCode::Escape)),
+            (KeyAction::ToggleInventoryWindow, KeyChord::alt(KeyCode::KeyE)),
+            (KeyAction::ToggleEquipmentWindow, KeyChord::alt(KeyCode::KeyQ)),
+            (KeyAction::ToggleSkillTreeWindow, KeyChord::alt(KeyCode::KeyS)),
+            (KeyAction::ToggleStatsWindow, KeyChord::alt(KeyCode::KeyA)),
+            (KeyAction::ToggleFriendListWindow, KeyChord::alt(KeyCode::KeyZ)),
+            (KeyAction::TogglePartyWindow, KeyChord::alt(KeyCode::KeyP)),
+            (KeyAction::ToggleGuildWindow, KeyChord::alt(KeyCode::KeyG)),
+            (KeyAction::ToggleQuestLogWindow, KeyChord::alt(KeyCode::KeyU)),
+            (KeyAction::ToggleAchievementWindow, KeyChord::alt(KeyCode::KeyY)),
+            (KeyAction::ToggleEmotionWindow, KeyChord::alt(KeyCode::KeyL)),
+            (KeyAction::ToggleMinimapWindow, KeyChord::alt(KeyCode::KeyM)),
+            (KeyAction::ToggleGameSettingsWindow, KeyChord::control(KeyCode::KeyS)),
+            (KeyAction::ToggleInterfaceSettingsWindow, KeyChord::control(KeyCode::KeyI)),
+            (KeyAction::ToggleGraphicsSettingsWindow, KeyChord::control(KeyCode::KeyG)),
+            (KeyAction::ToggleAudioSettingsWindow, KeyChord::control(KeyCode::KeyA)),
+            (KeyAction::ToggleKeymapSettingsWindow, KeyChord::control(KeyCode::KeyK)),
+            (KeyAction::ToggleShowInterface, KeyChord::control(KeyCode::KeyH)),
+            (KeyAction::CloseTopWindow, KeyChord::control(KeyCode::KeyQ)),
+            (KeyAction::UseHotbarSlot(0), KeyChord::key(KeyCode::KeyJ)),
+            (KeyAction::UseHotbarSlot(1), KeyChord::key(KeyCode::KeyL)),
+            (KeyAction::UseHotbarSlot(2), KeyChord::key(KeyCode::KeyU)),
+            #[cfg(feature = "debug")]
+            (KeyAction::ToggleMapsWindow, KeyChord::control(KeyCode::KeyM)),
+            #[cfg(feature = "debug")]
+            (KeyAction::ToggleClientStateInspectorWindow, KeyChord::control(KeyCode::KeyC)),
+            #[cfg(feature = "debug")]
+            (KeyAction::ToggleRenderOptionsWindow, KeyChord::control(KeyCode::KeyR)),
+            #[cfg(feature = "debug")]
+            (KeyAction::ToggleProfilerWindow, KeyChord::control(KeyCode::KeyP)),
+            #[cfg(feature = "debug")]
+            (KeyAction::TogglePacketInspectorWindow, KeyChord::control(KeyCode::KeyN)),
+        ];
+
+        bindings.into_iter().collect()
+    }
+
+    pub fn new() -> Self {
+        match Self::load() {
+            Ok(keymap_settings) => keymap_settings,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "failed to load keymap settings from {}: {}",
+                    Self::FILE_NAME.magenta(),
+                    _error.red()
+                );
+
+                Self {
+                    read_only: true,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+
+    /// Load the keymap from disk. Actions that were added after the keymap
+    /// was saved are bound to their default chord. If there is no keymap file
+    /// yet, the default keymap is returned.
+    pub fn load() -> Result<Self, String> {
+        #[cfg(feature = "debug")]
+        print_debug!("loading keymap settings from {}", Self::FILE_NAME.magenta());
+
+        let data = match std::fs::read_to_string(Self::FILE_NAME) {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let mut keymap_settings: Self = ron::from_str(&data).map_err(|error| error.to_string())?;
+        keymap_settings.bind_new_actions();
+
+        Ok(keymap_settings)
+    }
+
+    /// Bind all actions that are neither bound nor unbound to their default
+    /// chord, unless the chord is already used by another action.
+    fn bind_new_actions(&mut self) {
+        for (action, chord) in Self::default_bindings() {
+            let is_configured = self.bindings.contains_key(&action) || self.unbound.contains(&action);
+            let is_chord_used = self.bindings.values().any(|bound_chord| *bound_chord == chord);
+
+            if !is_configured && !is_chord_used {
+                self.bindings.insert(action, chord);
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(feature = "debug")]
+        print_debug!("saving keymap settings to {}", Self::FILE_NAME.magenta());
+
+        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+
+        if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "failed to save keymap settings to {}: {:?}",
+                Self::FILE_NAME.magenta(),
+                _error.red()
+            );
+        }
+    }
+
+    pub fn get_chord(&self, action: KeyAction) -> Option<KeyChord> {
+        self.bindings.get(&action).copied()
+    }
+
+    /// Get the action that is already bound to the chord, ignoring the action
+    /// that is being rebound. Actions that can't be bound in this build are
+    /// ignored as well.
+    pub fn conflicting_action(&self, action: KeyAction, chord: KeyChord) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|(bound_action, bound_chord)| **bound_action != action && **bound_chord == chord && KeyAction::ALL.contains(bound_action))
+            .map(|(bound_action, _)| *bound_action)
+    }
+
+    pub fn bind(&mut self, action: KeyAction, chord: KeyChord) {
+        self.bindings.insert(action, chord);
+        self.unbound.remove(&action);
+    }
+
+    pub fn unbind(&mut self, action: KeyAction) {
+        self.bindings.remove(&action);
+        self.unbound.insert(action);
+    }
+
+    /// Restore the default keymap.
+    pub fn reset(&mut self) {
+        self.bindings = Self::default_bindings();
+        self.unbound.clear();
+    }
+}
+
+impl Drop for KeymapSettings {
+    fn drop(&mut self) {
+        if !self.read_only {
+            self.save();
+        }
+    }
+}
+
+#[cfg(test)]
+mod bindings {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use winit::keyboard::KeyCode;
+
+    use super::{KeyAction, KeyChord, KeymapSettings};
+
+    fn keymap(bindings: BTreeMap<KeyAction, KeyChord>, unbound: BTreeSet<KeyAction>) -> KeymapSettings {
+        // Read only so that dropping the keymap doesn't write to disk.
+        KeymapSettings {
+            bindings,
+            unbound,
+            read_only: true,
+        }
+    }
+
+    #[test]
+    fn new_actions_are_bound() {
+        let mut keymap_settings = keymap(
+            BTreeMap::from([(KeyAction::ToggleMenuWindow, KeyChord::key(KeyCode::F1))]),
+            BTreeSet::from([KeyAction::ToggleQuestLogWindow]),
+        );
+        keymap_settings.bind_new_actions();
+
+        assert_eq!(
+            keymap_settings.get_chord(KeyAction::ToggleMenuWindow),
+            Some(KeyChord::key(KeyCode::F1))
+        );
+        assert_eq!(
+            keymap_settings.get_chord(KeyAction::ToggleAchievementWindow),
+            Some(KeyChord::alt(KeyCode::KeyY))
+        );
+        assert_eq!(keymap_settings.get_chord(KeyAction::ToggleQuestLogWindow), None);
+    }
+
+    #[test]
+    fn used_chords_are_not_bound() {
+        let mut keymap_settings = keymap(
+            BTreeMap::from([(KeyAction::ToggleMenuWindow, KeyChord::alt(KeyCode::KeyY))]),
+            BTreeSet::new(),
+        );
+        keymap_settings.bind_new_actions();
+
+        assert_eq!(keymap_settings.get_chord(KeyAction::ToggleAchievementWindow), None);
+    }
+
+    #[test]
+    fn keymap_without_unbound_actions_is_valid() {
+        let data = "(bindings: {ToggleMenuWindow: (key: Escape), ToggleMapsWindow: (key: KeyM, control: true)})";
+        let mut keymap_settings: KeymapSettings = ron::from_str(data).unwrap();
+        keymap_settings.read_only = true;
+
+        assert!(keymap_settings.unbound.is_empty());
+        assert_eq!(keymap_settings.bindings.len(), 2);
+    }
+}
@@ -4,10 +4,12 @@ mod audio;
 mod game;
 mod graphic;
 mod interface;
+mod keymap;
 mod login;
 
 pub use audio::*;
 pub use game::*;
 pub use graphic::*;
 pub use interface::*;
+pub use keymap::*;
 pub use login::*;
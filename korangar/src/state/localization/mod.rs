@@ -15,6 +15,7 @@ use super::ClientState;
 #[cfg(feature = "debug")]
 use crate::input::InputEvent;
 use crate::loaders::GameFileLoader;
+use crate::settings::KeyAction;
 
 /// Supported languages.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, RustState, StateElement)]
@@ -147,26 +148,54 @@ pub struct Localization {
     game_settings_button_text: String,
     interface_settings_button_text: String,
     audio_settings_button_text: String,
+    keymap_settings_button_text: String,
     log_out_button_text: String,
     exit_button_text: String,
     character_overview_window_title: String,
     name_text: String,
     base_level_text: String,
     job_level_text: String,
+    base_experience_text: String,
+    job_experience_text: String,
     inventory_button_text: String,
     equipment_button_text: String,
     stats_button_text: String,
     skill_tree_button_text: String,
     friend_list_button_text: String,
+    party_button_text: String,
+    guild_button_text: String,
+    quest_log_button_text: String,
+    achievement_button_text: String,
+    emotion_button_text: String,
+    minimap_button_text: String,
+    mail_button_text: String,
     menu_button_text: String,
     chat_window_title: String,
     chat_text_box_message: String,
     audio_settings_window_title: String,
     mute_audio_on_focus_loss_button_text: String,
+    keymap_settings_window_title: String,
+    press_key_text: String,
+    unbind_key_button_text: String,
+    reset_keymap_button_text: String,
+    key_already_bound_text: String,
+    show_interface_action_text: String,
+    close_window_action_text: String,
+    hotbar_slot_action_text: String,
     create_character_window_title: String,
     character_name_text: String,
     create_character_button_text: String,
     create_character_button_tooltip: String,
+    pincode_window_title: String,
+    enter_pincode_text: String,
+    set_pincode_text: String,
+    enter_current_pincode_text: String,
+    enter_new_pincode_text: String,
+    clear_pincode_button_text: String,
+    confirm_pincode_button_text: String,
+    cancel_pincode_button_text: String,
+    wrong_pincode_text: String,
+    illegal_pincode_text: String,
     dialog_window_title: String,
     next_button_text: String,
     close_button_text: String,
@@ -174,8 +203,67 @@ pub struct Localization {
     friend_list_window_title: String,
     friend_list_text_box_message: String,
     remove_button_text: String,
+    party_window_title: String,
+    party_text_box_message: String,
+    not_in_party_text: String,
+    party_leader_text: String,
+    offline_text: String,
+    create_party_button_text: String,
+    invite_button_text: String,
+    leave_party_button_text: String,
+    make_leader_button_text: String,
+    expel_button_text: String,
+    guild_window_title: String,
+    guild_text_box_message: String,
+    not_in_guild_text: String,
+    guild_notice_text: String,
+    create_guild_button_text: String,
+    leave_guild_button_text: String,
+    quest_log_window_title: String,
+    no_quests_text: String,
+    quest_text: String,
+    inactive_quest_text: String,
+    achievement_window_title: String,
+    achievement_level_text: String,
+    achievement_score_text: String,
+    achievement_completed_text: String,
+    achievement_not_completed_text: String,
+    no_achievements_text: String,
+    emotion_window_title: String,
+    minimap_window_title: String,
+    mailbox_window_title: String,
+    no_mail_text: String,
+    new_mail_text: String,
+    write_mail_button_text: String,
+    refresh_mailbox_button_text: String,
+    mail_window_title: String,
+    retrieve_zeny_button_text: String,
+    retrieve_items_button_text: String,
+    delete_mail_button_text: String,
+    write_mail_window_title: String,
+    mail_receiver_text: String,
+    mail_title_text: String,
+    mail_text_text: String,
+    send_mail_button_text: String,
+    cancel_mail_button_text: String,
+    trade_window_title: String,
+    request_trade_button_text: String,
+    zeny_text_box_message: String,
+    add_zeny_button_text: String,
+    lock_trade_button_text: String,
+    confirm_trade_button_text: String,
+    cancel_trade_button_text: String,
+    trade_locked_text: String,
+    trade_not_locked_text: String,
     hotbar_window_title: String,
     inventory_window_title: String,
+    storage_window_title: String,
+    amount_text_box_message: String,
+    move_button_text: String,
+    item_pickup_failed_text: String,
+    item_pickup_overweight_text: String,
+    item_pickup_no_space_text: String,
+    item_pickup_limit_text: String,
     respawn_window_title: String,
     respawn_button_text: String,
     disconnect_button_text: String,
@@ -200,6 +288,38 @@ pub struct Localization {
 }
 
 impl Localization {
+    /// Name of the action in the keymap settings. Actions for debug windows
+    /// are not localized, since the debug windows aren't either.
+    pub fn key_action_text(&self, action: KeyAction) -> String {
+        match action {
+            KeyAction::ToggleMenuWindow => self.menu_button_text.clone(),
+            KeyAction::ToggleInventoryWindow => self.inventory_button_text.clone(),
+            KeyAction::ToggleEquipmentWindow => self.equipment_button_text.clone(),
+            KeyAction::ToggleSkillTreeWindow => self.skill_tree_button_text.clone(),
+            KeyAction::ToggleStatsWindow => self.stats_button_text.clone(),
+            KeyAction::ToggleFriendListWindow => self.friend_list_button_text.clone(),
+            KeyAction::TogglePartyWindow => self.party_button_text.clone(),
+            KeyAction::ToggleGuildWindow => self.guild_button_text.clone(),
+            KeyAction::ToggleQuestLogWindow => self.quest_log_button_text.clone(),
+            KeyAction::ToggleAchievementWindow => self.achievement_button_text.clone(),
+            KeyAction::ToggleEmotionWindow => self.emotion_button_text.clone(),
+            KeyAction::ToggleMinimapWindow => self.minimap_button_text.clone(),
+            KeyAction::ToggleGameSettingsWindow => self.game_settings_button_text.clone(),
+            KeyAction::ToggleInterfaceSettingsWindow => self.interface_settings_button_text.clone(),
+            KeyAction::ToggleGraphicsSettingsWindow => self.graphics_settings_button_text.clone(),
+            KeyAction::ToggleAudioSettingsWindow => self.audio_settings_button_text.clone(),
+            KeyAction::ToggleKeymapSettingsWindow => self.keymap_settings_button_text.clone(),
+            KeyAction::ToggleShowInterface => self.show_interface_action_text.clone(),
+            KeyAction::CloseTopWindow => self.close_window_action_text.clone(),
+            KeyAction::UseHotbarSlot(slot) => format!("{} {}", self.hotbar_slot_action_text, slot + 1),
+            KeyAction::ToggleMapsWindow => "Maps".to_owned(),
+            KeyAction::ToggleClientStateInspectorWindow => "Client state inspector".to_owned(),
+            KeyAction::ToggleRenderOptionsWindow => "Render options".to_owned(),
+            KeyAction::ToggleProfilerWindow => "Profiler".to_owned(),
+            KeyAction::TogglePacketInspectorWindow => "Packet inspector".to_owned(),
+        }
+    }
+
     /// Save the localization to a file based on the provided language.
     // TODO: Currently this will just save to the file system but we might want to
     // save using the `GameFileLoader` instead.
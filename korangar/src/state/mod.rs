@@ -18,11 +18,11 @@ use korangar_interface::element::StateElement;
 use korangar_interface::layout::tooltip::TooltipTheme;
 use korangar_interface::theme::ThemePathGetter;
 use korangar_interface::window::{StateWindow, WindowTheme};
-use korangar_networking::{MessageColor, SellItem, ShopItem};
+use korangar_networking::{GuildInformation, GuildMember, GuildNotice, MessageColor, PartyMember, QuestLogEntry, SellItem, ShopItem};
 use localization::Localization;
 #[cfg(feature = "debug")]
 use ragnarok_formats::map::{EffectSource, LightSource, MapData, SoundSource};
-use ragnarok_packets::{CharacterId, CharacterServerInformation, EntityId, Friend};
+use ragnarok_packets::{CharacterId, CharacterServerInformation, EntityId, Friend, GroundItemId, GuildPositionName};
 #[cfg(feature = "debug")]
 use rust_state::{ManuallyAssertExt, VecIndexExt};
 use rust_state::{Path, RustState, Selector};
@@ -37,17 +37,22 @@ use crate::character_slots::CharacterSlots;
 use crate::graphics::RenderOptions;
 use crate::graphics::{Color, CornerDiameter, ScreenClip, ScreenPosition, ScreenSize, ShadowPadding};
 use crate::input::{InputEvent, MouseInputMode};
-use crate::interface::windows::{ChatWindowState, DialogWindowState, FriendListWindowState, LoginWindowState, WindowCache, WindowClass};
+use crate::interface::windows::{
+    ChatWindowState, DialogWindowState, FriendListWindowState, GuildWindowState, ItemAmountWindowState, KeymapSettingsWindowState,
+    LoginWindowState, PartyWindowState, PincodeWindowState, TradeWindowState, WindowCache, WindowClass, WriteMailWindowState,
+};
 #[cfg(feature = "debug")]
 use crate::interface::windows::{ProfilerWindowState, ThemeInspectorWindowState};
-use crate::inventory::{Hotbar, Inventory, SkillTree};
+use crate::inventory::{Hotbar, Inventory, Mailbox, SkillTree, Storage, Trade};
 use crate::loaders::{ClientInfo, FontLoader, FontSize, GameFileLoader, OverflowBehavior, load_client_info};
 use crate::renderer::InterfaceRenderer;
-use crate::settings::{GameSettings, GraphicsSettingsCapabilities, InterfaceSettings, InterfaceSettingsCapabilities, LoginSettings};
+use crate::settings::{
+    GameSettings, GraphicsSettingsCapabilities, InterfaceSettings, InterfaceSettingsCapabilities, KeymapSettings, LoginSettings,
+};
 use crate::state::theme::WorldTheme;
 #[cfg(feature = "debug")]
 use crate::world::Object;
-use crate::world::{Entity, Player, ResourceMetadata};
+use crate::world::{Achievements, Entity, GroundItem, Minimap, Player, ResourceMetadata};
 use crate::{AudioSettings, GraphicsSettings};
 
 /// A message in the in-game chat.
@@ -83,6 +88,8 @@ pub struct ClientState {
     login_settings: LoginSettings,
     /// Saved audio settings.
     audio_settings: AudioSettings,
+    /// Saved key bindings.
+    keymap_settings: KeymapSettings,
     /// Saved game settings.
     game_settings: GameSettings,
     /// Saved interface settings.
@@ -106,10 +113,24 @@ pub struct ClientState {
     client_info: ClientInfo,
     /// Internal state of the login window.
     login_window: LoginWindowState,
+    /// Internal state of the pincode window.
+    pincode_window: PincodeWindowState,
     /// Internal state of the chat window.
     chat_window: ChatWindowState,
     /// Internal state of the friend list window.
     friend_list_window: FriendListWindowState,
+    /// Internal state of the party window.
+    party_window: PartyWindowState,
+    /// Internal state of the guild window.
+    guild_window: GuildWindowState,
+    /// Internal state of the trade window.
+    trade_window: TradeWindowState,
+    /// Internal state of the write mail window.
+    write_mail_window: WriteMailWindowState,
+    /// Internal state of the item amount window.
+    item_amount_window: ItemAmountWindowState,
+    /// Internal state of the keymap settings window.
+    keymap_settings_window: KeymapSettingsWindowState,
     /// Internal state of the dialog window.
     dialog_window: DialogWindowState,
 
@@ -117,11 +138,29 @@ pub struct ClientState {
     entities: Vec<Entity>,
     /// All dead entities on the map.
     dead_entities: Vec<Entity>,
+    /// All items lying on the ground of the map.
+    ground_items: Vec<GroundItem>,
 
     /// List of all received chat messages.
     chat_messages: Vec<ChatMessage>,
     /// List of all friends.
     friend_list: Vec<Friend>,
+    /// Name of the party the player is in.
+    party_name: Option<String>,
+    /// List of all members of the party, including the player.
+    party_members: Vec<PartyMember>,
+    /// All quests in the quest log of the player.
+    quests: Vec<QuestLogEntry>,
+    /// Achievements of the player and their progress.
+    achievements: Achievements,
+    /// General information about the guild the player is in.
+    guild_information: Option<GuildInformation>,
+    /// Notice of the guild the player is in.
+    guild_notice: GuildNotice,
+    /// List of all members of the guild, including the player.
+    guild_members: Vec<GuildMember>,
+    /// Names of all positions in the guild.
+    guild_positions: Vec<GuildPositionName>,
     /// List of items offered in the shop.
     // TODO: Unhide this
     #[hidden_element]
@@ -145,8 +184,16 @@ pub struct ClientState {
     hotbar: Hotbar,
     /// Player inventory.
     inventory: Inventory,
+    /// Kafra storage of the account. Only filled while the storage is open.
+    storage: Storage,
+    /// Current trade with another player.
+    trade: Trade,
+    /// Mailbox of the player.
+    mailbox: Mailbox,
     /// Player skill tree.
     skill_tree: SkillTree,
+    /// Minimap of the current map and the positions marked on it.
+    minimap: Minimap,
 
     /// List of all available character servers.
     character_servers: Vec<CharacterServerInformation>,
@@ -167,6 +214,9 @@ pub struct ClientState {
     /// Buffered attack entity. Like when attacking a target that is out of
     /// range.
     buffered_attack_entity: Option<EntityId>,
+    /// Buffered item pickup. Like when picking up an item that is out of
+    /// range.
+    buffered_pickup_item: Option<GroundItemId>,
 
     /// Map data that is viewed in the inspector. Once added to this vector they
     /// are never removed so we can ensure the user interface remains valid.
@@ -220,6 +270,7 @@ impl ClientState {
         time_phase!("load settings", {
             let mut login_settings = LoginSettings::new();
             let audio_settings = AudioSettings::new();
+            let keymap_settings = KeymapSettings::new();
             let game_settings = GameSettings::new();
             let interface_settings = InterfaceSettings::new();
             let interface_settings_capabilities = InterfaceSettingsCapabilities::default();
@@ -260,6 +311,7 @@ impl ClientState {
             }
 
             let login_window = LoginWindowState::new(selected_service);
+            let pincode_window = PincodeWindowState::default();
         });
 
         time_phase!("create window state", {
@@ -289,6 +341,35 @@ impl ClientState {
             let friend_list_window = FriendListWindowState::default();
         });
 
+        time_phase!("create party state", {
+            let party_name = None;
+            let party_members = Vec::default();
+            let party_window = PartyWindowState::default();
+        });
+
+        time_phase!("create quest state", {
+            let quests = Vec::default();
+            let achievements = Achievements::default();
+        });
+
+        time_phase!("create guild state", {
+            let guild_information = None;
+            let guild_notice = GuildNotice::default();
+            let guild_members = Vec::default();
+            let guild_positions = Vec::default();
+            let guild_window = GuildWindowState::default();
+        });
+
+        time_phase!("create trade state", {
+            let trade = Trade::default();
+            let trade_window = TradeWindowState::default();
+        });
+
+        time_phase!("create mail state", {
+            let mailbox = Mailbox::default();
+            let write_mail_window = WriteMailWindowState::default();
+        });
+
         time_phase!("create player resources", {
             let dialog_window = DialogWindowState::default();
 
@@ -299,7 +380,11 @@ impl ClientState {
             let player_name = String::new();
             let hotbar = Hotbar::default();
             let inventory = Inventory::default();
+            let storage = Storage::default();
+            let item_amount_window = ItemAmountWindowState::default();
+            let keymap_settings_window = KeymapSettingsWindowState::default();
             let skill_tree = SkillTree::default();
+            let minimap = Minimap::default();
         });
 
         time_phase!("create window resources", {
@@ -308,6 +393,7 @@ impl ClientState {
         });
 
         let buffered_attack_entity = None;
+        let buffered_pickup_item = None;
 
         #[cfg(feature = "debug")]
         let debug_timer = korangar_debug::logging::Timer::new("creating debug resources");
@@ -341,6 +427,7 @@ impl ClientState {
             localization,
             login_settings,
             audio_settings,
+            keymap_settings,
             game_settings,
             interface_settings,
             interface_settings_capabilities,
@@ -351,13 +438,29 @@ impl ClientState {
             world_theme,
             client_info,
             login_window,
+            pincode_window,
             chat_window,
             friend_list_window,
+            party_window,
+            guild_window,
+            trade_window,
+            write_mail_window,
+            item_amount_window,
+            keymap_settings_window,
             dialog_window,
             entities: Vec::new(),
             dead_entities: Vec::new(),
+            ground_items: Vec::new(),
             chat_messages,
             friend_list,
+            party_name,
+            party_members,
+            quests,
+            achievements,
+            guild_information,
+            guild_notice,
+            guild_members,
+            guild_positions,
             shop_items,
             buy_cart,
             sell_items,
@@ -365,7 +468,11 @@ impl ClientState {
             player_name,
             hotbar,
             inventory,
+            storage,
+            trade,
+            mailbox,
             skill_tree,
+            minimap,
             character_servers,
             character_slots,
             currently_deleting,
@@ -373,6 +480,7 @@ impl ClientState {
             create_character_name,
             window_size,
             buffered_attack_entity,
+            buffered_pickup_item,
             #[cfg(feature = "debug")]
             inspecting_maps,
             #[cfg(feature = "debug")]
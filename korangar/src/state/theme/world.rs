@@ -31,12 +31,16 @@ pub struct StatusBarTheme {
     pub enemy_health_color: Color,
     pub spell_point_color: Color,
     pub activity_point_color: Color,
+    pub cast_time_color: Color,
     pub player_bar_width: f32,
     pub enemy_bar_width: f32,
     pub health_height: f32,
     pub enemy_health_height: f32,
     pub spell_point_height: f32,
     pub activity_point_height: f32,
+    pub cast_time_height: f32,
+    /// Vertical offset of the cast bar from the position of the entity.
+    pub cast_bar_offset: f32,
     pub border_size: ScreenSize,
     pub gap: f32,
 }
@@ -49,18 +53,54 @@ impl Default for StatusBarTheme {
             enemy_health_color: Color::rgb_u8(206, 49, 116),
             spell_point_color: Color::rgb_u8(0, 129, 163),
             activity_point_color: Color::rgb_u8(218, 145, 81),
+            cast_time_color: Color::rgb_u8(220, 200, 90),
             player_bar_width: 85.0,
             enemy_bar_width: 60.0,
             health_height: 8.0,
             enemy_health_height: 6.0,
             spell_point_height: 4.0,
             activity_point_height: 4.0,
+            cast_time_height: 5.0,
+            cast_bar_offset: 30.0,
             border_size: ScreenSize { width: 2.0, height: 1.0 },
             gap: 1.0,
         }
     }
 }
 
+#[derive(Serialize, Deserialize, RustState, StateElement)]
+pub struct StatusEffectTheme {
+    pub buff_color: Color,
+    pub debuff_color: Color,
+    /// Color that is drawn over the part of the icon that represents the
+    /// elapsed time.
+    pub elapsed_color: Color,
+    pub text_color: Color,
+    pub font_size: FontSize,
+    pub icon_size: f32,
+    pub indicator_size: f32,
+    pub gap: f32,
+    /// Offset of the status effect bar from the top right corner of the
+    /// screen.
+    pub bar_offset: ScreenPosition,
+}
+
+impl Default for StatusEffectTheme {
+    fn default() -> Self {
+        Self {
+            buff_color: Color::rgb_u8(60, 120, 180),
+            debuff_color: Color::rgb_u8(180, 60, 70),
+            elapsed_color: Color::rgba_u8(0, 0, 0, 140),
+            text_color: Color::monochrome_u8(240),
+            font_size: FontSize(11.0),
+            icon_size: 28.0,
+            indicator_size: 5.0,
+            gap: 3.0,
+            bar_offset: ScreenPosition { left: 20.0, top: 60.0 },
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, RustState, StateElement)]
 pub struct IndicatorTheme {
     pub walking: Color,
@@ -91,6 +131,7 @@ impl Default for CursorTheme {
 pub struct WorldTheme {
     pub overlay: OverlayTheme,
     pub status_bar: StatusBarTheme,
+    pub status_effect: StatusEffectTheme,
     pub indicator: IndicatorTheme,
     pub cursor: CursorTheme,
 }
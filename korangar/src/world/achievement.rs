@@ -0,0 +1,186 @@
+use chrono::{DateTime, Local};
+use korangar_interface::element::StateElement;
+use korangar_networking::{AchievementLevel, AchievementProgress};
+use rust_state::RustState;
+
+/// A single objective of an achievement.
+#[derive(Debug, Clone, RustState, StateElement)]
+pub struct AchievementObjective {
+    pub text: String,
+    pub current: u32,
+    /// Value required to complete the objective. Will be 0 if the achievement
+    /// table doesn't specify it.
+    pub required: u32,
+}
+
+/// Achievement of the player, joined with its definition from the
+/// achievement table of the game data.
+#[derive(Debug, Clone, RustState, StateElement)]
+pub struct Achievement {
+    pub achievement_id: u32,
+    pub category: String,
+    pub title: String,
+    pub summary: String,
+    pub score: u32,
+    pub objectives: Vec<AchievementObjective>,
+    pub is_completed: bool,
+    /// Unix timestamp of when the achievement was completed.
+    pub completion_time: u32,
+}
+
+impl Achievement {
+    /// Date the achievement was completed on, in the local time zone.
+    pub fn completion_date(&self) -> Option<String> {
+        if !self.is_completed || self.completion_time == 0 {
+            return None;
+        }
+
+        DateTime::from_timestamp(self.completion_time as i64, 0).map(|date| date.with_timezone(&Local).format("%Y-%m-%d").to_string())
+    }
+}
+
+/// All achievements of the player.
+#[derive(Default, RustState, StateElement)]
+pub struct Achievements {
+    level: AchievementLevel,
+    /// Achievements sorted by category and id.
+    achievements: Vec<Achievement>,
+}
+
+impl Achievements {
+    pub fn set(&mut self, level: AchievementLevel, achievements: Vec<Achievement>) {
+        self.level = level;
+        self.achievements = achievements;
+        self.sort();
+    }
+
+    /// Update the progress of an achievement. Returns `true` if the
+    /// achievement was completed by this update.
+    pub fn update(&mut self, level: AchievementLevel, achievement: Achievement) -> bool {
+        self.level = level;
+
+        match self
+            .achievements
+            .iter_mut()
+            .find(|known| known.achievement_id == achievement.achievement_id)
+        {
+            Some(known) => {
+                let newly_completed = !known.is_completed && achievement.is_completed;
+                *known = achievement;
+                newly_completed
+            }
+            None => {
+                let newly_completed = achievement.is_completed;
+                self.achievements.push(achievement);
+                self.sort();
+                newly_completed
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn sort(&mut self) {
+        self.achievements
+            .sort_by_key(|achievement| (category_order(&achievement.category), achievement.achievement_id));
+    }
+}
+
+/// Definition of an achievement from the achievement table.
+#[derive(Debug, Clone)]
+pub(super) struct AchievementInfo {
+    pub(super) category: usize,
+    pub(super) title: String,
+    pub(super) summary: String,
+    pub(super) score: u32,
+    /// Text and required value of each objective.
+    pub(super) objectives: Vec<(String, u32)>,
+}
+
+/// Names of the major groups of the achievement table, in the order used by
+/// the original client. Achievements with an unknown group are put into the
+/// last category.
+pub const ACHIEVEMENT_CATEGORIES: [&str; 7] = ["Summary", "General", "Adventure", "Battle", "Memorial", "Feat", "Other"];
+
+fn category_order(category: &str) -> usize {
+    ACHIEVEMENT_CATEGORIES
+        .iter()
+        .position(|name| *name == category)
+        .unwrap_or(ACHIEVEMENT_CATEGORIES.len())
+}
+
+impl AchievementInfo {
+    pub(super) fn category_name(&self) -> &'static str {
+        ACHIEVEMENT_CATEGORIES[self.category.min(ACHIEVEMENT_CATEGORIES.len() - 1)]
+    }
+}
+
+/// Join the progress sent by the server with the achievement definition.
+/// Achievements missing from the table are still shown, using their id as the
+/// title.
+pub(super) fn create_achievement(info: Option<&AchievementInfo>, progress: AchievementProgress) -> Achievement {
+    let Some(info) = info else {
+        return Achievement {
+            achievement_id: progress.achievement_id,
+            category: ACHIEVEMENT_CATEGORIES[ACHIEVEMENT_CATEGORIES.len() - 1].to_owned(),
+            title: format!("Achievement {}", progress.achievement_id),
+            summary: String::new(),
+            score: 0,
+            objectives: Vec::new(),
+            is_completed: progress.is_completed,
+            completion_time: progress.completion_time,
+        };
+    };
+
+    let objectives = info
+        .objectives
+        .iter()
+        .zip(progress.objectives)
+        .map(|((text, required), current)| AchievementObjective {
+            text: text.clone(),
+            current,
+            required: *required,
+        })
+        .collect();
+
+    Achievement {
+        achievement_id: progress.achievement_id,
+        category: info.category_name().to_owned(),
+        title: info.title.clone(),
+        summary: info.summary.clone(),
+        score: info.score,
+        objectives,
+        is_completed: progress.is_completed,
+        completion_time: progress.completion_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use korangar_networking::{AchievementLevel, AchievementProgress};
+
+    use super::{Achievements, create_achievement};
+
+    fn progress(achievement_id: u32, is_completed: bool) -> AchievementProgress {
+        AchievementProgress {
+            achievement_id,
+            is_completed,
+            objectives: [0; 10],
+            completion_time: 0,
+            got_rewarded: false,
+        }
+    }
+
+    #[test]
+    fn update_reports_completion_once() {
+        let mut achievements = Achievements::default();
+        let level = AchievementLevel::default();
+
+        achievements.set(level, vec![create_achievement(None, progress(1, false))]);
+
+        assert!(achievements.update(level, create_achievement(None, progress(1, true))));
+        assert!(!achievements.update(level, create_achievement(None, progress(1, true))));
+    }
+}
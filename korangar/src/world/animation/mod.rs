@@ -3,14 +3,14 @@ use std::sync::Arc;
 use cgmath::{Array, Matrix4, Point3, Transform, Vector2, Vector3, Zero};
 use korangar_container::Cacheable;
 use korangar_interface::element::StateElement;
-use ragnarok_packets::{ClientTick, Direction, EntityId};
+use ragnarok_packets::{ClientTick, Direction};
 use rust_state::RustState;
 
 #[cfg(feature = "debug")]
 use crate::graphics::DebugRectangleInstruction;
-use crate::graphics::{Color, EntityInstruction};
+use crate::graphics::{Color, EntityInstruction, PickerTarget};
 use crate::loaders::Sprite;
-use crate::world::{ActionEvent, Actions, Camera, EntityType};
+use crate::world::{ActionEvent, Actions, Camera, EntityLook, EntityType};
 
 const TILE_SIZE: f32 = 10.0;
 const SPRITE_SCALE: f32 = 1.4;
@@ -291,10 +291,11 @@ impl AnimationData {
         instructions: &mut Vec<EntityInstruction>,
         camera: &dyn Camera,
         add_to_picker: bool,
-        entity_id: EntityId,
+        picker_target: PickerTarget,
         entity_position: Point3<f32>,
         animation_state: &AnimationState,
         direction: Direction,
+        look: EntityLook,
     ) {
         let frame = self.get_frame(animation_state, camera, direction);
         let world_matrix = self.calculate_world_matrix(camera, frame, entity_position);
@@ -321,9 +322,9 @@ impl AnimationData {
                 depth_offset,
                 extra_depth_offset: 0.005 * index as f32,
                 curvature,
-                color: frame_part.color,
+                color: look.apply(frame_part.color),
                 mirror: frame_part.mirror,
-                entity_id,
+                picker_target,
                 add_to_picker,
                 texture: texture.clone(),
                 distance,
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use cgmath::{Point3, Vector3};
+use korangar_interface::application::Clip;
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::{Color, ScreenClip, ScreenPosition, ScreenSize};
+use crate::loaders::Sprite;
+use crate::renderer::GameInterfaceRenderer;
+use crate::world::{Actions, Camera, SpriteAnimationState};
+
+/// Path of the sprite and actions containing all emotions, without the file
+/// extension.
+pub const EMOTION_FILE_PATH: &str = "이팩트\\emotion";
+
+/// Chat commands that trigger an emotion and the id of the emotion they
+/// trigger.
+pub const EMOTION_COMMANDS: &[(&str, u8)] = &[
+    ("/!", 0),
+    ("/?", 1),
+    ("/ho", 2),
+    ("/lv", 3),
+    ("/swt", 4),
+    ("/ic", 5),
+    ("/an", 6),
+    ("/ag", 7),
+    ("/$", 8),
+    ("/...", 9),
+    ("/lv2", 14),
+    ("/thx", 15),
+    ("/wah", 16),
+    ("/sry", 17),
+    ("/heh", 18),
+    ("/swt2", 19),
+    ("/hmm", 20),
+    ("/no1", 21),
+    ("/??", 22),
+    ("/omg", 23),
+    ("/oh", 24),
+    ("/x", 25),
+    ("/hlp", 26),
+    ("/go", 27),
+    ("/sob", 28),
+    ("/gg", 29),
+    ("/kis", 30),
+    ("/kis2", 31),
+    ("/pif", 32),
+    ("/ok", 33),
+];
+
+// TODO: Use the height of the entity as offset.
+const EMOTION_OFFSET: Vector3<f32> = Vector3::new(0.0, 25.0, 0.0);
+
+/// Get the id of the emotion that is triggered by a chat command.
+pub fn emotion_from_command(command: &str) -> Option<u8> {
+    EMOTION_COMMANDS
+        .iter()
+        .find(|(emotion_command, _)| emotion_command.eq_ignore_ascii_case(command))
+        .map(|(_, emotion)| *emotion)
+}
+
+/// An emotion that is displayed above an entity until its animation is over.
+#[derive(Clone)]
+pub struct Emotion {
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+    emotion: u8,
+    animation_state: SpriteAnimationState,
+    end_tick: ClientTick,
+}
+
+impl Emotion {
+    pub fn new(sprite: Arc<Sprite>, actions: Arc<Actions>, emotion: u8, client_tick: ClientTick) -> Self {
+        // Every emotion is a single action without any directions, so we select it
+        // through a combination of the base offset and the direction when rendering.
+        let mut animation_state = SpriteAnimationState::new(client_tick);
+        animation_state.action_base_offset = emotion as usize / 8;
+
+        let action_index = emotion as usize % actions.actions.len().max(1);
+        let duration = match (actions.actions.get(action_index), actions.delays.get(action_index)) {
+            (Some(action), Some(delay)) => (action.motions.len() as f32 * delay * 50.0) as u32,
+            _ => 0,
+        };
+
+        Self {
+            sprite,
+            actions,
+            emotion,
+            animation_state,
+            end_tick: ClientTick(client_tick.0.wrapping_add(duration)),
+        }
+    }
+
+    pub fn update(&mut self, client_tick: ClientTick) {
+        self.animation_state.update(client_tick);
+    }
+
+    pub fn is_over(&self, client_tick: ClientTick) -> bool {
+        (self.end_tick.0.wrapping_sub(client_tick.0) as i32) <= 0
+    }
+
+    pub fn render(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        camera: &dyn Camera,
+        entity_position: Point3<f32>,
+        window_size: ScreenSize,
+        scaling: f32,
+    ) {
+        let clip_space_position = camera.view_projection_matrix() * (entity_position + EMOTION_OFFSET).to_homogeneous();
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        self.actions.render_sprite(
+            renderer,
+            &self.sprite,
+            &self.animation_state,
+            final_position,
+            self.emotion as usize % 8,
+            ScreenClip::unbound(),
+            Color::WHITE,
+            scaling,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::emotion_from_command;
+
+    #[test]
+    fn emotion_commands() {
+        assert_eq!(emotion_from_command("/heh"), Some(18));
+        assert_eq!(emotion_from_command("/lv"), Some(3));
+        assert_eq!(emotion_from_command("/X"), Some(25));
+        assert_eq!(emotion_from_command("/nc"), None);
+        assert_eq!(emotion_from_command("heh"), None);
+    }
+}
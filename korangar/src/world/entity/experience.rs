@@ -0,0 +1,50 @@
+use korangar_interface::element::StateElement;
+use rust_state::RustState;
+
+/// Experience of the player towards the next base or job level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, RustState, StateElement)]
+pub struct Experience {
+    pub current: u64,
+    /// Experience required for the next level. Will be 0 until the server sent
+    /// the value or if the maximum level is reached.
+    pub next: u64,
+}
+
+impl Experience {
+    pub fn new(current: u64) -> Self {
+        Self { current, next: 0 }
+    }
+
+    /// Progress towards the next level, in the range 0.0 to 1.0.
+    pub fn fraction(&self) -> f32 {
+        match self.next {
+            0 => 0.0,
+            next => (self.current as f64 / next as f64).clamp(0.0, 1.0) as f32,
+        }
+    }
+
+    /// Progress towards the next level in percent, formatted the same way as
+    /// in the original client.
+    pub fn percentage_text(&self) -> String {
+        format!("{:.1}%", self.fraction() * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Experience;
+
+    #[test]
+    fn experience_fraction() {
+        let experience = Experience { current: 250, next: 1000 };
+
+        assert_eq!(experience.fraction(), 0.25);
+        assert_eq!(experience.percentage_text(), "25.0%");
+    }
+
+    #[test]
+    fn experience_fraction_without_next_level() {
+        assert_eq!(Experience::new(500).fraction(), 0.0);
+        assert_eq!(Experience { current: 2000, next: 1000 }.fraction(), 1.0);
+    }
+}
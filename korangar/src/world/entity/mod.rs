@@ -1,3 +1,7 @@
+mod emotion;
+mod experience;
+mod status;
+
 use std::string::String;
 use std::sync::Arc;
 
@@ -16,20 +20,23 @@ use smallvec::smallvec_inline;
 #[cfg(feature = "debug")]
 use wgpu::{BufferUsages, Device, Queue};
 
+pub use self::emotion::{EMOTION_COMMANDS, EMOTION_FILE_PATH, Emotion, emotion_from_command};
+pub use self::experience::Experience;
+pub use self::status::{EntityLook, StatusEffect};
 #[cfg(feature = "debug")]
 use crate::graphics::reduce_vertices;
 #[cfg(feature = "debug")]
 use crate::graphics::{BindlessSupport, DebugRectangleInstruction};
-use crate::graphics::{EntityInstruction, ScreenPosition, ScreenSize};
+use crate::graphics::{EntityInstruction, ModelBatch, ModelInstruction, PickerTarget, ScreenPosition, ScreenSize};
 use crate::loaders::GameFileLoader;
 #[cfg(feature = "debug")]
 use crate::loaders::{GAT_TILE_SIZE, split_mesh_by_texture};
-use crate::renderer::GameInterfaceRenderer;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
+use crate::renderer::{AlignHorizontal, GameInterfaceRenderer};
 use crate::state::ClientState;
 use crate::state::theme::{InterfaceThemeType, WorldTheme};
-use crate::world::{ActionEvent, AnimationData, AnimationState, Camera, Library, MAX_WALK_PATH_SIZE, Map, PathFinder};
+use crate::world::{ActionEvent, AnimationData, AnimationState, Camera, EntityModel, Library, MAX_WALK_PATH_SIZE, Map, PathFinder};
 #[cfg(feature = "debug")]
 use crate::world::{MarkerIdentifier, SubMesh};
 #[cfg(feature = "debug")]
@@ -85,6 +92,26 @@ pub struct Pathing {
     pub submeshes: Vec<SubMesh>,
 }
 
+/// Skill that an entity is currently casting.
+#[derive(Copy, Clone)]
+struct Cast {
+    start_tick: ClientTick,
+    end_tick: ClientTick,
+}
+
+impl Cast {
+    fn progress(&self, client_tick: ClientTick) -> f32 {
+        let duration = self.end_tick.0.wrapping_sub(self.start_tick.0);
+        let elapsed = client_tick.0.wrapping_sub(self.start_tick.0);
+
+        (elapsed as f32 / duration as f32).clamp(0.0, 1.0)
+    }
+
+    fn is_over(&self, client_tick: ClientTick) -> bool {
+        (self.end_tick.0.wrapping_sub(client_tick.0) as i32) <= 0
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct Step {
     arrival_position: TilePosition,
@@ -158,6 +185,8 @@ pub struct Common {
     pub entity_type: EntityType,
     pub active_movement: Option<Movement>,
     pub animation_data: Option<Arc<AnimationData>>,
+    #[hidden_element]
+    model: Option<Arc<EntityModel>>,
     pub tile_position: TilePosition,
     pub world_position: Point3<f32>,
     #[hidden_element]
@@ -167,6 +196,16 @@ pub struct Common {
     stopped_moving: bool,
     #[hidden_element]
     sound_state: SoundState,
+    #[hidden_element]
+    status_effects: Vec<StatusEffect>,
+    body_state: u16,
+    effect_state: u32,
+    #[hidden_element]
+    look: EntityLook,
+    #[hidden_element]
+    cast: Option<Cast>,
+    #[hidden_element]
+    emotion: Option<Emotion>,
 }
 
 #[cfg_attr(feature = "debug", korangar_debug::profile)]
@@ -320,6 +359,19 @@ fn get_sprite_path_for_player_job(job_id: usize) -> &'static str {
     }
 }
 
+/// Monsters and NPCs that are rendered with a Granny model instead of their
+/// sprite.
+fn get_model_path_for_job(job_id: usize) -> Option<&'static str> {
+    match job_id {
+        722 => Some("3dmob\\guildflag90_1.gr2"),  // GUILD_FLAG
+        1285 => Some("3dmob\\aguardian90_8.gr2"), // ARCHER_GUARDIAN
+        1286 => Some("3dmob\\kguardian90_7.gr2"), // KNIGHT_GUARDIAN
+        1287 => Some("3dmob\\sguardian90_9.gr2"), // SOLDIER_GUARDIAN
+        1288 => Some("3dmob\\empelium90_0.gr2"),  // EMPELIUM
+        _ => None,
+    }
+}
+
 fn get_entity_part_files(library: &Library, entity_type: EntityType, job_id: usize, sex: Sex, head: Option<usize>) -> Vec<String> {
     let sex_sprite_path = match sex == Sex::Female {
         true => "여",
@@ -369,6 +421,9 @@ impl Common {
         let health_points = entity_data.health_points as usize;
         let maximum_health_points = entity_data.maximum_health_points as usize;
         let sex = entity_data.sex;
+        let body_state = entity_data.body_state;
+        let effect_state = entity_data.effect_state;
+        let look = EntityLook::from_state(body_state, effect_state, &[]);
 
         let active_movement = None;
         let entity_type = job_id.into();
@@ -390,10 +445,17 @@ impl Common {
             health_points,
             maximum_health_points,
             animation_data: None,
+            model: None,
             details,
             animation_state,
             stopped_moving: false,
             sound_state: SoundState::default(),
+            status_effects: Vec::new(),
+            body_state,
+            effect_state,
+            look,
+            cast: None,
+            emotion: None,
         }
     }
 
@@ -405,6 +467,27 @@ impl Common {
         self.update_movement(map, client_tick);
         self.animation_state.update(client_tick);
 
+        // The server notifies us when a status effect ends, but we remove expired
+        // status effects anyway in case the packet is lost.
+        let status_effect_count = self.status_effects.len();
+        self.status_effects.retain(|status_effect| !status_effect.is_expired(client_tick));
+
+        if self.status_effects.len() != status_effect_count {
+            self.update_look();
+        }
+
+        if self.cast.is_some_and(|cast| cast.is_over(client_tick)) {
+            self.cast = None;
+        }
+
+        if self.emotion.as_ref().is_some_and(|emotion| emotion.is_over(client_tick)) {
+            self.emotion = None;
+        }
+
+        if let Some(emotion) = &mut self.emotion {
+            emotion.update(client_tick);
+        }
+
         if let Some(animation_data) = self.animation_data.as_ref() {
             if animation_data.is_animation_over(&self.animation_state) && self.animation_state.is_attack() {
                 self.animation_state.idle(self.entity_type, client_tick);
@@ -475,6 +558,30 @@ impl Common {
         }
     }
 
+    /// Render a small indicator for every status effect of the entity,
+    /// centered horizontally on the given position.
+    fn render_status_indicators(&self, renderer: &GameInterfaceRenderer, theme: &WorldTheme, position: ScreenPosition) {
+        let theme = &theme.status_effect;
+        let count = self.status_effects.len() as f32;
+        let total_width = count * theme.indicator_size + (count - 1.0).max(0.0) * theme.gap;
+
+        let mut position = position - ScreenPosition::only_left(total_width / 2.0);
+
+        for status_effect in &self.status_effects {
+            let color = match status_effect.is_debuff() {
+                true => theme.debuff_color,
+                false => theme.buff_color,
+            };
+
+            renderer.render_rectangle(position, ScreenSize::uniform(theme.indicator_size), color);
+            position.left += theme.indicator_size + theme.gap;
+        }
+    }
+
+    fn update_look(&mut self) {
+        self.look = EntityLook::from_state(self.body_state, self.effect_state, &self.status_effects);
+    }
+
     fn set_position(&mut self, map: &Map, position: TilePosition, client_tick: ClientTick) {
         let Some(world_position) = map.get_world_position(position) else {
             #[cfg(feature = "debug")]
@@ -809,15 +916,32 @@ impl Common {
 
     pub fn render(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera, add_to_picker: bool) {
         if let Some(animation_data) = self.animation_data.as_ref() {
+            let offset = instructions.len();
+
             animation_data.render(
                 instructions,
                 camera,
                 add_to_picker,
-                self.entity_id,
+                PickerTarget::Entity(self.entity_id),
                 self.world_position,
                 &self.animation_state,
                 self.direction,
+                self.look,
             );
+
+            // Entities with a model still need their sprite to be picked, so we keep it
+            // as an invisible hitbox.
+            if self.model.is_some() {
+                instructions[offset..]
+                    .iter_mut()
+                    .for_each(|instruction| instruction.color.alpha = 0.0);
+            }
+        }
+    }
+
+    pub fn render_model(&self, instructions: &mut Vec<ModelInstruction>, batches: &mut Vec<ModelBatch>, camera: &dyn Camera) {
+        if let Some(model) = self.model.as_ref() {
+            model.render_geometry(instructions, batches, self.world_position, self.direction, camera);
         }
     }
 
@@ -858,6 +982,8 @@ pub struct Player {
     pub maximum_activity_points: usize,
     pub base_level: usize,
     pub job_level: usize,
+    pub base_experience: Experience,
+    pub job_experience: Experience,
     pub stat_points: u32,
     pub strength: i32,
     pub bonus_strength: i32,
@@ -892,6 +1018,8 @@ impl Player {
         let maximum_activity_points = 0;
         let base_level = character_information.base_level as usize;
         let job_level = character_information.job_level as usize;
+        let base_experience = Experience::new(character_information.experience.max(0) as u64);
+        let job_experience = Experience::new(character_information.job_experience.max(0) as u64);
         let stat_points = character_information.stat_points as u32;
 
         let entity_data = EntityData::from_character(account_id, character_information, WorldPosition::origin());
@@ -909,6 +1037,8 @@ impl Player {
             maximum_activity_points,
             base_level,
             job_level,
+            base_experience,
+            job_experience,
             stat_points,
             strength: character_information.strength as i32,
             bonus_strength: 0,
@@ -951,6 +1081,10 @@ impl Player {
             StatType::MovementSpeed(value) => self.common.movement_speed = value as usize,
             StatType::BaseLevel(value) => self.base_level = value as usize,
             StatType::JobLevel(value) => self.job_level = value as usize,
+            StatType::BaseExperience(value) => self.base_experience.current = value,
+            StatType::JobExperience(value) => self.job_experience.current = value,
+            StatType::NextBaseExperience(value) => self.base_experience.next = value,
+            StatType::NextJobExperience(value) => self.job_experience.next = value,
             StatType::StatPoints(stat_points) => self.stat_points = stat_points,
             StatType::Strength(base, bonus) => {
                 self.strength = base;
@@ -1049,6 +1183,69 @@ impl Player {
         );
     }
 
+    /// Render the status effects of the player as a bar of icons in the top
+    /// right corner of the screen.
+    pub fn render_status_effects(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        theme: &WorldTheme,
+        client_tick: ClientTick,
+        window_size: ScreenSize,
+    ) {
+        let theme = &theme.status_effect;
+        let icon_size = ScreenSize::uniform(theme.icon_size);
+
+        let mut position = ScreenPosition {
+            left: window_size.width - theme.bar_offset.left - theme.icon_size,
+            top: theme.bar_offset.top,
+        };
+
+        for status_effect in &self.common.status_effects {
+            let color = match status_effect.is_debuff() {
+                true => theme.debuff_color,
+                false => theme.buff_color,
+            };
+
+            renderer.render_rectangle(position, icon_size, color);
+
+            // Cover the elapsed part of the duration, so the icon empties from the top.
+            if let Some(remaining_fraction) = status_effect.remaining_fraction(client_tick) {
+                let elapsed_size = ScreenSize {
+                    width: theme.icon_size,
+                    height: theme.icon_size * (1.0 - remaining_fraction),
+                };
+
+                renderer.render_rectangle(position, elapsed_size, theme.elapsed_color);
+            }
+
+            let label = match status_effect.label() {
+                Some(label) => label.to_owned(),
+                None => status_effect.status_id.to_string(),
+            };
+
+            let center = position + ScreenPosition::only_left(theme.icon_size / 2.0);
+            renderer.render_text(&label, center, theme.text_color, theme.font_size, AlignHorizontal::Mid);
+
+            if let Some(remaining) = status_effect.remaining(client_tick) {
+                let seconds = remaining.div_ceil(1000);
+                let remaining_text = match seconds {
+                    0..60 => format!("{seconds}s"),
+                    _ => format!("{}m", seconds.div_ceil(60)),
+                };
+
+                renderer.render_text(
+                    &remaining_text,
+                    center + ScreenPosition::only_top(theme.icon_size / 2.0),
+                    theme.text_color,
+                    theme.font_size,
+                    AlignHorizontal::Mid,
+                );
+            }
+
+            position.left -= theme.icon_size + theme.gap;
+        }
+    }
+
     pub fn get_entity_part_files(&self, library: &Library) -> Vec<String> {
         let common = self.get_common();
         get_entity_part_files(library, common.entity_type, common.job_id, common.sex, Some(self.hair_id))
@@ -1096,10 +1293,6 @@ impl Npc {
     }
 
     pub fn render_status(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, theme: &WorldTheme, window_size: ScreenSize) {
-        if self.common.entity_type != EntityType::Monster {
-            return;
-        }
-
         let clip_space_position = camera.view_projection_matrix() * self.common.world_position.to_homogeneous();
         let screen_position = camera.clip_to_screen_space(clip_space_position);
         let final_position = ScreenPosition {
@@ -1107,6 +1300,11 @@ impl Npc {
             top: screen_position.y * window_size.height + 5.0,
         };
 
+        if self.common.entity_type != EntityType::Monster {
+            self.common.render_status_indicators(renderer, theme, final_position);
+            return;
+        }
+
         let bar_width = theme.status_bar.enemy_bar_width;
 
         renderer.render_rectangle(
@@ -1128,6 +1326,12 @@ impl Npc {
             self.common.maximum_health_points as f32,
             self.common.health_points as f32,
         );
+
+        let indicator_position = final_position
+            + ScreenPosition::only_top(
+                theme.status_bar.enemy_health_height + theme.status_bar.border_size.height + theme.status_bar.gap * 2.0,
+            );
+        self.common.render_status_indicators(renderer, theme, indicator_position);
     }
 }
 
@@ -1168,7 +1372,9 @@ impl Entity {
     }
 
     pub fn set_job(&mut self, job_id: usize) {
-        self.get_common_mut().job_id = job_id;
+        let common = self.get_common_mut();
+        common.job_id = job_id;
+        common.model = None;
     }
 
     pub fn set_hair(&mut self, hair_id: usize) {
@@ -1188,6 +1394,16 @@ impl Entity {
         }
     }
 
+    /// Returns the model file for entities that are rendered with a model
+    /// instead of their sprite.
+    pub fn get_model_file(&self) -> Option<&'static str> {
+        get_model_path_for_job(self.get_common().job_id)
+    }
+
+    pub fn set_model(&mut self, model: Arc<EntityModel>) {
+        self.get_common_mut().model = Some(model)
+    }
+
     pub fn set_details_requested(&mut self) {
         self.get_common_mut().details = ResourceState::Requested;
     }
@@ -1246,10 +1462,63 @@ impl Entity {
         self.get_common().stopped_moving
     }
 
+    pub fn is_moving(&self) -> bool {
+        self.get_common().active_movement.is_some()
+    }
+
     pub fn stop_movement(&mut self) {
         self.get_common_mut().active_movement = None;
     }
 
+    /// Add a status effect to the entity. If the status effect is already
+    /// active, its duration is refreshed.
+    pub fn add_status_effect(&mut self, status_effect: StatusEffect) {
+        let common = self.get_common_mut();
+
+        match common
+            .status_effects
+            .iter_mut()
+            .find(|known| known.status_id == status_effect.status_id)
+        {
+            Some(known) => *known = status_effect,
+            None => common.status_effects.push(status_effect),
+        }
+
+        common.update_look();
+    }
+
+    pub fn remove_status_effect(&mut self, status_id: u16) {
+        let common = self.get_common_mut();
+        common.status_effects.retain(|status_effect| status_effect.status_id != status_id);
+        common.update_look();
+    }
+
+    pub fn set_state(&mut self, body_state: u16, effect_state: u32) {
+        let common = self.get_common_mut();
+        common.body_state = body_state;
+        common.effect_state = effect_state;
+        common.update_look();
+    }
+
+    pub fn start_cast(&mut self, cast_time: u32, client_tick: ClientTick) {
+        self.get_common_mut().cast = (cast_time > 0).then(|| Cast {
+            start_tick: client_tick,
+            end_tick: ClientTick(client_tick.0.wrapping_add(cast_time)),
+        });
+    }
+
+    pub fn cancel_cast(&mut self) {
+        self.get_common_mut().cast = None;
+    }
+
+    pub fn is_casting(&self) -> bool {
+        self.get_common().cast.is_some()
+    }
+
+    pub fn show_emotion(&mut self, emotion: Emotion) {
+        self.get_common_mut().emotion = Some(emotion);
+    }
+
     pub fn update_health(&mut self, health_points: usize, maximum_health_points: usize) {
         let common = self.get_common_mut();
         common.health_points = health_points;
@@ -1277,9 +1546,26 @@ impl Entity {
     }
 
     pub fn render(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera, add_to_picker: bool) {
+        // Hidden entities are only visible to themselves.
+        if let Self::Npc(npc) = self
+            && npc.common.look == EntityLook::Translucent
+        {
+            return;
+        }
+
         self.get_common().render(instructions, camera, add_to_picker);
     }
 
+    pub fn render_model(&self, instructions: &mut Vec<ModelInstruction>, batches: &mut Vec<ModelBatch>, camera: &dyn Camera) {
+        if let Self::Npc(npc) = self
+            && npc.common.look == EntityLook::Translucent
+        {
+            return;
+        }
+
+        self.get_common().render_model(instructions, batches, camera);
+    }
+
     #[cfg(feature = "debug")]
     pub fn render_debug(&self, instructions: &mut Vec<DebugRectangleInstruction>, camera: &dyn Camera) {
         self.get_common().render_debug(instructions, camera);
@@ -1310,6 +1596,79 @@ impl Entity {
             Self::Npc(npc) => npc.render_status(renderer, camera, theme, window_size),
         }
     }
+
+    /// Render the progress of the skill the entity is currently casting.
+    pub fn render_cast_bar(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        camera: &dyn Camera,
+        theme: &WorldTheme,
+        client_tick: ClientTick,
+        window_size: ScreenSize,
+    ) {
+        let common = self.get_common();
+
+        let Some(cast) = common.cast else {
+            return;
+        };
+
+        let clip_space_position = camera.view_projection_matrix() * common.world_position.to_homogeneous();
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height + theme.status_bar.cast_bar_offset,
+        };
+
+        let bar_width = theme.status_bar.enemy_bar_width;
+
+        renderer.render_rectangle(
+            final_position - theme.status_bar.border_size - ScreenSize::only_width(bar_width / 2.0),
+            ScreenSize {
+                width: bar_width,
+                height: theme.status_bar.cast_time_height,
+            } + (theme.status_bar.border_size * 2.0),
+            theme.status_bar.background_color,
+        );
+
+        renderer.render_bar(
+            final_position,
+            ScreenSize {
+                width: bar_width,
+                height: theme.status_bar.cast_time_height,
+            },
+            theme.status_bar.cast_time_color,
+            1.0,
+            cast.progress(client_tick),
+        );
+    }
+
+    /// Render the emotion the entity is currently showing.
+    pub fn render_emotion(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, window_size: ScreenSize, scaling: f32) {
+        // Hidden entities are only visible to themselves.
+        if let Self::Npc(npc) = self
+            && npc.common.look == EntityLook::Translucent
+        {
+            return;
+        }
+
+        let common = self.get_common();
+
+        if let Some(emotion) = &common.emotion {
+            emotion.render(renderer, camera, common.world_position, window_size, scaling);
+        }
+    }
+
+    pub fn render_status_effects(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        theme: &WorldTheme,
+        client_tick: ClientTick,
+        window_size: ScreenSize,
+    ) {
+        if let Self::Player(player) = self {
+            player.render_status_effects(renderer, theme, client_tick, window_size);
+        }
+    }
 }
 
 impl VecItem for Entity {
@@ -0,0 +1,193 @@
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::Color;
+
+const STATUS_HIDING: u16 = 4;
+const STATUS_CLOAKING: u16 = 5;
+
+const BODY_STATE_STONE: u16 = 1;
+const BODY_STATE_FREEZE: u16 = 2;
+const BODY_STATE_STONE_WAIT: u16 = 6;
+
+const EFFECT_STATE_HIDE: u32 = 0x2;
+const EFFECT_STATE_CLOAK: u32 = 0x4;
+const EFFECT_STATE_INVISIBLE: u32 = 0x40;
+const EFFECT_STATE_CHASE_WALK: u32 = 0x4000;
+
+/// A buff or debuff that is active on an entity.
+#[derive(Clone, Debug)]
+pub struct StatusEffect {
+    pub status_id: u16,
+    /// Total duration in milliseconds. `None` for status effects that don't
+    /// expire or if the server didn't send the duration.
+    duration: Option<u32>,
+    /// Client tick at which the status effect ends.
+    end_tick: Option<ClientTick>,
+}
+
+impl StatusEffect {
+    pub fn new(status_id: u16, duration: Option<u32>, remaining: Option<u32>, client_tick: ClientTick) -> Self {
+        let end_tick = remaining
+            .or(duration)
+            .map(|remaining| ClientTick(client_tick.0.wrapping_add(remaining)));
+
+        Self {
+            status_id,
+            duration,
+            end_tick,
+        }
+    }
+
+    /// Remaining time in milliseconds.
+    pub fn remaining(&self, client_tick: ClientTick) -> Option<u32> {
+        self.end_tick.map(|end_tick| end_tick.0.saturating_sub(client_tick.0))
+    }
+
+    /// Fraction of the total duration that is still remaining, in the range
+    /// 0.0 to 1.0.
+    pub fn remaining_fraction(&self, client_tick: ClientTick) -> Option<f32> {
+        let duration = self.duration.filter(|duration| *duration > 0)?;
+        let remaining = self.remaining(client_tick)?;
+
+        Some((remaining as f32 / duration as f32).clamp(0.0, 1.0))
+    }
+
+    pub fn is_expired(&self, client_tick: ClientTick) -> bool {
+        self.remaining(client_tick) == Some(0)
+    }
+
+    /// Short label to display in place of the status icon.
+    pub fn label(&self) -> Option<&'static str> {
+        let label = match self.status_id {
+            0 => "PRV",
+            1 => "END",
+            2 => "THQ",
+            3 => "CON",
+            4 => "HID",
+            5 => "CLK",
+            6 => "EPN",
+            7 => "PRC",
+            8 => "QAG",
+            9 => "ANG",
+            10 => "BLS",
+            11 => "SGN",
+            12 => "AGI",
+            13 => "DAG",
+            14 => "SPN",
+            15 => "IMP",
+            16 => "SUF",
+            17 => "ASP",
+            18 => "BEN",
+            19 => "KYR",
+            20 => "MAG",
+            21 => "GLO",
+            22 => "LEX",
+            23 => "ADR",
+            24 => "WPF",
+            25 => "OVT",
+            26 => "MAX",
+            29 => "PLD",
+            30 => "SHT",
+            31 => "ECT",
+            32 => "BRA",
+            33 => "BRW",
+            35 => "W50",
+            36 => "W90",
+            _ => return None,
+        };
+
+        Some(label)
+    }
+
+    /// Whether the status effect is harmful to the entity.
+    pub fn is_debuff(&self) -> bool {
+        matches!(self.status_id, 0 | 8 | 13 | 22 | 32 | 33 | 35 | 36)
+    }
+}
+
+/// Changes to the look of an entity that are caused by its state or status
+/// effects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EntityLook {
+    #[default]
+    Normal,
+    /// The entity is hiding or cloaked. Only visible to the entity itself.
+    Translucent,
+    /// The entity was hit by stone curse.
+    Petrified,
+    Frozen,
+}
+
+impl EntityLook {
+    pub fn from_state(body_state: u16, effect_state: u32, status_effects: &[StatusEffect]) -> Self {
+        let is_hidden = effect_state & (EFFECT_STATE_HIDE | EFFECT_STATE_CLOAK | EFFECT_STATE_INVISIBLE | EFFECT_STATE_CHASE_WALK) != 0
+            || status_effects
+                .iter()
+                .any(|status_effect| matches!(status_effect.status_id, STATUS_HIDING | STATUS_CLOAKING));
+
+        match body_state {
+            BODY_STATE_STONE | BODY_STATE_STONE_WAIT => Self::Petrified,
+            BODY_STATE_FREEZE => Self::Frozen,
+            _ if is_hidden => Self::Translucent,
+            _ => Self::Normal,
+        }
+    }
+
+    /// Apply the look to the color of a sprite part.
+    pub fn apply(self, color: Color) -> Color {
+        let brightness = || color.red * 0.3 + color.green * 0.59 + color.blue * 0.11;
+
+        match self {
+            Self::Normal => color,
+            Self::Translucent => Color {
+                alpha: color.alpha * 0.4,
+                ..color
+            },
+            Self::Petrified => {
+                let brightness = brightness() * 0.8;
+                Color::rgba(brightness, brightness, brightness, color.alpha)
+            }
+            Self::Frozen => {
+                let brightness = brightness();
+                Color::rgba(brightness * 0.6, brightness * 0.8, brightness.max(0.6), color.alpha)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ragnarok_packets::ClientTick;
+
+    use super::{EntityLook, StatusEffect};
+
+    #[test]
+    fn status_effect_expires() {
+        let status_effect = StatusEffect::new(12, Some(10_000), Some(4_000), ClientTick(1_000));
+
+        assert_eq!(status_effect.remaining(ClientTick(2_000)), Some(3_000));
+        assert_eq!(status_effect.remaining_fraction(ClientTick(3_000)), Some(0.2));
+        assert!(!status_effect.is_expired(ClientTick(4_999)));
+        assert!(status_effect.is_expired(ClientTick(5_000)));
+    }
+
+    #[test]
+    fn status_effect_without_duration() {
+        let status_effect = StatusEffect::new(12, None, None, ClientTick(1_000));
+
+        assert_eq!(status_effect.remaining(ClientTick(100_000)), None);
+        assert_eq!(status_effect.remaining_fraction(ClientTick(100_000)), None);
+        assert!(!status_effect.is_expired(ClientTick(100_000)));
+    }
+
+    #[test]
+    fn look_from_state() {
+        let hiding = [StatusEffect::new(4, None, None, ClientTick(0))];
+
+        assert_eq!(EntityLook::from_state(0, 0, &[]), EntityLook::Normal);
+        assert_eq!(EntityLook::from_state(0, 0, &hiding), EntityLook::Translucent);
+        assert_eq!(EntityLook::from_state(0, 0x2, &[]), EntityLook::Translucent);
+        assert_eq!(EntityLook::from_state(1, 0x2, &hiding), EntityLook::Petrified);
+        assert_eq!(EntityLook::from_state(2, 0, &[]), EntityLook::Frozen);
+    }
+}
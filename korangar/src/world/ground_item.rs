@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use cgmath::{Array, Matrix4, Point3, SquareMatrix, Transform, Vector2, Vector3};
+use korangar_interface::element::StateElement;
+use korangar_networking::GroundItemData;
+use ragnarok_packets::{ClientTick, Direction, GroundItemId, ItemId, TilePosition};
+use rust_state::RustState;
+
+use crate::graphics::{Color, EntityInstruction, PickerTarget, Texture};
+use crate::world::{AnimationData, AnimationState, Camera, EntityLook, EntityType};
+
+const TILE_SIZE: f32 = 10.0;
+const SPRITE_SCALE: f32 = 1.4;
+/// Maximum distance in tiles from which the server lets the player pick up
+/// an item.
+const PICKUP_RANGE: u16 = 2;
+
+/// Item lying on the ground of the current map.
+#[derive(Clone, RustState, StateElement)]
+pub struct GroundItem {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub name: String,
+    pub count: u16,
+    pub tile_position: TilePosition,
+    pub world_position: Point3<f32>,
+    /// Sprite of the item lying on the ground.
+    #[hidden_element]
+    pub animation_data: Option<Arc<AnimationData>>,
+    #[hidden_element]
+    animation_state: AnimationState,
+    /// Inventory icon of the item. Only used if the item doesn't have a
+    /// sprite.
+    #[hidden_element]
+    pub texture: Option<Arc<Texture>>,
+}
+
+impl GroundItem {
+    pub fn new(
+        ground_item_data: &GroundItemData,
+        name: String,
+        world_position: Point3<f32>,
+        animation_data: Option<Arc<AnimationData>>,
+        texture: Option<Arc<Texture>>,
+    ) -> Self {
+        Self {
+            ground_item_id: ground_item_data.ground_item_id,
+            item_id: ground_item_data.item_id,
+            name,
+            count: ground_item_data.count,
+            tile_position: ground_item_data.position,
+            world_position,
+            animation_data,
+            animation_state: AnimationState::new(EntityType::Npc, ClientTick(0)),
+            texture,
+        }
+    }
+
+    /// Name of the item as it is displayed when hovering it.
+    pub fn get_display_name(&self) -> String {
+        match self.count {
+            0 | 1 => self.name.clone(),
+            count => format!("{} x {count}", self.name),
+        }
+    }
+
+    pub fn is_in_pickup_range(&self, position: TilePosition) -> bool {
+        self.tile_position.x.abs_diff(position.x) <= PICKUP_RANGE && self.tile_position.y.abs_diff(position.y) <= PICKUP_RANGE
+    }
+
+    pub fn render(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera) {
+        if let Some(animation_data) = self.animation_data.as_ref() {
+            animation_data.render(
+                instructions,
+                camera,
+                true,
+                PickerTarget::GroundItem(self.ground_item_id),
+                self.world_position,
+                &self.animation_state,
+                Direction::North,
+                EntityLook::default(),
+            );
+            return;
+        }
+
+        let Some(texture) = self.texture.as_ref() else {
+            return;
+        };
+
+        let texture_size = texture.get_size();
+        let frame_size = Vector2::new(texture_size.width as f32, texture_size.height as f32);
+
+        // The item is placed with its lower border on the ground, the same way
+        // entity sprites are.
+        let origin = Point3::new(0.0, 0.5, 0.0) * SPRITE_SCALE / TILE_SIZE + Vector3::unit_z();
+        let size = frame_size * SPRITE_SCALE / TILE_SIZE;
+        let world_matrix = camera.billboard_matrix(self.world_position, origin, size);
+
+        let (depth_offset, curvature) = camera.calculate_depth_offset_and_curvature(&world_matrix, SPRITE_SCALE, SPRITE_SCALE);
+        let distance = camera.distance_to(world_matrix.transform_point(Point3::from_value(0.0)));
+
+        instructions.push(EntityInstruction {
+            world: world_matrix,
+            frame_part_transform: Matrix4::identity(),
+            texture_position: Vector2::new(0.0, 0.0),
+            texture_size: Vector2::new(1.0, 1.0),
+            frame_size,
+            extra_depth_offset: 0.0,
+            depth_offset,
+            curvature,
+            color: Color::WHITE,
+            mirror: false,
+            picker_target: PickerTarget::GroundItem(self.ground_item_id),
+            add_to_picker: true,
+            texture: texture.clone(),
+            distance,
+        });
+    }
+}
@@ -1,14 +1,19 @@
 use std::sync::Arc;
 
+use cgmath::Point3;
 use encoding_rs::EUC_KR;
 use hashbrown::HashMap;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
 use korangar_loaders::FileLoader;
-use korangar_networking::{InventoryItem, NoMetadata, ShopItem};
+use korangar_networking::{AchievementProgress, GroundItemData, InventoryItem, NoMetadata, ShopItem};
 use mlua::{Lua, Value};
 use ragnarok_packets::ItemId;
 
+use super::achievement::{AchievementInfo, create_achievement};
 use crate::graphics::{Color, Texture};
 use crate::loaders::{AsyncLoader, GameFileLoader, ImageType, ItemLocation};
+use crate::world::{Achievement, GroundItem};
 
 #[derive(Debug, Clone)]
 pub struct ResourceMetadata {
@@ -57,6 +62,7 @@ pub struct Library {
     job_identity_table: HashMap<usize, String>,
     item_table: HashMap<ItemId, ItemInfo>,
     map_sky_data_table: HashMap<String, MapSkyData>,
+    achievement_table: HashMap<u32, AchievementInfo>,
 }
 
 impl Library {
@@ -93,10 +99,35 @@ impl Library {
             Err(_) => HashMap::new(),
         };
 
+        // Achievements are optional, so a missing or broken achievement list should
+        // not prevent the client from starting.
+        let achievement_table = game_file_loader
+            .get("system\\achievement_list.lub")
+            .map_err(|error| format!("{error:?}"))
+            .and_then(|data| {
+                let state = Lua::new();
+                state.load(&data).exec().map_err(|error| error.to_string())?;
+                Self::load_achievement_table(&state).map_err(|error| error.to_string())
+            })
+            .unwrap_or_else(|error| {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] can't load achievement list {}: {}",
+                    "error".red(),
+                    "system\\achievement_list.lub".magenta(),
+                    error
+                );
+                #[cfg(not(feature = "debug"))]
+                eprintln!("[error] can't load achievement list system\\achievement_list.lub: {error}");
+
+                HashMap::new()
+            });
+
         Ok(Self {
             job_identity_table,
             item_table,
             map_sky_data_table,
+            achievement_table,
         })
     }
 
@@ -217,6 +248,44 @@ impl Library {
         }
     }
 
+    fn load_achievement_table(state: &Lua) -> mlua::Result<HashMap<u32, AchievementInfo>> {
+        let globals = state.globals();
+        let mut result = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("achievement_tbl") {
+            for (achievement_id, achievement_table) in table.pairs::<u32, mlua::Table>().flatten() {
+                let summary = achievement_table
+                    .get::<mlua::Table>("content")
+                    .and_then(|content| content.get("summary"))
+                    .map(fix_encoding)
+                    .unwrap_or_default();
+
+                let mut objectives = Vec::new();
+
+                if let Ok(resource_table) = achievement_table.get::<mlua::Table>("resource") {
+                    for resource in resource_table.sequence_values::<mlua::Table>().flatten() {
+                        let text = resource.get("text").map(fix_encoding).unwrap_or_default();
+                        let count = resource.get("count").unwrap_or_default();
+
+                        objectives.push((text, count));
+                    }
+                }
+
+                let info = AchievementInfo {
+                    category: achievement_table.get("major").unwrap_or_default(),
+                    title: achievement_table.get("title").map(fix_encoding).unwrap_or_default(),
+                    summary,
+                    score: achievement_table.get("score").unwrap_or_default(),
+                    objectives,
+                };
+
+                result.insert(achievement_id, info);
+            }
+        }
+
+        Ok(result)
+    }
+
     pub fn get_job_identity_from_id(&self, job_id: usize) -> &str {
         self.job_identity_table
             .get(&job_id)
@@ -261,6 +330,10 @@ impl Library {
         InventoryItem { metadata, ..item }
     }
 
+    pub fn load_achievement(&self, progress: AchievementProgress) -> Achievement {
+        create_achievement(self.achievement_table.get(&progress.achievement_id), progress)
+    }
+
     pub fn load_shop_item_metadata(&self, async_loader: &AsyncLoader, item: ShopItem<NoMetadata>) -> ShopItem<ResourceMetadata> {
         let resource_name = self.get_item_resource_from_id(item.item_id, true);
         let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
@@ -271,6 +344,40 @@ impl Library {
 
         ShopItem { metadata, ..item }
     }
+
+    pub fn load_ground_item(
+        &self,
+        game_file_loader: &GameFileLoader,
+        async_loader: &AsyncLoader,
+        ground_item_data: &GroundItemData,
+        world_position: Point3<f32>,
+    ) -> GroundItem {
+        let is_identified = ground_item_data.is_identified;
+
+        let resource_name = self.get_item_resource_from_id(ground_item_data.item_id, is_identified);
+        let name = self.get_item_name_from_id(ground_item_data.item_id, is_identified).to_string();
+
+        // Items have a dedicated sprite for lying on the ground. The sprite and action
+        // loaders would replace missing files with the fallback sprite, so we need to
+        // check if the files exist first.
+        let sprite_path = format!("아이템\\{resource_name}");
+        let has_ground_sprite = ["spr", "act"]
+            .iter()
+            .all(|extension| game_file_loader.file_exists(&format!("data\\sprite\\{sprite_path}.{extension}")));
+
+        match has_ground_sprite {
+            true => {
+                let animation_data = async_loader.request_ground_item_animation_data_load(ground_item_data.item_id, sprite_path);
+                GroundItem::new(ground_item_data, name, world_position, animation_data, None)
+            }
+            false => {
+                let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
+                let texture =
+                    async_loader.request_item_sprite_load(ItemLocation::Ground, ground_item_data.item_id, &full_path, ImageType::Color);
+                GroundItem::new(ground_item_data, name, world_position, None, texture)
+            }
+        }
+    }
 }
 
 fn fix_encoding(broken: String) -> String {
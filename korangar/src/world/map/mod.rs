@@ -24,14 +24,14 @@ use rust_state::RustState;
 use wgpu::Queue;
 
 pub use self::lighting::Lighting;
-use super::{Camera, Entity, Object, PointLightId, PointLightManager, ResourceSet, ResourceSetBuffer, SubMesh, Video};
+use super::{Camera, Entity, GroundItem, Object, PointLightId, PointLightManager, ResourceSet, ResourceSetBuffer, SubMesh, Video};
 #[cfg(feature = "debug")]
 use super::{LightSourceExt, Model, PointLightSet};
 #[cfg(feature = "debug")]
+use crate::graphics::{DebugAabbInstruction, DebugCircleInstruction, DebugRectangleInstruction, RenderOptions, ScreenPosition, ScreenSize};
 use crate::graphics::{
-    DebugAabbInstruction, DebugCircleInstruction, DebugRectangleInstruction, ModelBatch, RenderOptions, ScreenPosition, ScreenSize,
+    EntityInstruction, IndicatorInstruction, ModelBatch, ModelInstruction, Texture, TextureSet, WaterInstruction, WaterVertex,
 };
-use crate::graphics::{EntityInstruction, IndicatorInstruction, ModelInstruction, Texture, TextureSet, WaterInstruction, WaterVertex};
 use crate::loaders::GAT_TILE_SIZE;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
@@ -246,6 +246,11 @@ impl Map {
         ))
     }
 
+    /// Size of the map in tiles.
+    pub fn get_size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
     pub fn get_tile(&self, position: TilePosition) -> Option<&Tile> {
         self.tiles.get(position.x as usize + position.y as usize * self.width as usize)
     }
@@ -408,11 +413,29 @@ impl Map {
             .for_each(|(index, entity)| entity.render(instructions, camera, index != 0));
     }
 
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn render_entity_models(
+        &self,
+        model_instructions: &mut Vec<ModelInstruction>,
+        model_batches: &mut Vec<ModelBatch>,
+        entities: &[Entity],
+        camera: &dyn Camera,
+    ) {
+        entities
+            .iter()
+            .for_each(|entity| entity.render_model(model_instructions, model_batches, camera));
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn render_dead_entities(&self, instructions: &mut Vec<EntityInstruction>, entities: &[Entity], camera: &dyn Camera) {
         entities.iter().for_each(|entity| entity.render(instructions, camera, false));
     }
 
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn render_ground_items(&self, instructions: &mut Vec<EntityInstruction>, ground_items: &[GroundItem], camera: &dyn Camera) {
+        ground_items.iter().for_each(|ground_item| ground_item.render(instructions, camera));
+    }
+
     #[cfg(feature = "debug")]
     #[korangar_debug::profile]
     pub fn render_entities_debug(&self, instructions: &mut Vec<DebugRectangleInstruction>, entities: &[Entity], camera: &dyn Camera) {
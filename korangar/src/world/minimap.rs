@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use image::{Rgba, RgbaImage};
+use korangar_interface::element::StateElement;
+use ragnarok_packets::{ClientTick, EntityId, TilePosition};
+use rust_state::RustState;
+
+use crate::graphics::{Color, Texture};
+use crate::world::Map;
+use crate::world::pathing::Traversable;
+
+/// Time in milliseconds that temporary markers stay on the minimap.
+const TEMPORARY_MARKER_DURATION: u32 = 15000;
+/// Marker id used for the navigation target, so it doesn't collide with
+/// markers set by NPCs.
+const NAVIGATION_MARKER_ID: u8 = u8::MAX;
+
+const WALKABLE_COLOR: Rgba<u8> = Rgba([214, 206, 181, 255]);
+const BLOCKED_COLOR: Rgba<u8> = Rgba([66, 66, 74, 255]);
+
+/// A position marked on the minimap by the server.
+#[derive(Debug, Clone)]
+pub struct MinimapMarker {
+    pub npc_id: EntityId,
+    pub marker_id: u8,
+    pub position: TilePosition,
+    pub color: Color,
+    expire_tick: Option<ClientTick>,
+}
+
+/// State of the minimap of the current map.
+#[derive(Default, RustState, StateElement)]
+pub struct Minimap {
+    map_name: String,
+    map_width: u16,
+    map_height: u16,
+    #[hidden_element]
+    texture: Option<Arc<Texture>>,
+    #[hidden_element]
+    markers: Vec<MinimapMarker>,
+}
+
+impl Minimap {
+    /// Set the minimap for a newly loaded map. All markers from the previous
+    /// map are removed.
+    pub fn set_map(&mut self, map_name: String, map: &Map, texture: Arc<Texture>) {
+        let (map_width, map_height) = map.get_size();
+
+        *self = Self {
+            map_name,
+            map_width,
+            map_height,
+            texture: Some(texture),
+            markers: Vec::new(),
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn get_map_name(&self) -> &str {
+        &self.map_name
+    }
+
+    /// Size of the map in tiles.
+    pub fn get_map_size(&self) -> (u16, u16) {
+        (self.map_width, self.map_height)
+    }
+
+    pub fn get_texture(&self) -> Option<&Arc<Texture>> {
+        self.texture.as_ref()
+    }
+
+    pub fn get_markers(&self) -> &[MinimapMarker] {
+        &self.markers
+    }
+
+    pub fn add_marker(
+        &mut self,
+        npc_id: EntityId,
+        marker_id: u8,
+        position: TilePosition,
+        color: Color,
+        is_temporary: bool,
+        client_tick: ClientTick,
+    ) {
+        let expire_tick = is_temporary.then(|| ClientTick(client_tick.0.wrapping_add(TEMPORARY_MARKER_DURATION)));
+        let marker = MinimapMarker {
+            npc_id,
+            marker_id,
+            position,
+            color,
+            expire_tick,
+        };
+
+        match self
+            .markers
+            .iter_mut()
+            .find(|known| known.npc_id == npc_id && known.marker_id == marker_id)
+        {
+            Some(known) => *known = marker,
+            None => self.markers.push(marker),
+        }
+    }
+
+    pub fn remove_marker(&mut self, npc_id: EntityId, marker_id: u8) {
+        self.markers
+            .retain(|marker| marker.npc_id != npc_id || marker.marker_id != marker_id);
+    }
+
+    /// Mark the target of the navigation system. Only the latest target is
+    /// shown.
+    pub fn set_navigation_target(&mut self, position: TilePosition) {
+        self.add_marker(
+            EntityId(0),
+            NAVIGATION_MARKER_ID,
+            position,
+            Color::rgb_u8(255, 60, 60),
+            false,
+            ClientTick(0),
+        );
+    }
+
+    /// Remove all temporary markers that expired.
+    pub fn update_markers(&mut self, client_tick: ClientTick) {
+        self.markers.retain(|marker| match marker.expire_tick {
+            // Compare the difference to handle the tick wrapping around.
+            Some(expire_tick) => (expire_tick.0.wrapping_sub(client_tick.0) as i32) > 0,
+            None => true,
+        });
+    }
+
+    /// Generate a minimap from the walkability of the tiles. Used for maps
+    /// that don't have a minimap image in the game files.
+    pub fn generate_image(map: &Map) -> RgbaImage {
+        let (width, height) = map.get_size();
+
+        RgbaImage::from_fn(width as u32, height as u32, |x, y| {
+            // The first row of the image is the northern edge of the map.
+            let position = TilePosition {
+                x: x as u16,
+                y: height - 1 - y as u16,
+            };
+
+            match map.is_walkable(position) {
+                true => WALKABLE_COLOR,
+                false => BLOCKED_COLOR,
+            }
+        })
+    }
+}
@@ -1,11 +1,14 @@
+mod achievement;
 mod action;
 mod animation;
 mod cameras;
 mod effect;
 mod entity;
+mod ground_item;
 mod library;
 mod light;
 mod map;
+mod minimap;
 mod model;
 mod object;
 mod particles;
@@ -13,14 +16,20 @@ mod pathing;
 mod sound;
 mod video;
 
+pub use self::achievement::{
+    ACHIEVEMENT_CATEGORIES, Achievement, AchievementObjective, AchievementObjectivePathExt, AchievementPathExt, Achievements,
+    AchievementsPathExt,
+};
 pub use self::action::*;
 pub use self::animation::*;
 pub use self::cameras::*;
 pub use self::effect::*;
 pub use self::entity::*;
+pub use self::ground_item::GroundItem;
 pub use self::library::*;
 pub use self::light::*;
 pub use self::map::*;
+pub use self::minimap::{Minimap, MinimapMarker};
 pub use self::model::*;
 pub use self::object::*;
 pub use self::particles::*;
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use cgmath::{Deg, Point3, Vector3};
+use ragnarok_formats::transform::Transform;
+use ragnarok_packets::Direction;
+
+use super::Model;
+use crate::graphics::{Buffer, ModelBatch, ModelInstruction, ModelVertex, TextureSet};
+use crate::world::Camera;
+
+/// Model that is rendered in place of the sprite of an entity, like the
+/// emperium or the guardians. Entity models aren't part of a map, so they
+/// carry their own buffers and textures.
+pub struct EntityModel {
+    model: Model,
+    texture_set: Arc<TextureSet>,
+    vertex_buffer: Arc<Buffer<ModelVertex>>,
+    index_buffer: Arc<Buffer<u32>>,
+}
+
+impl EntityModel {
+    pub fn new(
+        model: Model,
+        texture_set: Arc<TextureSet>,
+        vertex_buffer: Arc<Buffer<ModelVertex>>,
+        index_buffer: Arc<Buffer<u32>>,
+    ) -> Self {
+        Self {
+            model,
+            texture_set,
+            vertex_buffer,
+            index_buffer,
+        }
+    }
+
+    pub fn render_geometry(
+        &self,
+        instructions: &mut Vec<ModelInstruction>,
+        batches: &mut Vec<ModelBatch>,
+        position: Point3<f32>,
+        direction: Direction,
+        camera: &dyn Camera,
+    ) {
+        // Directions are counted clockwise, starting at north.
+        let rotation = Deg(u16::from(direction) as f32 * -45.0);
+        let transform = Transform::from(
+            position,
+            Vector3::new(Deg(0.0), rotation, Deg(0.0)),
+            Vector3::new(1.0, 1.0, 1.0),
+        );
+
+        let offset = instructions.len();
+
+        self.model.render_geometry(instructions, &transform, 0.0, camera);
+
+        batches.push(ModelBatch {
+            offset,
+            count: instructions.len() - offset,
+            texture_set: self.texture_set.clone(),
+            vertex_buffer: self.vertex_buffer.clone(),
+            index_buffer: self.index_buffer.clone(),
+        });
+    }
+}
@@ -1,3 +1,4 @@
+mod entity;
 mod node;
 
 use std::ops::Mul;
@@ -11,6 +12,7 @@ use ragnarok_formats::transform::Transform;
 use ragnarok_formats::version::InternalVersion;
 use rust_state::RustState;
 
+pub use self::entity::EntityModel;
 pub use self::node::{Node, SubMesh};
 #[cfg(feature = "debug")]
 use crate::graphics::Color;
@@ -26,7 +28,7 @@ pub struct Model {
     pub bounding_box: AABB,
     pub is_static: bool,
     #[cfg(feature = "debug")]
-    pub model_data: ModelData,
+    pub model_data: Option<ModelData>,
 }
 
 impl Model {
@@ -35,7 +37,7 @@ impl Model {
         root_nodes: Vec<Node>,
         bounding_box: AABB,
         is_static: bool,
-        #[cfg(feature = "debug")] model_data: ModelData,
+        #[cfg(feature = "debug")] model_data: Option<ModelData>,
     ) -> Self {
         Self {
             version,
@@ -5,7 +5,7 @@ use cgmath::{Point3, Vector3};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::Colorize;
 use korangar_interface::application::Clip;
-use ragnarok_packets::{EntityId, QuestColor, QuestEffectPacket};
+use ragnarok_packets::{EntityId, ExperienceType, QuestColor, QuestEffectPacket};
 use rand_aes::tls::rand_f32;
 
 use crate::graphics::{Color, ScreenClip, ScreenPosition, ScreenSize, Texture};
@@ -149,6 +149,92 @@ impl Particle for HealNumber {
     }
 }
 
+pub struct ExperienceNumber {
+    position: Point3<f32>,
+    experience_text: String,
+    color: Color,
+    timer: f32,
+}
+
+impl ExperienceNumber {
+    /// The `experience_label` is the localized name of the experience type.
+    pub fn new(position: Point3<f32>, amount: u64, experience_type: ExperienceType, experience_label: &str) -> Self {
+        let experience_text = format!("+{amount} {experience_label}");
+        let (color, offset) = match experience_type {
+            ExperienceType::BaseExperience => (Color::rgb_u8(255, 230, 120), 0.0),
+            ExperienceType::JobExperience => (Color::rgb_u8(150, 200, 255), 4.0),
+        };
+
+        Self {
+            // Offset the job experience so it doesn't overlap with the base experience that
+            // is usually gained at the same time.
+            position: position + Vector3::new(0.0, offset, 0.0),
+            experience_text,
+            color,
+            timer: 1.5,
+        }
+    }
+}
+
+impl Particle for ExperienceNumber {
+    fn update(&mut self, delta_time: f32) -> bool {
+        self.position.y += 12.0 * delta_time;
+
+        self.timer -= delta_time;
+        self.timer > 0.0
+    }
+
+    fn render(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, window_size: ScreenSize) {
+        let clip_space_position = camera.view_projection_matrix() * self.position.to_homogeneous();
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+        let alpha = (self.timer * 2.0).min(1.0);
+
+        renderer.render_damage_text(
+            &self.experience_text,
+            final_position,
+            Color {
+                alpha: self.color.alpha * alpha,
+                ..self.color
+            },
+            FontSize(14.0),
+        );
+    }
+}
+
+/// Notification at the top of the screen that is shown when the player
+/// completes an achievement.
+pub struct AchievementToast {
+    text: String,
+    timer: f32,
+}
+
+impl AchievementToast {
+    pub fn new(text: String) -> Self {
+        Self { text, timer: 4.0 }
+    }
+}
+
+impl Particle for AchievementToast {
+    fn update(&mut self, delta_time: f32) -> bool {
+        self.timer -= delta_time;
+        self.timer > 0.0
+    }
+
+    fn render(&self, renderer: &GameInterfaceRenderer, _: &dyn Camera, window_size: ScreenSize) {
+        let position = ScreenPosition {
+            left: window_size.width / 2.0,
+            top: window_size.height * 0.15,
+        };
+        let alpha = self.timer.min(1.0);
+
+        renderer.render_damage_text(&self.text, position, Color::rgba(1.0, 0.8, 0.3, alpha), FontSize(20.0));
+    }
+}
+
 pub struct QuestIcon {
     position: Point3<f32>,
     texture: Arc<Texture>,
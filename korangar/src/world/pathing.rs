@@ -1,7 +1,7 @@
 //! Implements pathfinding algorithms.
 
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
 
 use hashbrown::{HashMap, HashSet};
 use ragnarok_packets::{AttackRange, TilePosition};
@@ -69,6 +69,24 @@ impl PathFinder {
         start: TilePosition,
         goal: TilePosition,
         attack_range: AttackRange,
+    ) -> Option<&[TilePosition]> {
+        self.search(map, start, goal, attack_range, Some(MAX_WALK_PATH_SIZE))
+    }
+
+    /// Returns a walkable path between start and goal without any length
+    /// limit. The path can't be sent to the server directly, use the
+    /// [`RoutePlanner`] to split it into valid segments.
+    pub fn find_route(&mut self, map: &impl Traversable, start: TilePosition, goal: TilePosition) -> Option<&[TilePosition]> {
+        self.search(map, start, goal, AttackRange(0), None)
+    }
+
+    fn search(
+        &mut self,
+        map: &impl Traversable,
+        start: TilePosition,
+        goal: TilePosition,
+        attack_range: AttackRange,
+        maximum_length: Option<usize>,
     ) -> Option<&[TilePosition]> {
         self.open_set.clear();
         self.closed_set.clear();
@@ -85,7 +103,7 @@ impl PathFinder {
 
         while let Some(current) = self.open_set.pop() {
             if current.position.x.abs_diff(goal.x).max(current.position.y.abs_diff(goal.y)) <= attack_range.0 {
-                return match self.reconstruct_path(start, current.position) {
+                return match self.reconstruct_path(start, current.position, maximum_length) {
                     true => Some(&self.path),
                     false => None,
                 };
@@ -236,14 +254,14 @@ impl PathFinder {
         }
     }
 
-    fn reconstruct_path(&mut self, start: TilePosition, goal: TilePosition) -> bool {
+    fn reconstruct_path(&mut self, start: TilePosition, goal: TilePosition, maximum_length: Option<usize>) -> bool {
         let mut current = goal;
 
         while current != start {
             self.path.push(current);
             current = *self.came_from.get(&current).unwrap();
 
-            if self.path.len() >= MAX_WALK_PATH_SIZE {
+            if maximum_length.is_some_and(|maximum_length| self.path.len() >= maximum_length) {
                 return false;
             }
         }
@@ -255,6 +273,94 @@ impl PathFinder {
     }
 }
 
+/// Plans routes across the whole map. Since the server only accepts paths of
+/// up to [`MAX_WALK_PATH_SIZE`] tiles, the route is split into segments that
+/// are walked one after another.
+#[derive(Default)]
+pub struct RoutePlanner {
+    waypoints: VecDeque<TilePosition>,
+    current_waypoint: Option<TilePosition>,
+    awaiting_confirmation: bool,
+}
+
+impl RoutePlanner {
+    /// Plans a route from start to goal and returns the first waypoint that
+    /// should be sent to the server.
+    pub fn plan(
+        &mut self,
+        map: &impl Traversable,
+        path_finder: &mut PathFinder,
+        start: TilePosition,
+        goal: TilePosition,
+    ) -> Option<TilePosition> {
+        self.cancel();
+
+        let route = path_finder.find_route(map, start, goal)?.to_vec();
+        let mut segment_start = 0;
+
+        while segment_start < route.len() - 1 {
+            let mut segment_end = usize::min(segment_start + MAX_WALK_PATH_SIZE - 1, route.len() - 1);
+
+            // The server uses its own path finding, which doesn't necessarily find a
+            // short enough path to every tile of our route. So we shorten the
+            // segment until the server is guaranteed to accept it.
+            while segment_end > segment_start + 1
+                && path_finder
+                    .find_walkable_path(map, route[segment_start], route[segment_end])
+                    .is_none()
+            {
+                segment_end -= 1;
+            }
+
+            self.waypoints.push_back(route[segment_end]);
+            segment_start = segment_end;
+        }
+
+        self.next_waypoint()
+    }
+
+    /// Drop the remaining route.
+    pub fn cancel(&mut self) {
+        self.waypoints.clear();
+        self.current_waypoint = None;
+        self.awaiting_confirmation = false;
+    }
+
+    /// Returns true while the player is following a route.
+    pub fn is_active(&self) -> bool {
+        self.current_waypoint.is_some()
+    }
+
+    /// Must be called when the server confirmed a player movement.
+    pub fn movement_confirmed(&mut self) {
+        self.awaiting_confirmation = false;
+    }
+
+    /// Returns the next waypoint that should be sent to the server once the
+    /// player arrived at the current one. If the player stopped anywhere else,
+    /// the route is dropped.
+    pub fn update(&mut self, player_position: TilePosition, is_moving: bool) -> Option<TilePosition> {
+        let current_waypoint = self.current_waypoint?;
+
+        if self.awaiting_confirmation || is_moving {
+            return None;
+        }
+
+        if player_position != current_waypoint {
+            self.cancel();
+            return None;
+        }
+
+        self.next_waypoint()
+    }
+
+    fn next_waypoint(&mut self) -> Option<TilePosition> {
+        self.current_waypoint = self.waypoints.pop_front();
+        self.awaiting_confirmation = self.current_waypoint.is_some();
+        self.current_waypoint
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -421,4 +527,66 @@ mod tests {
 
         assert!(pathfinder.find_snipable_path(&map, start, goal).is_none());
     }
+
+    #[test]
+    fn test_long_route() {
+        let map = TestMap::new(100, 10);
+        let mut pathfinder = PathFinder::default();
+
+        let start = TilePosition { x: 0, y: 0 };
+        let goal = TilePosition { x: 99, y: 0 };
+
+        assert!(pathfinder.find_walkable_path(&map, start, goal).is_none());
+
+        let route = pathfinder.find_route(&map, start, goal).unwrap();
+        assert_eq!(route.len(), 100);
+    }
+
+    #[test]
+    fn test_route_segments() {
+        let mut map = TestMap::new(100, 20);
+        // Add a wall with a single gap, so that the route isn't a straight line.
+        map.set_unwalkable(&(0..19).map(|y| TilePosition { x: 50, y }).collect::<Vec<_>>());
+
+        let mut pathfinder = PathFinder::default();
+        let mut route_planner = RoutePlanner::default();
+
+        let start = TilePosition { x: 0, y: 0 };
+        let goal = TilePosition { x: 99, y: 0 };
+
+        let mut position = start;
+        let mut waypoint = route_planner.plan(&map, &mut pathfinder, start, goal);
+
+        while let Some(next) = waypoint {
+            // Every segment must be a path the server accepts.
+            let path = pathfinder.find_walkable_path(&map, position, next).unwrap();
+            assert!(path.len() <= MAX_WALK_PATH_SIZE);
+
+            route_planner.movement_confirmed();
+            assert_eq!(route_planner.update(position, true), None);
+
+            position = next;
+            waypoint = route_planner.update(position, false);
+        }
+
+        assert_eq!(position, goal);
+    }
+
+    #[test]
+    fn test_route_cancelled_when_stopped_early() {
+        let map = TestMap::new(100, 10);
+        let mut pathfinder = PathFinder::default();
+        let mut route_planner = RoutePlanner::default();
+
+        let start = TilePosition { x: 0, y: 0 };
+        let goal = TilePosition { x: 99, y: 0 };
+
+        assert!(route_planner.plan(&map, &mut pathfinder, start, goal).is_some());
+
+        route_planner.movement_confirmed();
+
+        assert_eq!(route_planner.update(TilePosition { x: 5, y: 0 }, false), None);
+        assert_eq!(route_planner.update(TilePosition { x: 5, y: 0 }, false), None);
+        assert!(route_planner.current_waypoint.is_none());
+    }
 }
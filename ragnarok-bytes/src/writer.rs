@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use encoding_rs::{EUC_KR, Encoding};
 
 use crate::{ConversionError, ConversionErrorType, ConversionResult};
@@ -5,9 +7,15 @@ use crate::{ConversionError, ConversionErrorType, ConversionResult};
 /// A writer of bytes into a [`Vec<u8>`].
 ///
 /// used in conjunction with the [`ToBytes`] trait.
+///
+/// Just like the [`ByteReader`](crate::ByteReader), the writer carries
+/// metadata about the write operation (for example a version). The metadata
+/// defaults to `()` and can be replaced with
+/// [`set_metadata`](ByteWriter::set_metadata).
 pub struct ByteWriter {
     data: Vec<u8>,
     encoding: &'static Encoding,
+    metadata: Box<dyn Any + Send + Sync>,
 }
 
 impl Default for ByteWriter {
@@ -22,6 +30,7 @@ impl ByteWriter {
         Self {
             data: Vec::default(),
             encoding: EUC_KR,
+            metadata: Box::new(()),
         }
     }
 
@@ -31,9 +40,40 @@ impl ByteWriter {
         Self {
             data: Vec::default(),
             encoding,
+            metadata: Box::new(()),
         }
     }
 
+    /// Replaces the metadata of the writer.
+    pub fn set_metadata<Meta>(&mut self, metadata: Meta)
+    where
+        Meta: Any + Send + Sync,
+    {
+        self.metadata = Box::new(metadata);
+    }
+
+    pub fn get_metadata<Caller, As>(&self) -> ConversionResult<&As>
+    where
+        As: Any + 'static,
+    {
+        self.metadata.downcast_ref::<As>().ok_or_else(|| {
+            ConversionError::from_error_type(ConversionErrorType::IncorrectMetadata {
+                type_name: std::any::type_name::<Caller>(),
+            })
+        })
+    }
+
+    pub fn get_metadata_mut<Caller, As>(&mut self) -> ConversionResult<&mut As>
+    where
+        As: Any + 'static,
+    {
+        self.metadata.downcast_mut::<As>().ok_or_else(|| {
+            ConversionError::from_error_type(ConversionErrorType::IncorrectMetadata {
+                type_name: std::any::type_name::<Caller>(),
+            })
+        })
+    }
+
     /// Executes the given write function and returns the count of bytes
     /// written.
     pub fn write_counted(&mut self, write: impl FnOnce(&mut Self) -> ConversionResult<()>) -> ConversionResult<usize> {
@@ -114,3 +154,35 @@ impl ByteWriter {
         self.data.clear();
     }
 }
+
+#[cfg(test)]
+mod metadata {
+    use crate::ByteWriter;
+
+    #[test]
+    fn get_metadata() {
+        let byte_writer = ByteWriter::new();
+
+        assert!(byte_writer.get_metadata::<(), ()>().is_ok());
+        assert!(byte_writer.get_metadata::<(), u32>().is_err());
+    }
+
+    #[test]
+    fn set_metadata() {
+        let mut byte_writer = ByteWriter::new();
+        byte_writer.set_metadata(9i32);
+
+        assert_eq!(byte_writer.get_metadata::<(), i32>(), Ok(&9));
+        assert!(byte_writer.get_metadata::<(), ()>().is_err());
+    }
+
+    #[test]
+    fn get_metadata_mut() {
+        let mut byte_writer = ByteWriter::new();
+        byte_writer.set_metadata(9i32);
+
+        *byte_writer.get_metadata_mut::<(), i32>().unwrap() = 4;
+
+        assert_eq!(byte_writer.get_metadata::<(), i32>(), Ok(&4));
+    }
+}
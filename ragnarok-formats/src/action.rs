@@ -4,7 +4,7 @@ use ragnarok_bytes::ByteConvertable;
 use crate::signature::Signature;
 use crate::version::{MinorFirst, Version};
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct SpriteClip {
     pub position: Vector2<i32>,
@@ -24,7 +24,7 @@ pub struct SpriteClip {
     pub size: Option<Vector2<u32>>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct AttachPoint {
     pub ignored: u32,
@@ -32,7 +32,7 @@ pub struct AttachPoint {
     pub attribute: u32,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct Motion {
     pub range1: [i32; 4], // maybe just skip this?
@@ -50,7 +50,7 @@ pub struct Motion {
     pub attach_points: Vec<AttachPoint>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct Action {
     #[new_derive]
@@ -59,14 +59,14 @@ pub struct Action {
     pub motions: Vec<Motion>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct Event {
     #[length(40)]
     pub name: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ActionsData {
     #[new_default]
@@ -88,3 +88,77 @@ pub struct ActionsData {
     #[repeating(action_count)]
     pub delays: Option<Vec<f32>>,
 }
+
+#[cfg(test)]
+mod conversion {
+    use cgmath::Vector2;
+
+    use super::{Action, ActionsData, AttachPoint, Event, Motion, SpriteClip};
+    use crate::round_trip::assert_round_trip;
+    use crate::version::{InternalVersion, Version};
+
+    const VERSIONS: &[(u8, u8)] = &[(1, 0), (2, 0), (2, 1), (2, 2), (2, 3), (2, 4), (2, 5)];
+
+    fn actions_data(major: u8, minor: u8) -> ActionsData {
+        let version = InternalVersion { major, minor };
+        let sprite_clip = SpriteClip {
+            position: Vector2::new(-12, 7),
+            sprite_number: 3,
+            mirror_on: 1,
+            color: version.equals_or_above(2, 0).then_some(0xFF00FFFF),
+            zoom: version.smaller(2, 4).then_some(1.5),
+            zoom2: version.equals_or_above(2, 4).then_some(Vector2::new(1.0, 0.5)),
+            angle: version.equals_or_above(2, 0).then_some(45),
+            sprite_type: version.equals_or_above(2, 0).then_some(1),
+            size: version.equals_or_above(2, 5).then_some(Vector2::new(32, 48)),
+        };
+        let attach_points = match version.equals_or_above(2, 3) {
+            true => vec![AttachPoint {
+                ignored: 0,
+                position: Vector2::new(1, -1),
+                attribute: 2,
+            }],
+            false => Vec::new(),
+        };
+        let motion = Motion {
+            range1: [0, 1, 2, 3],
+            range2: [-3, -2, -1, 0],
+            sprite_clip_count: 1,
+            sprite_clips: vec![sprite_clip],
+            event_id: version.equals_or_above(2, 0).then_some(-1),
+            attach_point_count: version.equals_or_above(2, 3).then_some(attach_points.len() as u32),
+            attach_points,
+        };
+        let events = match version.equals_or_above(2, 1) {
+            true => vec![Event { name: "atk".to_owned() }],
+            false => Vec::new(),
+        };
+
+        ActionsData {
+            signature: Default::default(),
+            version: Version::new(major, minor),
+            action_count: 2,
+            reserved: [0; 10],
+            actions: vec![
+                Action {
+                    motion_count: 1,
+                    motions: vec![motion],
+                },
+                Action {
+                    motion_count: 0,
+                    motions: Vec::new(),
+                },
+            ],
+            event_count: version.equals_or_above(2, 1).then_some(events.len() as u32),
+            events,
+            delays: version.equals_or_above(2, 2).then(|| vec![4.0, 1.5]),
+        }
+    }
+
+    #[test]
+    fn actions_data_versions() {
+        for (major, minor) in VERSIONS.iter().copied() {
+            assert_round_trip(&actions_data(major, minor), None);
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use ragnarok_bytes::{ByteConvertable, FixedByteSize};
+use ragnarok_bytes::{
+    ByteConvertable, ByteReader, ByteWriter, ConversionError, ConversionResult, ConversionResultExt, FixedByteSize, FromBytes,
+    FromBytesExt, ToBytes,
+};
 
 use crate::signature::Signature;
 
@@ -17,9 +20,29 @@ pub struct Header {
 
 impl Header {
     pub const FILE_OFFSET: usize = 7;
+    /// Version 0x300 uses a 64 bit file table offset, which takes the place of
+    /// the reserved files.
+    pub const LARGE_OFFSET_VERSION: u32 = 0x300;
 
-    pub fn get_file_count(&self) -> usize {
-        (self.file_count - self.reserved_files) as usize - Self::FILE_OFFSET
+    pub fn get_file_table_offset(&self) -> u64 {
+        match self.version {
+            Self::LARGE_OFFSET_VERSION => (u64::from(self.reserved_files) << 32) | u64::from(self.file_table_offset),
+            _ => u64::from(self.file_table_offset),
+        }
+    }
+
+    /// Returns an error if the header is corrupt and the file count is smaller
+    /// than the number of reserved files.
+    pub fn get_file_count(&self) -> ConversionResult<usize> {
+        let reserved_files = match self.version {
+            Self::LARGE_OFFSET_VERSION => 0,
+            _ => self.reserved_files,
+        };
+
+        self.file_count
+            .checked_sub(reserved_files)
+            .and_then(|file_count| (file_count as usize).checked_sub(Self::FILE_OFFSET))
+            .ok_or_else(|| ConversionError::from_message("invalid file count"))
     }
 }
 
@@ -34,9 +57,266 @@ pub struct FileTableRow {
     pub offset: u32,
 }
 
+/// Same as [`FileTableRow`] but used by GRFs of version 0x300, which can be
+/// larger than 4 GiB.
+#[derive(Clone, Debug, ByteConvertable)]
+pub struct LargeFileTableRow {
+    pub file_name: String,
+    pub compressed_size: u32,
+    pub compressed_size_aligned: u32,
+    pub uncompressed_size: u32,
+    pub flags: u8,
+    pub offset: u64,
+}
+
 /// Stores the table of files the parent GRF is holding.
 #[derive(Clone, ByteConvertable, FixedByteSize)]
 pub struct AssetTable {
     pub compressed_size: u32,
     pub uncompressed_size: u32,
 }
+
+/// Header of a Thor patch file.
+#[derive(Clone, Debug, ByteConvertable)]
+pub struct ThorHeader {
+    #[new_default]
+    pub signature: Signature<b"ASSF (C) 2007 Aeomin DEV">,
+    /// If set, the files are merged into the target GRF instead of being
+    /// written to the client folder.
+    pub use_grf_merging: u8,
+    pub file_count: u32,
+    pub mode: i16,
+    pub target_grf_name_length: u8,
+    #[length(target_grf_name_length)]
+    pub target_grf_name: String,
+}
+
+impl ThorHeader {
+    /// The patch contains a compressed file table.
+    pub const MULTIPLE_FILES_MODE: i16 = 0x21;
+    /// The patch contains a single file directly after the header.
+    pub const SINGLE_FILE_MODE: i16 = 0x30;
+}
+
+/// The single file entry of a Thor patch in single file mode. The file data
+/// follows directly after it.
+#[derive(Clone, Debug, ByteConvertable)]
+pub struct ThorSingleFileEntry {
+    pub compressed_size: u32,
+    pub uncompressed_size: u32,
+    pub file_name_length: u8,
+    #[length(file_name_length)]
+    pub file_name: String,
+}
+
+/// Location of the compressed file table of a Thor patch in multiple files
+/// mode. Follows directly after the [`ThorHeader`].
+#[derive(Clone, Debug, ByteConvertable, FixedByteSize)]
+pub struct ThorFileTable {
+    pub compressed_size: u32,
+    pub offset: u32,
+}
+
+/// Location of a file inside of a Thor patch.
+#[derive(Clone, Debug, ByteConvertable, FixedByteSize)]
+pub struct ThorFileLocation {
+    pub offset: u32,
+    pub compressed_size: u32,
+    pub uncompressed_size: u32,
+}
+
+/// Single row of the file table of a Thor patch.
+#[derive(Clone, Debug)]
+pub struct ThorFileTableRow {
+    pub file_name: String,
+    pub flags: u8,
+    /// Location of the file data. [`None`] if the file is removed by the
+    /// patch.
+    pub location: Option<ThorFileLocation>,
+}
+
+impl ThorFileTableRow {
+    /// The file is removed from the target instead of being added.
+    pub const FLAG_REMOVE: u8 = 0x01;
+
+    pub fn is_removed(&self) -> bool {
+        self.flags & Self::FLAG_REMOVE != 0
+    }
+}
+
+impl FromBytes for ThorFileTableRow {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let file_name_length = u8::from_bytes(byte_reader).trace::<Self>()?;
+        let file_name = String::from_n_bytes(byte_reader, file_name_length as usize).trace::<Self>()?;
+        let flags = u8::from_bytes(byte_reader).trace::<Self>()?;
+
+        let location = match flags & Self::FLAG_REMOVE {
+            0 => Some(ThorFileLocation::from_bytes(byte_reader).trace::<Self>()?),
+            _ => None,
+        };
+
+        Ok(Self {
+            file_name,
+            flags,
+            location,
+        })
+    }
+}
+
+impl ToBytes for ThorFileTableRow {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        byte_writer.write_counted(|writer| {
+            let mut file_name_writer = ByteWriter::new();
+            file_name_writer.encode_string(&self.file_name);
+            // Names in Thor files are not zero terminated.
+            file_name_writer.pop();
+            let file_name = file_name_writer.into_inner();
+
+            let file_name_length = u8::try_from(file_name.len()).map_err(|_| ConversionError::from_message("file name too long"))?;
+
+            file_name_length.to_bytes(writer).trace::<Self>()?;
+            writer.extend_from_slice(&file_name);
+            self.flags.to_bytes(writer).trace::<Self>()?;
+
+            if let Some(location) = &self.location {
+                location.to_bytes(writer).trace::<Self>()?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Single entry of a gzip compressed RGZ patch.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RgzEntry {
+    File { file_name: String, data: Vec<u8> },
+    Directory { file_name: String },
+    End,
+}
+
+impl RgzEntry {
+    const DIRECTORY: u8 = b'd';
+    const END: u8 = b'e';
+    const FILE: u8 = b'f';
+}
+
+impl FromBytes for RgzEntry {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let entry_type = u8::from_bytes(byte_reader).trace::<Self>()?;
+        let file_name_length = u8::from_bytes(byte_reader).trace::<Self>()?;
+        let file_name = String::from_n_bytes(byte_reader, file_name_length as usize).trace::<Self>()?;
+
+        match entry_type {
+            Self::FILE => {
+                let size = u32::from_bytes(byte_reader).trace::<Self>()?;
+                let data = byte_reader.slice::<Self>(size as usize)?.to_vec();
+
+                Ok(Self::File { file_name, data })
+            }
+            Self::DIRECTORY => Ok(Self::Directory { file_name }),
+            Self::END => Ok(Self::End),
+            _ => Err(ConversionError::from_message("invalid rgz entry type")),
+        }
+    }
+}
+
+impl ToBytes for RgzEntry {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        byte_writer.write_counted(|writer| {
+            let (entry_type, file_name) = match self {
+                Self::File { file_name, .. } => (Self::FILE, file_name.as_str()),
+                Self::Directory { file_name } => (Self::DIRECTORY, file_name.as_str()),
+                Self::End => (Self::END, "end"),
+            };
+
+            let mut file_name_writer = ByteWriter::new();
+            file_name_writer.encode_string(file_name);
+            let file_name = file_name_writer.into_inner();
+
+            let file_name_length = u8::try_from(file_name.len()).map_err(|_| ConversionError::from_message("file name too long"))?;
+
+            entry_type.to_bytes(writer).trace::<Self>()?;
+            file_name_length.to_bytes(writer).trace::<Self>()?;
+            writer.extend_from_slice(&file_name);
+
+            if let Self::File { data, .. } = self {
+                (data.len() as u32).to_bytes(writer).trace::<Self>()?;
+                writer.extend_from_slice(data);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes, ToBytes};
+
+    use super::{Header, RgzEntry, ThorFileTableRow};
+
+    fn encode_decode<T: FromBytes + ToBytes>(input: &[u8]) {
+        let mut byte_reader = ByteReader::without_metadata(input);
+
+        let data = T::from_bytes(&mut byte_reader).unwrap();
+
+        let mut byte_writer = ByteWriter::new();
+        data.to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(input, byte_writer.into_inner().as_slice());
+    }
+
+    #[test]
+    fn header_file_table_offset() {
+        let header = Header::new(0x10, 0x2, 107, 0x200);
+        assert_eq!(header.get_file_table_offset(), 0x10);
+
+        // The reserved files hold the upper 32 bits of the offset.
+        let header = Header::new(0x10, 0x2, 107, Header::LARGE_OFFSET_VERSION);
+        assert_eq!(header.get_file_table_offset(), 0x2_0000_0010);
+    }
+
+    #[test]
+    fn header_file_count() {
+        assert_eq!(Header::new(0, 2, 107, 0x200).get_file_count().unwrap(), 98);
+        assert_eq!(
+            Header::new(0, 2, 107, Header::LARGE_OFFSET_VERSION).get_file_count().unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn header_file_count_corrupt() {
+        assert!(Header::new(0, 10, 5, 0x200).get_file_count().is_err());
+        assert!(Header::new(0, 0, 3, 0x200).get_file_count().is_err());
+    }
+
+    #[test]
+    fn thor_file_table_row() {
+        encode_decode::<ThorFileTableRow>(&[
+            8, b'd', b'a', b't', b'a', b'\\', b'a', b'.', b'b', 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn thor_file_table_row_removed() {
+        let input = &[8, b'd', b'a', b't', b'a', b'\\', b'a', b'.', b'b', 1];
+        let row = ThorFileTableRow::from_bytes(&mut ByteReader::without_metadata(input)).unwrap();
+
+        assert!(row.is_removed());
+        assert!(row.location.is_none());
+
+        encode_decode::<ThorFileTableRow>(input);
+    }
+
+    #[test]
+    fn rgz_file_entry() {
+        encode_decode::<RgzEntry>(&[b'f', 4, b'a', b'.', b'b', 0, 3, 0, 0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn rgz_directory_entry() {
+        encode_decode::<RgzEntry>(&[b'd', 5, b'd', b'a', b't', b'a', 0]);
+    }
+}
@@ -4,14 +4,14 @@ use ragnarok_bytes::ByteConvertable;
 use crate::signature::Signature;
 use crate::version::{MajorFirst, Version};
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct TextureName {
     #[length(128)]
     pub name: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct Frame {
     pub frame_index: i32,
@@ -29,7 +29,7 @@ pub struct Frame {
     pub mt_present: i32,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct LayerData {
     #[new_derive]
@@ -42,7 +42,7 @@ pub struct LayerData {
     pub frames: Vec<Frame>,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct EffectData {
     #[new_default]
@@ -60,3 +60,58 @@ pub struct EffectData {
     #[repeating(layer_count)]
     pub layers: Vec<LayerData>,
 }
+
+#[cfg(test)]
+mod conversion {
+    use cgmath::Vector2;
+
+    use super::{EffectData, Frame, LayerData, TextureName};
+    use crate::round_trip::assert_round_trip;
+    use crate::version::Version;
+
+    #[test]
+    fn effect_data() {
+        let frame = |frame_index| Frame {
+            frame_index,
+            frame_type: 1,
+            offset: Vector2::new(320.0, 290.0),
+            uv: [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            xy: [-10.0, -10.0, 10.0, -10.0, 10.0, 10.0, -10.0, 10.0],
+            texture_index: 0.0,
+            animation_type: 0,
+            delay: 0.5,
+            angle: 90.0,
+            color: [255.0, 255.0, 255.0, 128.0],
+            source_blend_factor: 5,
+            destination_blend_factor: 2,
+            mt_present: 0,
+        };
+        let effect_data = EffectData {
+            signature: Default::default(),
+            version: Version::new(148, 0),
+            _skip0: [0; 2],
+            frames_per_second: 60,
+            max_key: 30,
+            layer_count: 2,
+            _skip1: [0; 16],
+            layers: vec![
+                LayerData {
+                    texture_count: 1,
+                    texture_names: vec![TextureName {
+                        name: "effect.bmp".to_owned(),
+                    }],
+                    frame_count: 2,
+                    frames: vec![frame(0), frame(29)],
+                },
+                LayerData {
+                    texture_count: 0,
+                    texture_names: Vec::new(),
+                    frame_count: 0,
+                    frames: Vec::new(),
+                },
+            ],
+        };
+
+        assert_round_trip(&effect_data, None);
+    }
+}
@@ -0,0 +1,2090 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cgmath::{Matrix3, Matrix4, Point3, Quaternion, SquareMatrix, Vector2, Vector3};
+use ragnarok_bytes::{ByteConvertable, ByteReader, ConversionError, ConversionResult, ConversionResultExt, FixedByteSize, FromBytes};
+
+/// Signatures of little endian Granny files with 32 bit pointers. The first
+/// one is used by file format version 6, the other two by version 7.
+const LITTLE_ENDIAN_32_SIGNATURES: [[u8; 16]; 3] = [
+    [
+        0xB8, 0x67, 0xB0, 0xCA, 0xF8, 0x6D, 0xB1, 0x0F, 0x84, 0x72, 0x8C, 0x7E, 0x5E, 0x19, 0x00, 0x1E,
+    ],
+    [
+        0x29, 0xDE, 0x6C, 0xC0, 0xBA, 0xA4, 0x53, 0x2B, 0x25, 0xF5, 0xB7, 0xA5, 0xF6, 0x66, 0xE2, 0xEE,
+    ],
+    [
+        0x29, 0x75, 0x31, 0x82, 0xBA, 0x02, 0x11, 0x77, 0x25, 0x3A, 0x60, 0x2F, 0xF6, 0x6A, 0x8C, 0x2E,
+    ],
+];
+
+/// Offset of the first field after the file signature block. Offsets to the
+/// section headers are relative to it.
+const SIGNATURE_BLOCK_SIZE: usize = 32;
+
+/// Size of a single member definition inside a type definition.
+const MEMBER_DEFINITION_SIZE: u32 = 32;
+
+/// Size of a transform member (flags, position, orientation and scale shear).
+const TRANSFORM_SIZE: u32 = 68;
+
+/// Upper bound for nested inline types, so that malformed files can't send us
+/// into an endless recursion.
+const MAXIMUM_TYPE_DEPTH: usize = 32;
+
+/// Reference to a location inside one of the sections of a Granny file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ByteConvertable, FixedByteSize)]
+pub struct SectionReference {
+    pub section: u32,
+    pub offset: u32,
+}
+
+impl SectionReference {
+    fn add(self, offset: u32) -> ConversionResult<Self> {
+        let offset = self
+            .offset
+            .checked_add(offset)
+            .ok_or_else(|| ConversionError::from_message("granny offset overflows"))?;
+
+        Ok(Self {
+            section: self.section,
+            offset,
+        })
+    }
+}
+
+fn checked_mul(left: u32, right: u32) -> ConversionResult<u32> {
+    left.checked_mul(right)
+        .ok_or_else(|| ConversionError::from_message("granny size overflows"))
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+pub struct GrannyFileHeader {
+    pub signature: [u8; 16],
+    pub header_size: u32,
+    pub header_format: u32,
+    pub reserved: [u32; 2],
+    pub version: u32,
+    pub file_size: u32,
+    pub crc: u32,
+    pub section_offset: u32,
+    pub section_count: u32,
+    pub root_type: SectionReference,
+    pub root_object: SectionReference,
+    pub type_tag: u32,
+    pub extra_tags: [u32; 4],
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+pub struct GrannySectionHeader {
+    pub compression: u32,
+    pub data_offset: u32,
+    pub data_size: u32,
+    pub decompressed_size: u32,
+    pub alignment: u32,
+    pub first_16_bit: u32,
+    pub first_8_bit: u32,
+    pub relocation_offset: u32,
+    pub relocation_count: u32,
+    pub marshalling_offset: u32,
+    pub marshalling_count: u32,
+}
+
+impl GrannySectionHeader {
+    pub const NO_COMPRESSION: u32 = 0;
+}
+
+/// Pointer inside of a section. Pointers are stored as zero in the file and
+/// need to be resolved through these relocations.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+pub struct GrannyRelocation {
+    pub offset: u32,
+    pub target: SectionReference,
+}
+
+/// Types of members in the type definitions stored inside Granny files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberType {
+    Inline,
+    Reference,
+    ReferenceToArray,
+    ArrayOfReferences,
+    VariantReference,
+    ReferenceToVariantArray,
+    String,
+    Transform,
+    Real32,
+    Int8,
+    UInt8,
+    BinormalInt8,
+    NormalUInt8,
+    Int16,
+    UInt16,
+    BinormalInt16,
+    NormalUInt16,
+    Int32,
+    UInt32,
+    Real16,
+    EmptyReference,
+}
+
+impl MemberType {
+    /// Returns `None` for the member type that marks the end of a type
+    /// definition.
+    fn from_raw(raw: u32) -> ConversionResult<Option<Self>> {
+        let member_type = match raw {
+            0 => return Ok(None),
+            1 => Self::Inline,
+            2 => Self::Reference,
+            3 => Self::ReferenceToArray,
+            4 => Self::ArrayOfReferences,
+            5 => Self::VariantReference,
+            7 => Self::ReferenceToVariantArray,
+            8 => Self::String,
+            9 => Self::Transform,
+            10 => Self::Real32,
+            11 => Self::Int8,
+            12 => Self::UInt8,
+            13 => Self::BinormalInt8,
+            14 => Self::NormalUInt8,
+            15 => Self::Int16,
+            16 => Self::UInt16,
+            17 => Self::BinormalInt16,
+            18 => Self::NormalUInt16,
+            19 => Self::Int32,
+            20 => Self::UInt32,
+            21 => Self::Real16,
+            22 => Self::EmptyReference,
+            invalid => return Err(ConversionError::from_message(format!("invalid granny member type {invalid}"))),
+        };
+
+        Ok(Some(member_type))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Member {
+    member_type: MemberType,
+    name: String,
+    definition: Option<SectionReference>,
+    array_size: u32,
+    offset: u32,
+}
+
+/// Layout of a type definition with the offsets of all members.
+#[derive(Debug)]
+struct Layout {
+    members: Vec<Member>,
+    size: u32,
+}
+
+/// Decoded sections of a Granny file together with the pointer relocations.
+struct GrannyFile {
+    sections: Vec<Vec<u8>>,
+    relocations: HashMap<SectionReference, SectionReference>,
+    layouts: RefCell<HashMap<SectionReference, Rc<Layout>>>,
+}
+
+impl GrannyFile {
+    fn from_bytes(bytes: &[u8]) -> ConversionResult<(Self, GrannyFileHeader)> {
+        let mut byte_reader = ByteReader::without_metadata(bytes);
+        let header = GrannyFileHeader::from_bytes(&mut byte_reader).trace::<GrannyData>()?;
+
+        if !LITTLE_ENDIAN_32_SIGNATURES.contains(&header.signature) {
+            return Err(ConversionError::from_message(
+                "unsupported granny file, only little endian files with 32 bit pointers are supported",
+            ));
+        }
+
+        let section_headers_offset = SIGNATURE_BLOCK_SIZE + header.section_offset as usize;
+        let section_header_bytes = bytes.get(section_headers_offset..).unwrap_or_default();
+
+        if checked_mul(header.section_count, GrannySectionHeader::size_in_bytes() as u32)? as usize > section_header_bytes.len() {
+            return Err(ConversionError::from_message("granny section headers are out of bounds"));
+        }
+
+        let mut byte_reader = ByteReader::without_metadata(section_header_bytes);
+        let section_headers = (0..header.section_count)
+            .map(|_| GrannySectionHeader::from_bytes(&mut byte_reader).trace::<GrannyData>())
+            .collect::<ConversionResult<Vec<_>>>()?;
+
+        let mut sections = Vec::with_capacity(section_headers.len());
+        let mut relocations = HashMap::new();
+
+        for (section_index, section_header) in section_headers.iter().enumerate() {
+            if section_header.data_size > 0 && section_header.compression != GrannySectionHeader::NO_COMPRESSION {
+                return Err(ConversionError::from_message(format!(
+                    "compressed granny sections are not supported (compression {})",
+                    section_header.compression
+                )));
+            }
+
+            // Uncompressed sections are stored as they are, so they can't grow when
+            // decompressing them.
+            if section_header.decompressed_size > section_header.data_size {
+                return Err(ConversionError::from_message(
+                    "uncompressed granny section is larger than its data",
+                ));
+            }
+
+            let start = section_header.data_offset;
+            let end = start
+                .checked_add(section_header.data_size)
+                .ok_or_else(|| ConversionError::from_message("granny section is out of bounds"))?;
+            let data = bytes
+                .get(start as usize..end as usize)
+                .ok_or_else(|| ConversionError::from_message("granny section is out of bounds"))?
+                .to_vec();
+
+            sections.push(data);
+
+            let relocation_bytes = bytes.get(section_header.relocation_offset as usize..).unwrap_or_default();
+
+            if checked_mul(section_header.relocation_count, GrannyRelocation::size_in_bytes() as u32)? as usize > relocation_bytes.len() {
+                return Err(ConversionError::from_message("granny relocations are out of bounds"));
+            }
+
+            let mut byte_reader = ByteReader::without_metadata(relocation_bytes);
+
+            for _ in 0..section_header.relocation_count {
+                let relocation = GrannyRelocation::from_bytes(&mut byte_reader).trace::<GrannyData>()?;
+                let source = SectionReference {
+                    section: section_index as u32,
+                    offset: relocation.offset,
+                };
+
+                relocations.insert(source, relocation.target);
+            }
+        }
+
+        let file = Self {
+            sections,
+            relocations,
+            layouts: RefCell::new(HashMap::new()),
+        };
+
+        Ok((file, header))
+    }
+
+    fn bytes<const SIZE: usize>(&self, address: SectionReference) -> ConversionResult<[u8; SIZE]> {
+        let start = address.offset as usize;
+
+        self.sections
+            .get(address.section as usize)
+            .and_then(|section| section.get(start..start.checked_add(SIZE)?))
+            .map(|bytes| bytes.try_into().unwrap())
+            .ok_or_else(|| ConversionError::from_message("granny data is out of bounds"))
+    }
+
+    fn u32(&self, address: SectionReference) -> ConversionResult<u32> {
+        self.bytes(address).map(u32::from_le_bytes)
+    }
+
+    fn i32(&self, address: SectionReference) -> ConversionResult<i32> {
+        self.bytes(address).map(i32::from_le_bytes)
+    }
+
+    fn f32(&self, address: SectionReference) -> ConversionResult<f32> {
+        self.bytes(address).map(f32::from_le_bytes)
+    }
+
+    fn pointer(&self, address: SectionReference) -> Option<SectionReference> {
+        self.relocations.get(&address).copied()
+    }
+
+    fn string(&self, address: SectionReference) -> ConversionResult<String> {
+        let bytes = self
+            .sections
+            .get(address.section as usize)
+            .and_then(|section| section.get(address.offset as usize..))
+            .ok_or_else(|| ConversionError::from_message("granny string is out of bounds"))?;
+
+        String::from_bytes(&mut ByteReader::without_metadata(bytes)).trace::<GrannyData>()
+    }
+
+    /// Makes sure that `count` elements of `element_size` bytes starting at
+    /// `address` fit into the section, so malformed counts can't make us
+    /// allocate large amounts of memory.
+    fn check_array(&self, address: SectionReference, count: u32, element_size: u32) -> ConversionResult<()> {
+        let size = checked_mul(count, element_size)? as usize;
+        let remaining = self
+            .sections
+            .get(address.section as usize)
+            .and_then(|section| section.len().checked_sub(address.offset as usize));
+
+        match remaining {
+            Some(remaining) if size <= remaining => Ok(()),
+            _ => Err(ConversionError::from_message("granny array is out of bounds")),
+        }
+    }
+
+    fn layout(&self, definition: SectionReference) -> ConversionResult<Rc<Layout>> {
+        self.layout_with_depth(definition, 0)
+    }
+
+    fn layout_with_depth(&self, definition: SectionReference, depth: usize) -> ConversionResult<Rc<Layout>> {
+        if let Some(layout) = self.layouts.borrow().get(&definition) {
+            return Ok(layout.clone());
+        }
+
+        if depth > MAXIMUM_TYPE_DEPTH {
+            return Err(ConversionError::from_message("granny type definitions are nested too deep"));
+        }
+
+        let mut members = Vec::new();
+        let mut offset = 0;
+
+        for index in 0.. {
+            let address = definition.add(checked_mul(index, MEMBER_DEFINITION_SIZE)?)?;
+
+            let Some(member_type) = MemberType::from_raw(self.u32(address)?)? else {
+                break;
+            };
+
+            let name = match self.pointer(address.add(4)?) {
+                Some(name_address) => self.string(name_address)?,
+                None => String::new(),
+            };
+            let definition = self.pointer(address.add(8)?);
+            let array_size = self.u32(address.add(12)?)?;
+
+            let size = match member_type {
+                MemberType::Inline => {
+                    let definition = definition.ok_or_else(|| ConversionError::from_message("inline granny member without a type"))?;
+                    self.layout_with_depth(definition, depth + 1)?.size
+                }
+                MemberType::Reference | MemberType::String | MemberType::EmptyReference => 4,
+                MemberType::ReferenceToArray | MemberType::ArrayOfReferences | MemberType::VariantReference => 8,
+                MemberType::ReferenceToVariantArray => 12,
+                MemberType::Transform => TRANSFORM_SIZE,
+                MemberType::Real32 | MemberType::Int32 | MemberType::UInt32 => 4,
+                MemberType::Int16 | MemberType::UInt16 | MemberType::BinormalInt16 | MemberType::NormalUInt16 | MemberType::Real16 => 2,
+                MemberType::Int8 | MemberType::UInt8 | MemberType::BinormalInt8 | MemberType::NormalUInt8 => 1,
+            };
+
+            members.push(Member {
+                member_type,
+                name,
+                definition,
+                array_size,
+                offset,
+            });
+
+            offset = offset
+                .checked_add(checked_mul(size, array_size.max(1))?)
+                .ok_or_else(|| ConversionError::from_message("granny type is too large"))?;
+        }
+
+        let layout = Rc::new(Layout { members, size: offset });
+        self.layouts.borrow_mut().insert(definition, layout.clone());
+
+        Ok(layout)
+    }
+
+    /// Reads `count` objects of the given type, that are stored back to back
+    /// starting at `address`.
+    fn objects(
+        &self,
+        address: Option<SectionReference>,
+        definition: Option<SectionReference>,
+        count: u32,
+    ) -> ConversionResult<Vec<Object<'_>>> {
+        let (Some(address), Some(definition)) = (address, definition) else {
+            return Ok(Vec::new());
+        };
+
+        let layout = self.layout(definition)?;
+
+        // Empty types still count as one byte, so the number of objects is always
+        // bounded by the size of the section.
+        self.check_array(address, count, layout.size.max(1))?;
+
+        (0..count)
+            .map(|index| {
+                Ok(Object {
+                    file: self,
+                    address: address.add(checked_mul(index, layout.size)?)?,
+                    layout: layout.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Instance of a type definition inside a Granny file.
+#[derive(Clone)]
+struct Object<'a> {
+    file: &'a GrannyFile,
+    address: SectionReference,
+    layout: Rc<Layout>,
+}
+
+impl<'a> Object<'a> {
+    fn member_field(&self, member: &Member) -> ConversionResult<Field<'a>> {
+        Ok(Field {
+            file: self.file,
+            member: member.clone(),
+            address: self.address.add(member.offset)?,
+        })
+    }
+
+    fn field(&self, name: &str) -> ConversionResult<Option<Field<'a>>> {
+        self.layout
+            .members
+            .iter()
+            .find(|member| member.name == name)
+            .map(|member| self.member_field(member))
+            .transpose()
+    }
+
+    fn required_field(&self, name: &str) -> ConversionResult<Field<'a>> {
+        self.field(name)?
+            .ok_or_else(|| ConversionError::from_message(format!("granny object has no member {name}")))
+    }
+
+    fn first_field(&self) -> ConversionResult<Field<'a>> {
+        let member = self
+            .layout
+            .members
+            .first()
+            .ok_or_else(|| ConversionError::from_message("granny object has no members"))?;
+
+        self.member_field(member)
+    }
+
+    fn string(&self, name: &str) -> ConversionResult<String> {
+        match self.field(name)? {
+            Some(field) => field.as_string(),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn reference(&self, name: &str) -> ConversionResult<Option<Object<'a>>> {
+        match self.field(name)? {
+            Some(field) => field.as_reference(),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns all elements of an array member. Missing members are treated
+    /// like empty arrays, since not all versions of Granny have the same
+    /// members.
+    fn array(&self, name: &str) -> ConversionResult<Vec<Object<'a>>> {
+        match self.field(name)? {
+            Some(field) => field.as_array(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Member of an [`Object`].
+struct Field<'a> {
+    file: &'a GrannyFile,
+    member: Member,
+    address: SectionReference,
+}
+
+impl<'a> Field<'a> {
+    fn expect_type(&self, member_types: &[MemberType]) -> ConversionResult<()> {
+        match member_types.contains(&self.member.member_type) {
+            true => Ok(()),
+            false => Err(ConversionError::from_message(format!(
+                "granny member {} has unexpected type {:?}",
+                self.member.name, self.member.member_type
+            ))),
+        }
+    }
+
+    fn as_string(&self) -> ConversionResult<String> {
+        self.expect_type(&[MemberType::String])?;
+
+        match self.file.pointer(self.address) {
+            Some(address) => self.file.string(address),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn as_i32(&self) -> ConversionResult<i32> {
+        Ok(self.as_numbers()?.first().copied().unwrap_or_default() as i32)
+    }
+
+    fn as_usize(&self) -> ConversionResult<usize> {
+        usize::try_from(self.as_i32()?)
+            .map_err(|_| ConversionError::from_message(format!("granny member {} must not be negative", self.member.name)))
+    }
+
+    fn as_f32(&self) -> ConversionResult<f32> {
+        Ok(self.as_numbers()?.first().copied().unwrap_or_default())
+    }
+
+    /// Reads all elements of a numeric member and converts them to floats.
+    /// Normalized integer types are mapped to the range they represent.
+    fn as_numbers(&self) -> ConversionResult<Vec<f32>> {
+        let element_size = match self.member.member_type {
+            MemberType::Real32 | MemberType::Int32 | MemberType::UInt32 => 4,
+            MemberType::Int16 | MemberType::UInt16 | MemberType::BinormalInt16 | MemberType::NormalUInt16 | MemberType::Real16 => 2,
+            MemberType::Int8 | MemberType::UInt8 | MemberType::BinormalInt8 | MemberType::NormalUInt8 => 1,
+            member_type => {
+                return Err(ConversionError::from_message(format!(
+                    "granny member {} of type {member_type:?} is not a number",
+                    self.member.name
+                )));
+            }
+        };
+
+        let count = self.member.array_size.max(1);
+        self.file.check_array(self.address, count, element_size)?;
+
+        (0..count)
+            .map(|index| {
+                let file = self.file;
+                let address = self.address.add(checked_mul(index, element_size)?)?;
+
+                match self.member.member_type {
+                    MemberType::Real32 => file.f32(address),
+                    MemberType::Int32 => file.i32(address).map(|value| value as f32),
+                    MemberType::UInt32 => file.u32(address).map(|value| value as f32),
+                    MemberType::Int16 => file.bytes(address).map(|bytes| i16::from_le_bytes(bytes) as f32),
+                    MemberType::UInt16 => file.bytes(address).map(|bytes| u16::from_le_bytes(bytes) as f32),
+                    MemberType::BinormalInt16 => file.bytes(address).map(|bytes| i16::from_le_bytes(bytes) as f32 / i16::MAX as f32),
+                    MemberType::NormalUInt16 => file.bytes(address).map(|bytes| u16::from_le_bytes(bytes) as f32 / u16::MAX as f32),
+                    MemberType::Real16 => file.bytes(address).map(|bytes| half_to_f32(u16::from_le_bytes(bytes))),
+                    MemberType::Int8 => file.bytes::<1>(address).map(|bytes| bytes[0] as i8 as f32),
+                    MemberType::UInt8 => file.bytes::<1>(address).map(|bytes| bytes[0] as f32),
+                    MemberType::BinormalInt8 => file.bytes::<1>(address).map(|bytes| bytes[0] as i8 as f32 / i8::MAX as f32),
+                    MemberType::NormalUInt8 => file.bytes::<1>(address).map(|bytes| bytes[0] as f32 / u8::MAX as f32),
+                    _ => unreachable!(),
+                }
+            })
+            .collect()
+    }
+
+    fn as_transform(&self) -> ConversionResult<GrannyTransform> {
+        self.expect_type(&[MemberType::Transform])?;
+
+        let floats = (0..16)
+            .map(|index| self.file.f32(self.address.add(4 + index * 4)?))
+            .collect::<ConversionResult<Vec<f32>>>()?;
+
+        Ok(GrannyTransform {
+            flags: self.file.u32(self.address)?,
+            position: Vector3::new(floats[0], floats[1], floats[2]),
+            orientation: Quaternion::new(floats[6], floats[3], floats[4], floats[5]),
+            scale_shear: Matrix3::new(
+                floats[7], floats[8], floats[9], floats[10], floats[11], floats[12], floats[13], floats[14], floats[15],
+            ),
+        })
+    }
+
+    fn as_inline(&self) -> ConversionResult<Object<'a>> {
+        self.expect_type(&[MemberType::Inline])?;
+
+        let definition = self
+            .member
+            .definition
+            .ok_or_else(|| ConversionError::from_message("inline granny member without a type"))?;
+
+        Ok(Object {
+            file: self.file,
+            address: self.address,
+            layout: self.file.layout(definition)?,
+        })
+    }
+
+    fn as_reference(&self) -> ConversionResult<Option<Object<'a>>> {
+        self.expect_type(&[MemberType::Reference])?;
+
+        Ok(self.file.objects(self.file.pointer(self.address), self.member.definition, 1)?.pop())
+    }
+
+    fn as_variant(&self) -> ConversionResult<Option<Object<'a>>> {
+        self.expect_type(&[MemberType::VariantReference])?;
+
+        let definition = self.file.pointer(self.address);
+        let address = self.file.pointer(self.address.add(4)?);
+
+        Ok(self.file.objects(address, definition, 1)?.pop())
+    }
+
+    fn as_array(&self) -> ConversionResult<Vec<Object<'a>>> {
+        let file = self.file;
+
+        match self.member.member_type {
+            MemberType::ReferenceToArray => {
+                let count = file.u32(self.address)?;
+                file.objects(file.pointer(self.address.add(4)?), self.member.definition, count)
+            }
+            MemberType::ArrayOfReferences => {
+                let count = file.u32(self.address)?;
+                let Some(pointers) = file.pointer(self.address.add(4)?) else {
+                    return Ok(Vec::new());
+                };
+
+                file.check_array(pointers, count, 4)?;
+
+                let mut objects = Vec::with_capacity(count as usize);
+                for index in 0..count {
+                    let pointer = pointers.add(checked_mul(index, 4)?)?;
+                    objects.extend(file.objects(file.pointer(pointer), self.member.definition, 1)?);
+                }
+
+                Ok(objects)
+            }
+            MemberType::ReferenceToVariantArray => {
+                let definition = file.pointer(self.address);
+                let count = file.u32(self.address.add(4)?)?;
+                file.objects(file.pointer(self.address.add(8)?), definition, count)
+            }
+            member_type => Err(ConversionError::from_message(format!(
+                "granny member {} of type {member_type:?} is not an array",
+                self.member.name
+            ))),
+        }
+    }
+
+    /// Reads an array of numbers. Every element is expected to be a type with
+    /// a single numeric member.
+    fn as_number_array(&self) -> ConversionResult<Vec<f32>> {
+        let mut numbers = Vec::new();
+
+        for object in self.as_array()? {
+            numbers.extend(object.first_field()?.as_numbers()?);
+        }
+
+        Ok(numbers)
+    }
+}
+
+fn half_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((half >> 10) & 0x1F) as i32;
+    let mantissa = (half & 0x3FF) as f32;
+
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        0x1F if mantissa == 0.0 => sign * f32::INFINITY,
+        0x1F => f32::NAN,
+        exponent => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+/// Transform as it is stored in Granny files.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrannyTransform {
+    pub flags: u32,
+    pub position: Vector3<f32>,
+    pub orientation: Quaternion<f32>,
+    pub scale_shear: Matrix3<f32>,
+}
+
+impl GrannyTransform {
+    pub const HAS_ORIENTATION: u32 = 0b010;
+    pub const HAS_POSITION: u32 = 0b001;
+    pub const HAS_SCALE_SHEAR: u32 = 0b100;
+
+    /// Combines position, orientation and scale shear into a single matrix.
+    /// Components that are not flagged as present are ignored.
+    pub fn to_matrix(&self) -> Matrix4<f32> {
+        let mut matrix = Matrix4::identity();
+
+        if self.flags & Self::HAS_POSITION != 0 {
+            matrix = matrix * Matrix4::from_translation(self.position);
+        }
+
+        if self.flags & Self::HAS_ORIENTATION != 0 {
+            matrix = matrix * Matrix4::from(self.orientation);
+        }
+
+        if self.flags & Self::HAS_SCALE_SHEAR != 0 {
+            matrix = matrix * Matrix4::from(self.scale_shear);
+        }
+
+        matrix
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyTexture {
+    pub file_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyMaterialMap {
+    pub usage: String,
+    pub material_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyMaterial {
+    pub name: String,
+    pub texture_index: Option<usize>,
+    pub maps: Vec<GrannyMaterialMap>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyBone {
+    pub name: String,
+    pub parent_index: Option<usize>,
+    pub local_transform: GrannyTransform,
+    pub inverse_world_matrix: Matrix4<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannySkeleton {
+    pub name: String,
+    pub bones: Vec<GrannyBone>,
+}
+
+impl GrannySkeleton {
+    /// Calculates the world matrix of every bone in the rest pose.
+    pub fn world_matrices(&self) -> Vec<Matrix4<f32>> {
+        let mut world_matrices: Vec<Matrix4<f32>> = Vec::with_capacity(self.bones.len());
+
+        for bone in &self.bones {
+            let local_matrix = bone.local_transform.to_matrix();
+
+            // Parents are always stored before their children.
+            let world_matrix = match bone.parent_index.and_then(|parent_index| world_matrices.get(parent_index)) {
+                Some(parent_matrix) => parent_matrix * local_matrix,
+                None => local_matrix,
+            };
+
+            world_matrices.push(world_matrix);
+        }
+
+        world_matrices
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyVertex {
+    pub position: Point3<f32>,
+    pub normal: Vector3<f32>,
+    pub texture_coordinates: Vector2<f32>,
+    /// Indices into the bone bindings of the mesh.
+    pub bone_indices: [u32; 4],
+    pub bone_weights: [f32; 4],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyTriangleGroup {
+    /// Index into the material bindings of the mesh.
+    pub material_index: usize,
+    pub first_triangle: usize,
+    pub triangle_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyMesh {
+    pub name: String,
+    pub vertices: Vec<GrannyVertex>,
+    /// Rigid meshes don't have bone weights. Their vertices are relative to
+    /// the first bone binding.
+    pub is_rigid: bool,
+    pub indices: Vec<u32>,
+    pub triangle_groups: Vec<GrannyTriangleGroup>,
+    /// Indices into the materials of the file.
+    pub material_bindings: Vec<Option<usize>>,
+    pub bone_bindings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyModel {
+    pub name: String,
+    pub skeleton_index: Option<usize>,
+    pub initial_placement: GrannyTransform,
+    pub mesh_indices: Vec<usize>,
+}
+
+/// Animation curve, normalized from the different curve formats Granny
+/// supports.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrannyCurve {
+    /// The curve always evaluates to the identity.
+    Identity,
+    /// The curve always evaluates to the same value.
+    Constant(Vec<f32>),
+    /// One key per time step of the animation.
+    Keyframes { dimension: usize, controls: Vec<f32> },
+    /// B-spline with explicit knots.
+    Spline { degree: u8, knots: Vec<f32>, controls: Vec<f32> },
+    /// Quantized curve formats that are not decoded yet.
+    Unsupported { format: u8 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyTransformTrack {
+    pub name: String,
+    pub orientation: GrannyCurve,
+    pub position: GrannyCurve,
+    pub scale_shear: GrannyCurve,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyTrackGroup {
+    pub name: String,
+    pub transform_tracks: Vec<GrannyTransformTrack>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyAnimation {
+    pub name: String,
+    pub duration: f32,
+    pub time_step: f32,
+    pub track_groups: Vec<GrannyTrackGroup>,
+}
+
+/// Contents of a Granny (`.gr2`) file.
+///
+/// Granny files carry the type definitions of the data they contain, so the
+/// members are looked up by name instead of relying on a fixed layout.
+/// Compressed sections are not supported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrannyData {
+    pub version: u32,
+    pub from_file_name: String,
+    pub textures: Vec<GrannyTexture>,
+    pub materials: Vec<GrannyMaterial>,
+    pub skeletons: Vec<GrannySkeleton>,
+    pub meshes: Vec<GrannyMesh>,
+    pub models: Vec<GrannyModel>,
+    pub animations: Vec<GrannyAnimation>,
+}
+
+impl GrannyData {
+    /// Returns the texture of a material, following the material maps if the
+    /// material doesn't reference a texture directly.
+    pub fn material_texture(&self, material_index: usize) -> Option<&GrannyTexture> {
+        let mut pending = vec![material_index];
+        let mut visited = Vec::new();
+
+        while let Some(material_index) = pending.pop() {
+            if visited.contains(&material_index) {
+                continue;
+            }
+            visited.push(material_index);
+
+            let material = self.materials.get(material_index)?;
+
+            if let Some(texture_index) = material.texture_index {
+                return self.textures.get(texture_index);
+            }
+
+            pending.extend(material.maps.iter().rev().filter_map(|map| map.material_index));
+        }
+
+        None
+    }
+}
+
+fn index_of(addresses: &[SectionReference], object: Option<&Object>) -> Option<usize> {
+    object.and_then(|object| addresses.iter().position(|address| *address == object.address))
+}
+
+fn parse_curve(object: &Object) -> ConversionResult<GrannyCurve> {
+    // Curves of newer Granny versions have a variant with a format header.
+    if let Some(curve_data) = object.field("CurveData")? {
+        let Some(curve_data) = curve_data.as_variant()? else {
+            return Ok(GrannyCurve::Identity);
+        };
+
+        let header = curve_data.required_field("CurveDataHeader")?.as_inline()?;
+        let format = header.required_field("Format")?.as_i32()? as u8;
+        let degree = header.required_field("Degree")?.as_i32()? as u8;
+
+        let dimension = || -> ConversionResult<usize> { curve_data.required_field("Dimension")?.as_usize() };
+        let controls = || -> ConversionResult<Vec<f32>> {
+            let field = curve_data.required_field("Controls")?;
+            match field.member.member_type {
+                MemberType::Real32 => field.as_numbers(),
+                _ => field.as_number_array(),
+            }
+        };
+
+        let curve = match format {
+            0 => GrannyCurve::Keyframes {
+                dimension: dimension()?,
+                controls: controls()?,
+            },
+            1 => GrannyCurve::Spline {
+                degree,
+                knots: curve_data.required_field("Knots")?.as_number_array()?,
+                controls: controls()?,
+            },
+            2 => GrannyCurve::Identity,
+            3..=5 => GrannyCurve::Constant(controls()?),
+            format => GrannyCurve::Unsupported { format },
+        };
+
+        return Ok(curve);
+    }
+
+    // Older versions store the knots and controls directly.
+    let degree = match object.field("Degree")? {
+        Some(field) => field.as_i32()? as u8,
+        None => 0,
+    };
+    let knots = match object.field("Knots")? {
+        Some(field) => field.as_number_array()?,
+        None => Vec::new(),
+    };
+    let controls = match object.field("Controls")? {
+        Some(field) => field.as_number_array()?,
+        None => Vec::new(),
+    };
+
+    match (knots.is_empty(), controls.is_empty()) {
+        (_, true) => Ok(GrannyCurve::Identity),
+        (true, false) => Ok(GrannyCurve::Constant(controls)),
+        (false, false) => Ok(GrannyCurve::Spline { degree, knots, controls }),
+    }
+}
+
+fn parse_vertex(object: &Object) -> ConversionResult<GrannyVertex> {
+    let numbers = |name: &str| -> ConversionResult<Vec<f32>> {
+        match object.field(name)? {
+            Some(field) => field.as_numbers(),
+            None => Ok(Vec::new()),
+        }
+    };
+    let component = |values: &[f32], index: usize| values.get(index).copied().unwrap_or_default();
+
+    let position = numbers("Position")?;
+    let normal = numbers("Normal")?;
+    let texture_coordinates = numbers("TextureCoordinates0")?;
+    let bone_weights = numbers("BoneWeights")?;
+    let bone_indices = numbers("BoneIndices")?;
+
+    // Vertices bound to a single bone may store the index without a weight.
+    let bone_weights = match bone_weights.is_empty() {
+        true => [1.0, 0.0, 0.0, 0.0],
+        false => std::array::from_fn(|index| component(&bone_weights, index)),
+    };
+
+    Ok(GrannyVertex {
+        position: Point3::new(component(&position, 0), component(&position, 1), component(&position, 2)),
+        normal: Vector3::new(component(&normal, 0), component(&normal, 1), component(&normal, 2)),
+        texture_coordinates: Vector2::new(component(&texture_coordinates, 0), component(&texture_coordinates, 1)),
+        bone_indices: std::array::from_fn(|index| component(&bone_indices, index) as u32),
+        bone_weights,
+    })
+}
+
+impl FromBytes for GrannyData {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let bytes = byte_reader.remaining_bytes();
+        let (file, header) = GrannyFile::from_bytes(&bytes)?;
+
+        let root = file
+            .objects(Some(header.root_object), Some(header.root_type), 1)?
+            .pop()
+            .ok_or_else(|| ConversionError::from_message("granny file has no root object"))?;
+
+        let texture_objects = root.array("Textures")?;
+        let material_objects = root.array("Materials")?;
+        let skeleton_objects = root.array("Skeletons")?;
+        let mesh_objects = root.array("Meshes")?;
+
+        let texture_addresses: Vec<_> = texture_objects.iter().map(|object| object.address).collect();
+        let material_addresses: Vec<_> = material_objects.iter().map(|object| object.address).collect();
+        let skeleton_addresses: Vec<_> = skeleton_objects.iter().map(|object| object.address).collect();
+        let mesh_addresses: Vec<_> = mesh_objects.iter().map(|object| object.address).collect();
+
+        let textures = texture_objects
+            .iter()
+            .map(|texture| {
+                Ok(GrannyTexture {
+                    file_name: texture.string("FromFileName")?,
+                })
+            })
+            .collect::<ConversionResult<_>>()?;
+
+        let materials = material_objects
+            .iter()
+            .map(|material| {
+                let maps = material
+                    .array("Maps")?
+                    .iter()
+                    .map(|map| {
+                        Ok(GrannyMaterialMap {
+                            usage: map.string("Usage")?,
+                            material_index: index_of(&material_addresses, map.reference("Map")?.as_ref()),
+                        })
+                    })
+                    .collect::<ConversionResult<_>>()?;
+
+                Ok(GrannyMaterial {
+                    name: material.string("Name")?,
+                    texture_index: index_of(&texture_addresses, material.reference("Texture")?.as_ref()),
+                    maps,
+                })
+            })
+            .collect::<ConversionResult<_>>()?;
+
+        let skeletons = skeleton_objects
+            .iter()
+            .map(|skeleton| {
+                let bones = skeleton
+                    .array("Bones")?
+                    .iter()
+                    .map(|bone| {
+                        let inverse_world = bone.required_field("InverseWorld4x4")?.as_numbers()?;
+                        let inverse_world_matrix = match inverse_world.len() {
+                            16 => Matrix4::from(<[[f32; 4]; 4]>::try_from(inverse_world.as_chunks::<4>().0).unwrap()),
+                            _ => return Err(ConversionError::from_message("granny bone has an invalid inverse world matrix")),
+                        };
+
+                        Ok(GrannyBone {
+                            name: bone.string("Name")?,
+                            parent_index: usize::try_from(bone.required_field("ParentIndex")?.as_i32()?).ok(),
+                            local_transform: bone.required_field("LocalTransform")?.as_transform()?,
+                            inverse_world_matrix,
+                        })
+                    })
+                    .collect::<ConversionResult<_>>()?;
+
+                Ok(GrannySkeleton {
+                    name: skeleton.string("Name")?,
+                    bones,
+                })
+            })
+            .collect::<ConversionResult<_>>()?;
+
+        let meshes = mesh_objects
+            .iter()
+            .map(|mesh| {
+                let (vertices, is_rigid) = match mesh.reference("PrimaryVertexData")? {
+                    Some(vertex_data) => {
+                        let vertex_objects = vertex_data.array("Vertices")?;
+                        let is_rigid = match vertex_objects.first() {
+                            Some(vertex) => vertex.field("BoneWeights")?.is_none(),
+                            None => true,
+                        };
+                        let vertices = vertex_objects.iter().map(parse_vertex).collect::<ConversionResult<_>>()?;
+
+                        (vertices, is_rigid)
+                    }
+                    None => (Vec::new(), true),
+                };
+
+                let (indices, triangle_groups) = match mesh.reference("PrimaryTopology")? {
+                    Some(topology) => {
+                        let mut indices = Vec::new();
+
+                        if let Some(field) = topology.field("Indices")? {
+                            indices = field.as_number_array()?;
+                        }
+
+                        if indices.is_empty()
+                            && let Some(field) = topology.field("Indices16")?
+                        {
+                            indices = field.as_number_array()?;
+                        }
+
+                        let triangle_groups = topology
+                            .array("Groups")?
+                            .iter()
+                            .map(|group| {
+                                Ok(GrannyTriangleGroup {
+                                    material_index: group.required_field("MaterialIndex")?.as_usize()?,
+                                    first_triangle: group.required_field("TriFirst")?.as_usize()?,
+                                    triangle_count: group.required_field("TriCount")?.as_usize()?,
+                                })
+                            })
+                            .collect::<ConversionResult<_>>()?;
+
+                        (indices.into_iter().map(|index| index as u32).collect(), triangle_groups)
+                    }
+                    None => (Vec::new(), Vec::new()),
+                };
+
+                let material_bindings = mesh
+                    .array("MaterialBindings")?
+                    .iter()
+                    .map(|binding| Ok(index_of(&material_addresses, binding.reference("Material")?.as_ref())))
+                    .collect::<ConversionResult<_>>()?;
+
+                let bone_bindings = mesh
+                    .array("BoneBindings")?
+                    .iter()
+                    .map(|binding| binding.string("BoneName"))
+                    .collect::<ConversionResult<_>>()?;
+
+                Ok(GrannyMesh {
+                    name: mesh.string("Name")?,
+                    vertices,
+                    is_rigid,
+                    indices,
+                    triangle_groups,
+                    material_bindings,
+                    bone_bindings,
+                })
+            })
+            .collect::<ConversionResult<_>>()?;
+
+        let models = root
+            .array("Models")?
+            .iter()
+            .map(|model| {
+                let initial_placement = match model.field("InitialPlacement")? {
+                    Some(field) => field.as_transform()?,
+                    None => GrannyTransform {
+                        flags: 0,
+                        position: Vector3::new(0.0, 0.0, 0.0),
+                        orientation: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+                        scale_shear: Matrix3::identity(),
+                    },
+                };
+
+                let mesh_indices = model
+                    .array("MeshBindings")?
+                    .iter()
+                    .map(|binding| Ok(index_of(&mesh_addresses, binding.reference("Mesh")?.as_ref())))
+                    .collect::<ConversionResult<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+
+                Ok(GrannyModel {
+                    name: model.string("Name")?,
+                    skeleton_index: index_of(&skeleton_addresses, model.reference("Skeleton")?.as_ref()),
+                    initial_placement,
+                    mesh_indices,
+                })
+            })
+            .collect::<ConversionResult<_>>()?;
+
+        let animations = root
+            .array("Animations")?
+            .iter()
+            .map(|animation| {
+                let track_groups = animation
+                    .array("TrackGroups")?
+                    .iter()
+                    .map(|track_group| {
+                        let transform_tracks = track_group
+                            .array("TransformTracks")?
+                            .iter()
+                            .map(|track| {
+                                Ok(GrannyTransformTrack {
+                                    name: track.string("Name")?,
+                                    orientation: parse_curve(&track.required_field("OrientationCurve")?.as_inline()?)?,
+                                    position: parse_curve(&track.required_field("PositionCurve")?.as_inline()?)?,
+                                    scale_shear: parse_curve(&track.required_field("ScaleShearCurve")?.as_inline()?)?,
+                                })
+                            })
+                            .collect::<ConversionResult<_>>()?;
+
+                        Ok(GrannyTrackGroup {
+                            name: track_group.string("Name")?,
+                            transform_tracks,
+                        })
+                    })
+                    .collect::<ConversionResult<_>>()?;
+
+                Ok(GrannyAnimation {
+                    name: animation.string("Name")?,
+                    duration: animation.required_field("Duration")?.as_f32()?,
+                    time_step: animation.required_field("TimeStep")?.as_f32()?,
+                    track_groups,
+                })
+            })
+            .collect::<ConversionResult<_>>()?;
+
+        Ok(Self {
+            version: header.version,
+            from_file_name: root.string("FromFileName")?,
+            textures,
+            materials,
+            skeletons,
+            meshes,
+            models,
+            animations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use cgmath::{Matrix3, Matrix4, Point3, Quaternion, SquareMatrix, Vector2, Vector3};
+    use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes, ToBytes};
+
+    use super::{
+        GrannyBone, GrannyCurve, GrannyData, GrannyFileHeader, GrannyRelocation, GrannySectionHeader, GrannyTransform, GrannyTriangleGroup,
+        GrannyVertex, LITTLE_ENDIAN_32_SIGNATURES, SectionReference,
+    };
+
+    const INLINE: u32 = 1;
+    const REFERENCE: u32 = 2;
+    const REFERENCE_TO_ARRAY: u32 = 3;
+    const ARRAY_OF_REFERENCES: u32 = 4;
+    const VARIANT_REFERENCE: u32 = 5;
+    const STRING: u32 = 8;
+    const TRANSFORM: u32 = 9;
+    const REFERENCE_TO_VARIANT_ARRAY: u32 = 7;
+    const REAL32: u32 = 10;
+    const UINT8: u32 = 12;
+    const NORMAL_UINT8: u32 = 14;
+    const INT16: u32 = 15;
+    const UINT16: u32 = 16;
+    const INT32: u32 = 19;
+
+    /// Builds a Granny file out of uncompressed sections. Data is written to
+    /// the current section, which can be switched with
+    /// [`section`](Self::section).
+    #[derive(Default)]
+    struct GrannyBuilder {
+        sections: Vec<Vec<u8>>,
+        relocations: Vec<Vec<GrannyRelocation>>,
+        current: usize,
+    }
+
+    impl GrannyBuilder {
+        fn section(&mut self, index: usize) {
+            if self.sections.len() <= index {
+                self.sections.resize_with(index + 1, Vec::new);
+                self.relocations.resize_with(index + 1, Vec::new);
+            }
+
+            self.current = index;
+        }
+
+        fn data(&mut self) -> &mut Vec<u8> {
+            self.section(self.current);
+            &mut self.sections[self.current]
+        }
+
+        fn position(&mut self) -> SectionReference {
+            SectionReference {
+                section: self.current as u32,
+                offset: self.data().len() as u32,
+            }
+        }
+
+        fn u32(&mut self, value: u32) {
+            self.data().extend_from_slice(&value.to_le_bytes());
+        }
+
+        fn i32(&mut self, value: i32) {
+            self.data().extend_from_slice(&value.to_le_bytes());
+        }
+
+        fn f32s(&mut self, values: &[f32]) {
+            values.iter().for_each(|value| self.data().extend_from_slice(&value.to_le_bytes()));
+        }
+
+        fn pointer(&mut self, target: Option<SectionReference>) {
+            if let Some(target) = target {
+                let offset = self.position().offset;
+                self.relocations[self.current].push(GrannyRelocation { offset, target });
+            }
+
+            self.u32(0);
+        }
+
+        fn array(&mut self, count: usize, target: SectionReference) {
+            self.u32(count as u32);
+            self.pointer(Some(target));
+        }
+
+        fn transform(&mut self, flags: u32, position: [f32; 3]) {
+            self.u32(flags);
+            self.f32s(&position);
+            self.f32s(&[0.0, 0.0, 0.0, 1.0]);
+            self.f32s(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        }
+
+        fn align(&mut self) {
+            let data = self.data();
+            data.resize(data.len().next_multiple_of(4), 0);
+        }
+
+        fn string(&mut self, string: &str) -> SectionReference {
+            let position = self.position();
+            self.data().extend_from_slice(string.as_bytes());
+            self.data().push(0);
+            self.align();
+            position
+        }
+
+        fn object(&mut self, write: impl FnOnce(&mut Self)) -> SectionReference {
+            let position = self.position();
+            write(self);
+            self.align();
+            position
+        }
+
+        fn type_definition(&mut self, members: &[(u32, &str, Option<SectionReference>, u32)]) -> SectionReference {
+            let names: Vec<SectionReference> = members.iter().map(|(_, name, ..)| self.string(name)).collect();
+
+            self.object(|builder| {
+                for ((member_type, _, definition, array_size), name) in members.iter().zip(names) {
+                    builder.u32(*member_type);
+                    builder.pointer(Some(name));
+                    builder.pointer(*definition);
+                    builder.u32(*array_size);
+                    builder.f32s(&[0.0; 4]);
+                }
+
+                builder.data().extend_from_slice(&[0; 32]);
+            })
+        }
+
+        fn finish(self, root_type: SectionReference, root_object: SectionReference, compression: u32) -> Vec<u8> {
+            const HEADER_SIZE: u32 = 88;
+            const SECTION_HEADER_SIZE: u32 = 44;
+
+            let mut offset = HEADER_SIZE + SECTION_HEADER_SIZE * self.sections.len() as u32;
+            let section_headers: Vec<GrannySectionHeader> = self
+                .sections
+                .iter()
+                .zip(&self.relocations)
+                .map(|(data, relocations)| {
+                    let data_offset = offset;
+                    let relocation_offset = data_offset + data.len() as u32;
+                    offset = relocation_offset + relocations.len() as u32 * 12;
+
+                    GrannySectionHeader {
+                        compression,
+                        data_offset,
+                        data_size: data.len() as u32,
+                        decompressed_size: data.len() as u32,
+                        alignment: 4,
+                        first_16_bit: data.len() as u32,
+                        first_8_bit: data.len() as u32,
+                        relocation_offset,
+                        relocation_count: relocations.len() as u32,
+                        marshalling_offset: offset,
+                        marshalling_count: 0,
+                    }
+                })
+                .collect();
+            let file_size = offset;
+
+            let header = GrannyFileHeader {
+                signature: LITTLE_ENDIAN_32_SIGNATURES[1],
+                header_size: HEADER_SIZE,
+                header_format: 0,
+                reserved: [0; 2],
+                version: 7,
+                file_size,
+                crc: 0,
+                section_offset: HEADER_SIZE - 32,
+                section_count: self.sections.len() as u32,
+                root_type,
+                root_object,
+                type_tag: 0x80000037,
+                extra_tags: [0; 4],
+            };
+
+            let mut byte_writer = ByteWriter::new();
+            header.to_bytes(&mut byte_writer).unwrap();
+            section_headers
+                .iter()
+                .for_each(|section_header| _ = section_header.to_bytes(&mut byte_writer).unwrap());
+
+            for (data, relocations) in self.sections.iter().zip(&self.relocations) {
+                byte_writer.extend_from_slice(data);
+                relocations
+                    .iter()
+                    .for_each(|relocation| _ = relocation.to_bytes(&mut byte_writer).unwrap());
+            }
+
+            assert_eq!(byte_writer.len(), file_size as usize);
+            byte_writer.into_inner()
+        }
+    }
+
+    fn build_file(compression: u32, first_triangle: i32) -> Vec<u8> {
+        let mut builder = GrannyBuilder::default();
+
+        let real32_type = builder.type_definition(&[(REAL32, "Real32", None, 0)]);
+        let int32_type = builder.type_definition(&[(INT32, "Int32", None, 0)]);
+        let texture_type = builder.type_definition(&[(STRING, "FromFileName", None, 0)]);
+        let material_type = builder.type_definition(&[(STRING, "Name", None, 0), (REFERENCE, "Texture", Some(texture_type), 0)]);
+        let bone_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (INT32, "ParentIndex", None, 0),
+            (TRANSFORM, "LocalTransform", None, 0),
+            (REAL32, "InverseWorld4x4", None, 16),
+        ]);
+        let skeleton_type = builder.type_definition(&[(STRING, "Name", None, 0), (REFERENCE_TO_ARRAY, "Bones", Some(bone_type), 0)]);
+        let vertex_type = builder.type_definition(&[
+            (REAL32, "Position", None, 3),
+            (REAL32, "Normal", None, 3),
+            (REAL32, "TextureCoordinates0", None, 2),
+        ]);
+        let vertex_data_type = builder.type_definition(&[(REFERENCE_TO_ARRAY, "Vertices", Some(vertex_type), 0)]);
+        let group_type = builder.type_definition(&[
+            (INT32, "MaterialIndex", None, 0),
+            (INT32, "TriFirst", None, 0),
+            (INT32, "TriCount", None, 0),
+        ]);
+        let topology_type = builder.type_definition(&[
+            (REFERENCE_TO_ARRAY, "Groups", Some(group_type), 0),
+            (REFERENCE_TO_ARRAY, "Indices", Some(int32_type), 0),
+        ]);
+        let material_binding_type = builder.type_definition(&[(REFERENCE, "Material", Some(material_type), 0)]);
+        let bone_binding_type = builder.type_definition(&[(STRING, "BoneName", None, 0)]);
+        let mesh_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE, "PrimaryVertexData", Some(vertex_data_type), 0),
+            (REFERENCE, "PrimaryTopology", Some(topology_type), 0),
+            (REFERENCE_TO_ARRAY, "MaterialBindings", Some(material_binding_type), 0),
+            (REFERENCE_TO_ARRAY, "BoneBindings", Some(bone_binding_type), 0),
+        ]);
+        let mesh_binding_type = builder.type_definition(&[(REFERENCE, "Mesh", Some(mesh_type), 0)]);
+        let model_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE, "Skeleton", Some(skeleton_type), 0),
+            (TRANSFORM, "InitialPlacement", None, 0),
+            (REFERENCE_TO_ARRAY, "MeshBindings", Some(mesh_binding_type), 0),
+        ]);
+        let old_curve_type = builder.type_definition(&[
+            (INT32, "Degree", None, 0),
+            (REFERENCE_TO_ARRAY, "Knots", Some(real32_type), 0),
+            (REFERENCE_TO_ARRAY, "Controls", Some(real32_type), 0),
+        ]);
+        let curve_header_type = builder.type_definition(&[(UINT8, "Format", None, 0), (UINT8, "Degree", None, 0)]);
+        let constant_curve_type = builder.type_definition(&[
+            (INLINE, "CurveDataHeader", Some(curve_header_type), 0),
+            (INT16, "Padding", None, 0),
+            (REAL32, "Controls", None, 3),
+        ]);
+        let curve_type = builder.type_definition(&[(VARIANT_REFERENCE, "CurveData", None, 0)]);
+        let track_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (INLINE, "OrientationCurve", Some(old_curve_type), 0),
+            (INLINE, "PositionCurve", Some(curve_type), 0),
+            (INLINE, "ScaleShearCurve", Some(curve_type), 0),
+        ]);
+        let track_group_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE_TO_ARRAY, "TransformTracks", Some(track_type), 0),
+        ]);
+        let animation_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REAL32, "Duration", None, 0),
+            (REAL32, "TimeStep", None, 0),
+            (ARRAY_OF_REFERENCES, "TrackGroups", Some(track_group_type), 0),
+        ]);
+        let root_type = builder.type_definition(&[
+            (STRING, "FromFileName", None, 0),
+            (ARRAY_OF_REFERENCES, "Textures", Some(texture_type), 0),
+            (ARRAY_OF_REFERENCES, "Materials", Some(material_type), 0),
+            (ARRAY_OF_REFERENCES, "Skeletons", Some(skeleton_type), 0),
+            (ARRAY_OF_REFERENCES, "Meshes", Some(mesh_type), 0),
+            (ARRAY_OF_REFERENCES, "Models", Some(model_type), 0),
+            (ARRAY_OF_REFERENCES, "Animations", Some(animation_type), 0),
+        ]);
+
+        let texture_name = builder.string("emperium.bmp");
+        let texture = builder.object(|builder| builder.pointer(Some(texture_name)));
+
+        let material_name = builder.string("crystal");
+        let material = builder.object(|builder| {
+            builder.pointer(Some(material_name));
+            builder.pointer(Some(texture));
+        });
+
+        let root_bone_name = builder.string("root");
+        let child_bone_name = builder.string("crystal");
+        let bones = builder.object(|builder| {
+            let identity: [f32; 16] = *Matrix4::identity().as_ref();
+
+            builder.pointer(Some(root_bone_name));
+            builder.i32(-1);
+            builder.transform(0, [0.0; 3]);
+            builder.f32s(&identity);
+
+            builder.pointer(Some(child_bone_name));
+            builder.i32(0);
+            builder.transform(GrannyTransform::HAS_POSITION, [0.0, 5.0, 0.0]);
+            builder.f32s(&identity);
+        });
+        let skeleton_name = builder.string("skeleton");
+        let skeleton = builder.object(|builder| {
+            builder.pointer(Some(skeleton_name));
+            builder.array(2, bones);
+        });
+
+        let vertices = builder.object(|builder| {
+            builder.f32s(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+            builder.f32s(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0]);
+            builder.f32s(&[0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        });
+        let vertex_data = builder.object(|builder| builder.array(3, vertices));
+        let groups = builder.object(|builder| {
+            builder.i32(0);
+            builder.i32(first_triangle);
+            builder.i32(1);
+        });
+        let indices = builder.object(|builder| [0, 2, 1].into_iter().for_each(|index| builder.i32(index)));
+        let topology = builder.object(|builder| {
+            builder.array(1, groups);
+            builder.array(3, indices);
+        });
+        let material_bindings = builder.object(|builder| builder.pointer(Some(material)));
+        let bone_bindings = builder.object(|builder| builder.pointer(Some(child_bone_name)));
+        let mesh_name = builder.string("mesh");
+        let mesh = builder.object(|builder| {
+            builder.pointer(Some(mesh_name));
+            builder.pointer(Some(vertex_data));
+            builder.pointer(Some(topology));
+            builder.array(1, material_bindings);
+            builder.array(1, bone_bindings);
+        });
+
+        let mesh_bindings = builder.object(|builder| builder.pointer(Some(mesh)));
+        let model_name = builder.string("emperium");
+        let model = builder.object(|builder| {
+            builder.pointer(Some(model_name));
+            builder.pointer(Some(skeleton));
+            builder.transform(0, [0.0; 3]);
+            builder.array(1, mesh_bindings);
+        });
+
+        let orientation_controls = builder.object(|builder| builder.f32s(&[0.0, 0.0, 0.0, 1.0]));
+        let constant_curve = builder.object(|builder| {
+            builder.data().extend_from_slice(&[4, 0, 0, 0]);
+            builder.f32s(&[0.0, 2.0, 0.0]);
+        });
+        let track_name = builder.string("crystal");
+        let tracks = builder.object(|builder| {
+            builder.pointer(Some(track_name));
+            builder.i32(0);
+            builder.array(0, orientation_controls);
+            builder.array(4, orientation_controls);
+            builder.pointer(Some(constant_curve_type));
+            builder.pointer(Some(constant_curve));
+            builder.pointer(None);
+            builder.pointer(None);
+        });
+        let track_group_name = builder.string("emperium");
+        let track_group = builder.object(|builder| {
+            builder.pointer(Some(track_group_name));
+            builder.array(1, tracks);
+        });
+        let track_groups = builder.object(|builder| builder.pointer(Some(track_group)));
+        let animation_name = builder.string("idle");
+        let animation = builder.object(|builder| {
+            builder.pointer(Some(animation_name));
+            builder.f32s(&[2.0, 0.5]);
+            builder.array(1, track_groups);
+        });
+
+        let file_name = builder.string("emperium.max");
+        let references: Vec<SectionReference> = [texture, material, skeleton, mesh, model, animation]
+            .into_iter()
+            .map(|target| builder.object(|builder| builder.pointer(Some(target))))
+            .collect();
+        let root = builder.object(|builder| {
+            builder.pointer(Some(file_name));
+            references.iter().for_each(|reference| builder.array(1, *reference));
+        });
+
+        builder.finish(root_type, root, compression)
+    }
+
+    /// Builds a file with the layout of the 3D monsters shipped with the
+    /// client. The members of the file info follow the order Granny 2.7
+    /// exports them in, vertices use the skinned `pwnt3432` format and the
+    /// vertices and indices live in their own sections.
+    fn build_monster_file() -> Vec<u8> {
+        const MAIN_SECTION: usize = 0;
+        const VERTEX_SECTION: usize = 1;
+        const INDEX_SECTION: usize = 2;
+
+        let mut builder = GrannyBuilder::default();
+        builder.section(MAIN_SECTION);
+
+        let real32_type = builder.type_definition(&[(REAL32, "Real32", None, 0)]);
+        let int32_type = builder.type_definition(&[(INT32, "Int32", None, 0)]);
+        let uint16_type = builder.type_definition(&[(UINT16, "UInt16", None, 0)]);
+        let string_type = builder.type_definition(&[(STRING, "String", None, 0)]);
+        let art_tool_type = builder.type_definition(&[
+            (STRING, "FromArtToolName", None, 0),
+            (INT32, "ArtToolMajorRevision", None, 0),
+            (INT32, "ArtToolMinorRevision", None, 0),
+            (REAL32, "UnitsPerMeter", None, 0),
+        ]);
+        let texture_type = builder.type_definition(&[
+            (STRING, "FromFileName", None, 0),
+            (INT32, "TextureType", None, 0),
+            (INT32, "Width", None, 0),
+            (INT32, "Height", None, 0),
+        ]);
+        let material_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE_TO_ARRAY, "Maps", None, 0),
+            (REFERENCE, "Texture", Some(texture_type), 0),
+            (VARIANT_REFERENCE, "ExtendedData", None, 0),
+        ]);
+        let bone_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (INT32, "ParentIndex", None, 0),
+            (TRANSFORM, "LocalTransform", None, 0),
+            (REAL32, "InverseWorld4x4", None, 16),
+            (REAL32, "LODError", None, 0),
+            (VARIANT_REFERENCE, "ExtendedData", None, 0),
+        ]);
+        let skeleton_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE_TO_ARRAY, "Bones", Some(bone_type), 0),
+            (INT32, "LODType", None, 0),
+        ]);
+        let vertex_type = builder.type_definition(&[
+            (REAL32, "Position", None, 3),
+            (NORMAL_UINT8, "BoneWeights", None, 4),
+            (UINT8, "BoneIndices", None, 4),
+            (REAL32, "Normal", None, 3),
+            (REAL32, "TextureCoordinates0", None, 2),
+        ]);
+        let vertex_data_type = builder.type_definition(&[
+            (REFERENCE_TO_VARIANT_ARRAY, "Vertices", None, 0),
+            (REFERENCE_TO_ARRAY, "VertexComponentNames", Some(string_type), 0),
+            (REFERENCE_TO_ARRAY, "VertexAnnotationSets", None, 0),
+        ]);
+        let group_type = builder.type_definition(&[
+            (INT32, "MaterialIndex", None, 0),
+            (INT32, "TriFirst", None, 0),
+            (INT32, "TriCount", None, 0),
+        ]);
+        let topology_type = builder.type_definition(&[
+            (REFERENCE_TO_ARRAY, "Groups", Some(group_type), 0),
+            (REFERENCE_TO_ARRAY, "Indices", Some(int32_type), 0),
+            (REFERENCE_TO_ARRAY, "Indices16", Some(uint16_type), 0),
+        ]);
+        let material_binding_type = builder.type_definition(&[(REFERENCE, "Material", Some(material_type), 0)]);
+        let bone_binding_type = builder.type_definition(&[
+            (STRING, "BoneName", None, 0),
+            (REAL32, "OBBMin", None, 3),
+            (REAL32, "OBBMax", None, 3),
+        ]);
+        let mesh_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE, "PrimaryVertexData", Some(vertex_data_type), 0),
+            (REFERENCE_TO_ARRAY, "MorphTargets", None, 0),
+            (REFERENCE, "PrimaryTopology", Some(topology_type), 0),
+            (REFERENCE_TO_ARRAY, "MaterialBindings", Some(material_binding_type), 0),
+            (REFERENCE_TO_ARRAY, "BoneBindings", Some(bone_binding_type), 0),
+            (VARIANT_REFERENCE, "ExtendedData", None, 0),
+        ]);
+        let mesh_binding_type = builder.type_definition(&[(REFERENCE, "Mesh", Some(mesh_type), 0)]);
+        let model_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE, "Skeleton", Some(skeleton_type), 0),
+            (TRANSFORM, "InitialPlacement", None, 0),
+            (REFERENCE_TO_ARRAY, "MeshBindings", Some(mesh_binding_type), 0),
+        ]);
+        let curve_header_type = builder.type_definition(&[(UINT8, "Format", None, 0), (UINT8, "Degree", None, 0)]);
+        let keyframe_curve_type = builder.type_definition(&[
+            (INLINE, "CurveDataHeader", Some(curve_header_type), 0),
+            (INT16, "Dimension", None, 0),
+            (REFERENCE_TO_ARRAY, "Controls", Some(real32_type), 0),
+        ]);
+        let curve_type = builder.type_definition(&[(VARIANT_REFERENCE, "CurveData", None, 0)]);
+        let track_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (INT32, "Flags", None, 0),
+            (INLINE, "OrientationCurve", Some(curve_type), 0),
+            (INLINE, "PositionCurve", Some(curve_type), 0),
+            (INLINE, "ScaleShearCurve", Some(curve_type), 0),
+        ]);
+        let track_group_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REFERENCE_TO_ARRAY, "VectorTracks", None, 0),
+            (REFERENCE_TO_ARRAY, "TransformTracks", Some(track_type), 0),
+            (TRANSFORM, "InitialPlacement", None, 0),
+        ]);
+        let animation_type = builder.type_definition(&[
+            (STRING, "Name", None, 0),
+            (REAL32, "Duration", None, 0),
+            (REAL32, "TimeStep", None, 0),
+            (REAL32, "Oversampling", None, 0),
+            (ARRAY_OF_REFERENCES, "TrackGroups", Some(track_group_type), 0),
+        ]);
+        let root_type = builder.type_definition(&[
+            (REFERENCE, "ArtToolInfo", Some(art_tool_type), 0),
+            (REFERENCE, "ExporterInfo", None, 0),
+            (STRING, "FromFileName", None, 0),
+            (ARRAY_OF_REFERENCES, "Textures", Some(texture_type), 0),
+            (ARRAY_OF_REFERENCES, "Materials", Some(material_type), 0),
+            (ARRAY_OF_REFERENCES, "Skeletons", Some(skeleton_type), 0),
+            (ARRAY_OF_REFERENCES, "VertexDatas", Some(vertex_data_type), 0),
+            (ARRAY_OF_REFERENCES, "TriTopologies", Some(topology_type), 0),
+            (ARRAY_OF_REFERENCES, "Meshes", Some(mesh_type), 0),
+            (ARRAY_OF_REFERENCES, "Models", Some(model_type), 0),
+            (ARRAY_OF_REFERENCES, "TrackGroups", Some(track_group_type), 0),
+            (ARRAY_OF_REFERENCES, "Animations", Some(animation_type), 0),
+            (VARIANT_REFERENCE, "ExtendedData", None, 0),
+        ]);
+
+        let empty_array = |builder: &mut GrannyBuilder| {
+            builder.u32(0);
+            builder.pointer(None);
+        };
+
+        let art_tool_name = builder.string("3D Studio MAX");
+        let art_tool = builder.object(|builder| {
+            builder.pointer(Some(art_tool_name));
+            builder.i32(5);
+            builder.i32(1);
+            builder.f32s(&[1.0]);
+        });
+
+        let texture_name = builder.string("texture\\kguardian.bmp");
+        let texture = builder.object(|builder| {
+            builder.pointer(Some(texture_name));
+            builder.i32(0);
+            builder.i32(256);
+            builder.i32(256);
+        });
+
+        let material_name = builder.string("kguardian");
+        let material = builder.object(|builder| {
+            builder.pointer(Some(material_name));
+            empty_array(builder);
+            builder.pointer(Some(texture));
+            builder.pointer(None);
+            builder.pointer(None);
+        });
+
+        let bone_names = ["Bip01", "Bip01 Spine", "Bip01 R Hand"].map(|name| builder.string(name));
+        let bones = builder.object(|builder| {
+            let identity: [f32; 16] = *Matrix4::identity().as_ref();
+
+            for (index, name) in bone_names.iter().enumerate() {
+                builder.pointer(Some(*name));
+                builder.i32(index as i32 - 1);
+                builder.transform(GrannyTransform::HAS_POSITION, [0.0, index as f32, 0.0]);
+                builder.f32s(&identity);
+                builder.f32s(&[0.0]);
+                builder.pointer(None);
+                builder.pointer(None);
+            }
+        });
+        let skeleton_name = builder.string("kguardian");
+        let skeleton = builder.object(|builder| {
+            builder.pointer(Some(skeleton_name));
+            builder.array(3, bones);
+            builder.i32(0);
+        });
+
+        // Vertices and indices are stored in their own sections, like the
+        // exporter does.
+        builder.section(VERTEX_SECTION);
+        let vertices = [0, 1].map(|mesh_index| {
+            builder.object(|builder| {
+                for vertex_index in 0..3 {
+                    builder.f32s(&[vertex_index as f32, mesh_index as f32, 0.0]);
+                    builder.data().extend_from_slice(&[128, 127, 0, 0]);
+                    builder.data().extend_from_slice(&[mesh_index, mesh_index + 1, 0, 0]);
+                    builder.f32s(&[0.0, 1.0, 0.0]);
+                    builder.f32s(&[vertex_index as f32 * 0.5, 0.0]);
+                }
+            })
+        });
+
+        builder.section(INDEX_SECTION);
+        let indices = [0, 1].map(|_| {
+            builder.object(|builder| {
+                [0u16, 1, 2]
+                    .iter()
+                    .for_each(|index| builder.data().extend_from_slice(&index.to_le_bytes()))
+            })
+        });
+
+        builder.section(MAIN_SECTION);
+        let vertex_datas = vertices.map(|vertices| {
+            builder.object(|builder| {
+                builder.pointer(Some(vertex_type));
+                builder.u32(3);
+                builder.pointer(Some(vertices));
+                empty_array(builder);
+                empty_array(builder);
+            })
+        });
+        let groups = builder.object(|builder| {
+            builder.i32(0);
+            builder.i32(0);
+            builder.i32(1);
+        });
+        let topologies = indices.map(|indices| {
+            builder.object(|builder| {
+                builder.array(1, groups);
+                empty_array(builder);
+                builder.array(3, indices);
+            })
+        });
+        let material_bindings = builder.object(|builder| builder.pointer(Some(material)));
+        let bone_bindings = builder.object(|builder| {
+            for name in &bone_names[..2] {
+                builder.pointer(Some(*name));
+                builder.f32s(&[-1.0, -1.0, -1.0, 1.0, 1.0, 1.0]);
+            }
+        });
+        let mesh_names = ["body", "weapon"].map(|name| builder.string(name));
+        let meshes: Vec<SectionReference> = (0..2)
+            .map(|index| {
+                builder.object(|builder| {
+                    builder.pointer(Some(mesh_names[index]));
+                    builder.pointer(Some(vertex_datas[index]));
+                    empty_array(builder);
+                    builder.pointer(Some(topologies[index]));
+                    builder.array(1, material_bindings);
+                    builder.array(2, bone_bindings);
+                    builder.pointer(None);
+                    builder.pointer(None);
+                })
+            })
+            .collect();
+
+        let mesh_bindings = builder.object(|builder| meshes.iter().for_each(|mesh| builder.pointer(Some(*mesh))));
+        let model_name = builder.string("kguardian");
+        let model = builder.object(|builder| {
+            builder.pointer(Some(model_name));
+            builder.pointer(Some(skeleton));
+            builder.transform(0, [0.0; 3]);
+            builder.array(2, mesh_bindings);
+        });
+
+        let position_controls = builder.object(|builder| builder.f32s(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+        let position_curve = builder.object(|builder| {
+            builder.data().extend_from_slice(&[0, 0, 3, 0]);
+            builder.array(6, position_controls);
+        });
+        let tracks = builder.object(|builder| {
+            for (index, name) in bone_names.iter().enumerate() {
+                builder.pointer(Some(*name));
+                builder.i32(0);
+                builder.pointer(None);
+                builder.pointer(None);
+
+                match index {
+                    1 => {
+                        builder.pointer(Some(keyframe_curve_type));
+                        builder.pointer(Some(position_curve));
+                    }
+                    _ => {
+                        builder.pointer(None);
+                        builder.pointer(None);
+                    }
+                }
+
+                builder.pointer(None);
+                builder.pointer(None);
+            }
+        });
+        let track_group_name = builder.string("kguardian");
+        let track_group = builder.object(|builder| {
+            builder.pointer(Some(track_group_name));
+            empty_array(builder);
+            builder.array(3, tracks);
+            builder.transform(0, [0.0; 3]);
+        });
+        let track_groups = builder.object(|builder| builder.pointer(Some(track_group)));
+        let animation_name = builder.string("kguardian90_0");
+        let animation = builder.object(|builder| {
+            builder.pointer(Some(animation_name));
+            builder.f32s(&[1.0, 1.0 / 30.0, 1.0]);
+            builder.array(1, track_groups);
+        });
+
+        let file_name = builder.string("C:\\ro\\3dmob\\kguardian90_0.max");
+        let single = |builder: &mut GrannyBuilder, target: SectionReference| builder.object(|builder| builder.pointer(Some(target)));
+        let textures = single(&mut builder, texture);
+        let materials = single(&mut builder, material);
+        let skeletons = single(&mut builder, skeleton);
+        let vertex_data_references = builder.object(|builder| vertex_datas.iter().for_each(|data| builder.pointer(Some(*data))));
+        let topology_references = builder.object(|builder| topologies.iter().for_each(|topology| builder.pointer(Some(*topology))));
+        let mesh_references = builder.object(|builder| meshes.iter().for_each(|mesh| builder.pointer(Some(*mesh))));
+        let models = single(&mut builder, model);
+        let animations = single(&mut builder, animation);
+        let root = builder.object(|builder| {
+            builder.pointer(Some(art_tool));
+            builder.pointer(None);
+            builder.pointer(Some(file_name));
+            builder.array(1, textures);
+            builder.array(1, materials);
+            builder.array(1, skeletons);
+            builder.array(2, vertex_data_references);
+            builder.array(2, topology_references);
+            builder.array(2, mesh_references);
+            builder.array(1, models);
+            builder.array(1, track_groups);
+            builder.array(1, animations);
+            builder.pointer(None);
+            builder.pointer(None);
+        });
+
+        builder.finish(root_type, root, 0)
+    }
+
+    /// Builds a file whose root has a single array with the given count, that
+    /// points to an empty part of the section.
+    fn build_array_file(count: u32, array_size: u32) -> Vec<u8> {
+        let mut builder = GrannyBuilder::default();
+
+        let real32_type = builder.type_definition(&[(REAL32, "Values", None, array_size)]);
+        let root_type = builder.type_definition(&[(REFERENCE_TO_ARRAY, "Textures", Some(real32_type), 0)]);
+        let texture = builder.object(|builder| builder.f32s(&[0.0; 4]));
+        let root = builder.object(|builder| {
+            builder.u32(count);
+            builder.pointer(Some(texture));
+        });
+
+        builder.finish(root_type, root, 0)
+    }
+
+    #[test]
+    fn granny_data() {
+        let bytes = build_file(0, 0);
+        let granny_data = GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).unwrap();
+
+        assert_eq!(granny_data.version, 7);
+        assert_eq!(granny_data.from_file_name, "emperium.max");
+        assert_eq!(granny_data.textures[0].file_name, "emperium.bmp");
+        assert_eq!(granny_data.materials[0].texture_index, Some(0));
+        assert_eq!(granny_data.material_texture(0), Some(&granny_data.textures[0]));
+
+        let skeleton = &granny_data.skeletons[0];
+        assert_eq!(skeleton.bones.len(), 2);
+        assert_eq!(skeleton.bones[0].parent_index, None);
+        assert_eq!(skeleton.bones[1], GrannyBone {
+            name: "crystal".to_owned(),
+            parent_index: Some(0),
+            local_transform: GrannyTransform {
+                flags: GrannyTransform::HAS_POSITION,
+                position: Vector3::new(0.0, 5.0, 0.0),
+                orientation: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+                scale_shear: Matrix3::identity(),
+            },
+            inverse_world_matrix: Matrix4::identity(),
+        });
+        assert_eq!(
+            skeleton.world_matrices()[1],
+            Matrix4::from_translation(Vector3::new(0.0, 5.0, 0.0))
+        );
+
+        let mesh = &granny_data.meshes[0];
+        assert!(mesh.is_rigid);
+        assert_eq!(mesh.vertices[1], GrannyVertex {
+            position: Point3::new(1.0, 0.0, 0.0),
+            normal: Vector3::new(0.0, 1.0, 0.0),
+            texture_coordinates: Vector2::new(1.0, 0.0),
+            bone_indices: [0; 4],
+            bone_weights: [1.0, 0.0, 0.0, 0.0],
+        });
+        assert_eq!(mesh.indices, vec![0, 2, 1]);
+        assert_eq!(mesh.triangle_groups, vec![GrannyTriangleGroup {
+            material_index: 0,
+            first_triangle: 0,
+            triangle_count: 1,
+        }]);
+        assert_eq!(mesh.material_bindings, vec![Some(0)]);
+        assert_eq!(mesh.bone_bindings, vec!["crystal".to_owned()]);
+
+        let model = &granny_data.models[0];
+        assert_eq!(model.name, "emperium");
+        assert_eq!(model.skeleton_index, Some(0));
+        assert_eq!(model.mesh_indices, vec![0]);
+
+        let animation = &granny_data.animations[0];
+        assert_eq!(animation.duration, 2.0);
+        assert_eq!(animation.time_step, 0.5);
+
+        let track = &animation.track_groups[0].transform_tracks[0];
+        assert_eq!(track.name, "crystal");
+        assert_eq!(track.orientation, GrannyCurve::Constant(vec![0.0, 0.0, 0.0, 1.0]));
+        assert_eq!(track.position, GrannyCurve::Constant(vec![0.0, 2.0, 0.0]));
+        assert_eq!(track.scale_shear, GrannyCurve::Identity);
+    }
+
+    #[test]
+    fn compressed_section() {
+        let bytes = build_file(2, 0);
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_err());
+    }
+
+    #[test]
+    fn unsupported_signature() {
+        let mut bytes = build_file(0, 0);
+        bytes[0] = 0;
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_err());
+    }
+
+    #[test]
+    fn monster_file() {
+        let bytes = build_monster_file();
+        let granny_data = GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).unwrap();
+
+        assert_eq!(granny_data.from_file_name, "C:\\ro\\3dmob\\kguardian90_0.max");
+        assert_eq!(granny_data.textures.len(), 1);
+        assert_eq!(granny_data.material_texture(0).unwrap().file_name, "texture\\kguardian.bmp");
+        assert_eq!(granny_data.meshes.len(), 2);
+        assert_eq!(granny_data.skeletons.len(), 1);
+        assert_eq!(granny_data.skeletons[0].bones.len(), 3);
+        assert_eq!(granny_data.skeletons[0].bones[2].parent_index, Some(1));
+        assert_eq!(granny_data.animations.len(), 1);
+        assert_eq!(granny_data.models[0].skeleton_index, Some(0));
+        assert_eq!(granny_data.models[0].mesh_indices, vec![0, 1]);
+
+        let weapon = &granny_data.meshes[1];
+        assert_eq!(weapon.name, "weapon");
+        assert!(!weapon.is_rigid);
+        assert_eq!(weapon.vertices.len(), 3);
+        assert_eq!(weapon.vertices[2], GrannyVertex {
+            position: Point3::new(2.0, 1.0, 0.0),
+            normal: Vector3::new(0.0, 1.0, 0.0),
+            texture_coordinates: Vector2::new(1.0, 0.0),
+            bone_indices: [1, 2, 0, 0],
+            bone_weights: [128.0 / 255.0, 127.0 / 255.0, 0.0, 0.0],
+        });
+        assert_eq!(weapon.indices, vec![0, 1, 2]);
+        assert_eq!(weapon.material_bindings, vec![Some(0)]);
+        assert_eq!(weapon.bone_bindings, vec!["Bip01".to_owned(), "Bip01 Spine".to_owned()]);
+
+        let animation = &granny_data.animations[0];
+        assert_eq!(animation.name, "kguardian90_0");
+        assert_eq!(animation.track_groups[0].transform_tracks.len(), 3);
+
+        let track = &animation.track_groups[0].transform_tracks[1];
+        assert_eq!(track.name, "Bip01 Spine");
+        assert_eq!(track.orientation, GrannyCurve::Identity);
+        assert_eq!(track.position, GrannyCurve::Keyframes {
+            dimension: 3,
+            controls: vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+        });
+    }
+
+    #[test]
+    fn negative_triangle_group() {
+        let bytes = build_file(0, -1);
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_err());
+    }
+
+    #[test]
+    fn array_count_out_of_bounds() {
+        let bytes = build_array_file(4, 0);
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_ok());
+
+        let bytes = build_array_file(0x0100_0000, 0);
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_err());
+    }
+
+    #[test]
+    fn type_size_overflow() {
+        let bytes = build_array_file(1, u32::MAX);
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_err());
+    }
+
+    #[test]
+    fn section_larger_than_data() {
+        let mut bytes = build_file(0, 0);
+        // Decompressed size of the first section header.
+        bytes[88 + 12..88 + 16].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(GrannyData::from_bytes(&mut ByteReader::without_metadata(&bytes)).is_err());
+    }
+}
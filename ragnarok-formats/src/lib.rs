@@ -8,8 +8,11 @@ pub mod action;
 pub mod archive;
 pub mod color;
 pub mod effect;
+pub mod granny;
 pub mod map;
 pub mod model;
+#[cfg(test)]
+mod round_trip;
 pub mod signature;
 pub mod sprite;
 pub mod transform;
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use cgmath::{Point3, Vector3};
+use cgmath::Point3;
 use ragnarok_bytes::{ByteConvertable, ByteReader, ByteWriter, ConversionError, ConversionResult, ConversionResultExt, FromBytes, ToBytes};
 
 use crate::color::{ColorBGRA, ColorRGB};
@@ -8,7 +8,7 @@ use crate::signature::Signature;
 use crate::transform::Transform;
 use crate::version::{InternalVersion, MajorFirst, Version};
 
-#[derive(Clone, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[cfg_attr(feature = "interface", derive(korangar_interface::window::StateWindow))]
 #[cfg_attr(feature = "interface", window_title("Map Inspector"))]
@@ -55,7 +55,7 @@ pub struct MapData {
     // TODO: Uncomment this once the compiler stops overflowing its stack
     derive(rust_state::RustState, /* korangar_interface::element::StateElement */)
 )]
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct QuadTreeData {
     pub max: [f32; 3],
     pub min: [f32; 3],
@@ -140,6 +140,24 @@ impl FromBytes for QuadTreeData {
     }
 }
 
+impl ToBytes for QuadTreeData {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        // Nodes are stored in the same depth first order that they are read in.
+        byte_writer.write_counted(|write| {
+            self.max.to_bytes(write).trace::<Self>()?;
+            self.min.to_bytes(write).trace::<Self>()?;
+            self.half_size.to_bytes(write).trace::<Self>()?;
+            self.center.to_bytes(write).trace::<Self>()?;
+
+            for child in &self.children {
+                child.to_bytes(write).trace::<Self>()?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
 impl FromBytes for TileFlags {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
         match <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).trace::<Self>()? {
@@ -187,7 +205,7 @@ impl ToBytes for TileFlags {
     }
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 pub struct Tile {
     pub southwest_corner_height: f32,
     pub southeast_corner_height: f32,
@@ -198,7 +216,7 @@ pub struct Tile {
     pub unused: [u8; 3],
 }
 
-#[derive(ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 pub struct GatData {
     #[new_default]
     pub signature: Signature<b"GRAT">,
@@ -210,7 +228,7 @@ pub struct GatData {
     pub tiles: Vec<Tile>,
 }
 
-#[derive(ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 pub struct GroundData {
     #[new_default]
     pub signature: Signature<b"GRGN">,
@@ -243,8 +261,13 @@ pub struct GroundData {
     pub surfaces: Vec<Surface>,
     #[repeating_expr(width as usize * height as usize)]
     pub ground_tiles: Vec<GroundTile>,
+    // TODO: Parse water settings
+    #[version_equals_or_above(1, 8)]
+    #[new_default]
+    pub _water_data: Option<Vec<u8>>,
 }
 
+#[derive(Debug, PartialEq)]
 pub struct GroundTile {
     pub southwest_corner_height: f32,
     pub southeast_corner_height: f32,
@@ -294,8 +317,31 @@ impl FromBytes for GroundTile {
 }
 
 impl ToBytes for GroundTile {
-    fn to_bytes(&self, _byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
-        panic!("GroundTile can not be serialized currently because it depends on a version requirement");
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        let version = byte_writer
+            .get_metadata::<Self, Option<InternalVersion>>()?
+            .ok_or(ConversionError::from_message("version not set"))?;
+
+        byte_writer.write_counted(|write| {
+            self.southwest_corner_height.to_bytes(write).trace::<Self>()?;
+            self.southeast_corner_height.to_bytes(write).trace::<Self>()?;
+            self.northwest_corner_height.to_bytes(write).trace::<Self>()?;
+            self.northeast_corner_height.to_bytes(write).trace::<Self>()?;
+
+            for surface_index in [self.top_surface_index, self.north_surface_index, self.east_surface_index] {
+                match version.equals_or_above(1, 7) {
+                    true => surface_index.to_bytes(write).trace::<Self>()?,
+                    false => i16::try_from(surface_index)
+                        .map_err(|_| {
+                            ConversionError::from_message(format!("surface index {surface_index} is too big for version {version}"))
+                        })?
+                        .to_bytes(write)
+                        .trace::<Self>()?,
+                };
+            }
+
+            Ok(())
+        })
     }
 }
 
@@ -306,7 +352,7 @@ pub enum SurfaceType {
     Top,
 }
 
-#[derive(ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 pub struct Surface {
     pub u: [f32; 4],
     pub v: [f32; 4],
@@ -315,28 +361,17 @@ pub struct Surface {
     pub color: ColorBGRA,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ByteConvertable)]
+#[numeric_type(i32)]
 pub enum ResourceType {
+    #[numeric_value(1)]
     Object,
     LightSource,
     SoundSource,
     EffectSource,
 }
 
-impl FromBytes for ResourceType {
-    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        let index = i32::from_bytes(byte_reader).trace::<Self>()?;
-        match index {
-            1 => Ok(ResourceType::Object),
-            2 => Ok(ResourceType::LightSource),
-            3 => Ok(ResourceType::SoundSource),
-            4 => Ok(ResourceType::EffectSource),
-            _ => Err(ConversionError::from_message(format!("invalid object type {index}"))),
-        }
-    }
-}
-
-#[derive(Clone, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ObjectData {
     #[length(40)]
@@ -359,7 +394,11 @@ pub struct ObjectData {
     pub transform: Transform,
 }
 
-#[derive(Clone)]
+/// Resources of a map, grouped by their type.
+///
+/// The values are stored exactly as they are in the file, so positions of
+/// light, sound and effect sources are *not* flipped along the y axis yet.
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct MapResources {
     pub resources_amount: u32,
@@ -367,6 +406,10 @@ pub struct MapResources {
     pub light_sources: Vec<LightSource>,
     pub sound_sources: Vec<SoundSource>,
     pub effect_sources: Vec<EffectSource>,
+    /// The order in which the resources are stored in the file, so that
+    /// writing them back doesn't shuffle them.
+    #[cfg_attr(feature = "interface", hidden_element)]
+    pub resource_order: Vec<ResourceType>,
 }
 
 impl MapResources {
@@ -380,12 +423,19 @@ impl MapResources {
             .try_into()
             .expect("too many resources");
 
+        let resource_order = std::iter::repeat_n(ResourceType::Object, objects.len())
+            .chain(std::iter::repeat_n(ResourceType::LightSource, light_sources.len()))
+            .chain(std::iter::repeat_n(ResourceType::SoundSource, sound_sources.len()))
+            .chain(std::iter::repeat_n(ResourceType::EffectSource, effect_sources.len()))
+            .collect();
+
         Self {
             resources_amount,
             objects,
             light_sources,
             sound_sources,
             effect_sources,
+            resource_order,
         }
     }
 }
@@ -398,44 +448,19 @@ impl FromBytes for MapResources {
         let mut light_sources = Vec::new();
         let mut sound_sources = Vec::new();
         let mut effect_sources = Vec::new();
+        let mut resource_order = Vec::with_capacity(resources_amount as usize);
 
-        for index in 0..resources_amount {
+        for _ in 0..resources_amount {
             let resource_type = ResourceType::from_bytes(byte_reader).trace::<Self>()?;
 
             match resource_type {
-                ResourceType::Object => {
-                    let mut object = ObjectData::from_bytes(byte_reader).trace::<Self>()?;
-                    // offset the objects slightly to avoid depth buffer fighting
-                    object.transform.position += Vector3::new(0.0, 0.0005, 0.0) * index as f32;
-                    objects.push(object);
-                }
-                ResourceType::LightSource => {
-                    let mut light_source = LightSource::from_bytes(byte_reader).trace::<Self>()?;
-                    light_source.position.y = -light_source.position.y;
-
-                    // Some light sources have color channels with values bigger than 1.0 (255), so
-                    // we need to clamp them.
-                    // TODO: Does this maybe have a special meaning?
-                    light_source.color.clamp_color_channels();
-
-                    light_sources.push(light_source);
-                }
-                ResourceType::SoundSource => {
-                    let mut sound_source = SoundSource::from_bytes(byte_reader).trace::<Self>()?;
-                    sound_source.position.y = -sound_source.position.y;
-
-                    if sound_source.cycle.is_none() {
-                        sound_source.cycle = Some(4.0);
-                    }
-
-                    sound_sources.push(sound_source);
-                }
-                ResourceType::EffectSource => {
-                    let mut effect_source = EffectSource::from_bytes(byte_reader).trace::<Self>()?;
-                    effect_source.position.y = -effect_source.position.y;
-                    effect_sources.push(effect_source);
-                }
+                ResourceType::Object => objects.push(ObjectData::from_bytes(byte_reader).trace::<Self>()?),
+                ResourceType::LightSource => light_sources.push(LightSource::from_bytes(byte_reader).trace::<Self>()?),
+                ResourceType::SoundSource => sound_sources.push(SoundSource::from_bytes(byte_reader).trace::<Self>()?),
+                ResourceType::EffectSource => effect_sources.push(EffectSource::from_bytes(byte_reader).trace::<Self>()?),
             }
+
+            resource_order.push(resource_type);
         }
 
         Ok(Self {
@@ -444,37 +469,49 @@ impl FromBytes for MapResources {
             light_sources,
             sound_sources,
             effect_sources,
+            resource_order,
         })
     }
 }
 
 impl ToBytes for MapResources {
     fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        let mut objects = self.objects.iter();
+        let mut light_sources = self.light_sources.iter();
+        let mut sound_sources = self.sound_sources.iter();
+        let mut effect_sources = self.effect_sources.iter();
+
+        let missing_resource = || ConversionError::from_message("resource order doesn't match the resources");
+
         byte_writer.write_counted(|write| {
-            self.resources_amount.to_bytes(write)?;
+            self.resources_amount.to_bytes(write).trace::<Self>()?;
 
-            for object in &self.objects {
-                object.to_bytes(write)?;
-            }
+            for resource_type in &self.resource_order {
+                resource_type.to_bytes(write).trace::<Self>()?;
 
-            for light_source in &self.light_sources {
-                light_source.to_bytes(write)?;
+                match resource_type {
+                    ResourceType::Object => objects.next().ok_or_else(missing_resource)?.to_bytes(write),
+                    ResourceType::LightSource => light_sources.next().ok_or_else(missing_resource)?.to_bytes(write),
+                    ResourceType::SoundSource => sound_sources.next().ok_or_else(missing_resource)?.to_bytes(write),
+                    ResourceType::EffectSource => effect_sources.next().ok_or_else(missing_resource)?.to_bytes(write),
+                }
+                .trace::<Self>()?;
             }
 
-            for sound_source in &self.sound_sources {
-                sound_source.to_bytes(write)?;
-            }
+            let has_remaining = objects.next().is_some()
+                || light_sources.next().is_some()
+                || sound_sources.next().is_some()
+                || effect_sources.next().is_some();
 
-            for effect_source in &self.effect_sources {
-                effect_source.to_bytes(write)?;
+            match has_remaining {
+                true => Err(missing_resource()),
+                false => Ok(()),
             }
-
-            Ok(())
         })
     }
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct WaterSettings {
     #[version_equals_or_above(1, 3)]
@@ -491,7 +528,7 @@ pub struct WaterSettings {
     pub texture_cycling_interval: Option<u32>,
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct LightSettings {
     #[version_equals_or_above(1, 5)]
@@ -506,7 +543,7 @@ pub struct LightSettings {
     pub shadow_map_alpha: Option<f32>,
 }
 
-#[derive(Clone, PartialEq, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[cfg_attr(feature = "interface", derive(korangar_interface::window::StateWindow))]
 #[cfg_attr(feature = "interface", window_title("Light Source"))]
@@ -518,7 +555,7 @@ pub struct LightSource {
     pub range: f32,
 }
 
-#[derive(Clone, PartialEq, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[cfg_attr(feature = "interface", derive(korangar_interface::window::StateWindow))]
 #[cfg_attr(feature = "interface", window_title("Effect Source"))]
@@ -534,7 +571,7 @@ pub struct EffectSource {
     pub _param3: f32,
 }
 
-#[derive(Clone, PartialEq, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[cfg_attr(feature = "interface", derive(korangar_interface::window::StateWindow))]
 #[cfg_attr(feature = "interface", window_title("Sound Source"))]
@@ -675,4 +712,304 @@ mod conversion {
             }
         }
     }
+
+    mod map_data {
+        use cgmath::{Deg, Point3, Vector3};
+
+        use crate::color::ColorRGB;
+        use crate::map::{
+            EffectSource, LightSettings, LightSource, MapData, MapResources, ObjectData, QuadTreeData, SoundSource, WaterSettings,
+        };
+        use crate::round_trip::{assert_bytes_round_trip, assert_round_trip};
+        use crate::transform::Transform;
+        use crate::version::{InternalVersion, Version};
+
+        const VERSIONS: &[(u8, u8)] = &[(1, 2), (1, 4), (1, 5), (1, 7), (1, 9), (2, 0), (2, 1), (2, 2), (2, 5), (2, 6)];
+
+        fn quadtree(depth: usize) -> QuadTreeData {
+            let value = depth as f32;
+
+            QuadTreeData {
+                max: [value, 1.0, 2.0],
+                min: [-value, -1.0, -2.0],
+                half_size: [value * 0.5, 1.0, 2.0],
+                center: [0.0, 0.5, value],
+                children: match depth < 5 {
+                    true => (0..4).map(|_| quadtree(depth + 1)).collect(),
+                    false => Vec::new(),
+                },
+            }
+        }
+
+        fn resources(version: InternalVersion) -> MapResources {
+            let object = |name: &str| ObjectData {
+                name: version.equals_or_above(1, 3).then(|| name.to_owned()),
+                _animation_type: version.equals_or_above(1, 3).then_some(1),
+                _animation_speed: version.equals_or_above(1, 3).then_some(1.5),
+                _block_type: version.equals_or_above(1, 3).then_some(0),
+                _unknown: version.equals_or_above(2, 6).then_some(0),
+                model_name: "model.rsm".to_owned(),
+                _node_name: String::new(),
+                transform: Transform::from(
+                    Point3::new(1.0, -2.0, 3.0),
+                    Vector3::new(Deg(0.1), Deg(90.0), Deg(-33.3)),
+                    Vector3::new(1.0, 1.0, -1.0),
+                ),
+            };
+            let light_source = LightSource {
+                name: "light".to_owned(),
+                position: Point3::new(4.0, 5.0, 6.0),
+                color: ColorRGB {
+                    red: 1.5,
+                    green: 0.5,
+                    blue: 0.25,
+                },
+                range: 20.0,
+            };
+            let sound_source = SoundSource {
+                name: "sound".to_owned(),
+                sound_file: "sound.wav".to_owned(),
+                position: Point3::new(7.0, 8.0, 9.0),
+                volume: 0.8,
+                width: 10,
+                height: 10,
+                range: 50.0,
+                cycle: version.equals_or_above(2, 0).then_some(2.5),
+            };
+            let effect_source = EffectSource {
+                name: "effect".to_owned(),
+                position: Point3::new(-1.0, -2.0, -3.0),
+                effect_type: 47,
+                emit_speed: 1.0,
+                _param0: 0.0,
+                _param1: 1.0,
+                _param2: 2.0,
+                _param3: 3.0,
+            };
+
+            MapResources::new(
+                vec![object("first"), object("second")],
+                vec![light_source],
+                vec![sound_source],
+                vec![effect_source],
+            )
+        }
+
+        fn map_data(major: u8, minor: u8) -> MapData {
+            let version = InternalVersion { major, minor };
+
+            MapData {
+                signature: Default::default(),
+                version: Version::new(major, minor),
+                build_number: version.equals_or_above(2, 5).then_some(187),
+                _unknown: version.equals_or_above(2, 2).then_some(0),
+                _ini_file: String::new(),
+                ground_file: "map.gnd".to_owned(),
+                gat_file: "map.gat".to_owned(),
+                _source_file: version.equals_or_above(1, 4).then(|| "map.src".to_owned()),
+                water_settings: version.smaller(2, 6).then(|| WaterSettings {
+                    water_level: version.equals_or_above(1, 3).then_some(-1.0),
+                    water_type: version.equals_or_above(1, 8).then_some(2),
+                    wave_height: version.equals_or_above(1, 8).then_some(1.0),
+                    wave_speed: version.equals_or_above(1, 8).then_some(2.0),
+                    wave_pitch: version.equals_or_above(1, 8).then_some(50.0),
+                    texture_cycling_interval: version.equals_or_above(1, 9).then_some(3),
+                }),
+                light_settings: LightSettings {
+                    light_longitude: version.equals_or_above(1, 5).then_some(45),
+                    light_latitude: version.equals_or_above(1, 5).then_some(45),
+                    diffuse_color: version.equals_or_above(1, 5).then_some(ColorRGB {
+                        red: 1.0,
+                        green: 1.0,
+                        blue: 1.0,
+                    }),
+                    ambient_color: version.equals_or_above(1, 5).then_some(ColorRGB {
+                        red: 0.3,
+                        green: 0.3,
+                        blue: 0.3,
+                    }),
+                    shadow_map_alpha: version.equals_or_above(1, 7).then_some(0.5),
+                },
+                ground_top: version.equals_or_above(1, 6).then_some(-500),
+                ground_bottom: version.equals_or_above(1, 6).then_some(500),
+                ground_left: version.equals_or_above(1, 6).then_some(-500),
+                ground_right: version.equals_or_above(1, 6).then_some(500),
+                resources: resources(version),
+                quadtree: version.equals_or_above(2, 1).then(|| quadtree(0)),
+            }
+        }
+
+        #[test]
+        fn map_data_versions() {
+            for (major, minor) in VERSIONS.iter().copied() {
+                assert_round_trip(&map_data(major, minor), None);
+            }
+        }
+
+        #[test]
+        fn resource_order() {
+            let version = InternalVersion { major: 2, minor: 0 };
+            let mut resources = resources(version);
+            resources.resource_order.rotate_left(2);
+
+            assert_round_trip(&resources, Some(version));
+        }
+
+        #[test]
+        fn resource_order_mismatch() {
+            let version = InternalVersion { major: 2, minor: 0 };
+            let mut resources = resources(version);
+            resources.resource_order.pop();
+
+            let mut byte_writer = ragnarok_bytes::ByteWriter::new();
+            byte_writer.set_metadata(Some(version));
+
+            assert!(ragnarok_bytes::ToBytes::to_bytes(&resources, &mut byte_writer).is_err());
+        }
+
+        #[test]
+        fn resources_bytes() {
+            #[rustfmt::skip]
+            let input = &[
+                // resource count
+                2, 0, 0, 0,
+                // effect source
+                4, 0, 0, 0,
+                b'e', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64,
+                1, 0, 0, 0,
+                0, 0, 128, 63,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                // light source
+                2, 0, 0, 0,
+                b'l', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 128, 191, 0, 0, 0, 192, 0, 0, 64, 192,
+                0, 0, 192, 63, 0, 0, 0, 0, 0, 0, 128, 63,
+                0, 0, 160, 65,
+            ];
+
+            assert_bytes_round_trip::<MapResources>(input, Some(InternalVersion { major: 2, minor: 0 }));
+        }
+    }
+
+    mod ground_data {
+        use crate::color::ColorBGRA;
+        use crate::map::{GroundData, GroundTile, Surface};
+        use crate::round_trip::{assert_bytes_round_trip, assert_round_trip};
+        use crate::version::{InternalVersion, Version};
+
+        const VERSIONS: &[(u8, u8)] = &[(1, 5), (1, 6), (1, 7), (1, 8), (1, 9)];
+
+        fn ground_data(major: u8, minor: u8) -> GroundData {
+            let version = InternalVersion { major, minor };
+            let (width, height, light_map_count) = (2, 3, 2);
+
+            GroundData {
+                signature: Default::default(),
+                version: Version::new(major, minor),
+                width,
+                height,
+                zoom: 10.0,
+                texture_count: 2,
+                texture_name_length: 80,
+                textures: vec!["first.bmp".to_owned(), "second.bmp".to_owned()],
+                light_map_count,
+                light_map_width: 8,
+                light_map_height: 8,
+                light_map_cells_per_grid: 1,
+                _skip: version
+                    .equals_or_above(1, 7)
+                    .then(|| (0..light_map_count as usize * 8 * 8 * 4).map(|index| index as u8).collect()),
+                _skip2: version
+                    .smaller(1, 7)
+                    .then(|| (0..light_map_count as usize * 16).map(|index| index as u8).collect()),
+                surface_count: 1,
+                surfaces: vec![Surface {
+                    u: [0.0, 1.0, 0.0, 1.0],
+                    v: [0.0, 0.0, 1.0, 1.0],
+                    texture_index: 1,
+                    light_map_index: 0,
+                    color: ColorBGRA {
+                        blue: 255,
+                        green: 128,
+                        red: 64,
+                        alpha: 255,
+                    },
+                }],
+                ground_tiles: (0..width * height)
+                    .map(|index| GroundTile {
+                        southwest_corner_height: index as f32,
+                        southeast_corner_height: -1.0,
+                        northwest_corner_height: 2.5,
+                        northeast_corner_height: 0.0,
+                        top_surface_index: 0,
+                        north_surface_index: -1,
+                        east_surface_index: -1,
+                    })
+                    .collect(),
+                _water_data: version.equals_or_above(1, 8).then(|| vec![1, 2, 3, 4, 5, 6, 7, 8]),
+            }
+        }
+
+        #[test]
+        fn ground_data_versions() {
+            for (major, minor) in VERSIONS.iter().copied() {
+                assert_round_trip(&ground_data(major, minor), None);
+            }
+        }
+
+        #[test]
+        fn ground_tile_small_indices() {
+            #[rustfmt::skip]
+            let input = &[
+                0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64,
+                1, 0, 255, 255, 2, 0,
+            ];
+
+            assert_bytes_round_trip::<GroundTile>(input, Some(InternalVersion { major: 1, minor: 6 }));
+        }
+
+        #[test]
+        fn ground_tile_large_indices() {
+            #[rustfmt::skip]
+            let input = &[
+                0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64,
+                1, 0, 1, 0, 255, 255, 255, 255, 2, 0, 0, 0,
+            ];
+
+            assert_bytes_round_trip::<GroundTile>(input, Some(InternalVersion { major: 1, minor: 7 }));
+        }
+    }
+
+    mod gat_data {
+        use crate::map::{GatData, Tile, TileFlags};
+        use crate::round_trip::assert_round_trip;
+        use crate::version::Version;
+
+        #[test]
+        fn gat_data() {
+            let flags = [TileFlags::WALKABLE, TileFlags::empty(), TileFlags::WATER, TileFlags::CLIFF];
+            let gat_data = GatData {
+                signature: Default::default(),
+                version: Version::new(1, 2),
+                map_width: 2,
+                map_height: 2,
+                tiles: flags
+                    .into_iter()
+                    .map(|flags| Tile {
+                        southwest_corner_height: 1.0,
+                        southeast_corner_height: 2.0,
+                        northwest_corner_height: -3.0,
+                        northeast_corner_height: 0.5,
+                        flags,
+                        unused: [0; 3],
+                    })
+                    .collect(),
+            };
+
+            assert_round_trip(&gat_data, None);
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use cgmath::{Matrix3, Point3, Quaternion, Vector2, Vector3};
 use ragnarok_bytes::{
     ByteConvertable, ByteReader, ByteWriter, ConversionError, ConversionResult, ConversionResultExt, FromBytes, FromBytesExt, ToBytes,
+    ToBytesExt,
 };
 use rust_state::Path;
 
@@ -33,8 +34,28 @@ impl<const LENGTH: usize> FromBytes for ModelString<LENGTH> {
 }
 
 impl<const LENGTH: usize> ToBytes for ModelString<LENGTH> {
-    fn to_bytes(&self, _byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
-        panic!("ModelString can not be serialized currently because it depends on a version requirement");
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        let is_length_prefixed = byte_writer
+            .get_metadata::<Self, Option<InternalVersion>>()?
+            .ok_or(ConversionError::from_message("version not set"))?
+            .equals_or_above(2, 2);
+
+        match is_length_prefixed {
+            true => byte_writer.write_counted(|write| {
+                // The length isn't known before the string is encoded, so we write a
+                // placeholder first.
+                let length_position = write.len();
+                0u32.to_bytes(write).trace::<Self>()?;
+
+                // Length prefixed strings are not zero terminated.
+                write.encode_string(&self.inner);
+                write.pop();
+
+                let length = (write.len() - length_position - size_of::<u32>()) as u32;
+                write.overwrite_at(length_position, length.to_le_bytes()).trace::<Self>()
+            }),
+            false => self.inner.to_n_bytes(byte_writer, LENGTH).trace::<Self>(),
+        }
     }
 }
 
@@ -75,7 +96,7 @@ where
     }
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ScaleKeyframeData {
     pub frame: i32,
@@ -83,14 +104,14 @@ pub struct ScaleKeyframeData {
     reserved: f32,
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct RotationKeyframeData {
     pub frame: i32,
     pub quaternions: Quaternion<f32>,
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct TranslationKeyframeData {
     pub frame: i32,
@@ -98,7 +119,7 @@ pub struct TranslationKeyframeData {
     reserved: f32,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct TexturesKeyframeData {
     pub texture_index: u32,
@@ -110,7 +131,7 @@ pub struct TexturesKeyframeData {
 
 /// List of texture operation types.
 /// See: https://rathena.org/board/topic/127587-rsm2-file-format/
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[numeric_type(u32)]
 pub enum TextureOperation {
@@ -126,7 +147,7 @@ pub enum TextureOperation {
     Rotation,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct TextureKeyframeData {
     pub operation_type: TextureOperation,
@@ -136,14 +157,14 @@ pub struct TextureKeyframeData {
     pub texture_frames: Vec<TextureFrameData>,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct TextureFrameData {
     pub frame: i32,
     pub operation_value: f32,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct FaceData {
     #[version_equals_or_above(2, 2)]
@@ -159,7 +180,7 @@ pub struct FaceData {
     pub smooth_group_extra: Option<Vec<i32>>,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct TextureCoordinateData {
     #[version_equals_or_above(1, 2)]
@@ -167,7 +188,7 @@ pub struct TextureCoordinateData {
     pub coordinates: Vector2<f32>, // possibly wrong if version < 1.2
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct NodeData {
     pub node_name: ModelString<40>,
@@ -226,7 +247,7 @@ pub struct NodeData {
     pub textures_keyframes: Vec<TexturesKeyframeData>,
 }
 
-#[derive(Debug, ByteConvertable)]
+#[derive(Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ModelData {
     #[new_default]
@@ -259,3 +280,165 @@ pub struct ModelData {
     #[repeating(node_count)]
     pub nodes: Vec<NodeData>,
 }
+
+#[cfg(test)]
+mod conversion {
+    use cgmath::{Matrix3, Point3, Quaternion, Vector2, Vector3};
+
+    use super::{
+        FaceData, ModelData, ModelString, NodeData, RotationKeyframeData, ScaleKeyframeData, TextureCoordinateData, TextureFrameData,
+        TextureKeyframeData, TextureOperation, TexturesKeyframeData, TranslationKeyframeData,
+    };
+    use crate::round_trip::{assert_bytes_round_trip, assert_round_trip};
+    use crate::version::{InternalVersion, Version};
+
+    const VERSIONS: &[(u8, u8)] = &[(1, 2), (1, 4), (1, 5), (2, 2), (2, 3)];
+
+    fn model_string<const LENGTH: usize>(inner: &str) -> ModelString<LENGTH> {
+        ModelString { inner: inner.to_owned() }
+    }
+
+    fn node_data(version: InternalVersion, node_name: &str, parent_node_name: &str) -> NodeData {
+        let texture_indices = match version.smaller(2, 3) {
+            true => vec![0, 1],
+            false => Vec::new(),
+        };
+        let texture_names = match version.equals_or_above(2, 3) {
+            true => vec![model_string("first.bmp"), model_string("second.bmp")],
+            false => Vec::new(),
+        };
+        let smooth_group_extra = vec![1, 2];
+        let scale_keyframes = match version.equals_or_above(1, 6) {
+            true => vec![ScaleKeyframeData {
+                frame: 0,
+                scale: Vector3::new(1.0, 2.0, 1.0),
+                reserved: 0.0,
+            }],
+            false => Vec::new(),
+        };
+        let translation_keyframes = match version.equals_or_above(2, 2) {
+            true => vec![TranslationKeyframeData {
+                frame: 10,
+                translation: Vector3::new(0.0, -5.0, 2.5),
+                reserved: 0.0,
+            }],
+            false => Vec::new(),
+        };
+        let textures_keyframes = match version.equals_or_above(2, 3) {
+            true => vec![TexturesKeyframeData {
+                texture_index: 1,
+                texture_keyframe_count: 1,
+                texture_keyframes: vec![TextureKeyframeData {
+                    operation_type: TextureOperation::ScaleY,
+                    frame_count: 2,
+                    texture_frames: vec![
+                        TextureFrameData {
+                            frame: 0,
+                            operation_value: 1.0,
+                        },
+                        TextureFrameData {
+                            frame: 20,
+                            operation_value: 0.5,
+                        },
+                    ],
+                }],
+            }],
+            false => Vec::new(),
+        };
+
+        NodeData {
+            node_name: model_string(node_name),
+            parent_node_name: model_string(parent_node_name),
+            texture_count: version.smaller(2, 3).then_some(texture_indices.len() as u32),
+            texture_indices,
+            texture_name_count: version.equals_or_above(2, 3).then_some(texture_names.len() as u32),
+            texture_names,
+            offset_matrix: Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0),
+            translation1: version.smaller(2, 2).then_some(Vector3::new(1.0, 2.0, 3.0)),
+            translation2: Vector3::new(-1.0, -2.0, -3.0),
+            rotation_angle: version.smaller(2, 2).then_some(0.25),
+            rotation_axis: version.smaller(2, 2).then_some(Vector3::new(0.0, 1.0, 0.0)),
+            scale: version.smaller(2, 2).then_some(Vector3::new(1.0, 1.0, 1.0)),
+            vertex_position_count: 3,
+            vertex_positions: vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            texture_coordinate_count: 1,
+            texture_coordinates: vec![TextureCoordinateData {
+                color: version.equals_or_above(1, 2).then_some(0xFFFFFFFF),
+                coordinates: Vector2::new(0.5, 0.25),
+            }],
+            face_count: 1,
+            faces: vec![FaceData {
+                length: version.equals_or_above(2, 2).then_some(24 + smooth_group_extra.len() as u32 * 4),
+                vertex_position_indices: [0, 1, 2],
+                texture_coordinate_indices: [0, 0, 0],
+                texture_index: 1,
+                padding: 0,
+                two_sided: 1,
+                smooth_group: 0,
+                smooth_group_extra: version.equals_or_above(2, 2).then_some(smooth_group_extra),
+            }],
+            scale_keyframe_count: version.equals_or_above(1, 6).then_some(scale_keyframes.len() as u32),
+            scale_keyframes,
+            rotation_keyframe_count: 1,
+            rotation_keyframes: vec![RotationKeyframeData {
+                frame: 5,
+                quaternions: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            }],
+            translation_keyframe_count: version.equals_or_above(2, 2).then_some(translation_keyframes.len() as u32),
+            translation_keyframes,
+            textures_keyframe_count: version.equals_or_above(2, 3).then_some(textures_keyframes.len() as u32),
+            textures_keyframes,
+        }
+    }
+
+    fn model_data(major: u8, minor: u8) -> ModelData {
+        let version = InternalVersion { major, minor };
+        let texture_names = match version.smaller(2, 3) {
+            true => vec![model_string("first.bmp"), model_string("second.bmp")],
+            false => Vec::new(),
+        };
+        let root_node_names = match version.equals_or_above(2, 2) {
+            true => vec![model_string("root")],
+            false => Vec::new(),
+        };
+
+        ModelData {
+            signature: Default::default(),
+            version: Version::new(major, minor),
+            animation_length: 100,
+            shade_type: 2,
+            alpha: version.equals_or_above(1, 4).then_some(255),
+            reserved: version.smaller(2, 2).then_some([0; 16]),
+            frames_per_second: version.equals_or_above(2, 2).then_some(30.0),
+            texture_count: version.smaller(2, 3).then_some(texture_names.len() as u32),
+            texture_names,
+            root_node_name: version.smaller(2, 2).then(|| model_string("root")),
+            root_node_count: version.equals_or_above(2, 2).then_some(root_node_names.len() as u32),
+            root_node_names,
+            node_count: 2,
+            nodes: vec![node_data(version, "root", ""), node_data(version, "child", "root")],
+        }
+    }
+
+    #[test]
+    fn model_data_versions() {
+        for (major, minor) in VERSIONS.iter().copied() {
+            assert_round_trip(&model_data(major, minor), None);
+        }
+    }
+
+    #[test]
+    fn model_string_fixed_length() {
+        let mut input = [0; 40];
+        input[..4].copy_from_slice(b"root");
+
+        assert_bytes_round_trip::<ModelString<40>>(&input, Some(InternalVersion { major: 2, minor: 1 }));
+    }
+
+    #[test]
+    fn model_string_length_prefixed() {
+        let input = &[4, 0, 0, 0, b'r', b'o', b'o', b't'];
+
+        assert_bytes_round_trip::<ModelString<40>>(input, Some(InternalVersion { major: 2, minor: 2 }));
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+
+use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes, ToBytes};
+
+use crate::version::InternalVersion;
+
+fn to_bytes<T: ToBytes>(value: &T, version: Option<InternalVersion>) -> Vec<u8> {
+    let mut byte_writer = ByteWriter::new();
+    byte_writer.set_metadata(version);
+    value.to_bytes(&mut byte_writer).unwrap();
+    byte_writer.into_inner()
+}
+
+/// Serializes the value, parses it again and makes sure that the result is
+/// equal to the value and serializes to the exact same bytes.
+///
+/// The version only needs to be set for types that don't contain their own
+/// version.
+pub(crate) fn assert_round_trip<T>(value: &T, version: Option<InternalVersion>)
+where
+    T: FromBytes + ToBytes + PartialEq + Debug,
+{
+    let bytes = to_bytes(value, version);
+    assert_bytes_round_trip::<T>(&bytes, version);
+
+    let mut byte_reader = ByteReader::with_metadata(&bytes, version);
+    assert_eq!(&T::from_bytes(&mut byte_reader).unwrap(), value);
+}
+
+/// Parses the bytes and makes sure that serializing the result produces the
+/// exact same bytes.
+pub(crate) fn assert_bytes_round_trip<T>(bytes: &[u8], version: Option<InternalVersion>)
+where
+    T: FromBytes + ToBytes,
+{
+    let mut byte_reader = ByteReader::with_metadata(bytes, version);
+    let value = T::from_bytes(&mut byte_reader).unwrap();
+
+    assert!(byte_reader.is_empty(), "not all bytes were parsed");
+    assert_eq!(to_bytes(&value, version), bytes);
+}
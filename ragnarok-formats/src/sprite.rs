@@ -5,7 +5,7 @@ use ragnarok_bytes::{
 use crate::signature::Signature;
 use crate::version::{InternalVersion, MinorFirst, Version};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct PaletteImageData {
     pub width: u16,
@@ -13,7 +13,7 @@ pub struct PaletteImageData {
     pub data: EncodedData,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct EncodedData(pub Vec<u8>);
 
@@ -76,12 +76,61 @@ impl FromBytes for PaletteImageData {
 }
 
 impl ToBytes for PaletteImageData {
-    fn to_bytes(&self, _byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
-        panic!("PalletteImageData can not be serialized currently because it depends on a version requirement");
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        let image_size = self.width as usize * self.height as usize;
+
+        if self.data.0.len() != image_size {
+            return Err(ConversionError::from_message("palette image data doesn't match the image size"));
+        }
+
+        let uses_encoding = byte_writer
+            .get_metadata::<Self, Option<InternalVersion>>()?
+            .ok_or(ConversionError::from_message("version not set"))?
+            .equals_or_above(2, 1);
+
+        byte_writer.write_counted(|write| {
+            self.width.to_bytes(write).trace::<Self>()?;
+            self.height.to_bytes(write).trace::<Self>()?;
+
+            if image_size == 0 {
+                return Ok(());
+            }
+
+            if !uses_encoding {
+                write.extend_from_slice(&self.data.0);
+                return Ok(());
+            }
+
+            // Runs of zeros are encoded as a zero followed by the length of the run.
+            let mut encoded = Vec::new();
+            let mut bytes = self.data.0.iter().copied().peekable();
+
+            while let Some(byte) = bytes.next() {
+                if byte != 0 {
+                    encoded.push(byte);
+                    continue;
+                }
+
+                let mut length = 1u8;
+                while length < u8::MAX && bytes.next_if_eq(&0).is_some() {
+                    length += 1;
+                }
+
+                encoded.extend_from_slice(&[0, length]);
+            }
+
+            let encoded_size =
+                u16::try_from(encoded.len()).map_err(|_| ConversionError::from_message("encoded palette image is too big"))?;
+
+            encoded_size.to_bytes(write).trace::<Self>()?;
+            write.extend_from_slice(&encoded);
+
+            Ok(())
+        })
     }
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct RgbaImageData {
     pub width: u16,
@@ -90,7 +139,7 @@ pub struct RgbaImageData {
     pub data: Vec<u8>,
 }
 
-#[derive(Copy, Clone, Debug, Default, ByteConvertable)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct PaletteColor {
     pub red: u8,
@@ -99,13 +148,13 @@ pub struct PaletteColor {
     pub reserved: u8,
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct Palette {
     pub colors: [PaletteColor; 256],
 }
 
-#[derive(Clone, Debug, ByteConvertable)]
+#[derive(Clone, Debug, PartialEq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct SpriteData {
     #[new_default]
@@ -124,3 +173,79 @@ pub struct SpriteData {
     #[version_equals_or_above(1, 1)]
     pub palette: Option<Palette>,
 }
+
+#[cfg(test)]
+mod conversion {
+    use super::{EncodedData, Palette, PaletteColor, PaletteImageData, RgbaImageData, SpriteData};
+    use crate::round_trip::{assert_bytes_round_trip, assert_round_trip};
+    use crate::version::{InternalVersion, Version};
+
+    const VERSIONS: &[(u8, u8)] = &[(1, 0), (1, 1), (2, 0), (2, 1)];
+
+    fn sprite_data(major: u8, minor: u8) -> SpriteData {
+        let version = InternalVersion { major, minor };
+        let palette_image_data = vec![
+            PaletteImageData {
+                width: 4,
+                height: 3,
+                data: EncodedData(vec![0, 0, 0, 1, 2, 0, 3, 0, 0, 4, 5, 0]),
+            },
+            PaletteImageData {
+                width: 0,
+                height: 0,
+                data: EncodedData(Vec::new()),
+            },
+            PaletteImageData {
+                width: 30,
+                height: 10,
+                data: EncodedData(vec![0; 300]),
+            },
+        ];
+        let rgba_image_data = match version.equals_or_above(1, 2) {
+            true => vec![RgbaImageData {
+                width: 2,
+                height: 1,
+                data: vec![255, 0, 0, 255, 0, 255, 0, 128],
+            }],
+            false => Vec::new(),
+        };
+
+        SpriteData {
+            signature: Default::default(),
+            version: Version::new(major, minor),
+            palette_image_count: palette_image_data.len() as u16,
+            rgba_image_count: version.equals_or_above(1, 2).then_some(rgba_image_data.len() as u16),
+            palette_image_data,
+            rgba_image_data,
+            palette: version.equals_or_above(1, 1).then(|| Palette {
+                colors: std::array::from_fn(|index| PaletteColor {
+                    red: index as u8,
+                    green: 255 - index as u8,
+                    blue: 0,
+                    reserved: 0,
+                }),
+            }),
+        }
+    }
+
+    #[test]
+    fn sprite_data_versions() {
+        for (major, minor) in VERSIONS.iter().copied() {
+            assert_round_trip(&sprite_data(major, minor), None);
+        }
+    }
+
+    #[test]
+    fn palette_image_raw() {
+        let input = &[3, 0, 1, 0, 0, 7, 0];
+
+        assert_bytes_round_trip::<PaletteImageData>(input, Some(InternalVersion { major: 2, minor: 0 }));
+    }
+
+    #[test]
+    fn palette_image_encoded() {
+        let input = &[4, 0, 2, 0, 6, 0, 0, 3, 7, 0, 3, 9];
+
+        assert_bytes_round_trip::<PaletteImageData>(input, Some(InternalVersion { major: 2, minor: 1 }));
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::Add;
 
-use cgmath::{Deg, EuclideanSpace, Point3, Rad, Vector3};
+use cgmath::{Deg, EuclideanSpace, Point3, Vector3};
 use ragnarok_bytes::{ByteReader, ByteWriter, ConversionResult, ConversionResultExt, FromBytes, ToBytes};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -8,7 +8,7 @@ use ragnarok_bytes::{ByteReader, ByteWriter, ConversionResult, ConversionResultE
 pub struct Transform {
     pub position: Point3<f32>,
     #[cfg_attr(feature = "interface", hidden_element)] // TODO: unhide
-    pub rotation: Vector3<Rad<f32>>,
+    pub rotation: Vector3<Deg<f32>>,
     pub scale: Vector3<f32>,
 }
 
@@ -19,9 +19,9 @@ impl FromBytes for Transform {
         let scale = <Vector3<f32>>::from_bytes(byte_reader).trace::<Self>()?;
 
         // Convert from a standard Rust float (which is in degrees) to a stronger cgmath
-        // type that also represents degrees. We can then easily convert it to
-        // radians.
-        let rotation = rotation.map(|degrees| Deg(degrees).into());
+        // type that also represents degrees. We keep the rotation in degrees so that
+        // it can be written back without losing precision.
+        let rotation = rotation.map(Deg);
 
         // TODO: make this nicer
         position.y = -position.y;
@@ -34,7 +34,7 @@ impl ToBytes for Transform {
     fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
         byte_writer.write_counted(|write| {
             let position = Vector3::new(self.position.x, -self.position.y, self.position.z);
-            let rotation = self.rotation.map(|radiants| Deg::from(radiants).0);
+            let rotation = self.rotation.map(|degrees| degrees.0);
             let scale = self.scale;
 
             position.to_bytes(write).trace::<Self>()?;
@@ -48,14 +48,13 @@ impl ToBytes for Transform {
 
 impl Transform {
     pub fn from(position: Point3<f32>, rotation: Vector3<Deg<f32>>, scale: Vector3<f32>) -> Self {
-        let rotation = rotation.map(|degrees| degrees.into());
         Self { position, rotation, scale }
     }
 
     pub fn position(position: Point3<f32>) -> Self {
         Self {
             position,
-            rotation: Vector3::new(Rad(0.0), Rad(0.0), Rad(0.0)),
+            rotation: Vector3::new(Deg(0.0), Deg(0.0), Deg(0.0)),
             scale: Vector3::new(1.0, 1.0, 1.0),
         }
     }
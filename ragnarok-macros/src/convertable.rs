@@ -46,8 +46,6 @@ fn derive_for_struct(
     let to = implement_to.then(|| {
         quote! {
             impl #impl_generics ragnarok_bytes::ToBytes for #name #type_generics #where_clause {
-                // Temporary until serialization is always possible
-                #[allow(unreachable_code)]
                 fn to_bytes(&self, byte_writer: &mut ragnarok_bytes::ByteWriter) -> ragnarok_bytes::ConversionResult<usize> {
                     byte_writer.write_counted(|writer| {
                         #(#to_bytes_implementations)*
@@ -114,8 +112,6 @@ fn derive_for_enum(
     let to = add_to.then(|| {
         quote! {
             impl #impl_generics ragnarok_bytes::ToBytes for #name #type_generics #where_clause {
-                // Temporary until serialization is always possible
-                #[allow(unreachable_code)]
                 fn to_bytes(&self, byte_writer: &mut ragnarok_bytes::ByteWriter) -> ragnarok_bytes::ConversionResult<usize> {
                     match self {
                         #( #name::#values => ragnarok_bytes::ConversionResultExt::trace::<Self>((#indices as #numeric_type).to_bytes(byte_writer)), )*
@@ -53,13 +53,14 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             None => quote!(ragnarok_bytes::FromBytes::from_bytes(byte_reader)),
         };
 
-        let to_length = match length {
+        let to_length = |value: TokenStream| match length.clone() {
             Some(length) if syn::parse::<syn::Ident>(length.clone().into()).is_ok() => {
-                quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(&self.#field_identifier, writer, self.#length as usize))
+                quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(#value, writer, self.#length as usize))
             }
-            Some(length) => quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(&self.#field_identifier, writer, #length as usize)),
-            None => quote!(ragnarok_bytes::ToBytes::to_bytes(&self.#field_identifier, writer)),
+            Some(length) => quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(#value, writer, #length as usize)),
+            None => quote!(ragnarok_bytes::ToBytes::to_bytes(#value, writer)),
         };
+        let has_length = length.is_some();
 
         let mut repeating: Option<(syn::Ident, bool)> = None;
 
@@ -100,6 +101,17 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
         let version_function = version_smaller.or(version_equals_or_above);
         let version_restricted = version_function.is_some();
 
+        // the length applies to every element of repeating fields, so they need to be
+        // written one by one
+        let is_repeating = repeating.is_some() || repeating_remaining || repeating_expr.is_some();
+        let to_length = |value: TokenStream| match is_repeating && has_length {
+            true => {
+                let to_length = to_length(quote!(item));
+                quote!((#value).iter().map(|item| #to_length).sum::<ragnarok_bytes::ConversionResult<usize>>())
+            }
+            false => to_length(value),
+        };
+
         // base from bytes implementation
         let from_implementation = quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#from_length)?);
 
@@ -184,10 +196,21 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
         };
         from_bytes_implementations.push(from_implementation);
 
-        // base to byte implementation
+        // base to byte implementation, version restricted fields are only written if
+        // they are present
         let to_implementation = match version_restricted {
-            true => quote!(panic!("version restricted fields can't be serialized at the moment");),
-            false => quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length)?;),
+            true => {
+                let to_length = to_length(quote!(value));
+                quote! {
+                    if let Some(value) = &self.#field_identifier {
+                        ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length)?;
+                    }
+                }
+            }
+            false => {
+                let to_length = to_length(quote!(&self.#field_identifier));
+                quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length)?;)
+            }
         };
         to_bytes_implementations.push(to_implementation);
 
@@ -195,6 +218,8 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             from_bytes_implementations.push(
                 quote!(*byte_reader.get_metadata_mut::<Self, Option<ragnarok_formats::version::InternalVersion>>()? = Some(ragnarok_formats::version::InternalVersion::from(#field_variable));),
             );
+            to_bytes_implementations
+                .push(quote!(writer.set_metadata(Some(ragnarok_formats::version::InternalVersion::from(self.#field_identifier)));));
         }
     }
 
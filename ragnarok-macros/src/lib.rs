@@ -136,6 +136,7 @@ pub fn derive_to_bytes(token_stream: InterfaceTokenStream) -> InterfaceTokenStre
         new_value,
         ping,
         repeating,
+        repeating_expr,
         repeating_option,
         repeating_remaining,
         variable_length,
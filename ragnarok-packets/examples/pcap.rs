@@ -185,11 +185,13 @@ fn main() {
         LoginFailedPacket,
         CharacterServerLoginSuccessPacket,
         RequestCharacterListSuccessPacket,
+        LegacyRequestCharacterListSuccessPacket,
         Packet0b18,
         CharacterSelectionSuccessPacket,
         CharacterSelectionFailedPacket,
         MapServerUnavailablePacket,
         CreateCharacterSuccessPacket,
+        LegacyCreateCharacterSuccessPacket,
         CharacterCreationFailedPacket,
         CharacterDeletionSuccessPacket,
         CharacterDeletionFailedPacket,
@@ -203,6 +205,9 @@ fn main() {
         CharacterSlotPagePacket,
         CharacterBanListPacket,
         LoginPincodePacket,
+        CheckPincodePacket,
+        SetPincodePacket,
+        ChangePincodePacket,
         SelectCharacterPacket,
         CreateCharacterPacket,
         DeleteCharacterPacket,
@@ -239,6 +244,7 @@ fn main() {
         InventoyStartPacket,
         RegularItemListPacket,
         EquippableItemListPacket,
+        LegacyEquippableItemListPacket,
         InventoyEndPacket,
         EquippableSwitchItemListPacket,
         MapTypePacket,
@@ -272,6 +278,10 @@ fn main() {
         StateChangePacket,
         QuestEffectPacket,
         ItemPickupPacket,
+        LegacyItemPickupPacket,
+        ItemAppearedPacket,
+        ItemDroppedPacket,
+        ItemDisappearedPacket,
         RemoveItemFromInventoryPacket,
         ServerTickPacket,
         RequestPlayerDetailsSuccessPacket,
@@ -297,10 +307,48 @@ fn main() {
         FriendRequestResultPacket,
         NotifyFriendRemovedPacket,
         PartyInvitePacket,
+        CreatePartyResultPacket,
+        PartyInviteResultPacket,
+        PartyOptionsPacket,
+        PartyInformationPacket,
+        PartyMemberInformationPacket,
+        PartyMemberLeftPacket,
+        PartyMemberHealthPointsPacket,
+        PartyMemberPositionPacket,
+        PartyLeaderChangedPacket,
+        PartyChatMessagePacket,
         StatusChangeSequencePacket,
         ReputationPacket,
         ClanInfoPacket,
         ClanOnlineCountPacket,
+        GuildMembershipPacket,
+        GuildInformationPacket,
+        GuildMemberListPacket,
+        GuildPositionInformationPacket,
+        GuildPositionNamesPacket,
+        CharacterNamePacket,
+        GuildNoticePacket,
+        GuildEmblemPacket,
+        CreateGuildResultPacket,
+        GuildInviteResultPacket,
+        GuildInvitePacket,
+        GuildMemberLeftPacket,
+        GuildMemberExpelledPacket,
+        GuildMemberStatePacket,
+        GuildChatMessagePacket,
+        TradeRequestPacket,
+        TradeRequestResultPacket,
+        TradeItemAddedPacket,
+        LegacyTradeItemAddedPacket,
+        AddTradeItemResultPacket,
+        TradeLockedPacket,
+        TradeCancelledPacket,
+        TradeCompletedPacket,
+        StorageItemCountPacket,
+        StorageItemAddedPacket,
+        LegacyStorageItemAddedPacket,
+        StorageItemRemovedPacket,
+        StorageClosedPacket,
         ChangeMapCellPacket,
         OpenMarketPacket,
         BuyOrSellPacket,
@@ -310,6 +358,16 @@ fn main() {
         SellListPacket,
         SellItemsPacket,
         SellItemsResultPacket,
+        MailListPacket,
+        MailContentPacket,
+        MailDeletedPacket,
+        RetrieveMailZenyResultPacket,
+        RetrieveMailItemsResultPacket,
+        OpenWriteMailResultPacket,
+        AddMailItemResultPacket,
+        RemoveMailItemResultPacket,
+        CheckMailReceiverResultPacket,
+        SendMailResultPacket,
     ]);
 
     let mut server_map_handler = create_handler!(ServerType::Map, Direction::Outgoing, [
@@ -320,11 +378,14 @@ fn main() {
         RequestDetailsPacket,
         RequestActionPacket,
         GlobalMessagePacket,
+        RequestEmotionPacket,
         StartDialogPacket,
         NextDialogPacket,
         CloseDialogPacket,
         ChooseDialogOptionPacket,
         RequestEquipItemPacket,
+        RequestPickUpItemPacket,
+        RequestDropItemPacket,
         RequestUnequipItemPacket,
         UseSkillAtIdPacket,
         UseSkillOnGroundPacket,
@@ -333,11 +394,51 @@ fn main() {
         AddFriendPacket,
         RemoveFriendPacket,
         FriendRequestResponsePacket,
+        CreatePartyPacket,
+        InviteToPartyPacket,
+        PartyInviteResponsePacket,
+        LeavePartyPacket,
+        ExpelPartyMemberPacket,
+        ChangePartyLeaderPacket,
+        ChangePartyOptionsPacket,
+        PartyMessagePacket,
+        RequestGuildInformationPacket,
+        RequestCharacterNamePacket,
+        ChangeGuildNoticePacket,
+        RequestGuildEmblemPacket,
+        CreateGuildPacket,
+        InviteToGuildPacket,
+        GuildInviteResponsePacket,
+        LeaveGuildPacket,
+        ExpelGuildMemberPacket,
+        GuildMessagePacket,
+        RequestTradePacket,
+        TradeRequestResponsePacket,
+        AddTradeItemPacket,
+        LockTradePacket,
+        CancelTradePacket,
+        CommitTradePacket,
+        MoveItemToStoragePacket,
+        MoveItemFromStoragePacket,
+        CloseStoragePacket,
         SetHotkeyData2Packet,
         SelectBuyOrSellPacket,
         BuyShopItemsPacket,
         CloseShopPacket,
         SellItemsPacket,
+        OpenMailboxPacket,
+        RefreshMailboxPacket,
+        CloseMailboxPacket,
+        ReadMailPacket,
+        DeleteMailPacket,
+        RetrieveMailZenyPacket,
+        RetrieveMailItemsPacket,
+        OpenWriteMailPacket,
+        CancelWriteMailPacket,
+        AddMailItemPacket,
+        RemoveMailItemPacket,
+        CheckMailReceiverPacket,
+        SendMailPacket,
         RequestServerTickPacket,
     ]);
 
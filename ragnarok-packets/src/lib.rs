@@ -116,10 +116,23 @@ pub struct CharacterId(pub u32);
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct PartyId(pub u32);
 
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildId(pub u32);
+
+#[derive(Clone, Copy, Debug, Default, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailId(pub u64);
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct EntityId(pub u32);
 
+/// Id of an item lying on the ground.
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GroundItemId(pub u32);
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct SkillId(pub u16);
@@ -205,7 +218,9 @@ pub struct InventoryIndex(pub u16);
 
 impl FromBytes for InventoryIndex {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        u16::from_bytes(byte_reader).map(|raw| Self(raw - 2))
+        // The item list packets are also used for the storage, where the index is
+        // only offset by one, so we can't assume that the raw value is at least 2.
+        u16::from_bytes(byte_reader).map(|raw| Self(raw.wrapping_sub(2)))
     }
 }
 
@@ -215,6 +230,23 @@ impl ToBytes for InventoryIndex {
     }
 }
 
+/// Storage index is always actual index + 1.
+#[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct StorageIndex(pub u16);
+
+impl FromBytes for StorageIndex {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        u16::from_bytes(byte_reader).map(|raw| Self(raw - 1))
+    }
+}
+
+impl ToBytes for StorageIndex {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        u16::to_bytes(&(self.0 + 1), byte_writer)
+    }
+}
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ItemId(pub u32);
@@ -329,13 +361,86 @@ pub struct CharacterBanInformation {
     pub ban_time: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum PincodeState {
+    /// The account doesn't need to enter a pincode.
+    #[numeric_value(0)]
+    Ok,
+    /// The player needs to enter the pincode of the account.
+    #[numeric_value(1)]
+    Ask,
+    /// The account has no pincode yet and the player needs to set one.
+    #[numeric_value(2)]
+    NotSet,
+    /// The pincode expired and the player needs to change it.
+    #[numeric_value(3)]
+    Expired,
+    /// The player needs to set a new pincode.
+    #[numeric_value(4)]
+    New,
+    /// The new pincode is not allowed, for example because the digits are
+    /// sequential.
+    #[numeric_value(5)]
+    Illegal,
+    /// Only used by Korean servers.
+    #[numeric_value(6)]
+    KoreanSocialSecurityNumber,
+    /// The pincode was entered correctly.
+    #[numeric_value(7)]
+    Passed,
+    /// The pincode was entered incorrectly.
+    #[numeric_value(8)]
+    Wrong,
+}
+
+/// Sent by the character server when the account has a pincode (second
+/// password). Either asks the player to enter, set, or change the pincode, or
+/// reports the result of a [`CheckPincodePacket`], [`SetPincodePacket`] or
+/// [`ChangePincodePacket`].
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x08B9)]
 pub struct LoginPincodePacket {
+    /// Seed used to encode the pincode before sending it to the server.
     pub pincode_seed: u32,
     pub account_id: AccountId,
-    pub state: u16,
+    pub state: PincodeState,
+}
+
+/// Sent by the client to the character server to check the pincode entered
+/// by the player. The digits are encoded with the seed of the last
+/// [`LoginPincodePacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x08B8)]
+pub struct CheckPincodePacket {
+    pub account_id: AccountId,
+    pub pincode: [u8; 4],
+}
+
+/// Sent by the client to the character server to set the pincode of an account
+/// that doesn't have one yet. The digits are encoded with the seed of the
+/// last [`LoginPincodePacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x08BA)]
+pub struct SetPincodePacket {
+    pub account_id: AccountId,
+    pub pincode: [u8; 4],
+}
+
+/// Sent by the client to the character server to change the pincode of the
+/// account. The digits of both pincodes are encoded with the seed of the last
+/// [`LoginPincodePacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x08BE)]
+pub struct ChangePincodePacket {
+    pub account_id: AccountId,
+    pub old_pincode: [u8; 4],
+    pub new_pincode: [u8; 4],
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
@@ -603,6 +708,110 @@ impl rust_state::VecItem for CharacterInformation {
     }
 }
 
+/// Character information used by clients before 2020-10-07. Health points
+/// and spell points are smaller than in [`CharacterInformation`].
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct LegacyCharacterInformation {
+    pub character_id: CharacterId,
+    pub experience: i64,
+    pub money: i32,
+    pub job_experience: i64,
+    pub job_level: i32,
+    pub body_state: i32,
+    pub health_state: i32,
+    pub effect_state: i32,
+    pub virtue: i32,
+    pub honor: i32,
+    pub stat_points: i16,
+    pub health_points: i32,
+    pub maximum_health_points: i32,
+    pub spell_points: i16,
+    pub maximum_spell_points: i16,
+    pub movement_speed: i16,
+    pub job: i16,
+    pub head: i16,
+    pub body: i16,
+    pub weapon: i16,
+    pub base_level: i16,
+    pub sp_point: i16,
+    pub accessory: i16,
+    pub shield: i16,
+    pub accessory2: i16,
+    pub accessory3: i16,
+    pub head_palette: i16,
+    pub body_palette: i16,
+    #[length(24)]
+    pub name: String,
+    pub strength: u8,
+    pub agility: u8,
+    pub vitality: u8,
+    pub intelligence: u8,
+    pub dexterity: u8,
+    pub luck: u8,
+    pub character_number: u8,
+    pub hair_color: u8,
+    pub b_is_changed_char: i16,
+    #[length(16)]
+    pub map_name: String,
+    pub deletion_reverse_date: i32,
+    pub robe_palette: i32,
+    pub character_slot_change_count: i32,
+    pub character_name_change_count: i32,
+    pub sex: Sex,
+}
+
+impl From<LegacyCharacterInformation> for CharacterInformation {
+    fn from(information: LegacyCharacterInformation) -> Self {
+        Self {
+            character_id: information.character_id,
+            experience: information.experience,
+            money: information.money,
+            job_experience: information.job_experience,
+            job_level: information.job_level,
+            body_state: information.body_state,
+            health_state: information.health_state,
+            effect_state: information.effect_state,
+            virtue: information.virtue,
+            honor: information.honor,
+            stat_points: information.stat_points,
+            health_points: information.health_points.into(),
+            maximum_health_points: information.maximum_health_points.into(),
+            spell_points: information.spell_points.into(),
+            maximum_spell_points: information.maximum_spell_points.into(),
+            movement_speed: information.movement_speed,
+            job: information.job,
+            head: information.head,
+            body: information.body,
+            weapon: information.weapon,
+            base_level: information.base_level,
+            sp_point: information.sp_point,
+            accessory: information.accessory,
+            shield: information.shield,
+            accessory2: information.accessory2,
+            accessory3: information.accessory3,
+            head_palette: information.head_palette,
+            body_palette: information.body_palette,
+            name: information.name,
+            strength: information.strength,
+            agility: information.agility,
+            vitality: information.vitality,
+            intelligence: information.intelligence,
+            dexterity: information.dexterity,
+            luck: information.luck,
+            character_number: information.character_number,
+            hair_color: information.hair_color,
+            b_is_changed_char: information.b_is_changed_char,
+            map_name: information.map_name,
+            deletion_reverse_date: information.deletion_reverse_date,
+            robe_palette: information.robe_palette,
+            character_slot_change_count: information.character_slot_change_count,
+            character_name_change_count: information.character_name_change_count,
+            sex: information.sex,
+        }
+    }
+}
+
 /// Sent by the character server as a response to [CreateCharacterPacket]
 /// succeeding. Provides all character information of the newly created
 /// character.
@@ -613,6 +822,15 @@ pub struct CreateCharacterSuccessPacket {
     pub character_information: CharacterInformation,
 }
 
+/// Same as [`CreateCharacterSuccessPacket`] but used by clients before
+/// 2020-10-07.
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x006D)]
+pub struct LegacyCreateCharacterSuccessPacket {
+    pub character_information: LegacyCharacterInformation,
+}
+
 /// Sent by the client to the character server.
 /// Requests a list of every character associated with the account.
 #[derive(Debug, Clone, Default, Packet, ClientPacket, CharacterServer)]
@@ -631,6 +849,17 @@ pub struct RequestCharacterListSuccessPacket {
     pub character_information: Vec<CharacterInformation>,
 }
 
+/// Same as [`RequestCharacterListSuccessPacket`] but used by clients before
+/// 2020-10-07.
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x099D)]
+#[variable_length]
+pub struct LegacyRequestCharacterListSuccessPacket {
+    #[repeating_remaining]
+    pub character_information: Vec<LegacyCharacterInformation>,
+}
+
 /// Sent by the map server to the client.
 #[derive(Debug, Clone, Default, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
@@ -804,6 +1033,8 @@ pub struct RequestEntityDetailsSuccessPacket {
     pub title: String,
 }
 
+/// Sent by the map server when logging in and whenever the player receives a
+/// new mail.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x09E7)]
@@ -1016,6 +1247,59 @@ pub struct EquippableItemListPacket {
     pub item_information: Vec<EquippableItemInformation>,
 }
 
+/// Equippable item information used by clients before 2020-07-23. The
+/// refinement level is sent before the cards and there is no enchantment
+/// level.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct LegacyEquippableItemInformation {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub equip_position: EquipPosition,
+    pub equipped_position: EquipPosition,
+    pub refinement_level: u8,
+    pub slot: [u32; 4], // card ?
+    pub hire_expiration_date: u32,
+    pub bind_on_equip_type: u16,
+    pub w_item_sprite_number: u16,
+    pub option_count: u8,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub flags: EquippableItemFlags,
+}
+
+impl From<LegacyEquippableItemInformation> for EquippableItemInformation {
+    fn from(information: LegacyEquippableItemInformation) -> Self {
+        Self {
+            index: information.index,
+            item_id: information.item_id,
+            item_type: information.item_type,
+            equip_position: information.equip_position,
+            equipped_position: information.equipped_position,
+            slot: information.slot,
+            hire_expiration_date: information.hire_expiration_date,
+            bind_on_equip_type: information.bind_on_equip_type,
+            w_item_sprite_number: information.w_item_sprite_number,
+            option_count: information.option_count,
+            option_data: information.option_data,
+            refinement_level: information.refinement_level,
+            enchantment_level: 0,
+            flags: information.flags,
+        }
+    }
+}
+
+/// Same as [`EquippableItemListPacket`] but used by clients before 2020-07-23.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0B0A)]
+#[variable_length]
+pub struct LegacyEquippableItemListPacket {
+    pub inventory_type: u8,
+    #[repeating_remaining]
+    pub item_information: Vec<LegacyEquippableItemInformation>,
+}
+
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct EquippableSwitchItemInformation {
@@ -1103,6 +1387,16 @@ pub struct DisplayEmotionPacket {
     pub emotion: u8,
 }
 
+/// Sent by the client to the map server when the player uses an emotion.
+/// The server will broadcast it to all nearby players using a
+/// [`DisplayEmotionPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00BF)]
+pub struct RequestEmotionPacket {
+    pub emotion: u8,
+}
+
 /// Every value that can be set from the server through [UpdateStatPacket],
 /// [UpdateStatPacket1], [UpdateStatPacket2], and [UpdateStatPacket3].
 /// All UpdateStatPackets do the same, they just have different sizes
@@ -3413,6 +3707,107 @@ pub struct ItemPickupPacket {
     pub enchantment_level: u8,
 }
 
+/// Same as [`ItemPickupPacket`] but used by clients before 2020-07-23.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A37)]
+pub struct LegacyItemPickupPacket {
+    pub index: InventoryIndex,
+    pub count: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub equip_position: EquipPosition,
+    pub item_type: u8,
+    pub result: ItemPickupResult,
+    pub hire_expiration_date: u32,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub favorite: u8,
+    pub look: u16,
+}
+
+impl From<LegacyItemPickupPacket> for ItemPickupPacket {
+    fn from(packet: LegacyItemPickupPacket) -> Self {
+        Self {
+            index: packet.index,
+            count: packet.count,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            equip_position: packet.equip_position,
+            item_type: packet.item_type,
+            result: packet.result,
+            hire_expiration_date: packet.hire_expiration_date,
+            bind_on_equip_type: packet.bind_on_equip_type,
+            option_data: packet.option_data,
+            favorite: packet.favorite,
+            look: packet.look,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server when an item on the ground comes into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x009D)]
+pub struct ItemAppearedPacket {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub count: u16,
+    pub sub_x: u8,
+    pub sub_y: u8,
+}
+
+/// Sent by the map server when an item is dropped on the ground, either by a
+/// player or by a monster.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0ADD)]
+pub struct ItemDroppedPacket {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub item_type: u16,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub sub_x: u8,
+    pub sub_y: u8,
+    pub count: u16,
+    pub show_drop_effect: u8,
+    pub drop_effect_mode: u16,
+}
+
+/// Sent by the map server when an item on the ground is picked up or leaves
+/// the view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00A1)]
+pub struct ItemDisappearedPacket {
+    pub ground_item_id: GroundItemId,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0362)]
+pub struct RequestPickUpItemPacket {
+    pub ground_item_id: GroundItemId,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0363)]
+pub struct RequestDropItemPacket {
+    pub index: InventoryIndex,
+    pub count: u16,
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[numeric_type(u16)]
@@ -4107,85 +4502,1038 @@ pub struct PartyInvitePacket {
     pub party_name: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-pub struct ReputationEntry {
-    pub reputation_type: u64,
-    pub points: i64,
+pub enum PartyItemPickupRule {
+    EachTake,
+    PartyShare,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-#[header(0x0B8D)]
-#[variable_length]
-pub struct ReputationPacket {
-    pub success: u8,
-    #[repeating_remaining]
-    pub entries: Vec<ReputationEntry>,
+pub enum PartyItemDivisionRule {
+    EachTake,
+    PartyShare,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-pub struct Aliance {
-    #[length(24)]
-    pub name: String,
+#[numeric_type(u32)]
+pub enum PartyExperienceRule {
+    EachTake,
+    EvenShare,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+/// Sent by the client to the map server when the player wants to create a new
+/// party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-pub struct Antagonist {
+#[header(0x01E8)]
+pub struct CreatePartyPacket {
     #[length(24)]
-    pub name: String,
+    pub party_name: String,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_division_rule: PartyItemDivisionRule,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-#[header(0x098A)]
-#[variable_length]
-pub struct ClanInfoPacket {
-    pub clan_id: u32,
-    #[length(24)]
-    pub clan_name: String,
-    #[length(24)]
-    pub clan_master: String,
-    #[length(16)]
-    pub clan_map: String,
-    #[new_derive]
-    pub aliance_count: u8,
-    #[new_derive]
-    pub antagonist_count: u8,
-    #[repeating(aliance_count)]
-    pub aliances: Vec<Aliance>,
-    #[repeating(antagonist_count)]
-    pub antagonists: Vec<Antagonist>,
+pub enum CreatePartyResult {
+    Success,
+    NameAlreadyExists,
+    AlreadyInParty,
+    NotAllowedOnMap,
 }
 
+/// Sent by the map server as a response to [CreatePartyPacket].
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-#[header(0x0988)]
-pub struct ClanOnlineCountPacket {
-    pub online_members: u16,
-    pub maximum_members: u16,
+#[header(0x00FA)]
+pub struct CreatePartyResultPacket {
+    pub result: CreatePartyResult,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+/// Sent by the client to the map server to invite a character to the party of
+/// the player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-#[header(0x0192)]
-pub struct ChangeMapCellPacket {
-    pub position: TilePosition,
-    pub cell_type: u16,
-    #[length(16)]
-    pub map_name: String,
+#[header(0x02C4)]
+pub struct InviteToPartyPacket {
+    #[length(24)]
+    pub character_name: String,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-pub struct MarketItemInformation {
-    pub name_id: u32,
-    pub item_type: u8,
-    pub price: Price,
-    pub quantity: u32,
-    pub weight: u16,
+#[numeric_type(u32)]
+pub enum PartyInviteResult {
+    AlreadyInParty,
+    Rejected,
+    Accepted,
+    PartyFull,
+    SameAccount,
+    InvitationBlocked,
+    #[numeric_value(7)]
+    NotOnline,
+    #[numeric_value(8)]
+    MapRestricted,
+    #[numeric_value(9)]
+    LevelRestricted,
+}
+
+/// Sent by the map server as a response to [InviteToPartyPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x02C5)]
+pub struct PartyInviteResultPacket {
+    #[length(24)]
+    pub character_name: String,
+    pub result: PartyInviteResult,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum PartyInviteResponse {
+    Reject,
+    Accept,
+}
+
+/// Sent by the client to the map server as a response to [PartyInvitePacket].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x02C7)]
+pub struct PartyInviteResponsePacket {
+    pub party_id: PartyId,
+    pub response: PartyInviteResponse,
+}
+
+/// Sent by the client to the map server when the player wants to leave their
+/// party.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0100)]
+pub struct LeavePartyPacket {}
+
+/// Sent by the client to the map server when the party leader wants to remove
+/// a member from the party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0103)]
+pub struct ExpelPartyMemberPacket {
+    pub account_id: AccountId,
+    #[length(24)]
+    pub character_name: String,
+}
+
+/// Sent by the client to the map server when the party leader wants to pass
+/// leadership to another member.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x07DA)]
+pub struct ChangePartyLeaderPacket {
+    pub account_id: AccountId,
+}
+
+/// Sent by the client to the map server when the party leader wants to change
+/// the party rules.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x07D7)]
+pub struct ChangePartyOptionsPacket {
+    pub experience_rule: PartyExperienceRule,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_division_rule: PartyItemDivisionRule,
+}
+
+/// Sent by the map server when the party rules changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x07D8)]
+pub struct PartyOptionsPacket {
+    pub experience_rule: PartyExperienceRule,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_division_rule: PartyItemDivisionRule,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum PartyMemberState {
+    Online,
+    Offline,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PartyMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub character_name: String,
+    #[length(16)]
+    pub map_name: String,
+    /// 0 for the party leader, 1 for everyone else.
+    pub role: u8,
+    pub state: PartyMemberState,
+    pub job: u16,
+    pub base_level: u16,
+}
+
+/// Sent by the map server when the player joins a party or logs in while
+/// being in a party. Contains all party members.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A44)]
+#[variable_length]
+pub struct PartyInformationPacket {
+    #[length(24)]
+    pub party_name: String,
+    // The member list is followed by the party options, so we can't use
+    // `repeating_remaining` here.
+    #[repeating_expr((__packet_length as usize).saturating_sub(2 + 2 + 24 + 6) / 54)]
+    pub members: Vec<PartyMemberInformation>,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_division_rule: PartyItemDivisionRule,
+    pub unknown: u32,
+}
+
+/// Sent by the map server when a member joins the party or a member changes
+/// their map.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A43)]
+pub struct PartyMemberInformationPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    /// 0 for the party leader, 1 for everyone else.
+    pub role: u32,
+    pub job: u16,
+    pub base_level: u16,
+    pub position: TilePosition,
+    pub state: PartyMemberState,
+    #[length(24)]
+    pub party_name: String,
+    #[length(24)]
+    pub character_name: String,
+    #[length(16)]
+    pub map_name: String,
+    pub item_pickup_rule: PartyItemPickupRule,
+    pub item_division_rule: PartyItemDivisionRule,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum LeavePartyReason {
+    Left,
+    Expelled,
+    LeaveNotAllowed,
+    ExpelNotAllowed,
+}
+
+/// Sent by the map server when a member left the party. If the member is the
+/// player, the party was left.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0105)]
+pub struct PartyMemberLeftPacket {
+    pub account_id: AccountId,
+    #[length(24)]
+    pub character_name: String,
+    pub reason: LeavePartyReason,
+}
+
+/// Sent by the map server to update the health points of a party member.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x080E)]
+pub struct PartyMemberHealthPointsPacket {
+    pub account_id: AccountId,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+}
+
+/// Sent by the map server to update the position of a party member on the
+/// minimap.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0107)]
+pub struct PartyMemberPositionPacket {
+    pub account_id: AccountId,
+    pub position: TilePosition,
+}
+
+/// Sent by the map server when the party leader changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x07FC)]
+pub struct PartyLeaderChangedPacket {
+    pub old_leader_account_id: AccountId,
+    pub new_leader_account_id: AccountId,
+}
+
+/// Sent by the client to the map server to send a message to the party chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0108)]
+#[variable_length]
+pub struct PartyMessagePacket {
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server when a member sends a message to the party chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0109)]
+#[variable_length]
+pub struct PartyChatMessagePacket {
+    pub account_id: AccountId,
+    #[length_remaining]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct ReputationEntry {
+    pub reputation_type: u64,
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0B8D)]
+#[variable_length]
+pub struct ReputationPacket {
+    pub success: u8,
+    #[repeating_remaining]
+    pub entries: Vec<ReputationEntry>,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct Aliance {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct Antagonist {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x098A)]
+#[variable_length]
+pub struct ClanInfoPacket {
+    pub clan_id: u32,
+    #[length(24)]
+    pub clan_name: String,
+    #[length(24)]
+    pub clan_master: String,
+    #[length(16)]
+    pub clan_map: String,
+    #[new_derive]
+    pub aliance_count: u8,
+    #[new_derive]
+    pub antagonist_count: u8,
+    #[repeating(aliance_count)]
+    pub aliances: Vec<Aliance>,
+    #[repeating(antagonist_count)]
+    pub antagonists: Vec<Antagonist>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0988)]
+pub struct ClanOnlineCountPacket {
+    pub online_members: u16,
+    pub maximum_members: u16,
+}
+
+/// Sent by the map server when the player logs in while being in a guild or
+/// joins a new guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x016C)]
+pub struct GuildMembershipPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    pub mode: u32,
+    pub is_master: u8,
+    pub inter_server_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u32)]
+pub enum GuildInformationRequest {
+    GuildInformation,
+    MemberList,
+    Positions,
+    Skills,
+    ExpelList,
+}
+
+/// Sent by the client to the map server to request information about the
+/// guild of the player. The server responds with a different packet depending
+/// on the request.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x014F)]
+pub struct RequestGuildInformationPacket {
+    pub request: GuildInformationRequest,
+}
+
+/// Sent by the map server as a response to [RequestGuildInformationPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A84)]
+pub struct GuildInformationPacket {
+    pub guild_id: GuildId,
+    pub level: u32,
+    pub online_members: u32,
+    pub maximum_members: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub maximum_experience: u32,
+    pub points: u32,
+    pub honor: u32,
+    pub virtue: u32,
+    pub emblem_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+    #[length(16)]
+    pub castle_map_name: String,
+    pub zeny: u32,
+    pub master_character_id: CharacterId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u32)]
+pub enum GuildMemberState {
+    Offline,
+    Online,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub head: u16,
+    pub head_palette: u16,
+    pub sex: u16,
+    pub job: u16,
+    pub base_level: u16,
+    pub contributed_experience: u32,
+    pub state: GuildMemberState,
+    pub position_id: u32,
+    pub last_login: u32,
+}
+
+/// Sent by the map server as a response to [RequestGuildInformationPacket].
+/// Member names are not included and need to be requested with
+/// [RequestCharacterNamePacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AA5)]
+#[variable_length]
+pub struct GuildMemberListPacket {
+    #[repeating_remaining]
+    pub members: Vec<GuildMemberInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildPositionInformation {
+    pub position_id: u32,
+    pub mode: u32,
+    pub ranking: u32,
+    pub experience_tax: u32,
+}
+
+/// Sent by the map server as a response to [RequestGuildInformationPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0160)]
+#[variable_length]
+pub struct GuildPositionInformationPacket {
+    #[repeating_remaining]
+    pub positions: Vec<GuildPositionInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildPositionName {
+    pub position_id: u32,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server as a response to [RequestGuildInformationPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0166)]
+#[variable_length]
+pub struct GuildPositionNamesPacket {
+    #[repeating_remaining]
+    pub positions: Vec<GuildPositionName>,
+}
+
+/// Sent by the client to the map server to request the name of a character
+/// by their character id.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0369)]
+pub struct RequestCharacterNamePacket {
+    pub character_id: CharacterId,
+}
+
+/// Sent by the map server as a response to [RequestCharacterNamePacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AF7)]
+pub struct CharacterNamePacket {
+    pub flag: u16,
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server when the guild notice changed or when the player
+/// logs in while being in a guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x016F)]
+pub struct GuildNoticePacket {
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
+}
+
+/// Sent by the client to the map server when the guild master wants to change
+/// the guild notice.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x016E)]
+pub struct ChangeGuildNoticePacket {
+    pub guild_id: GuildId,
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
+}
+
+/// Sent by the client to the map server to request the emblem of a guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0151)]
+pub struct RequestGuildEmblemPacket {
+    pub guild_id: GuildId,
+}
+
+/// Sent by the map server as a response to [RequestGuildEmblemPacket]. The
+/// emblem is a zlib compressed bitmap.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0152)]
+#[variable_length]
+pub struct GuildEmblemPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    #[repeating_remaining]
+    pub emblem_data: Vec<u8>,
+}
+
+/// Sent by the client to the map server when the player wants to create a new
+/// guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0165)]
+pub struct CreateGuildPacket {
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum CreateGuildResult {
+    Success,
+    AlreadyInGuild,
+    NameAlreadyExists,
+    EmperiumRequired,
+}
+
+/// Sent by the map server as a response to [CreateGuildPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0167)]
+pub struct CreateGuildResultPacket {
+    pub result: CreateGuildResult,
+}
+
+/// Sent by the client to the map server to invite a character to the guild of
+/// the player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0916)]
+pub struct InviteToGuildPacket {
+    #[length(24)]
+    pub character_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum GuildInviteResult {
+    AlreadyInGuild,
+    Rejected,
+    Accepted,
+    GuildFull,
+}
+
+/// Sent by the map server as a response to [InviteToGuildPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0169)]
+pub struct GuildInviteResultPacket {
+    pub result: GuildInviteResult,
+}
+
+/// Sent by the map server when another player invites the player to join
+/// their guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x016A)]
+pub struct GuildInvitePacket {
+    pub guild_id: GuildId,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u32)]
+pub enum GuildInviteResponse {
+    Reject,
+    Accept,
+}
+
+/// Sent by the client to the map server as a response to [GuildInvitePacket].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x016B)]
+pub struct GuildInviteResponsePacket {
+    pub guild_id: GuildId,
+    pub response: GuildInviteResponse,
+}
+
+/// Sent by the client to the map server when the player wants to leave their
+/// guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0159)]
+pub struct LeaveGuildPacket {
+    pub guild_id: GuildId,
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    #[length(40)]
+    pub reason: String,
+}
+
+/// Sent by the map server when a member left the guild. If the member is the
+/// player, the guild was left.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x015A)]
+pub struct GuildMemberLeftPacket {
+    #[length(24)]
+    pub character_name: String,
+    #[length(40)]
+    pub reason: String,
+}
+
+/// Sent by the client to the map server when the guild master wants to remove
+/// a member from the guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x015B)]
+pub struct ExpelGuildMemberPacket {
+    pub guild_id: GuildId,
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    #[length(40)]
+    pub reason: String,
+}
+
+/// Sent by the map server when a member was expelled from the guild. If the
+/// member is the player, the player is no longer in a guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x015C)]
+pub struct GuildMemberExpelledPacket {
+    #[length(24)]
+    pub character_name: String,
+    #[length(40)]
+    pub reason: String,
+    #[length(24)]
+    pub account_name: String,
+}
+
+/// Sent by the map server when a guild member logs in or out.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x016D)]
+pub struct GuildMemberStatePacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub state: GuildMemberState,
+}
+
+/// Sent by the client to the map server to send a message to the guild chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x017E)]
+#[variable_length]
+pub struct GuildMessagePacket {
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server when a member sends a message to the guild chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x017F)]
+#[variable_length]
+pub struct GuildChatMessagePacket {
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the client to the map server to request a trade with another
+/// player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E4)]
+pub struct RequestTradePacket {
+    pub account_id: AccountId,
+}
+
+/// Sent by the map server when another player wants to trade with the
+/// player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01F4)]
+pub struct TradeRequestPacket {
+    #[length(24)]
+    pub character_name: String,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeRequestResponse {
+    #[numeric_value(3)]
+    Accept,
+    #[numeric_value(4)]
+    Reject,
+}
+
+/// Sent by the client to the map server as a response to
+/// [TradeRequestPacket].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E6)]
+pub struct TradeRequestResponsePacket {
+    pub response: TradeRequestResponse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeRequestResult {
+    TooFarAway,
+    CharacterDoesNotExist,
+    Failed,
+    Accepted,
+    Cancelled,
+    Busy,
+}
+
+/// Sent by the map server to both players once a trade request was answered.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01F5)]
+pub struct TradeRequestResultPacket {
+    pub result: TradeRequestResult,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+/// Sent by the client to the map server to add an item or zeny to the
+/// current trade.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E8)]
+pub struct AddTradeItemPacket {
+    /// Raw inventory index (actual index + 2) of the item. Zeny is added by
+    /// using an index of 0.
+    pub index: u16,
+    pub amount: u32,
+}
+
+/// Sent by the map server when the trade partner added an item or zeny to
+/// the current trade.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0B42)]
+pub struct TradeItemAddedPacket {
+    /// Zeny are sent with an item id of 0.
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Same as [`TradeItemAddedPacket`] but used by clients before 2020-07-23.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A09)]
+pub struct LegacyTradeItemAddedPacket {
+    /// Zeny are sent with an item id of 0.
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+}
+
+impl From<LegacyTradeItemAddedPacket> for TradeItemAddedPacket {
+    fn from(packet: LegacyTradeItemAddedPacket) -> Self {
+        Self {
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            amount: packet.amount,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum AddTradeItemResult {
+    Success,
+    Overweight,
+    Failed,
+    TooManyItems,
+    TooManyOfSameItem,
+}
+
+/// Sent by the map server as a response to [AddTradeItemPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EA)]
+pub struct AddTradeItemResultPacket {
+    pub index: InventoryIndex,
+    pub result: AddTradeItemResult,
+}
+
+/// Sent by the client to the map server to lock the items of the current
+/// trade.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EB)]
+pub struct LockTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeParticipant {
+    Player,
+    Partner,
+}
+
+/// Sent by the map server when either side of the current trade locked
+/// their items.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EC)]
+pub struct TradeLockedPacket {
+    pub participant: TradeParticipant,
+}
+
+/// Sent by the client to the map server to cancel the current trade.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00ED)]
+pub struct CancelTradePacket {}
+
+/// Sent by the map server when the current trade was cancelled by either
+/// side.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EE)]
+pub struct TradeCancelledPacket {}
+
+/// Sent by the client to the map server to confirm the current trade once
+/// both sides are locked.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EF)]
+pub struct CommitTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeCompletedResult {
+    Success,
+    Failed,
+}
+
+/// Sent by the map server once both sides confirmed the current trade.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F0)]
+pub struct TradeCompletedPacket {
+    pub result: TradeCompletedResult,
+}
+
+/// Sent by the map server when the storage is opened, after the storage items
+/// were sent.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F2)]
+pub struct StorageItemCountPacket {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+}
+
+/// Sent by the client to the map server to move an item from the inventory
+/// into the storage.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0364)]
+pub struct MoveItemToStoragePacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to move an item from the storage
+/// into the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0365)]
+pub struct MoveItemFromStoragePacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+/// Sent by the map server when an item was added to the storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0B44)]
+pub struct StorageItemAddedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Same as [`StorageItemAddedPacket`] but used by clients before 2020-07-23.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A0A)]
+pub struct LegacyStorageItemAddedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+}
+
+impl From<LegacyStorageItemAddedPacket> for StorageItemAddedPacket {
+    fn from(packet: LegacyStorageItemAddedPacket) -> Self {
+        Self {
+            index: packet.index,
+            amount: packet.amount,
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server when an item was removed from the storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F6)]
+pub struct StorageItemRemovedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to close the storage.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F7)]
+pub struct CloseStoragePacket {}
+
+/// Sent by the map server when the storage was closed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F8)]
+pub struct StorageClosedPacket {}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0192)]
+pub struct ChangeMapCellPacket {
+    pub position: TilePosition,
+    pub cell_type: u16,
+    #[length(16)]
+    pub map_name: String,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MarketItemInformation {
+    pub name_id: u32,
+    pub item_type: u8,
+    pub price: Price,
+    pub quantity: u32,
+    pub weight: u16,
     pub location: u32,
 }
 
@@ -4375,3 +5723,457 @@ pub enum SellItemsResult {
 pub struct SellItemsResultPacket {
     pub result: SellItemsResult,
 }
+
+/// Mailbox of a mail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum MailOpenType {
+    /// Mail sent to the character.
+    #[default]
+    Character,
+    /// Mail sent to the account, shared between all characters.
+    Account,
+    /// Mail that was returned to the sender.
+    Returned,
+    Unset,
+}
+
+/// Sent by the client to the map server to open the mailbox.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AC0)]
+pub struct OpenMailboxPacket {
+    pub open_type: MailOpenType,
+    /// Id of the newest mail known to the client. Using 0 will request all
+    /// mails.
+    pub mail_id: MailId,
+    #[new_default]
+    pub unused: [u8; 15],
+}
+
+/// Sent by the client to the map server to request mails that arrived after
+/// the mailbox was opened.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AC1)]
+pub struct RefreshMailboxPacket {
+    pub open_type: MailOpenType,
+    /// Id of the newest mail known to the client. Using 0 will request all
+    /// mails.
+    pub mail_id: MailId,
+    #[new_default]
+    pub unused: [u8; 15],
+}
+
+/// Sent by the client to the map server to close the mailbox.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09E9)]
+pub struct CloseMailboxPacket {}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailListEntry {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub is_read: u8,
+    /// Bit flags of the mail content. `0x02` marks attached zeny and `0x04`
+    /// attached items.
+    pub mail_type: u8,
+    #[length(24)]
+    pub sender_name: String,
+    /// Seconds since the mail was sent.
+    pub registration_time: u32,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+    pub title_length: u16,
+    #[length(title_length)]
+    pub title: String,
+}
+
+/// Sent by the map server as a response to [OpenMailboxPacket] and
+/// [RefreshMailboxPacket]. Big mailboxes are split across multiple packets.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AC2)]
+#[variable_length]
+pub struct MailListPacket {
+    /// Set on the last packet of the list.
+    pub is_end: u8,
+    #[length_remaining]
+    pub mails: Vec<MailListEntry>,
+}
+
+/// Sent by the client to the map server to read the content of a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09EA)]
+pub struct ReadMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailItem {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub location: u32,
+    pub item_type: u8,
+    pub view_sprite: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5],
+    pub enchantment_level: u8,
+}
+
+/// Same as [`MailItem`] but used by clients before 2020-09-16.
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct LegacyMailItem {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub location: u32,
+    pub item_type: u8,
+    pub view_sprite: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5],
+}
+
+impl From<LegacyMailItem> for MailItem {
+    fn from(item: LegacyMailItem) -> Self {
+        Self {
+            amount: item.amount,
+            item_id: item.item_id,
+            is_identified: item.is_identified,
+            is_broken: item.is_broken,
+            refinement_level: item.refinement_level,
+            cards: item.cards,
+            location: item.location,
+            item_type: item.item_type,
+            view_sprite: item.view_sprite,
+            bind_on_equip_type: item.bind_on_equip_type,
+            option_data: item.option_data,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server as a response to [ReadMailPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct MailContentPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItem>,
+}
+
+/// Same as [`MailContentPacket`] but used by clients before 2020-09-16.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct LegacyMailContentPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<LegacyMailItem>,
+}
+
+impl From<LegacyMailContentPacket> for MailContentPacket {
+    fn from(packet: LegacyMailContentPacket) -> Self {
+        Self {
+            open_type: packet.open_type,
+            mail_id: packet.mail_id,
+            text_length: packet.text_length,
+            zeny: packet.zeny,
+            item_count: packet.item_count,
+            text: packet.text,
+            items: packet.items.into_iter().map(MailItem::from).collect(),
+        }
+    }
+}
+
+/// Sent by the client to the map server to delete a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09F5)]
+pub struct DeleteMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the map server once a mail was deleted.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09F6)]
+pub struct MailDeletedPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the client to the map server to move the zeny attached to a mail
+/// into the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09F1)]
+pub struct RetrieveMailZenyPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum RetrieveMailAttachmentResult {
+    Success,
+    Failed,
+    Overweight,
+}
+
+/// Sent by the map server as a response to [RetrieveMailZenyPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09F2)]
+pub struct RetrieveMailZenyResultPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub result: RetrieveMailAttachmentResult,
+}
+
+/// Sent by the client to the map server to move the items attached to a mail
+/// into the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09F3)]
+pub struct RetrieveMailItemsPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+}
+
+/// Sent by the map server as a response to [RetrieveMailItemsPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09F4)]
+pub struct RetrieveMailItemsResultPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub result: RetrieveMailAttachmentResult,
+}
+
+/// Sent by the client to the map server to start writing a mail. Items can
+/// only be attached after the server accepted this.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A08)]
+pub struct OpenWriteMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the map server as a response to [OpenWriteMailPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A12)]
+pub struct OpenWriteMailResultPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    pub success: u8,
+}
+
+/// Sent by the client to the map server to stop writing a mail. Any attached
+/// items are returned to the inventory.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A03)]
+pub struct CancelWriteMailPacket {}
+
+/// Sent by the client to the map server to attach an item to the mail that is
+/// currently being written.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A04)]
+pub struct AddMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum AddMailItemResult {
+    Success,
+    Overweight,
+    Failed,
+    TooManyItems,
+    NotTradable,
+}
+
+/// Sent by the map server as a response to [AddMailItemPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A05)]
+pub struct AddMailItemResultPacket {
+    pub result: AddMailItemResult,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    /// Total weight of all attached items.
+    pub weight: u16,
+    pub favorite: u8,
+    pub location: u32,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Same as [`AddMailItemResultPacket`] but used by clients before 2020-09-16.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A05)]
+pub struct LegacyAddMailItemResultPacket {
+    pub result: AddMailItemResult,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5],
+    /// Total weight of all attached items.
+    pub weight: u16,
+    pub favorite: u8,
+    pub location: u32,
+}
+
+impl From<LegacyAddMailItemResultPacket> for AddMailItemResultPacket {
+    fn from(packet: LegacyAddMailItemResultPacket) -> Self {
+        Self {
+            result: packet.result,
+            index: packet.index,
+            amount: packet.amount,
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            weight: packet.weight,
+            favorite: packet.favorite,
+            location: packet.location,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the client to the map server to detach an item from the mail that
+/// is currently being written.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A06)]
+pub struct RemoveMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+/// Sent by the map server as a response to [RemoveMailItemPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A07)]
+pub struct RemoveMailItemResultPacket {
+    /// 0 on success.
+    pub result: u8,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    /// Total weight of all attached items.
+    pub weight: u16,
+}
+
+/// Sent by the client to the map server to look up the receiver of a mail.
+/// The character id of the receiver is required to send the mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A13)]
+pub struct CheckMailReceiverPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the map server as a response to [CheckMailReceiverPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A51)]
+pub struct CheckMailReceiverResultPacket {
+    /// Will be 0 if the character does not exist.
+    pub character_id: CharacterId,
+    pub job: u16,
+    pub base_level: u16,
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the client to the map server to send the mail that is currently
+/// being written, including all attached items.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A6E)]
+#[variable_length]
+pub struct SendMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    #[length(24)]
+    pub sender_name: String,
+    pub zeny: u64,
+    /// Length of the title including the null terminator.
+    pub title_length: u16,
+    /// Length of the text including the null terminator.
+    pub text_length: u16,
+    pub receiver_character_id: CharacterId,
+    #[length(title_length)]
+    pub title: String,
+    #[length(text_length)]
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum SendMailResult {
+    Success,
+    Failed,
+    TooManyMails,
+    ReceiverNotFound,
+    InvalidContent,
+}
+
+/// Sent by the map server as a response to [SendMailPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09ED)]
+pub struct SendMailResultPacket {
+    pub result: SendMailResult,
+}